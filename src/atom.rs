@@ -0,0 +1,171 @@
+//! Atoms, as in Clojure;  a reference to a value, changed only by swapping in a new value for
+//! the old as a whole
+//!
+//! Everything goes through the methods here, rather than at our fields, so that what's behind
+//! them can change without the rest of the interpreter noticing;  say, to an Arc<Mutex<..>>,
+//! should Values come to be shared between threads.  swap is already written as a retry of
+//! compare_and_set, as it would have to be then
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocols;
+use crate::value::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Atom {
+    state: RefCell<Rc<Value>>,
+    /// Called on any new state before it's set;  it's only set if this gives back something
+    /// truthy
+    validator: Option<protocols::IFn>,
+    /// Called with their key, this atom, and its old and new state after each change, in the
+    /// order they were added
+    watches: RefCell<Vec<(Rc<Value>, protocols::IFn)>>,
+}
+impl Atom {
+    /// The atom holding state;  Err if validator rejects it
+    pub fn new(state: Rc<Value>, validator: Option<protocols::IFn>) -> Result<Atom, Rc<Value>> {
+        let atom = Atom {
+            state: RefCell::new(Rc::clone(&state)),
+            validator,
+            watches: RefCell::new(vec![]),
+        };
+        atom.validate(&state)?;
+        Ok(atom)
+    }
+    pub fn deref(&self) -> Rc<Value> {
+        Rc::clone(&self.state.borrow())
+    }
+    /// Sets our state to new, but only if it's still old itself (not just something equal to
+    /// it);  whether it was.  Err if new is invalid, or a watch throws
+    pub fn compare_and_set(
+        self: &Rc<Self>,
+        old: &Rc<Value>,
+        new: Rc<Value>,
+    ) -> Result<bool, Rc<Value>> {
+        self.validate(&new)?;
+        if !Rc::ptr_eq(&self.state.borrow(), old) {
+            return Ok(false);
+        }
+        self.state.replace(Rc::clone(&new));
+        self.notify_watches(old, &new)?;
+        Ok(true)
+    }
+    /// Sets our state to new, whatever it was;  the old state
+    pub fn reset(self: &Rc<Self>, new: Rc<Value>) -> Result<Rc<Value>, Rc<Value>> {
+        loop {
+            let old = self.deref();
+            if self.compare_and_set(&old, Rc::clone(&new))? {
+                return Ok(old);
+            }
+        }
+    }
+    /// Sets our state to what f gives back for it (and then args), retrying if our state was
+    /// changed while f was working it out;  the old state and the new
+    pub fn swap(
+        self: &Rc<Self>,
+        f: &dyn IFn,
+        args: &[Rc<Value>],
+    ) -> Result<(Rc<Value>, Rc<Value>), Rc<Value>> {
+        loop {
+            let old = self.deref();
+            let mut f_args = vec![Rc::clone(&old)];
+            f_args.extend(args.iter().cloned());
+            let new = Rc::new(f.invoke(f_args));
+            if let Some(interruption) = new.non_tail() {
+                return Err(interruption);
+            }
+            if self.compare_and_set(&old, Rc::clone(&new))? {
+                return Ok((old, new));
+            }
+        }
+    }
+    /// Adds f as a watch under key, replacing any watch already there
+    pub fn add_watch(&self, key: Rc<Value>, f: protocols::IFn) {
+        let mut watches = self.watches.borrow_mut();
+        match watches.iter_mut().find(|(watch_key, _)| *watch_key == key) {
+            Some(watch) => watch.1 = f,
+            None => watches.push((key, f)),
+        }
+    }
+    pub fn remove_watch(&self, key: &Rc<Value>) {
+        self.watches
+            .borrow_mut()
+            .retain(|(watch_key, _)| watch_key != key);
+    }
+
+    fn validate(&self, state: &Rc<Value>) -> Result<(), Rc<Value>> {
+        let validator = match &self.validator {
+            Some(validator) => validator,
+            None => return Ok(()),
+        };
+        let valid = Rc::new(validator.invoke(vec![Rc::clone(state)]));
+        if let Some(interruption) = valid.non_tail() {
+            return Err(interruption);
+        }
+        if !valid.is_truthy() {
+            return Err(Rc::new(error_message::condition(
+                ConditionKind::IllegalStateException,
+                "Invalid reference state",
+            )));
+        }
+        Ok(())
+    }
+    fn notify_watches(self: &Rc<Self>, old: &Rc<Value>, new: &Rc<Value>) -> Result<(), Rc<Value>> {
+        // A watch may add or remove watches, so we call those there were when we started
+        let watches = self.watches.borrow().clone();
+        for (key, f) in watches {
+            let watched = Rc::new(f.invoke(vec![
+                key,
+                Rc::new(Value::Atom(Rc::clone(self))),
+                Rc::clone(old),
+                Rc::clone(new),
+            ]));
+            if let Some(interruption) = watched.non_tail() {
+                return Err(interruption);
+            }
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#atom[{}]", self.deref().to_string_explicit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod atom_tests {
+        use crate::atom::Atom;
+        use crate::rust_core::AddFn;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn swap_and_reset() {
+            let atom = Rc::new(Atom::new(Rc::new(Value::I32(1)), None).unwrap());
+            let (old, new) = atom.swap(&AddFn {}, &[Rc::new(Value::I32(2))]).unwrap();
+            assert_eq!(
+                (Value::I32(1), Value::I32(3)),
+                ((*old).clone(), (*new).clone())
+            );
+            assert_eq!(Value::I32(3), *atom.reset(Rc::new(Value::I32(5))).unwrap());
+            assert_eq!(Value::I32(5), *atom.deref());
+        }
+
+        #[test]
+        fn compare_and_set_by_identity() {
+            let atom = Rc::new(Atom::new(Rc::new(Value::I32(1)), None).unwrap());
+            let equal = Value::I32(1).to_rc_value();
+            assert!(!atom
+                .compare_and_set(&equal, Rc::new(Value::I32(2)))
+                .unwrap());
+            let old = atom.deref();
+            assert!(atom.compare_and_set(&old, Rc::new(Value::I32(2))).unwrap());
+            assert_eq!(Value::I32(2), *atom.deref());
+        }
+    }
+}
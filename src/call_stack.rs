@@ -0,0 +1,118 @@
+//! The fns being run, and the forms that called them;  a snapshot of which is taken as the
+//! trace of every Condition created
+//!
+//! Only fns and macros defined in Clojure get a frame of their own;  the Rust builtins are
+//! leaves, so where one fails is already told by the position of the Condition itself
+use crate::source_position::SourcePosition;
+use crate::symbol::Symbol;
+use crate::value::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// One call in a trace
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// The name of the fn called, or fn if it had none
+    pub name: String,
+    /// Where the fn was called from
+    pub position: Option<SourcePosition>,
+}
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.position {
+            Some(position) => write!(f, "{} ({})", self.name, position),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// A fn being run;  positions are only worked out from its calling form when a trace is
+/// taken, as most calls never need them
+struct Call {
+    name: String,
+    form: Option<Rc<Value>>,
+}
+
+thread_local! {
+    /// The list forms being evaluated, innermost last
+    static FORMS: RefCell<Vec<Rc<Value>>> = const { RefCell::new(Vec::new()) };
+    /// The fns being run, innermost last
+    static CALLS: RefCell<Vec<Call>> = const { RefCell::new(Vec::new()) };
+}
+
+// These pop what was pushed onto our stacks when dropped, so we unwind them even on a panic
+struct FormPopper;
+impl Drop for FormPopper {
+    fn drop(&mut self) {
+        FORMS.with(|forms| forms.borrow_mut().pop());
+    }
+}
+struct CallPopper;
+impl Drop for CallPopper {
+    fn drop(&mut self) {
+        CALLS.with(|calls| calls.borrow_mut().pop());
+    }
+}
+
+/// Runs f as the evaluation of form, so that fns it calls know where they were called from
+pub fn in_form<T>(form: &Rc<Value>, f: impl FnOnce() -> T) -> T {
+    FORMS.with(|forms| forms.borrow_mut().push(Rc::clone(form)));
+    let _popper = FormPopper;
+    f()
+}
+
+/// Runs f as a call to the fn named name, from the innermost form being evaluated
+pub fn in_call<T>(name: Option<&Symbol>, f: impl FnOnce() -> T) -> T {
+    let call = Call {
+        name: name.map_or_else(|| String::from("fn"), |name| name.to_string()),
+        form: FORMS.with(|forms| forms.borrow().last().cloned()),
+    };
+    CALLS.with(|calls| calls.borrow_mut().push(call));
+    let _popper = CallPopper;
+    f()
+}
+
+/// The fns being run right now, innermost first
+pub fn trace() -> Vec<Frame> {
+    CALLS.with(|calls| {
+        calls
+            .borrow()
+            .iter()
+            .rev()
+            .map(|call| Frame {
+                name: call.name.clone(),
+                position: call.form.as_ref().and_then(|form| SourcePosition::of(form)),
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod call_stack_tests {
+        use crate::call_stack::{self, Frame};
+        use crate::symbol::Symbol;
+
+        #[test]
+        fn trace_is_innermost_first() {
+            let trace = call_stack::in_call(Some(&Symbol::intern("outer")), || {
+                call_stack::in_call(None, call_stack::trace)
+            });
+            assert_eq!(
+                vec![
+                    Frame {
+                        name: String::from("fn"),
+                        position: None
+                    },
+                    Frame {
+                        name: String::from("outer"),
+                        position: None
+                    },
+                ],
+                trace
+            );
+            assert_eq!(Vec::<Frame>::new(), call_stack::trace());
+        }
+    }
+}
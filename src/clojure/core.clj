@@ -1,15 +1,15 @@
-(def *flush-on-newline* true)
-(def *print-readably* true)
+(def ^:dynamic *flush-on-newline* true)
+(def ^:dynamic *print-readably* true)
+(def ^:dynamic *print-length* nil)
+(def ^:dynamic *print-level* nil)
 
 (defmacro when [test & body]
-  (list 'if test (concat (list 'do) body)))
+  `(if ~test (do ~@body)))
 
 (def list (fn [& ls] ls))
 
-(defmacro defn [name args & body]
-  (list (quote def) name 
-        (list (quote fn) args 
-              (concat (list (quote do)) body))))
+(defmacro defn [name & fdecl]
+  `(def ~name (fn ~name ~@fdecl)))
 
 
 ";; @TODO Make more like Clojure Proper's apply"
@@ -31,7 +31,7 @@
   (flush-stdout))
 
 (defn pr [& more]
-  (print-string (apply str more)))
+  (print-string (apply pr-str more)))
 
 (defn prn [& more]
   (apply pr more)
@@ -41,10 +41,14 @@
     nil))
 
 (defn print [& more]
-  (apply pr more))
+  (print-string (apply print-str more)))
 
 (defn println [& more]
-  (apply prn more))
+  (apply print more)
+  (newline)
+  (when *flush-on-newline*
+    (flush)
+    nil))
 
 (defn inc [x]
   (+ x 1))
@@ -53,26 +57,78 @@
   (- x 1))
 
 (defmacro time [expr]
-  (list (quote let) [(quote start) (quote (System/nanoTime)) (quote ret) expr]
-        (quote (do
-        (println (str "Elapsed time: " (/ (- (System/nanoTime) start) 1000000.0) " msecs"))
-        ret))))
+  `(let [start# (System/nanoTime) ret# ~expr]
+     (do
+       (println (str "Elapsed time: " (/ (- (System/nanoTime) start#) 1000000.0) " msecs"))
+       ret#)))
 
 (defn slurp [f & opts]
   (rust-slurp f opts))
 
+(defn inst? [x]
+  (= "clojure.lang.Inst" (type x)))
+
+(defn uuid? [x]
+  (= "clojure.lang.UUID" (type x)))
+
 "basic operations on collections"
 
 (defn rest [x]
   (more x))
 
 (defn next [x]
-  (let [result (rest x)]
-    (if (= '() result)
-      nil
-      result)))
+  (seq (rest x)))
+
+(defmacro lazy-seq [& body]
+  `(lazy-seq* (fn [] ~@body)))
 
 (defn ffirst [x]
   (first (first x)))
 (defmacro var [name]
   (var-fn* name))
+
+"dynamic vars"
+
+(defmacro binding [bindings & body]
+  `(binding*
+     ~(into {} (map (fn [[sym val]] [(list (quote var) sym) val]) (partition 2 bindings)))
+     ~@body))
+
+(defmacro set! [sym val]
+  `(var-set (var ~sym) ~val))
+
+(defmacro with-redefs [bindings & body]
+  `(with-redefs-fn
+     ~(into {} (map (fn [[sym val]] [(list (quote var) sym) val]) (partition 2 bindings)))
+     (fn [] ~@body)))
+
+"multimethods"
+
+(defmacro defmulti [name dispatch-fn]
+  `(def ~name (multi-fn* (quote ~name) ~dispatch-fn)))
+
+(defmacro defmethod [multifn dispatch-val & fn-tail]
+  `(add-method* ~multifn ~dispatch-val (fn ~@fn-tail)))
+
+"input and output"
+
+(defmulti print-method (fn [x writer] (type x)))
+
+(defmethod print-method :default [x writer]
+  (pr-on x writer))
+
+(defmacro with-out-str [& body]
+  `(let [out# (string-writer)]
+     (do (binding [*out* out#] ~@body)
+         (str out#))))
+
+(defmacro with-in-str [s & body]
+  `(binding [*in* (string-reader ~s)] ~@body))
+
+(defmacro with-open [bindings & body]
+  (if (seq bindings)
+    `(let [~(first bindings) ~(second bindings)]
+       (try
+         (with-open ~(into [] (drop 2 bindings)) ~@body)
+         (finally (clojure.java.io/close ~(first bindings)))))
+    `(do ~@body)))
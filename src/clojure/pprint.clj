@@ -0,0 +1,10 @@
+"clojure.pprint"
+
+"TODO : some special syntax required because of missing require"
+
+(def ^:dynamic clojure.pprint/*print-right-margin* 72)
+
+(def ^:dynamic clojure.pprint/*print-pprint-dispatch* clojure.pprint/simple-dispatch)
+
+(defmacro clojure.pprint/with-pprint-dispatch [function & body]
+  `(binding [clojure.pprint/*print-pprint-dispatch* ~function] ~@body))
@@ -1,7 +0,0 @@
-"clojure.string"
-
-"TODO : some special syntax required because of missing require"
-
-(def clojure.string/split-lines
-  (fn [s]
-    (clojure.string/split s #"\r?\n")))
\ No newline at end of file
@@ -0,0 +1,7 @@
+pub(crate) mod options;
+pub(crate) mod read;
+pub(crate) mod read_str;
+pub(crate) mod reader;
+pub(crate) mod write;
+pub(crate) mod write_str;
+pub(crate) mod writer;
@@ -0,0 +1,45 @@
+//! Options as clojure.data.json takes them, as keyword arguments after the ones it needs;
+//! (read-str s :key-fn keyword :eof-error? false)
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::Value;
+use std::rc::Rc;
+
+pub struct KeywordOptions<'a> {
+    options: &'a [Rc<Value>],
+}
+impl KeywordOptions<'_> {
+    pub fn new(options: &[Rc<Value>]) -> Result<KeywordOptions<'_>, Rc<Value>> {
+        if !options.len().is_multiple_of(2) {
+            return Err(Rc::new(error_message::condition(
+                ConditionKind::IllegalArgumentException,
+                format!(
+                    "Options must be given as keyword value pairs, not {} forms",
+                    options.len()
+                ),
+            )));
+        }
+        Ok(KeywordOptions { options })
+    }
+    /// The value given for :name, the last one if it's given more than once
+    pub fn get(&self, name: &str) -> Option<Rc<Value>> {
+        self.options
+            .chunks(2)
+            .rev()
+            .find(|option| matches!(&*option[0], Value::Keyword(keyword) if keyword.namespace().is_none() && keyword.name() == name))
+            .map(|option| Rc::clone(&option[1]))
+    }
+    /// The fn given for :name, if one was
+    pub fn get_fn(&self, name: &str) -> Result<Option<protocols::IFn>, Rc<Value>> {
+        match self.get(name) {
+            Some(f) if !matches!(*f, Value::Nil) => match f.try_as_protocol::<protocols::IFn>() {
+                Some(f) => Ok(Some(f)),
+                None => Err(Rc::new(error_message::type_mismatch(TypeTag::IFn, &f))),
+            },
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::clojure_data_json::reader::{self, Read, ReadOptions};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::Reader;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.data.json/read reads the next value of JSON from a reader
+/// (read reader & options)
+///
+/// Options are as for read-str;  what's read past the value is left for the next read
+#[derive(Debug, Clone)]
+pub struct ReadFn {}
+impl ToValue for ReadFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReadFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        let stream = match &*args[0] {
+            Value::Reader(stream) => stream,
+            _ => return error_message::type_mismatch(TypeTag::Reader, &args[0]),
+        };
+        match ReadOptions::new(&args[1..]).and_then(|options| read_from(stream, &options)) {
+            Ok(value) => (*value).clone(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// The next value of JSON from stream, a line at a time until there's a whole value
+fn read_from(stream: &Reader, options: &ReadOptions) -> Result<Rc<Value>, Rc<Value>> {
+    let mut text = String::new();
+    loop {
+        let more = match stream.read_line()? {
+            Some(line) => {
+                text.push_str(&line);
+                text.push('\n');
+                true
+            }
+            None => false,
+        };
+        match reader::read(&text, options)? {
+            Read::Value(value, rest) => {
+                stream.unread(rest);
+                return Ok(value);
+            }
+            _ if more => {}
+            Read::Nothing if options.eof_error => {
+                let line = text.matches('\n').count() + 1;
+                return Err(reader::json_error("end-of-file", line, 1));
+            }
+            Read::Nothing => return Ok(Rc::clone(&options.eof_value)),
+            Read::Incomplete(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_tests {
+        use crate::clojure_data_json::read::ReadFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::{KeywordFn, StringReaderFn};
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn string_reader(s: &str) -> Rc<Value> {
+            StringReaderFn {}
+                .invoke(vec![s.to_rc_value()])
+                .to_rc_value()
+        }
+
+        #[test]
+        fn read_values_one_after_another() {
+            let read_json = ReadFn {};
+            let reader = string_reader("{\"a\": [1,\n 2]} 3\n");
+            assert_eq!(
+                read("{:a [1 2]}"),
+                read_json.invoke(vec![
+                    Rc::clone(&reader),
+                    Rc::new(read(":key-fn")),
+                    KeywordFn {}.to_rc_value()
+                ])
+            );
+            assert_eq!(Value::I32(3), read_json.invoke(vec![Rc::clone(&reader)]));
+            assert_eq!(
+                read(":end"),
+                read_json.invoke(vec![
+                    reader,
+                    Rc::new(read(":eof-error?")),
+                    Rc::new(read("false")),
+                    Rc::new(read(":eof-value")),
+                    Rc::new(read(":end"))
+                ])
+            );
+        }
+
+        #[test]
+        fn read_past_the_end() {
+            let read_json = ReadFn {};
+            match read_json.invoke(vec![string_reader("[1")]) {
+                Value::Condition(condition) => assert_eq!(
+                    "JSON error (end-of-file) at line 2, column 1",
+                    condition.message
+                ),
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
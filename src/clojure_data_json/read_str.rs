@@ -0,0 +1,120 @@
+use crate::clojure_data_json::reader::{self, ReadOptions};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.data.json/read-str reads a string of JSON
+/// (read-str s & options)
+///
+/// Objects are read as maps, keeping the order of their keys, arrays as vectors, and
+/// integers as I32s (I64s if they're too big) and other numbers as F64s.  Options are
+///   :key-fn      fn given each key of an object, a string, for its key;  keyword, say
+///   :value-fn    fn given each key (as key-fn made it) and value, for its value
+///   :eof-error?  whether a string with nothing in it is an error, true by default
+///   :eof-value   what a string with nothing in it reads as, otherwise
+#[derive(Debug, Clone)]
+pub struct ReadStrFn {}
+impl ToValue for ReadStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReadStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        let s = match &*args[0] {
+            Value::String(s) => s,
+            _ => return error_message::type_mismatch(TypeTag::String, &args[0]),
+        };
+        match ReadOptions::new(&args[1..]).and_then(|options| reader::read_str(s, &options)) {
+            Ok(value) => (*value).clone(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_str_tests {
+        use crate::clojure_data_json::read_str::ReadStrFn;
+        use crate::clojure_data_json::write_str::WriteStrFn;
+        use crate::ifn::IFn;
+        use crate::persistent_vector::PersistentVector;
+        use crate::rust_core::KeywordFn;
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn read_str_reads_json() {
+            let read_str = ReadStrFn {};
+            assert_eq!(
+                read("{\"name\" \"x\" \"tags\" [\"a\" 1 2.5 nil true]}"),
+                read_str.invoke(vec![
+                    "{\"name\": \"x\", \"tags\": [\"a\", 1, 2.5, null, true]}".to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn read_str_with_options() {
+            let read_str = ReadStrFn {};
+            assert_eq!(
+                read("{:a 2 :b [1]}"),
+                read_str.invoke(vec![
+                    "{\"a\": 1, \"b\": [1]}".to_rc_value(),
+                    Rc::new(read(":key-fn")),
+                    KeywordFn {}.to_rc_value(),
+                    Rc::new(read(":value-fn")),
+                    Rc::new(eval_str("(fn [k v] (if (= k :a) (inc v) v))")),
+                ])
+            );
+            assert_eq!(
+                read(":none"),
+                read_str.invoke(read_args(&[
+                    "\" \"",
+                    ":eof-error?",
+                    "false",
+                    ":eof-value",
+                    ":none"
+                ]))
+            );
+        }
+
+        #[test]
+        fn read_str_errors_say_where() {
+            let read_str = ReadStrFn {};
+            match read_str.invoke(vec!["[1,\n ]".to_rc_value()]) {
+                Value::Condition(condition) => {
+                    assert_eq!(
+                        "JSON error (unexpected character: ]) at line 2, column 2",
+                        condition.message
+                    );
+                    assert_eq!(read("{:line 2 :column 2}"), *condition.data);
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn read_str_reads_wide_integers() {
+            let read_str = ReadStrFn {};
+            let wide = read_str.invoke(vec!["[12345678901, -1]".to_rc_value()]);
+            assert_eq!(
+                vec![Rc::new(Value::I64(12345678901)), Rc::new(Value::I32(-1))]
+                    .into_iter()
+                    .collect::<PersistentVector>()
+                    .to_value(),
+                wide
+            );
+            assert_eq!(
+                Value::String("[12345678901,-1]".to_string()),
+                WriteStrFn {}.invoke(vec![wide.to_rc_value()])
+            );
+        }
+    }
+}
@@ -0,0 +1,529 @@
+//! Reading JSON (see https://www.json.org) as Clojure data;  objects as maps, arrays as
+//! vectors, and integers as I32 (or I64 where they don't fit) and other numbers as F64
+//!
+//! Every error says the line and column it was found at, in its message and as the :line and
+//! :column of its ex-data
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::condition::{Condition, ConditionKind};
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::maps::MapEntry;
+use crate::persistent_hash_map;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::persistent_vector::ToPersistentVector;
+use crate::protocols;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// How clojure.data.json/read and read-str read, as given by their options
+pub struct ReadOptions {
+    /// :key-fn, given each key of an object (a string) for the key it's read as
+    key_fn: Option<protocols::IFn>,
+    /// :value-fn, given each key (as key-fn made it) and value of an object, for the value
+    /// it's read as
+    value_fn: Option<protocols::IFn>,
+    /// :eof-error?, whether reading nothing is an error (as it is by default), rather than
+    /// :eof-value
+    pub eof_error: bool,
+    pub eof_value: Rc<Value>,
+}
+impl ReadOptions {
+    /// The options in options, keyword value pairs
+    pub fn new(options: &[Rc<Value>]) -> Result<ReadOptions, Rc<Value>> {
+        let options = KeywordOptions::new(options)?;
+        Ok(ReadOptions {
+            key_fn: options.get_fn("key-fn")?,
+            value_fn: options.get_fn("value-fn")?,
+            eof_error: options
+                .get("eof-error?")
+                .is_none_or(|eof_error| eof_error.is_truthy()),
+            eof_value: options
+                .get("eof-value")
+                .unwrap_or_else(|| Rc::new(Value::Nil)),
+        })
+    }
+}
+
+/// What's found reading some input
+#[derive(Debug, PartialEq)]
+pub enum Read<'a> {
+    /// A value, and the input left after it
+    Value(Rc<Value>, &'a str),
+    /// Only whitespace
+    Nothing,
+    /// The start of a value that the input ends before the end of;  its error, should
+    /// there be no more input
+    Incomplete(Rc<Value>),
+}
+
+/// Reads the first value of input, which more input may follow
+pub fn read<'a>(input: &'a str, options: &ReadOptions) -> Result<Read<'a>, Rc<Value>> {
+    let mut parser = Parser::new(input, options, false);
+    parser.skip_whitespace();
+    if parser.pos == input.len() {
+        return Ok(Read::Nothing);
+    }
+    match parser.value() {
+        Ok(value) => Ok(Read::Value(value, &input[parser.pos..])),
+        Err(Error::Incomplete) => {
+            parser.pos = input.len();
+            Ok(Read::Incomplete(parser.error("end-of-file")))
+        }
+        Err(Error::Invalid(condition)) => Err(condition),
+    }
+}
+
+/// Reads input, which should be one value and nothing more, as read-str does
+pub fn read_str(input: &str, options: &ReadOptions) -> Result<Rc<Value>, Rc<Value>> {
+    let mut parser = Parser::new(input, options, true);
+    parser.skip_whitespace();
+    if parser.pos == input.len() {
+        return match options.eof_error {
+            true => Err(parser.error("end-of-file")),
+            false => Ok(Rc::clone(&options.eof_value)),
+        };
+    }
+    match parser.value() {
+        Ok(value) => {
+            parser.skip_whitespace();
+            if parser.pos < input.len() {
+                return Err(parser.error("unexpected data after value"));
+            }
+            Ok(value)
+        }
+        Err(Error::Incomplete) => {
+            parser.pos = input.len();
+            Err(parser.error("end-of-file"))
+        }
+        Err(Error::Invalid(condition)) => Err(condition),
+    }
+}
+
+/// A JSON error, found at line and column
+pub(crate) fn json_error(what: &str, line: usize, column: usize) -> Rc<Value> {
+    let data = persistent_hash_map::build_map(vec![
+        MapEntry {
+            key: Keyword::intern("line").to_rc_value(),
+            val: Rc::new(Value::I32(line as i32)),
+        },
+        MapEntry {
+            key: Keyword::intern("column").to_rc_value(),
+            val: Rc::new(Value::I32(column as i32)),
+        },
+    ]);
+    let message = format!("JSON error ({}) at line {}, column {}", what, line, column);
+    Rc::new(Value::Condition(Rc::new(
+        Condition::new(ConditionKind::ExceptionInfo, message).with_data(Rc::new(data)),
+    )))
+}
+
+enum Error {
+    /// The input ended partway through a value
+    Incomplete,
+    Invalid(Rc<Value>),
+}
+
+struct Parser<'a, 'o> {
+    input: &'a str,
+    /// How far into input we've read, in bytes
+    pos: usize,
+    options: &'o ReadOptions,
+    /// Whether input is all there is;  otherwise, a number at its end could go on
+    complete: bool,
+}
+impl<'a, 'o> Parser<'a, 'o> {
+    fn new(input: &'a str, options: &'o ReadOptions, complete: bool) -> Parser<'a, 'o> {
+        Parser {
+            input,
+            pos: 0,
+            options,
+            complete,
+        }
+    }
+    /// The error what, at where we are in the input
+    fn error(&self, what: &str) -> Rc<Value> {
+        let read = &self.input[..self.pos];
+        let line_start = read.rfind('\n').map_or(0, |newline| newline + 1);
+        json_error(
+            what,
+            read.matches('\n').count() + 1,
+            read[line_start..].chars().count() + 1,
+        )
+    }
+    fn invalid(&self, what: &str) -> Error {
+        Error::Invalid(self.error(what))
+    }
+    /// An error for the character we're at, or Incomplete if there isn't one
+    fn unexpected(&self) -> Error {
+        match self.peek() {
+            Some(ch) => self.invalid(&format!("unexpected character: {}", ch)),
+            None => Error::Incomplete,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        let skipped = rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+        self.pos += skipped;
+    }
+    /// Reads past ch, if it's the next character
+    fn skip(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += ch.len_utf8();
+            return true;
+        }
+        false
+    }
+
+    /// The value starting here, after any whitespace
+    fn value(&mut self) -> Result<Rc<Value>, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Rc::new(Value::String(self.string()?))),
+            Some('t') => self.literal("true", Value::Boolean(true)),
+            Some('f') => self.literal("false", Value::Boolean(false)),
+            Some('n') => self.literal("null", Value::Nil),
+            Some('-') | Some('0'..='9') => self.number(),
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Rc<Value>, Error> {
+        let rest = &self.input[self.pos..];
+        if rest.starts_with(word) {
+            self.pos += word.len();
+            return Ok(Rc::new(value));
+        }
+        if word.starts_with(rest) {
+            return Err(Error::Incomplete);
+        }
+        Err(self.invalid(&format!("unexpected token, expected {}", word)))
+    }
+
+    /// -?(0|[1-9][0-9]*)(.[0-9]+)?([eE][+-]?[0-9]+)?
+    fn number(&mut self) -> Result<Rc<Value>, Error> {
+        let start = self.pos;
+        let digits = |parser: &mut Parser| {
+            let rest = &parser.input[parser.pos..];
+            let count = rest.len()
+                - rest
+                    .trim_start_matches(|ch: char| ch.is_ascii_digit())
+                    .len();
+            parser.pos += count;
+            count
+        };
+        self.skip('-');
+        let int_start = self.pos;
+        let int_digits = digits(self);
+        if int_digits == 0 {
+            return Err(self.unexpected());
+        }
+        if int_digits > 1 && self.input[int_start..].starts_with('0') {
+            self.pos = int_start;
+            return Err(self.invalid("leading zero in number"));
+        }
+        let mut integral = true;
+        if self.skip('.') {
+            integral = false;
+            if digits(self) == 0 {
+                return Err(self.unexpected());
+            }
+        }
+        if self.skip('e') || self.skip('E') {
+            integral = false;
+            let _ = self.skip('+') || self.skip('-');
+            if digits(self) == 0 {
+                return Err(self.unexpected());
+            }
+        }
+        if !self.complete && self.pos == self.input.len() {
+            return Err(Error::Incomplete);
+        }
+        let number = &self.input[start..self.pos];
+        // Integers too wide for an I32 are read as I64s, as the EDN reader does;  only those
+        // too wide for those are refused, rather than losing their precision as an F64
+        if integral {
+            return match (number.parse::<i32>(), number.parse::<i64>()) {
+                (Ok(int), _) => Ok(Rc::new(Value::I32(int))),
+                (_, Ok(int)) => Ok(Rc::new(Value::I64(int))),
+                _ => {
+                    self.pos = start;
+                    Err(self.invalid(&format!("Integer out of range: {}", number)))
+                }
+            };
+        }
+        match number.parse::<f64>() {
+            Ok(float) if float.is_finite() => Ok(Rc::new(Value::F64(float))),
+            _ => {
+                self.pos = start;
+                Err(self.invalid("number out of range"))
+            }
+        }
+    }
+
+    /// The string starting here, at its "
+    fn string(&mut self) -> Result<String, Error> {
+        self.pos += 1;
+        let mut string = String::new();
+        loop {
+            let ch = self.peek().ok_or(Error::Incomplete)?;
+            match ch {
+                '"' => {
+                    self.pos += 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    self.pos += 1;
+                    string.push(self.escape()?);
+                }
+                '\u{0}'..='\u{1f}' => {
+                    return Err(self.invalid("control character in string"));
+                }
+                _ => {
+                    self.pos += ch.len_utf8();
+                    string.push(ch);
+                }
+            }
+        }
+    }
+    /// The character escaped here, after its \
+    fn escape(&mut self) -> Result<char, Error> {
+        let ch = self.peek().ok_or(Error::Incomplete)?;
+        let escaped = match ch {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                self.pos += 1;
+                let unit = self.code_unit()?;
+                return match unit {
+                    0xd800..=0xdbff => {
+                        let escape_start = self.pos;
+                        if !self.input[self.pos..].starts_with("\\u") {
+                            return match self.input.len() - self.pos {
+                                0 | 1 => Err(Error::Incomplete),
+                                _ => Err(self.invalid("unpaired surrogate in \\u escape")),
+                            };
+                        }
+                        self.pos += 2;
+                        let low = self.code_unit()?;
+                        if !(0xdc00..=0xdfff).contains(&low) {
+                            self.pos = escape_start;
+                            return Err(self.invalid("unpaired surrogate in \\u escape"));
+                        }
+                        let code = 0x10000 + ((unit - 0xd800) << 10) + (low - 0xdc00);
+                        Ok(char::from_u32(code).unwrap())
+                    }
+                    _ => char::from_u32(unit)
+                        .ok_or_else(|| self.invalid("unpaired surrogate in \\u escape")),
+                };
+            }
+            _ => return Err(self.invalid(&format!("invalid escape: \\{}", ch))),
+        };
+        self.pos += 1;
+        Ok(escaped)
+    }
+    /// The 4 hex digits of a \u escape, here after its \u
+    fn code_unit(&mut self) -> Result<u32, Error> {
+        let rest = &self.input[self.pos..];
+        let hex = rest
+            .chars()
+            .take(4)
+            .take_while(|ch| ch.is_ascii_hexdigit())
+            .count();
+        if hex < 4 {
+            return match hex == rest.len() {
+                true => Err(Error::Incomplete),
+                false => Err(self.invalid("invalid \\u escape")),
+            };
+        }
+        self.pos += 4;
+        Ok(u32::from_str_radix(&rest[..4], 16).unwrap())
+    }
+
+    fn array(&mut self) -> Result<Rc<Value>, Error> {
+        self.pos += 1;
+        let mut elements = vec![];
+        self.skip_whitespace();
+        if !self.skip(']') {
+            loop {
+                elements.push(self.value()?);
+                self.skip_whitespace();
+                if self.skip(']') {
+                    break;
+                }
+                if !self.skip(',') {
+                    return Err(self.unexpected());
+                }
+            }
+        }
+        Ok(Rc::new(elements.into_vector().to_value()))
+    }
+
+    fn object(&mut self) -> Result<Rc<Value>, Error> {
+        self.pos += 1;
+        let mut entries = vec![];
+        self.skip_whitespace();
+        if !self.skip('}') {
+            loop {
+                self.skip_whitespace();
+                if self.peek() != Some('"') {
+                    return Err(self.unexpected());
+                }
+                let key = Rc::new(Value::String(self.string()?));
+                let key = match &self.options.key_fn {
+                    Some(key_fn) => self.invoked(key_fn.invoke(vec![key]))?,
+                    None => key,
+                };
+                self.skip_whitespace();
+                if !self.skip(':') {
+                    return Err(self.unexpected());
+                }
+                let value = self.value()?;
+                let value = match &self.options.value_fn {
+                    Some(value_fn) => {
+                        self.invoked(value_fn.invoke(vec![Rc::clone(&key), value]))?
+                    }
+                    None => value,
+                };
+                entries.push((key, value));
+                self.skip_whitespace();
+                if self.skip('}') {
+                    break;
+                }
+                if !self.skip(',') {
+                    return Err(self.unexpected());
+                }
+            }
+        }
+        // Assoc'd last to first, so the map has its keys in the order they were read; the
+        // last of a key read twice is what it keeps
+        let mut map = PersistentListMap::Empty;
+        for (key, value) in entries.into_iter().rev() {
+            if !map.contains_key(&key) {
+                map = map.assoc(key, value);
+            }
+        }
+        Ok(Rc::new(map.to_value()))
+    }
+
+    /// What key-fn or value-fn gave back, unless it threw
+    fn invoked(&self, value: Value) -> Result<Rc<Value>, Error> {
+        let value = Rc::new(value);
+        match value.non_tail() {
+            Some(interruption) => Err(Error::Invalid(interruption)),
+            None => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod reader_tests {
+        use crate::clojure_data_json::reader::{read, read_str, Read, ReadOptions};
+        use crate::repl::Repl;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn json(input: &str) -> Value {
+            (*read_str(input, &ReadOptions::new(&[]).unwrap()).unwrap()).clone()
+        }
+        fn clojure(source: &str) -> Value {
+            Repl::read_string(source).unwrap()
+        }
+        fn error(input: &str) -> String {
+            match &*read_str(input, &ReadOptions::new(&[]).unwrap()).unwrap_err() {
+                Value::Condition(condition) => condition.message.clone(),
+                error => panic!("{} isn't a condition", error),
+            }
+        }
+
+        #[test]
+        fn read_values() {
+            assert_eq!(
+                clojure("[nil true false 0 -12 1.5 \"\"]"),
+                json("[null, true, false, 0, -12, 1.5, \"\"]")
+            );
+            assert_eq!(
+                clojure("{\"a\" [1 {\"b\" []}] \"c\" {}}"),
+                json(" {\"a\": [1, {\"b\": []}],\n \"c\": {}} ")
+            );
+            assert_eq!(Value::F64(-0.0025), json("-2.5E-3"));
+            assert_eq!(Value::F64(3e9), json("3e9"));
+        }
+
+        #[test]
+        fn read_strings() {
+            assert_eq!(
+                Value::String("a\"\\/\u{8}\u{c}\n\r\tü😀".to_string()),
+                json(r#""a\"\\\/\b\f\n\r\t\u00fc\ud83d\ude00""#)
+            );
+        }
+
+        #[test]
+        fn objects_keep_their_order() {
+            assert_eq!(
+                "{\"z\" 1, \"a\" 3}",
+                json("{\"z\": 1, \"a\": 2, \"a\": 3}").to_string()
+            );
+        }
+
+        #[test]
+        fn errors_say_where() {
+            assert_eq!(
+                "JSON error (unexpected character: }) at line 2, column 7",
+                error("{\"a\": 1,\n \"b\": }")
+            );
+            assert_eq!(
+                "JSON error (end-of-file) at line 1, column 8",
+                error("[1, 2, ")
+            );
+            assert_eq!(
+                "JSON error (unexpected data after value) at line 1, column 4",
+                error("[] []")
+            );
+            assert_eq!(
+                "JSON error (leading zero in number) at line 1, column 1",
+                error("012")
+            );
+            assert_eq!(
+                "JSON error (Integer out of range: 123456789012345678901) at line 1, column 2",
+                error("[123456789012345678901]")
+            );
+            assert_eq!(
+                "JSON error (invalid escape: \\x) at line 1, column 3",
+                error("\"\\x\"")
+            );
+            assert_eq!(
+                "JSON error (control character in string) at line 1, column 2",
+                error("\"\n\"")
+            );
+        }
+
+        #[test]
+        fn read_the_first_value() {
+            let options = ReadOptions::new(&[]).unwrap();
+            assert_eq!(
+                Read::Value(Rc::new(Value::I32(1)), " [2]"),
+                read("1 [2]", &options).unwrap()
+            );
+            assert_eq!(Read::Nothing, read(" \n ", &options).unwrap());
+            assert!(matches!(read("[1, ", &options), Ok(Read::Incomplete(_))));
+            assert!(matches!(read("12", &options), Ok(Read::Incomplete(_))));
+            assert!(matches!(
+                read("\"\\ud83d", &options),
+                Ok(Read::Incomplete(_))
+            ));
+        }
+    }
+}
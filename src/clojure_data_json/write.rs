@@ -0,0 +1,64 @@
+use crate::clojure_data_json::writer::{self, WriteOptions};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.data.json/write
+/// (write x writer & options)
+///
+/// Writes x as JSON to writer;  options are as for write-str
+#[derive(Debug, Clone)]
+pub struct WriteFn {}
+impl ToValue for WriteFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WriteFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() < 2 {
+            return error_message::wrong_variadic_arg_count(&[], 2, args.len());
+        }
+        let out = match &*args[1] {
+            Value::Writer(out) => out,
+            _ => return error_message::type_mismatch(TypeTag::Writer, &args[1]),
+        };
+        match WriteOptions::new(&args[2..])
+            .and_then(|options| writer::write_json(&args[0], &options))
+            .and_then(|json| out.write_str(&json))
+        {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod write_tests {
+        use crate::clojure_data_json::write::WriteFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::{StrFn, StringWriterFn};
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_to_a_writer() {
+            let write = WriteFn {};
+            let writer = StringWriterFn {}.invoke(vec![]).to_rc_value();
+            for x in &["[1 2]", "{}"] {
+                assert_eq!(
+                    Value::Nil,
+                    write.invoke(vec![Rc::new(read(x)), Rc::clone(&writer)])
+                );
+            }
+            assert_eq!(
+                Value::String("[1,2]{}".to_string()),
+                StrFn {}.invoke(vec![writer])
+            );
+        }
+    }
+}
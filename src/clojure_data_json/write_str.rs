@@ -0,0 +1,76 @@
+use crate::clojure_data_json::writer::{self, WriteOptions};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.data.json/write-str
+/// (write-str x & options)
+///
+/// x as a string of JSON.  Maps are written as objects, and lists, vectors, seqs and sets as
+/// arrays;  keywords and symbols are written as strings of their names.  Options are
+///   :key-fn    fn given each key of a map for the string it's written as
+///   :value-fn  fn given each key and value of a map for the value written
+#[derive(Debug, Clone)]
+pub struct WriteStrFn {}
+impl ToValue for WriteStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WriteStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match WriteOptions::new(&args[1..])
+            .and_then(|options| writer::write_json(&args[0], &options))
+        {
+            Ok(json) => Value::String(json),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod write_str_tests {
+        use crate::clojure_data_json::read_str::ReadStrFn;
+        use crate::clojure_data_json::write_str::WriteStrFn;
+        use crate::ifn::IFn;
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_str_writes_json() {
+            let write_str = WriteStrFn {};
+            assert_eq!(
+                Value::String("[{\"a\":[1,\"b\",null]},{\"c\":{\"d\":true}}]".to_string()),
+                write_str.invoke(vec![Rc::new(read("[{:a [1 :b nil]} {\"c\" {d true}}]"))])
+            );
+        }
+
+        #[test]
+        fn write_str_with_options() {
+            let write_str = WriteStrFn {};
+            assert_eq!(
+                Value::String("{\":A\":2}".to_string()),
+                write_str.invoke(vec![
+                    Rc::new(read("{:a 1}")),
+                    Rc::new(read(":key-fn")),
+                    Rc::new(eval_str("(fn [k] (clojure.string/upper-case (str k)))")),
+                    Rc::new(read(":value-fn")),
+                    Rc::new(eval_str("(fn [k v] (inc v))")),
+                ])
+            );
+        }
+
+        #[test]
+        fn write_str_round_trips() {
+            let x = read("{\"a\" [1 2.5 \"three\"] \"b\" {}}");
+            let written = WriteStrFn {}.invoke(vec![Rc::new(x.clone())]);
+            assert_eq!(x, ReadStrFn {}.invoke(vec![written.to_rc_value()]));
+        }
+    }
+}
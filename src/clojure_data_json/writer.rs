@@ -0,0 +1,189 @@
+//! Writing Clojure data as JSON;  maps as objects, and lists, vectors, seqs and sets as arrays
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::printer;
+use crate::protocols;
+use crate::seq;
+use crate::value::Value;
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// How clojure.data.json/write and write-str write, as given by their options
+#[derive(Default)]
+pub struct WriteOptions {
+    /// :key-fn, given each key of a map for the string it's written as;  by default,
+    /// keywords and symbols are written as their names, and anything else as str has it
+    key_fn: Option<protocols::IFn>,
+    /// :value-fn, given each key and value of a map, for the value written
+    value_fn: Option<protocols::IFn>,
+}
+impl WriteOptions {
+    /// The options in options, keyword value pairs
+    pub fn new(options: &[Rc<Value>]) -> Result<WriteOptions, Rc<Value>> {
+        let options = KeywordOptions::new(options)?;
+        Ok(WriteOptions {
+            key_fn: options.get_fn("key-fn")?,
+            value_fn: options.get_fn("value-fn")?,
+        })
+    }
+}
+
+/// x as JSON
+pub fn write_json(x: &Rc<Value>, options: &WriteOptions) -> Result<String, Rc<Value>> {
+    let mut written = String::new();
+    write(x, options, &mut written)?;
+    Ok(written)
+}
+
+fn cannot_write(x: &Value, why: &str) -> Rc<Value> {
+    Rc::new(error_message::condition(
+        ConditionKind::IllegalArgumentException,
+        format!("Cannot write {} as JSON{}: {}", x.type_tag(), why, x),
+    ))
+}
+
+fn write(x: &Rc<Value>, options: &WriteOptions, written: &mut String) -> Result<(), Rc<Value>> {
+    match &**x {
+        Value::Nil => written.push_str("null"),
+        Value::Boolean(boolean) => written.push_str(&boolean.to_string()),
+        Value::I32(int) => written.push_str(&int.to_string()),
+        Value::I64(int) => written.push_str(&int.to_string()),
+        Value::F64(float) if float.is_finite() => written.push_str(&printer::format_f64(*float)),
+        Value::F64(_) => return Err(cannot_write(x, ", as it's not finite")),
+        Value::String(string) => write_string(string, written),
+        Value::Keyword(keyword) => write_string(keyword.name(), written),
+        Value::Symbol(symbol) => write_string(symbol.name(), written),
+        Value::Inst(inst) => write_string(&inst.timestamp(), written),
+        Value::Uuid(uuid) => write_string(&uuid.canonical(), written),
+        Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {
+            written.push('{');
+            for (i, entry) in seq::iter(x).enumerate() {
+                let (key, value) = match &*entry {
+                    Value::PersistentVector(entry) => {
+                        let mut entry = entry.to_vec().into_iter();
+                        (entry.next().unwrap(), entry.next().unwrap())
+                    }
+                    _ => return Err(entry),
+                };
+                if i > 0 {
+                    written.push(',');
+                }
+                write_string(&key_string(&key, options)?, written);
+                written.push(':');
+                let value = match &options.value_fn {
+                    Some(value_fn) => invoked(value_fn.invoke(vec![key, value]))?,
+                    None => value,
+                };
+                write(&value, options, written)?;
+            }
+            written.push('}');
+        }
+        Value::PersistentVector(_)
+        | Value::PersistentList(_)
+        | Value::PersistentHashSet(_)
+        | Value::LazySeq(_)
+        | Value::Cons(_) => {
+            written.push('[');
+            for (i, element) in seq::iter(x).enumerate() {
+                if let Value::Condition(_) = &*element {
+                    return Err(element);
+                }
+                if i > 0 {
+                    written.push(',');
+                }
+                write(&element, options, written)?;
+            }
+            written.push(']');
+        }
+        Value::Condition(_) => return Err(Rc::clone(x)),
+        _ => return Err(cannot_write(x, "")),
+    }
+    Ok(())
+}
+
+/// The string key is written as, in an object
+fn key_string(key: &Rc<Value>, options: &WriteOptions) -> Result<String, Rc<Value>> {
+    let key = match &options.key_fn {
+        Some(key_fn) => invoked(key_fn.invoke(vec![Rc::clone(key)]))?,
+        None => Rc::clone(key),
+    };
+    match &*key {
+        Value::String(string) => Ok(string.clone()),
+        Value::Keyword(keyword) => Ok(keyword.name().to_string()),
+        Value::Symbol(symbol) => Ok(symbol.name().to_string()),
+        Value::Nil => Err(cannot_write(&key, " key")),
+        _ => Ok(key.to_string()),
+    }
+}
+
+/// What key-fn or value-fn gave back, unless it threw
+fn invoked(value: Value) -> Result<Rc<Value>, Rc<Value>> {
+    let value = Rc::new(value);
+    match value.non_tail() {
+        Some(interruption) => Err(interruption),
+        None => Ok(value),
+    }
+}
+
+/// string in quotes, escaped as JSON needs it to be;  anything not ASCII is written as it is
+fn write_string(string: &str, written: &mut String) {
+    written.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => written.push_str("\\\""),
+            '\\' => written.push_str("\\\\"),
+            '\n' => written.push_str("\\n"),
+            '\r' => written.push_str("\\r"),
+            '\t' => written.push_str("\\t"),
+            '\u{8}' => written.push_str("\\b"),
+            '\u{c}' => written.push_str("\\f"),
+            '\u{0}'..='\u{1f}' => {
+                let _ = write!(written, "\\u{:04x}", ch as u32);
+            }
+            _ => written.push(ch),
+        }
+    }
+    written.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    mod writer_tests {
+        use crate::clojure_data_json::reader::{read_str, ReadOptions};
+        use crate::clojure_data_json::writer::{write_json, WriteOptions};
+        use crate::repl::Repl;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn json(source: &str) -> String {
+            let x = Rc::new(Repl::read_string(source).unwrap());
+            write_json(&x, &WriteOptions::default()).unwrap()
+        }
+
+        #[test]
+        fn write_values() {
+            assert_eq!(
+                "[null,true,1,-2.5,\"a\\\"\\n\\u0001ü\",\"k\",\"s\",[1],[]]",
+                json("[nil true 1 -2.5 \"a\\\"\n\u{1}ü\" :k s (1) #{}]")
+            );
+        }
+
+        #[test]
+        fn write_reads_back() {
+            let written = json("[{\"a\" [1 2.5 nil]} {}]");
+            assert_eq!("[{\"a\":[1,2.5,null]},{}]", written);
+            assert_eq!(
+                Repl::read_string("[{\"a\" [1 2.5 nil]} {}]").unwrap(),
+                *read_str(&written, &ReadOptions::new(&[]).unwrap()).unwrap()
+            );
+        }
+
+        #[test]
+        fn refuse_what_is_not_json() {
+            let infinity = Rc::new(Value::F64(f64::INFINITY));
+            assert!(write_json(&infinity, &WriteOptions::default()).is_err());
+        }
+    }
+}
@@ -0,0 +1,6 @@
+pub(crate) mod read;
+pub(crate) mod read_string;
+pub(crate) mod reader;
+pub(crate) mod write;
+pub(crate) mod write_string;
+pub(crate) mod writer;
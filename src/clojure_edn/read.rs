@@ -0,0 +1,129 @@
+use crate::clojure_edn::reader::{self, Options, Read};
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::{self, Reader};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.edn/read reads the next form of EDN from a reader, *in* if it's not given one
+/// (read)
+/// (read stream)
+/// (read opts stream)
+///
+/// opts are as for read-string, but with no :eof, reading past the end is an error
+#[derive(Debug, Clone)]
+pub struct ReadFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl ReadFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> ReadFn {
+        ReadFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for ReadFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReadFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (opts, stream) = match args.as_slice() {
+            [] => (
+                Rc::new(Value::Nil),
+                stream::input(&self.enclosing_environment),
+            ),
+            [stream] => (Rc::new(Value::Nil), as_reader(stream)),
+            [opts, stream] => (Rc::clone(opts), as_reader(stream)),
+            _ => return error_message::wrong_varg_count(&[0, 1, 2], args.len()),
+        };
+        let read = stream.and_then(|stream| read_from(&stream, &Options::from_map(&opts)?));
+        match read {
+            Ok(form) => form,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+fn as_reader(stream: &Rc<Value>) -> Result<Reader, Rc<Value>> {
+    match &**stream {
+        Value::Reader(reader) => Ok(reader.clone()),
+        _ => Err(Rc::new(error_message::type_mismatch(
+            TypeTag::Reader,
+            stream,
+        ))),
+    }
+}
+
+/// The next form of EDN from stream, a line at a time until there's a whole form;  what's
+/// read past it is left for the next read
+fn read_from(stream: &Reader, options: &Options) -> Result<Value, Rc<Value>> {
+    let mut text = String::new();
+    loop {
+        let more = match stream.read_line()? {
+            Some(line) => {
+                text.push_str(&line);
+                text.push('\n');
+                true
+            }
+            None => false,
+        };
+        match reader::read(&text, options)? {
+            Read::Form(form, rest) => {
+                stream.unread(rest);
+                return Ok(Rc::unwrap_or_clone(form));
+            }
+            _ if more => {}
+            Read::Nothing => {
+                return options
+                    .eof
+                    .as_deref()
+                    .cloned()
+                    .ok_or_else(reader::eof_error)
+            }
+            Read::Incomplete => return Err(reader::eof_error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_tests {
+        use crate::clojure_edn::read::ReadFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::StringReaderFn;
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn read_forms_one_after_another() {
+            let read_edn = ReadFn::new(Environment::clojure_core_environment());
+            let reader = StringReaderFn {}
+                .invoke(vec!["{:a [1\n 2]} :b\n\"rest\"".to_rc_value()])
+                .to_rc_value();
+            assert_eq!(
+                read("{:a [1 2]}"),
+                read_edn.invoke(vec![Rc::clone(&reader)])
+            );
+            assert_eq!(read(":b"), read_edn.invoke(vec![Rc::clone(&reader)]));
+            assert_eq!(read("\"rest\""), read_edn.invoke(vec![Rc::clone(&reader)]));
+            match read_edn.invoke(vec![reader]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn read_from_in() {
+            assert_eq!(
+                read("[1 2]"),
+                eval_str("(with-in-str \"[1 2]\" (clojure.edn/read))")
+            );
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crate::clojure_edn::reader::{self, Options};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.edn/read-string reads one form of EDN from a string, and nothing but EDN
+/// (read-string s)
+/// (read-string opts s)
+///
+/// opts may have :readers, a map of tag symbols to fns for the values they tag, :default,
+/// a fn given the tag and value of any other tag, and :eof, what an empty string reads as
+#[derive(Debug, Clone)]
+pub struct ReadStringFn {}
+impl ToValue for ReadStringFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReadStringFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (opts, s) = match args.as_slice() {
+            [s] => (Rc::new(Value::Nil), s),
+            [opts, s] => (Rc::clone(opts), s),
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        let s = match &**s {
+            Value::String(s) => s,
+            Value::Nil => return Value::Nil,
+            _ => return error_message::type_mismatch(TypeTag::String, s),
+        };
+        let read = Options::from_map(&opts).and_then(|options| {
+            Ok(match reader::read_string(s, &options)? {
+                Some(form) => form,
+                None => options.eof.map_or(Value::Nil, |eof| (*eof).clone()),
+            })
+        });
+        match read {
+            Ok(form) => form,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_string_tests {
+        use crate::clojure_edn::read_string::ReadStringFn;
+        use crate::clojure_edn::write_string::WriteStringFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::InstMsFn;
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn read_string(s: &str) -> Value {
+            ReadStringFn {}.invoke(vec![s.to_rc_value()])
+        }
+
+        #[test]
+        fn read_string_reads_edn() {
+            assert_eq!(
+                read("{:port 8080 :hosts [\"a\" \"b\"]}"),
+                read_string("{:port 8080, :hosts [\"a\" \"b\"]}")
+            );
+            assert_eq!(Value::Nil, read_string("  "));
+            let read_string = ReadStringFn {};
+            assert_eq!(
+                read(":none"),
+                read_string.invoke(vec![Rc::new(read("{:eof :none}")), "".to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn read_string_reads_wide_integers() {
+            let wide = read_string("99999999999");
+            assert_eq!(Value::I64(99999999999), wide);
+            assert_eq!(
+                Value::String("99999999999".to_string()),
+                WriteStringFn {}.invoke(vec![wide.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn read_string_with_readers() {
+            let opts = eval_str(
+                "{:readers {'point (fn [[x y]] {:x x :y y})}
+                  :default (fn [tag value] [tag value])}",
+            );
+            let read_string = ReadStringFn {};
+            assert_eq!(
+                read("[{:x 1 :y 2} [unknown/tag \"a\"]]"),
+                read_string.invoke(vec![
+                    Rc::new(opts),
+                    "[#point [1 2] #unknown/tag \"a\"]".to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn read_string_rejects_code() {
+            match read_string("#'clojure.core/println") {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+            match read_string("#point [1 2]") {
+                Value::Condition(condition) => {
+                    assert_eq!("No reader function for tag point", condition.message)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn read_string_inst_and_uuid() {
+            let inst = read_string("#inst \"2020-01-01T00:00:00Z\"");
+            assert!(matches!(inst, Value::Inst(_)));
+            assert_eq!(
+                Value::F64(1_577_836_800_000.0),
+                InstMsFn {}.invoke(vec![Rc::new(inst)])
+            );
+            assert!(matches!(
+                read_string("#uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\""),
+                Value::Uuid(_)
+            ));
+        }
+    }
+}
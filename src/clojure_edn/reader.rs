@@ -0,0 +1,539 @@
+//! Reading EDN (see https://github.com/edn-format/edn);  data, rather than code
+//!
+//! Unlike reader.rs, which reads code, nothing read here refers to or runs anything, so it's
+//! safe to read from somewhere we don't trust.  There's no #', syntax quoting, metadata or
+//! regex, and #_ and tagged literals are the only # forms there are;  any of the rest is an
+//! error, rather than something read some other way
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::inst::Inst;
+use crate::keyword::Keyword;
+use crate::maps::MapEntry;
+use crate::persistent_hash_map;
+use crate::persistent_hash_set::PersistentHashSet;
+use crate::persistent_list::ToPersistentList;
+use crate::persistent_list_map::IPersistentMap;
+use crate::persistent_vector::ToPersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::symbol::Symbol;
+use crate::type_tag::TypeTag;
+use crate::uuid::Uuid;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// How clojure.edn/read and read-string read, as given by their opts map
+#[derive(Default)]
+pub struct Options {
+    /// :readers, tag symbols to the fn each tagged value is given to
+    readers: Option<protocols::IPersistentMap>,
+    /// :default, given the tag and value of any tagged value that isn't in readers, and
+    /// isn't #inst or #uuid
+    default: Option<protocols::IFn>,
+    /// :eof, what's read when there's nothing left to read;  if it's not given, that's an
+    /// error for read, and nil for read-string
+    pub eof: Option<Rc<Value>>,
+}
+impl Options {
+    /// The options in opts, a map
+    pub fn from_map(opts: &Rc<Value>) -> Result<Options, Rc<Value>> {
+        if let Value::Nil = **opts {
+            return Ok(Options::default());
+        }
+        let opts = match opts.try_as_protocol::<protocols::IPersistentMap>() {
+            Some(opts) => opts,
+            None => {
+                return Err(Rc::new(error_message::type_mismatch(
+                    TypeTag::PersistentListMap,
+                    opts,
+                )))
+            }
+        };
+        let option = |name| match opts.get(&Keyword::intern(name).to_rc_value()) {
+            nil if matches!(*nil, Value::Nil) => None,
+            option => Some(option),
+        };
+        let readers = match option("readers") {
+            Some(readers) => match readers.try_as_protocol::<protocols::IPersistentMap>() {
+                Some(readers) => Some(readers),
+                None => {
+                    return Err(Rc::new(error_message::type_mismatch(
+                        TypeTag::PersistentListMap,
+                        &readers,
+                    )))
+                }
+            },
+            None => None,
+        };
+        let default = match option("default") {
+            Some(default) => match default.try_as_protocol::<protocols::IFn>() {
+                Some(default) => Some(default),
+                None => {
+                    return Err(Rc::new(error_message::type_mismatch(
+                        TypeTag::IFn,
+                        &default,
+                    )))
+                }
+            },
+            None => None,
+        };
+        Ok(Options {
+            readers,
+            default,
+            eof: option("eof"),
+        })
+    }
+}
+
+/// What's found reading some input
+#[derive(Debug, PartialEq)]
+pub enum Read<'a> {
+    /// A form, and the input left after it
+    Form(Rc<Value>, &'a str),
+    /// Only whitespace, comments and discarded forms
+    Nothing,
+    /// The start of a form that the input ends before the end of
+    Incomplete,
+}
+
+/// Reads the first form of input;  Err if it isn't EDN
+pub fn read<'a>(input: &'a str, options: &Options) -> Result<Read<'a>, Rc<Value>> {
+    let mut parser = Parser {
+        rest: input,
+        options,
+    };
+    match parser.next(None) {
+        Ok(Some(form)) => Ok(Read::Form(Rc::new(form), parser.rest)),
+        Ok(None) => Ok(Read::Nothing),
+        Err(Error::Incomplete) => Ok(Read::Incomplete),
+        Err(Error::Invalid(condition)) => Err(condition),
+    }
+}
+
+/// Reads the one form of input (or nothing, if there's only whitespace), as read-string does
+pub fn read_string(input: &str, options: &Options) -> Result<Option<Value>, Rc<Value>> {
+    match read(input, options)? {
+        Read::Form(form, _) => Ok(Some(Rc::unwrap_or_clone(form))),
+        Read::Nothing => Ok(None),
+        Read::Incomplete => Err(eof_error()),
+    }
+}
+
+pub(crate) fn eof_error() -> Rc<Value> {
+    Rc::new(error_message::condition(
+        ConditionKind::RuntimeException,
+        "EOF while reading",
+    ))
+}
+
+enum Error {
+    /// The input ended partway through a form
+    Incomplete,
+    Invalid(Rc<Value>),
+}
+fn invalid(message: impl Into<String>) -> Error {
+    Error::Invalid(Rc::new(error_message::condition(
+        ConditionKind::RuntimeException,
+        message,
+    )))
+}
+
+/// Characters a symbol, keyword or number can't have in it, as they start or end something
+/// else
+fn is_delimiter(ch: char) -> bool {
+    ch.is_whitespace() || "()[]{}\";,".contains(ch)
+}
+
+struct Parser<'a, 'o> {
+    /// What we've yet to read
+    rest: &'a str,
+    options: &'o Options,
+}
+impl Parser<'_, '_> {
+    /// The next form, or None if we reach close (which we read past) or, without one, the
+    /// end of the input
+    fn next(&mut self, close: Option<char>) -> Result<Option<Value>, Error> {
+        loop {
+            self.skip_whitespace();
+            let ch = match self.rest.chars().next() {
+                Some(ch) => ch,
+                None if close.is_some() => return Err(Error::Incomplete),
+                None => return Ok(None),
+            };
+            if Some(ch) == close {
+                self.rest = &self.rest[1..];
+                return Ok(None);
+            }
+            if let Some(rest) = self.rest.strip_prefix("#_") {
+                self.rest = rest;
+                self.form()?;
+                continue;
+            }
+            return self.form_starting(ch).map(Some);
+        }
+    }
+    /// The next form, which there has to be
+    fn form(&mut self) -> Result<Value, Error> {
+        match self.next(None)? {
+            Some(form) => Ok(form),
+            None => Err(Error::Incomplete),
+        }
+    }
+    /// Skips whitespace (commas included) and comments
+    fn skip_whitespace(&mut self) {
+        loop {
+            self.rest = self
+                .rest
+                .trim_start_matches(|ch: char| ch.is_whitespace() || ch == ',');
+            if !self.rest.starts_with(';') {
+                return;
+            }
+            self.rest = match self.rest.find('\n') {
+                Some(newline) => &self.rest[newline + 1..],
+                None => "",
+            };
+        }
+    }
+    /// Reads up to the next delimiter
+    fn token(&mut self) -> &str {
+        let end = self.rest.find(is_delimiter).unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        token
+    }
+
+    /// The form starting at ch, the next character
+    fn form_starting(&mut self, ch: char) -> Result<Value, Error> {
+        match ch {
+            '(' => {
+                self.rest = &self.rest[1..];
+                Ok(self.forms_until(')')?.into_list().to_value())
+            }
+            '[' => {
+                self.rest = &self.rest[1..];
+                Ok(self.forms_until(']')?.into_vector().to_value())
+            }
+            '{' => {
+                self.rest = &self.rest[1..];
+                self.map()
+            }
+            ')' | ']' | '}' => Err(invalid(format!("Unmatched delimiter: {}", ch))),
+            '"' => {
+                self.rest = &self.rest[1..];
+                self.string()
+            }
+            '\\' => Err(invalid(
+                "Characters aren't supported, as there's no character type",
+            )),
+            ':' => {
+                self.rest = &self.rest[1..];
+                let token = self.token();
+                if token.is_empty() || token.starts_with(':') {
+                    return Err(invalid(format!("Invalid keyword: :{}", token)));
+                }
+                match symbol(token)? {
+                    Value::Symbol(sym) => Ok(Keyword { sym }.to_value()),
+                    _ => Err(invalid(format!("Invalid keyword: :{}", token))),
+                }
+            }
+            '#' => self.dispatch(),
+            '\'' | '`' | '~' | '@' | '^' => {
+                Err(invalid(format!("{} is code, and not allowed in EDN", ch)))
+            }
+            _ => {
+                let token = self.token();
+                match token {
+                    "nil" => Ok(Value::Nil),
+                    "true" => Ok(Value::Boolean(true)),
+                    "false" => Ok(Value::Boolean(false)),
+                    _ if is_number(token) => number(token),
+                    _ => symbol(token),
+                }
+            }
+        }
+    }
+
+    /// The forms up to close
+    fn forms_until(&mut self, close: char) -> Result<Vec<Rc<Value>>, Error> {
+        let mut forms = vec![];
+        while let Some(form) = self.next(Some(close))? {
+            forms.push(Rc::new(form));
+        }
+        Ok(forms)
+    }
+
+    fn map(&mut self) -> Result<Value, Error> {
+        let forms = self.forms_until('}')?;
+        if forms.len() % 2 != 0 {
+            return Err(invalid("Map literal must contain an even number of forms"));
+        }
+        let mut entries: Vec<MapEntry> = vec![];
+        for entry in forms.chunks(2) {
+            if entries.iter().any(|read| read.key == entry[0]) {
+                return Err(invalid(format!("Duplicate key: {}", entry[0])));
+            }
+            entries.push(MapEntry {
+                key: Rc::clone(&entry[0]),
+                val: Rc::clone(&entry[1]),
+            });
+        }
+        Ok(persistent_hash_map::build_map(entries))
+    }
+
+    fn string(&mut self) -> Result<Value, Error> {
+        let mut string = String::new();
+        let mut chars = self.rest.char_indices();
+        loop {
+            let (i, ch) = chars.next().ok_or(Error::Incomplete)?;
+            match ch {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(Value::String(string));
+                }
+                '\\' => match chars.next().ok_or(Error::Incomplete)?.1 {
+                    't' => string.push('\t'),
+                    'r' => string.push('\r'),
+                    'n' => string.push('\n'),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    '\\' => string.push('\\'),
+                    '"' => string.push('"'),
+                    'u' => {
+                        let mut hex = String::new();
+                        for _ in 0..4 {
+                            hex.push(chars.next().ok_or(Error::Incomplete)?.1);
+                        }
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(ch) => string.push(ch),
+                            None => {
+                                return Err(invalid(format!("Invalid unicode escape: \\u{}", hex)))
+                            }
+                        }
+                    }
+                    escape => {
+                        return Err(invalid(format!(
+                            "Unsupported escape character: \\{}",
+                            escape
+                        )))
+                    }
+                },
+                _ => string.push(ch),
+            }
+        }
+    }
+
+    /// What follows a #
+    fn dispatch(&mut self) -> Result<Value, Error> {
+        self.rest = &self.rest[1..];
+        match self.rest.chars().next() {
+            None => Err(Error::Incomplete),
+            Some('{') => {
+                self.rest = &self.rest[1..];
+                let mut set = PersistentHashSet::default();
+                for form in self.forms_until('}')? {
+                    if set.contains(&form) {
+                        return Err(invalid(format!("Duplicate key: {}", form)));
+                    }
+                    set = set.conj(form);
+                }
+                Ok(set.to_value())
+            }
+            Some('#') => {
+                self.rest = &self.rest[1..];
+                match self.token() {
+                    "Inf" => Ok(Value::F64(f64::INFINITY)),
+                    "-Inf" => Ok(Value::F64(f64::NEG_INFINITY)),
+                    "NaN" => Ok(Value::F64(f64::NAN)),
+                    token => Err(invalid(format!("Unknown symbolic value: ##{}", token))),
+                }
+            }
+            Some(ch) if ch.is_alphabetic() => {
+                let tag = match symbol(self.token())? {
+                    Value::Symbol(tag) => tag,
+                    _ => return Err(invalid("Reader tag must be a symbol")),
+                };
+                let form = self.form()?;
+                self.tagged(tag, form)
+            }
+            Some(ch) => Err(invalid(format!("#{} is code, and not allowed in EDN", ch))),
+        }
+    }
+
+    /// What tag makes of form;  as its fn in our readers has it, if it's there
+    fn tagged(&self, tag: Symbol, form: Value) -> Result<Value, Error> {
+        let tag_value = Rc::new(Value::Symbol(tag.clone()));
+        let form = Rc::new(form);
+        if let Some(readers) = &self.options.readers {
+            let reader = readers.get(&tag_value);
+            if !matches!(*reader, Value::Nil) {
+                return match reader.try_as_protocol::<protocols::IFn>() {
+                    Some(reader) => invoked(reader.invoke(vec![form])),
+                    None => Err(Error::Invalid(Rc::new(error_message::type_mismatch(
+                        TypeTag::IFn,
+                        &reader,
+                    )))),
+                };
+            }
+        }
+        match (tag.has_ns(), tag.name(), &*form) {
+            (false, "inst", Value::String(timestamp)) => {
+                Inst::parse(timestamp).map(Value::Inst).map_err(invalid)
+            }
+            (false, "uuid", Value::String(uuid)) => {
+                Uuid::parse(uuid).map(Value::Uuid).map_err(invalid)
+            }
+            (false, "inst", _) | (false, "uuid", _) => {
+                Err(invalid(format!("#{} takes a string, not {}", tag, form)))
+            }
+            _ => match &self.options.default {
+                Some(default) => invoked(default.invoke(vec![tag_value, form])),
+                None => Err(invalid(format!("No reader function for tag {}", tag))),
+            },
+        }
+    }
+}
+
+/// What a reader fn gave back, unless it threw
+fn invoked(value: Value) -> Result<Value, Error> {
+    let value = Rc::new(value);
+    match value.non_tail() {
+        Some(interruption) => Err(Error::Invalid(interruption)),
+        None => Ok(Rc::try_unwrap(value).unwrap_or_else(|value| (*value).clone())),
+    }
+}
+
+/// Whether token starts as a number does, with a digit, after any sign
+fn is_number(token: &str) -> bool {
+    let unsigned = token.strip_prefix(['+', '-']).unwrap_or(token);
+    unsigned.starts_with(|ch: char| ch.is_ascii_digit())
+}
+fn number(token: &str) -> Result<Value, Error> {
+    let is_digits = |digits: &str| {
+        let digits = digits.strip_prefix(['+', '-']).unwrap_or(digits);
+        !digits.is_empty() && digits.chars().all(|ch| ch.is_ascii_digit())
+    };
+    let integer = token.strip_suffix('N').unwrap_or(token);
+    if is_digits(integer) {
+        // As an I32 if it fits, an I64 if it's too wide for that
+        return match (integer.parse(), integer.parse()) {
+            (Ok(int), _) => Ok(Value::I32(int)),
+            (_, Ok(int)) => Ok(Value::I64(int)),
+            _ => Err(invalid(format!("Integer out of range: {}", token))),
+        };
+    }
+    // f64's parser takes a few things EDN doesn't, like inf, but none starting with a digit
+    match token.strip_suffix('M').unwrap_or(token).parse() {
+        Ok(float) => Ok(Value::F64(float)),
+        Err(_) => Err(invalid(format!("Invalid number: {}", token))),
+    }
+}
+fn symbol(token: &str) -> Result<Value, Error> {
+    let symbol = match token.split_once('/') {
+        // / is itself a symbol, as is clojure.core//
+        _ if token == "/" => Symbol::intern("/"),
+        Some((ns, "/")) if !ns.is_empty() => Symbol::intern_with_ns(ns, "/"),
+        Some((ns, name)) if !ns.is_empty() && !name.is_empty() && !name.contains('/') => {
+            Symbol::intern_with_ns(ns, name)
+        }
+        Some(_) => return Err(invalid(format!("Invalid symbol: {}", token))),
+        None => Symbol::intern(token),
+    };
+    Ok(Value::Symbol(symbol))
+}
+
+#[cfg(test)]
+mod tests {
+    mod reader_tests {
+        use crate::clojure_edn::reader::{read, read_string, Options, Read};
+        use crate::inst::Inst;
+        use crate::repl::Repl;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn edn(input: &str) -> Value {
+            read_string(input, &Options::default()).unwrap().unwrap()
+        }
+        fn code(input: &str) -> Value {
+            Repl::read_string(input).unwrap()
+        }
+
+        #[test]
+        fn read_data() {
+            assert_eq!(Value::Nil, edn("nil"));
+            assert_eq!(Value::I32(-12), edn(" -12N "));
+            assert_eq!(Value::F64(1500.0), edn("1.5e3"));
+            assert_eq!(
+                Value::String("a\"\n\u{e9}".to_string()),
+                edn("\"a\\\"\\n\\u00e9\"")
+            );
+            assert_eq!(
+                code("[:a/b c/d (1 2) #{true false} {:x {\"y\" nil}} /]"),
+                edn("[:a/b c/d (1, 2) #{true false} {:x {\"y\" nil}} /]")
+            );
+        }
+
+        #[test]
+        fn skip_comments_and_discards() {
+            assert_eq!(code("[1 3]"), edn("; first\n[1 #_2 #_ #_ (a) b 3] ; last"));
+            assert_eq!(
+                None,
+                read_string(" #_1 ; nothing\n", &Options::default()).unwrap()
+            );
+        }
+
+        #[test]
+        fn read_the_first_form() {
+            assert_eq!(
+                Read::Form(Rc::new(Value::I32(1)), " {:b 2}"),
+                read("1 {:b 2}", &Options::default()).unwrap()
+            );
+            assert_eq!(
+                Read::Incomplete,
+                read("{:a [1", &Options::default()).unwrap()
+            );
+            assert_eq!(Read::Incomplete, read("\"a", &Options::default()).unwrap());
+        }
+
+        #[test]
+        fn read_built_in_tags() {
+            assert_eq!(
+                Value::Inst(Inst::from_millis(0)),
+                edn("#inst \"1970-01-01T00:00:00.000-00:00\"")
+            );
+            assert_eq!(
+                "#uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\"",
+                edn("#uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\"").to_string()
+            );
+        }
+
+        #[test]
+        fn reject_code() {
+            for input in &[
+                "'a",
+                "`a",
+                "~a",
+                "@a",
+                "^:m a",
+                "#'a",
+                "#(inc %)",
+                "#\"a\"",
+                "::a",
+                "\\a",
+                "#point [1 2]",
+                "{:a}",
+                "{:a 1 :a 2}",
+                "#{1 1}",
+                "1/2",
+                "99999999999999999999",
+                ")",
+            ] {
+                assert!(
+                    read(input, &Options::default()).is_err(),
+                    "{} should not read",
+                    input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::clojure_edn::writer;
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.edn/write
+/// (write x)
+/// (write x writer)
+///
+/// Writes x as EDN to writer, *out* if it's not given one
+#[derive(Debug, Clone)]
+pub struct WriteFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl WriteFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> WriteFn {
+        WriteFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for WriteFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WriteFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let out = match args.as_slice() {
+            [_] => stream::out(&self.enclosing_environment),
+            [_, writer] => match &**writer {
+                Value::Writer(writer) => Ok(writer.clone()),
+                _ => return error_message::type_mismatch(TypeTag::Writer, writer),
+            },
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        match out.and_then(|out| out.write_str(&writer::write_edn(&args[0])?)) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod write_tests {
+        use crate::clojure_edn::write::WriteFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{StrFn, StringWriterFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_to_a_writer() {
+            let write = WriteFn::new(Environment::clojure_core_environment());
+            let writer = StringWriterFn {}.invoke(vec![]).to_rc_value();
+            assert_eq!(
+                Value::Nil,
+                write.invoke(vec![Rc::new(read("{:a \"b\"}")), Rc::clone(&writer)])
+            );
+            assert_eq!(
+                Value::String("{:a \"b\"}".to_string()),
+                StrFn {}.invoke(vec![writer])
+            );
+        }
+
+        #[test]
+        fn write_to_out() {
+            assert_eq!(
+                Value::String("[nil]".to_string()),
+                eval_str("(with-out-str (clojure.edn/write [nil]))")
+            );
+        }
+    }
+}
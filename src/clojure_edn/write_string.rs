@@ -0,0 +1,64 @@
+use crate::clojure_edn::writer;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.edn/write-string
+/// (write-string x)
+///
+/// x as a string of EDN, that clojure.edn/read-string reads back as x;  it's an error to
+/// write anything EDN can't express, like fns or regexes
+#[derive(Debug, Clone)]
+pub struct WriteStringFn {}
+impl ToValue for WriteStringFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WriteStringFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match writer::write_edn(&args[0]) {
+            Ok(edn) => Value::String(edn),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod write_string_tests {
+        use crate::clojure_edn::read_string::ReadStringFn;
+        use crate::clojure_edn::write_string::WriteStringFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::AddFn;
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_string_round_trips() {
+            let write_string = WriteStringFn {};
+            assert_eq!(
+                Value::String("[1 \"two\" :three (four) #{}]".to_string()),
+                write_string.invoke(vec![Rc::new(read("[1 \"two\" :three (four) #{}]"))])
+            );
+            let x = read("{:a [1 2.5 nil] :b #{:c}}");
+            let written = write_string.invoke(vec![Rc::new(x.clone())]);
+            assert_eq!(x, ReadStringFn {}.invoke(vec![Rc::new(written)]));
+        }
+
+        #[test]
+        fn write_string_refuses_fns() {
+            let write_string = WriteStringFn {};
+            let fns = Value::PersistentVector(vec![AddFn {}.to_rc_value()].into_iter().collect());
+            match write_string.invoke(vec![Rc::new(fns)]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
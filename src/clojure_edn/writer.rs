@@ -0,0 +1,109 @@
+//! Writing EDN;  what the EDN reader reads back, or an error for anything it couldn't, rather
+//! than something that would fail (or be read as something else) on the other end
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::printer;
+use crate::seq;
+use crate::value::Value;
+use std::rc::Rc;
+
+/// x as EDN
+pub fn write_edn(x: &Rc<Value>) -> Result<String, Rc<Value>> {
+    let mut written = String::new();
+    write(x, &mut written)?;
+    Ok(written)
+}
+
+fn write(x: &Rc<Value>, written: &mut String) -> Result<(), Rc<Value>> {
+    match &**x {
+        Value::Nil => written.push_str("nil"),
+        Value::Boolean(boolean) => written.push_str(&boolean.to_string()),
+        Value::I32(int) => written.push_str(&int.to_string()),
+        Value::I64(int) => written.push_str(&int.to_string()),
+        Value::F64(float) => written.push_str(&printer::format_f64(*float)),
+        Value::String(string) => written.push_str(&printer::escape_string(string)),
+        Value::Keyword(keyword) => written.push_str(&keyword.to_string()),
+        Value::Symbol(symbol) => written.push_str(&symbol.to_string()),
+        Value::Inst(inst) => written.push_str(&inst.to_string()),
+        Value::Uuid(uuid) => written.push_str(&uuid.to_string()),
+        Value::PersistentList(_) | Value::LazySeq(_) | Value::Cons(_) => {
+            write_all("(", seq::iter(x), ")", written)?
+        }
+        Value::PersistentVector(vector) => {
+            write_all("[", vector.to_vec().into_iter(), "]", written)?
+        }
+        Value::PersistentHashSet(_) => write_all("#{", seq::iter(x), "}", written)?,
+        Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {
+            written.push('{');
+            for (i, entry) in seq::iter(x).enumerate() {
+                if i > 0 {
+                    written.push_str(", ");
+                }
+                match &*entry {
+                    Value::PersistentVector(entry) => {
+                        write_all("", entry.to_vec().into_iter(), "", written)?
+                    }
+                    _ => return Err(entry),
+                }
+            }
+            written.push('}');
+        }
+        Value::Condition(_) => return Err(Rc::clone(x)),
+        _ => {
+            return Err(Rc::new(error_message::condition(
+                ConditionKind::IllegalArgumentException,
+                format!("Cannot write {} as EDN: {}", x.type_tag(), x),
+            )))
+        }
+    }
+    Ok(())
+}
+
+fn write_all(
+    open: &str,
+    xs: impl Iterator<Item = Rc<Value>>,
+    close: &str,
+    written: &mut String,
+) -> Result<(), Rc<Value>> {
+    written.push_str(open);
+    for (i, x) in xs.enumerate() {
+        if i > 0 {
+            written.push(' ');
+        }
+        write(&x, written)?;
+    }
+    written.push_str(close);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod writer_tests {
+        use crate::clojure_edn::reader::{read_string, Options};
+        use crate::clojure_edn::writer::write_edn;
+        use crate::persistent_vector::ToPersistentVector;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_reads_back() {
+            let edn = "[{:s \"a\\\"b\\n\"} 1 2.0 ##Inf nil (x/y #{:k}) \
+                       #inst \"2020-01-02T03:04:05.006-00:00\" \
+                       #uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\"]";
+            let read = Rc::new(read_string(edn, &Options::default()).unwrap().unwrap());
+            let written = write_edn(&read).unwrap();
+            assert_eq!(edn, written);
+            assert_eq!(
+                *read,
+                read_string(&written, &Options::default()).unwrap().unwrap()
+            );
+        }
+
+        #[test]
+        fn refuse_what_is_not_edn() {
+            let pattern = Rc::new(Value::Pattern(regex::Regex::new("a").unwrap()));
+            let vector = vec![Rc::new(Value::I32(1)), pattern].into_vector();
+            assert!(write_edn(&Rc::new(vector.to_value())).is_err());
+        }
+    }
+}
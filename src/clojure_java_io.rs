@@ -0,0 +1,11 @@
+pub(crate) mod close;
+pub(crate) mod delete_file;
+pub(crate) mod directory_qmark_;
+pub(crate) mod encoding;
+pub(crate) mod exists_qmark_;
+pub(crate) mod file;
+pub(crate) mod file_qmark_;
+pub(crate) mod length;
+pub(crate) mod make_parents;
+pub(crate) mod reader;
+pub(crate) mod writer;
@@ -0,0 +1,35 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.java.io/close ; closes a reader or writer, as with-open does once it's done with it
+/// (close x)
+///
+/// What's been written to a writer is flushed first
+#[derive(Debug, Clone)]
+pub struct CloseFn {}
+impl ToValue for CloseFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CloseFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Reader(reader) => {
+                reader.close();
+                Value::Nil
+            }
+            Value::Writer(writer) => match writer.close() {
+                Ok(()) => Value::Nil,
+                Err(condition) => (*condition).clone(),
+            },
+            _ => error_message::type_mismatch(TypeTag::Writer, &args[0]),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::clojure_java_io::file;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::fs;
+use std::rc::Rc;
+
+/// clojure.java.io/delete-file ; deletes the file f, or the directory f if it's empty
+/// (delete-file f & [silently])
+///
+/// Gives back true, or if f couldn't be deleted, silently if that's truthy;  otherwise,
+/// throws an IOException
+#[derive(Debug, Clone)]
+pub struct DeleteFileFn {}
+impl ToValue for DeleteFileFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DeleteFileFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let path = match file::path(&args[0]) {
+            Ok(path) => path,
+            Err(condition) => return (*condition).clone(),
+        };
+        let deleted = if path.is_dir() {
+            fs::remove_dir(path)
+        } else {
+            fs::remove_file(path)
+        };
+        match (deleted, args.get(1)) {
+            (Ok(()), _) => Value::Boolean(true),
+            (Err(_), Some(silently)) if silently.is_truthy() => (**silently).clone(),
+            (Err(_), _) => error_message::condition(
+                ConditionKind::IOException,
+                format!("Couldn't delete {}", path.display()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod delete_file_tests {
+        use crate::clojure_java_io::delete_file::DeleteFileFn;
+        use crate::clojure_java_io::directory_qmark_::DirectoryFn;
+        use crate::clojure_java_io::exists_qmark_::ExistsFn;
+        use crate::clojure_java_io::file::FileFn;
+        use crate::clojure_java_io::file_qmark_::IsFileFn;
+        use crate::clojure_java_io::length::LengthFn;
+        use crate::clojure_java_io::make_parents::MakeParentsFn;
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::rust_core::SpitFn;
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn make_check_and_delete_files() {
+            let dir = std::env::temp_dir().join(format!("delete-file-test-{}", std::process::id()));
+            let file = FileFn {}
+                .invoke(vec![
+                    dir.to_string_lossy().to_rc_value(),
+                    "a".to_rc_value(),
+                    "b.txt".to_rc_value(),
+                ])
+                .to_rc_value();
+            let call = |f: &dyn IFn| f.invoke(vec![Rc::clone(&file)]);
+            assert_eq!(Value::Boolean(true), call(&MakeParentsFn {}));
+            assert_eq!(Value::Boolean(false), call(&MakeParentsFn {}));
+            assert_eq!(Value::Boolean(false), call(&ExistsFn {}));
+            assert_eq!(Value::I32(0), call(&LengthFn {}));
+            SpitFn {}.invoke(vec![Rc::clone(&file), "abc".to_rc_value()]);
+            assert_eq!(Value::Boolean(true), call(&IsFileFn {}));
+            assert_eq!(Value::Boolean(false), call(&DirectoryFn {}));
+            assert_eq!(Value::I32(3), call(&LengthFn {}));
+            let delete_file = DeleteFileFn {};
+            assert_eq!(Value::Boolean(true), call(&delete_file));
+            assert_eq!(
+                read(":silently"),
+                delete_file.invoke(vec![Rc::clone(&file), Rc::new(read(":silently"))])
+            );
+            match call(&delete_file) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IOException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
+}
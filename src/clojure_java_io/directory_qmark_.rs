@@ -0,0 +1,26 @@
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// clojure.java.io/directory? ; whether f is a directory
+/// (directory? f)
+#[derive(Debug, Clone)]
+pub struct DirectoryFn {}
+impl ToValue for DirectoryFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DirectoryFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match file::path(&args[0]) {
+            Ok(path) => Value::Boolean(path.is_dir()),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Text encodings, as :encoding names them for slurp, spit, reader and writer;  our strings
+//! are UTF-8, so anything else is translated as it's read or written
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::stream::{Reader, Writer};
+use crate::type_tag::TypeTag;
+use crate::value::Value;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    Latin1,
+    Ascii,
+}
+impl Encoding {
+    /// The encoding of name, as Java names it (any case);  Err if it's not one we know
+    pub fn named(name: &str) -> Result<Encoding, Rc<Value>> {
+        match name.to_ascii_uppercase().as_str() {
+            "UTF-8" | "UTF8" => Ok(Encoding::Utf8),
+            "ISO-8859-1" | "ISO8859-1" | "ISO8859_1" | "LATIN1" => Ok(Encoding::Latin1),
+            "US-ASCII" | "ASCII" => Ok(Encoding::Ascii),
+            _ => Err(Rc::new(error_message::condition(
+                ConditionKind::IOException,
+                format!("Unsupported encoding: {}", name),
+            ))),
+        }
+    }
+    /// The encoding given as :encoding in options, or UTF-8 if none is
+    pub fn from_options(options: &KeywordOptions) -> Result<Encoding, Rc<Value>> {
+        Encoding::from_option(options, "encoding")
+    }
+    /// The encoding given as :name in options, or UTF-8 if none is
+    pub fn from_option(options: &KeywordOptions, name: &str) -> Result<Encoding, Rc<Value>> {
+        match options.get(name).as_deref() {
+            None | Some(Value::Nil) => Ok(Encoding::Utf8),
+            Some(Value::String(encoding)) => Encoding::named(encoding),
+            Some(encoding) => Err(Rc::new(error_message::type_mismatch(
+                TypeTag::String,
+                encoding,
+            ))),
+        }
+    }
+    /// bytes as text;  anything that isn't valid in this encoding is read as U+FFFD, as
+    /// Java reads it
+    pub fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes).into_owned(),
+            Encoding::Latin1 => bytes.iter().map(|byte| *byte as char).collect(),
+            Encoding::Ascii => bytes
+                .iter()
+                .map(|byte| match byte {
+                    0..=127 => *byte as char,
+                    _ => char::REPLACEMENT_CHARACTER,
+                })
+                .collect(),
+        }
+    }
+    /// text as bytes;  anything that can't be written in this encoding is written as ?, as
+    /// Java writes it
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        let max = match self {
+            Encoding::Utf8 => return text.as_bytes().to_vec(),
+            Encoding::Latin1 => 255,
+            Encoding::Ascii => 127,
+        };
+        text.chars()
+            .map(|ch| match ch as u32 {
+                code if code <= max => code as u8,
+                _ => b'?',
+            })
+            .collect()
+    }
+    /// A reader of the text read, in this encoding
+    pub fn reader(self, read: impl Read + 'static) -> Reader {
+        match self {
+            Encoding::Utf8 => Reader::new(BufReader::new(read)),
+            _ => Reader::new(Decoder {
+                read,
+                encoding: self,
+                decoded: vec![],
+                consumed: 0,
+            }),
+        }
+    }
+    /// A writer of text to write, in this encoding
+    pub fn writer(self, write: impl Write + 'static) -> Writer {
+        match self {
+            Encoding::Utf8 => Writer::new(BufWriter::new(write)),
+            _ => Writer::new(Encoder {
+                write: BufWriter::new(write),
+                encoding: self,
+            }),
+        }
+    }
+}
+
+/// Reads bytes of text in an encoding as UTF-8;  only for encodings of a byte a character,
+/// so that any bytes read can be decoded as they are
+struct Decoder<R> {
+    read: R,
+    encoding: Encoding,
+    decoded: Vec<u8>,
+    /// How much of decoded has been read
+    consumed: usize,
+}
+impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let read = available.len().min(buf.len());
+        buf[..read].copy_from_slice(&available[..read]);
+        self.consume(read);
+        Ok(read)
+    }
+}
+impl<R: Read> BufRead for Decoder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.consumed == self.decoded.len() {
+            let mut bytes = [0; 8192];
+            let read = self.read.read(&mut bytes)?;
+            self.decoded = self.encoding.decode(&bytes[..read]).into_bytes();
+            self.consumed = 0;
+        }
+        Ok(&self.decoded[self.consumed..])
+    }
+    fn consume(&mut self, amount: usize) {
+        self.consumed += amount;
+    }
+}
+
+/// Writes UTF-8 text as bytes in an encoding;  it's only ever given whole strings, so never
+/// part of a character
+struct Encoder<W: Write> {
+    write: W,
+    encoding: Encoding,
+}
+impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write
+            .write_all(&self.encoding.encode(&String::from_utf8_lossy(buf)))?;
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.write.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod encoding_tests {
+        use crate::clojure_java_io::encoding::Encoding;
+
+        #[test]
+        fn decode_and_encode() {
+            assert_eq!("añ", Encoding::Latin1.decode(&[0x61, 0xf1]));
+            assert_eq!("a\u{fffd}", Encoding::Ascii.decode(&[0x61, 0xf1]));
+            assert_eq!(vec![0x61, 0xf1, b'?'], Encoding::Latin1.encode("añ😀"));
+            assert_eq!(vec![0x61, b'?'], Encoding::Ascii.encode("añ"));
+            assert_eq!("añ".as_bytes(), &Encoding::Utf8.encode("añ")[..]);
+        }
+
+        #[test]
+        fn read_in_encoding() {
+            let reader = Encoding::Latin1.reader(&[0x61, 0xf1, b'\n', 0x62][..]);
+            assert_eq!(Some("añ".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("b".to_string()), reader.read_line().unwrap());
+            assert_eq!(None, reader.read_line().unwrap());
+        }
+
+        #[test]
+        fn name_encodings() {
+            assert_eq!(Encoding::Utf8, Encoding::named("utf-8").unwrap());
+            assert_eq!(Encoding::Latin1, Encoding::named("ISO-8859-1").unwrap());
+            assert!(Encoding::named("EBCDIC").is_err());
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// clojure.java.io/file ; the file at path, and then under each of more in turn
+/// (file path & more)
+///
+/// Files are the strings of their paths, so that they can be given to anything taking one
+#[derive(Debug, Clone)]
+pub struct FileFn {}
+impl ToValue for FileFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for FileFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match file(&args) {
+            Ok(file) => Value::String(file.to_string_lossy().into_owned()),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// The path of the file x is
+pub(crate) fn path(x: &Value) -> Result<&Path, Rc<Value>> {
+    match x {
+        Value::String(path) => Ok(Path::new(path)),
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::String, x))),
+    }
+}
+
+/// The file at the first of paths, and then under each of the rest in turn, as file gives it;
+/// Err if any of the rest isn't relative
+pub(crate) fn file(paths: &[Rc<Value>]) -> Result<PathBuf, Rc<Value>> {
+    let mut file = path(&paths[0])?.to_path_buf();
+    for child in &paths[1..] {
+        let child = path(child)?;
+        if child.has_root() {
+            return Err(Rc::new(error_message::condition(
+                ConditionKind::IllegalArgumentException,
+                format!("{} is not a relative path", child.display()),
+            )));
+        }
+        file.push(child);
+    }
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    mod file_tests {
+        use crate::clojure_java_io::file::FileFn;
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::test_util::read_args;
+        use crate::value::Value;
+
+        #[test]
+        fn file_under_parents() {
+            let file_fn = FileFn {};
+            let file = std::path::Path::new("a").join("b").join("c.txt");
+            assert_eq!(
+                Value::String(file.to_string_lossy().into_owned()),
+                file_fn.invoke(read_args(&[r#""a""#, r#""b""#, r#""c.txt""#]))
+            );
+            match file_fn.invoke(read_args(&[r#""a""#, r#""/b""#])) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IllegalArgumentException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
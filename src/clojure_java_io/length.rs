@@ -0,0 +1,35 @@
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::convert::TryFrom;
+use std::fs;
+use std::rc::Rc;
+
+/// clojure.java.io/length ; the size of the file f in bytes, or 0 if there's no such file
+/// (length f)
+///
+/// A float if it's too big for our integers
+#[derive(Debug, Clone)]
+pub struct LengthFn {}
+impl ToValue for LengthFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for LengthFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        let path = match file::path(&args[0]) {
+            Ok(path) => path,
+            Err(condition) => return (*condition).clone(),
+        };
+        let length = fs::metadata(path).map_or(0, |metadata| metadata.len());
+        match i32::try_from(length) {
+            Ok(length) => Value::I32(length),
+            Err(_) => Value::F64(length as f64),
+        }
+    }
+}
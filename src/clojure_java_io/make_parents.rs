@@ -0,0 +1,37 @@
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::fs;
+use std::rc::Rc;
+
+/// clojure.java.io/make-parents ; makes any directories missing above the file at path (and
+/// then under more, as file has it)
+/// (make-parents path & more)
+///
+/// Gives back whether any were made
+#[derive(Debug, Clone)]
+pub struct MakeParentsFn {}
+impl ToValue for MakeParentsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for MakeParentsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        let file = match file::file(&args) {
+            Ok(file) => file,
+            Err(condition) => return (*condition).clone(),
+        };
+        // As Java's mkdirs, we only say whether we made them, not why we couldn't
+        Value::Boolean(match file.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() && !parent.exists() => {
+                fs::create_dir_all(parent).is_ok()
+            }
+            _ => false,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::clojure_java_io::encoding::Encoding;
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::{self, Reader};
+use crate::value::{ToValue, Value};
+use std::fs::File;
+use std::rc::Rc;
+
+/// clojure.java.io/reader ; a reader of the file x, to be closed once it's been read
+/// (reader x & opts)
+///
+/// The only option is :encoding, the name of the encoding the file is in, UTF-8 by default.
+/// A reader is given back as it is
+#[derive(Debug, Clone)]
+pub struct ReaderFn {}
+impl ToValue for ReaderFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReaderFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match reader(&args[0], &args[1..]) {
+            Ok(reader) => Value::Reader(reader),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// A reader of x, a file or reader, as reader gives it
+pub(crate) fn reader(x: &Value, options: &[Rc<Value>]) -> Result<Reader, Rc<Value>> {
+    let encoding = Encoding::from_options(&KeywordOptions::new(options)?)?;
+    if let Value::Reader(reader) = x {
+        return Ok(reader.clone());
+    }
+    let path = file::path(x)?;
+    let file = File::open(path).map_err(|error| stream::file_error(path, error))?;
+    Ok(encoding.reader(file))
+}
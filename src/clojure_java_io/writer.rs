@@ -0,0 +1,113 @@
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::clojure_java_io::encoding::Encoding;
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::{self, Writer};
+use crate::value::{ToValue, Value};
+use std::fs::OpenOptions;
+use std::rc::Rc;
+
+/// clojure.java.io/writer ; a writer to the file x, to be closed once it's been written
+/// (writer x & opts)
+///
+/// With :append true, what's written goes after what the file already has, rather than
+/// in place of it;  :encoding names the encoding it's written in, UTF-8 by default.  A writer
+/// is given back as it is
+#[derive(Debug, Clone)]
+pub struct WriterFn {}
+impl ToValue for WriterFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WriterFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match writer(&args[0], &args[1..]) {
+            Ok(writer) => Value::Writer(writer),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// A writer to x, a file or writer, as writer gives it
+pub(crate) fn writer(x: &Value, options: &[Rc<Value>]) -> Result<Writer, Rc<Value>> {
+    let options = KeywordOptions::new(options)?;
+    let encoding = Encoding::from_options(&options)?;
+    if let Value::Writer(writer) = x {
+        return Ok(writer.clone());
+    }
+    let append = options
+        .get("append")
+        .is_some_and(|append| append.is_truthy());
+    let path = file::path(x)?;
+    let file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .map_err(|error| stream::file_error(path, error))?;
+    Ok(encoding.writer(file))
+}
+
+#[cfg(test)]
+mod tests {
+    mod writer_tests {
+        use crate::clojure_java_io::close::CloseFn;
+        use crate::clojure_java_io::reader::ReaderFn;
+        use crate::clojure_java_io::writer::WriterFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, LineSeqFn, PrintStringFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn write_then_read_back() {
+            let file = std::env::temp_dir().join(format!("writer-test-{}.txt", std::process::id()));
+            let path = file.to_string_lossy().to_rc_value();
+            let print_string = PrintStringFn::new(Environment::clojure_core_environment());
+            let write = |args: Vec<Rc<Value>>, strings: &[&str]| {
+                let writer = WriterFn {}.invoke(args).to_rc_value();
+                for string in strings {
+                    print_string.invoke(vec![Rc::clone(&writer), string.to_rc_value()]);
+                }
+                CloseFn {}.invoke(vec![writer]);
+            };
+            write(vec![Rc::clone(&path)], &["a\n", "b"]);
+            write(
+                vec![
+                    Rc::clone(&path),
+                    Rc::new(read(":append")),
+                    Rc::new(read("true")),
+                ],
+                &["c\n"],
+            );
+            let reader = ReaderFn {}.invoke(vec![path]).to_rc_value();
+            let lines = LineSeqFn {}.invoke(vec![Rc::clone(&reader)]);
+            assert_eq!(
+                read(r#"["a" "bc"]"#),
+                IntoFn {}.invoke(vec![Rc::new(read("[]")), lines.to_rc_value()])
+            );
+            CloseFn {}.invoke(vec![reader]);
+            std::fs::remove_file(&file).unwrap();
+        }
+
+        #[test]
+        fn closed_by_with_open() {
+            assert_eq!(
+                read(":caught"),
+                eval_str(
+                    r#"(let [r (with-open [r (string-reader "a")] r)]
+                         (try (binding [*in* r] (read-line))
+                              (catch java.io.IOException e :caught)))"#
+                )
+            );
+        }
+    }
+}
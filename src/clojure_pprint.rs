@@ -0,0 +1,3 @@
+pub(crate) mod dispatch;
+pub(crate) mod doc;
+pub(crate) mod pprint;
@@ -0,0 +1,339 @@
+use crate::clojure_pprint::doc::Doc;
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::printer;
+use crate::seq;
+use crate::stream;
+use crate::symbol::Symbol;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// How a list is laid out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Style {
+    /// As data, its elements aligned after the (
+    Data,
+    /// As code, if it's a call;  a body indented under the form it's in, and the
+    /// arguments of anything else aligned after the first
+    Code,
+}
+
+/// How many things on the first line of each form with a body, before the body
+/// (None for up to its parameter vector, as for fns)
+const HEADERS: &[(&str, Option<usize>)] = &[
+    ("def", Some(1)),
+    ("defmulti", Some(1)),
+    ("ns", Some(1)),
+    ("if", Some(1)),
+    ("if-not", Some(1)),
+    ("if-let", Some(1)),
+    ("when", Some(1)),
+    ("when-not", Some(1)),
+    ("when-let", Some(1)),
+    ("while", Some(1)),
+    ("let", Some(1)),
+    ("loop", Some(1)),
+    ("binding", Some(1)),
+    ("with-redefs", Some(1)),
+    ("doseq", Some(1)),
+    ("dotimes", Some(1)),
+    ("for", Some(1)),
+    ("catch", Some(2)),
+    ("do", Some(0)),
+    ("try", Some(0)),
+    ("finally", Some(0)),
+    ("cond", Some(0)),
+    ("fn", None),
+    ("defn", None),
+    ("defmacro", None),
+    ("defmethod", None),
+];
+/// Those of HEADERS whose first header is a vector of bindings, laid out in pairs
+const BINDING_FORMS: &[&str] = &[
+    "let",
+    "loop",
+    "binding",
+    "with-redefs",
+    "if-let",
+    "when-let",
+    "doseq",
+    "dotimes",
+    "for",
+];
+
+/// x laid out as pprint does in style, honoring *print-length* and *print-level*
+pub fn value_doc(environment: &Environment, x: &Rc<Value>, style: Style) -> Result<Doc, Rc<Value>> {
+    let builder = DocBuilder {
+        environment,
+        style,
+        readably: printer::print_readably(environment),
+        length: printer::print_limit(environment, "*print-length*"),
+        level: printer::print_limit(environment, "*print-level*"),
+    };
+    builder.doc(x, 0)
+}
+
+/// How wide pprint tries to keep its lines, *print-right-margin*
+pub fn right_margin(environment: &Environment) -> usize {
+    match &*environment.get(&Symbol::intern_with_ns(
+        "clojure.pprint",
+        "*print-right-margin*",
+    )) {
+        Value::I32(margin) if *margin > 0 => *margin as usize,
+        _ => 72,
+    }
+}
+
+/// Writes x to *out*, laid out in style within *print-right-margin*
+fn write_pretty(environment: &Environment, args: &[Rc<Value>], style: Style) -> Value {
+    if args.len() != 1 {
+        return error_message::wrong_arg_count(1, args.len());
+    }
+    match value_doc(environment, &args[0], style).and_then(|doc| {
+        stream::out(environment)
+            .and_then(|out| out.write_str(&doc.pretty(right_margin(environment))))
+    }) {
+        Ok(()) => Value::Nil,
+        Err(condition) => (*condition).clone(),
+    }
+}
+
+/// clojure.pprint/simple-dispatch
+/// (simple-dispatch x)
+///
+/// Writes x to *out*, laid out as data;  what pprint does by default
+#[derive(Debug, Clone)]
+pub struct SimpleDispatchFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl SimpleDispatchFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> SimpleDispatchFn {
+        SimpleDispatchFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for SimpleDispatchFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SimpleDispatchFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        write_pretty(&self.enclosing_environment, &args, Style::Data)
+    }
+}
+
+/// clojure.pprint/code-dispatch
+/// (code-dispatch x)
+///
+/// Writes x to *out*, laid out as code
+#[derive(Debug, Clone)]
+pub struct CodeDispatchFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl CodeDispatchFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> CodeDispatchFn {
+        CodeDispatchFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for CodeDispatchFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CodeDispatchFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        write_pretty(&self.enclosing_environment, &args, Style::Code)
+    }
+}
+
+struct DocBuilder<'a> {
+    environment: &'a Environment,
+    style: Style,
+    readably: bool,
+    length: Option<usize>,
+    level: Option<usize>,
+}
+impl DocBuilder<'_> {
+    /// x, depth collections in
+    fn doc(&self, x: &Rc<Value>, depth: usize) -> Result<Doc, Rc<Value>> {
+        if !printer::is_collection(x) || printer::has_print_method(self.environment, x) {
+            return Ok(Doc::text(printer::print_str(
+                self.environment,
+                x,
+                self.readably,
+            )?));
+        }
+        if self.level.is_some_and(|level| depth >= level) {
+            return Ok(Doc::text("#"));
+        }
+        match &**x {
+            Value::PersistentVector(vector) => {
+                let elements = self.docs(vector.to_vec().into_iter(), depth)?;
+                Ok(bracket("[", elements, "]"))
+            }
+            Value::PersistentHashSet(_) => Ok(bracket("#{", self.docs(seq::iter(x), depth)?, "}")),
+            Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {
+                let mut entries = vec![];
+                for (i, entry) in seq::iter(x).enumerate() {
+                    if self.length == Some(i) {
+                        entries.push(Doc::text("..."));
+                        break;
+                    }
+                    let entry = match &*entry {
+                        Value::PersistentVector(entry) => entry.to_vec(),
+                        _ => return Err(entry),
+                    };
+                    let entry = self.docs(entry.into_iter(), depth)?;
+                    entries.push(Doc::group(Doc::join(entry, Doc::Line)));
+                }
+                Ok(Doc::group(Doc::Concat(vec![
+                    Doc::text("{"),
+                    Doc::align(Doc::Concat(vec![
+                        Doc::join(entries, Doc::Concat(vec![Doc::text(","), Doc::Line])),
+                        Doc::text("}"),
+                    ])),
+                ])))
+            }
+            // Lists and seqs
+            _ => match self.style {
+                Style::Code => self.code_doc(x, depth),
+                Style::Data => Ok(bracket("(", self.docs(seq::iter(x), depth)?, ")")),
+            },
+        }
+    }
+
+    /// The docs of each of xs (each depth + 1 in), up to *print-length* of them
+    fn docs(
+        &self,
+        xs: impl Iterator<Item = Rc<Value>>,
+        depth: usize,
+    ) -> Result<Vec<Doc>, Rc<Value>> {
+        let mut docs = vec![];
+        for (i, x) in xs.enumerate() {
+            if self.length == Some(i) {
+                docs.push(Doc::text("..."));
+                break;
+            }
+            if let Value::Condition(_) = &*x {
+                return Err(x);
+            }
+            docs.push(self.doc(&x, depth + 1)?);
+        }
+        Ok(docs)
+    }
+
+    /// list laid out as code
+    fn code_doc(&self, list: &Rc<Value>, depth: usize) -> Result<Doc, Rc<Value>> {
+        // Past *print-length* forms, we needn't look
+        let forms = seq::iter(list)
+            .take(self.length.map_or(usize::MAX, |length| length + 1))
+            .collect::<Vec<Rc<Value>>>();
+        let head = match forms.first().map(|head| &**head) {
+            Some(Value::Symbol(head)) => head.clone(),
+            _ => return Ok(bracket("(", self.docs(forms.into_iter(), depth)?, ")")),
+        };
+        // 'x, @x and #'x, as they were read
+        let reader_macro = match head.name() {
+            "quote" => Some("'"),
+            "deref" => Some("@"),
+            "var" => Some("#'"),
+            _ => None,
+        };
+        if let (Some(reader_macro), 2) = (reader_macro, forms.len()) {
+            return Ok(Doc::Concat(vec![
+                Doc::text(reader_macro),
+                self.doc(&forms[1], depth + 1)?,
+            ]));
+        }
+
+        let mut docs = self.docs(forms.iter().cloned(), depth)?;
+        let rest = docs.split_off(1);
+        let head_doc = docs.pop().unwrap();
+        let header_count =
+            HEADERS
+                .iter()
+                .find(|(name, _)| *name == head.name())
+                .map(|(_, header_count)| match header_count {
+                    Some(header_count) => *header_count,
+                    None => forms
+                        .iter()
+                        .skip(1)
+                        .position(|form| matches!(**form, Value::PersistentVector(_)))
+                        .map_or(0, |params| params + 1),
+                });
+        let header_count = match header_count {
+            Some(header_count) => header_count.min(rest.len()),
+            // A call, its arguments aligned after the first
+            None if rest.is_empty() => {
+                return Ok(Doc::Concat(vec![Doc::text("("), head_doc, Doc::text(")")]))
+            }
+            None => {
+                return Ok(Doc::group(Doc::Concat(vec![
+                    Doc::text("("),
+                    head_doc,
+                    Doc::text(" "),
+                    Doc::align(Doc::Concat(vec![
+                        Doc::join(rest, Doc::Line),
+                        Doc::text(")"),
+                    ])),
+                ])))
+            }
+        };
+
+        let mut rest = rest.into_iter();
+        let mut laid_out = vec![Doc::text("("), head_doc];
+        for i in 0..header_count {
+            let header = match (i, &*forms[1]) {
+                (0, Value::PersistentVector(bindings)) if BINDING_FORMS.contains(&head.name()) => {
+                    rest.next();
+                    self.bindings_doc(&bindings.to_vec(), depth + 1)?
+                }
+                _ => rest.next().unwrap(),
+            };
+            laid_out.push(Doc::text(" "));
+            laid_out.push(header);
+        }
+        let body = rest.collect::<Vec<Doc>>();
+        let body = if head.name() == "cond" {
+            body.chunks(2)
+                .map(|clause| Doc::group(Doc::join(clause.to_vec(), Doc::Line)))
+                .collect()
+        } else {
+            body
+        };
+        for form in body {
+            laid_out.push(Doc::nest(2, Doc::Concat(vec![Doc::Line, form])));
+        }
+        laid_out.push(Doc::text(")"));
+        Ok(Doc::group(Doc::align(Doc::Concat(laid_out))))
+    }
+
+    /// A vector of bindings, laid out a pair to a line if it doesn't fit on one
+    fn bindings_doc(&self, bindings: &[Rc<Value>], depth: usize) -> Result<Doc, Rc<Value>> {
+        if self.level.is_some_and(|level| depth >= level) {
+            return Ok(Doc::text("#"));
+        }
+        let docs = self.docs(bindings.iter().cloned(), depth)?;
+        let pairs = docs
+            .chunks(2)
+            .map(|pair| Doc::group(Doc::join(pair.to_vec(), Doc::Line)))
+            .collect();
+        Ok(bracket("[", pairs, "]"))
+    }
+}
+
+/// elements between open and close, aligned after open if they don't fit on one line
+fn bracket(open: &str, elements: Vec<Doc>, close: &str) -> Doc {
+    Doc::group(Doc::Concat(vec![
+        Doc::text(open),
+        Doc::align(Doc::Concat(vec![
+            Doc::join(elements, Doc::Line),
+            Doc::text(close),
+        ])),
+    ]))
+}
@@ -0,0 +1,175 @@
+//! The layout engine under pprint;  Wadler's "prettier printer", with Oppen's lookahead
+//!
+//! A Doc is text with places it may break a line.  Each group is laid out flat, its lines
+//! as spaces, if all of it (and whatever follows it up to the next possible break) fits in
+//! what's left of the line;  otherwise each of its lines breaks, and its groups are tried
+//! in turn
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub enum Doc {
+    Text(String),
+    /// A space if laid out flat, otherwise a newline indented to the current indentation
+    Line,
+    /// Doc, indented by this much more than the current indentation
+    Nest(usize, Rc<Doc>),
+    /// Doc, indented to the column it starts at
+    Align(Rc<Doc>),
+    /// Doc, laid out flat if it fits
+    Group(Rc<Doc>),
+    Concat(Vec<Doc>),
+}
+impl Doc {
+    pub fn text(text: impl Into<String>) -> Doc {
+        Doc::Text(text.into())
+    }
+    pub fn nest(indent: usize, doc: Doc) -> Doc {
+        Doc::Nest(indent, Rc::new(doc))
+    }
+    pub fn align(doc: Doc) -> Doc {
+        Doc::Align(Rc::new(doc))
+    }
+    pub fn group(doc: Doc) -> Doc {
+        Doc::Group(Rc::new(doc))
+    }
+    /// docs, with separator between each
+    pub fn join(docs: Vec<Doc>, separator: Doc) -> Doc {
+        let mut joined = Vec::with_capacity(docs.len() * 2);
+        for (i, doc) in docs.into_iter().enumerate() {
+            if i > 0 {
+                joined.push(separator.clone());
+            }
+            joined.push(doc);
+        }
+        Doc::Concat(joined)
+    }
+
+    /// Lays us out in width columns (or as near as we can;  text is never broken)
+    pub fn pretty(&self, width: usize) -> String {
+        let mut laid_out = String::new();
+        let mut column = 0;
+        let mut pending: Vec<(usize, Mode, &Doc)> = vec![(0, Mode::Break, self)];
+        while let Some((indent, mode, doc)) = pending.pop() {
+            match doc {
+                Doc::Text(text) => {
+                    laid_out.push_str(text);
+                    column = match text.rfind('\n') {
+                        Some(newline) => text[newline + 1..].chars().count(),
+                        None => column + text.chars().count(),
+                    };
+                }
+                Doc::Line => match mode {
+                    Mode::Flat => {
+                        laid_out.push(' ');
+                        column += 1;
+                    }
+                    Mode::Break => {
+                        laid_out.push('\n');
+                        laid_out.extend(std::iter::repeat_n(' ', indent));
+                        column = indent;
+                    }
+                },
+                Doc::Nest(more, doc) => pending.push((indent + more, mode, doc)),
+                Doc::Align(doc) => pending.push((column, mode, doc)),
+                Doc::Group(doc) => {
+                    let mode = match mode {
+                        Mode::Flat => Mode::Flat,
+                        Mode::Break if fits(width as isize - column as isize, doc, &pending) => {
+                            Mode::Flat
+                        }
+                        Mode::Break => Mode::Break,
+                    };
+                    pending.push((indent, mode, doc));
+                }
+                Doc::Concat(docs) => {
+                    pending.extend(docs.iter().rev().map(|doc| (indent, mode, doc)));
+                }
+            }
+        }
+        laid_out
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+/// Whether doc laid out flat, and then what's pending up to its first break, fits in width
+fn fits(mut width: isize, doc: &Doc, pending: &[(usize, Mode, &Doc)]) -> bool {
+    let mut checking = vec![(Mode::Flat, doc)];
+    let mut pending = pending.iter().rev();
+    loop {
+        if width < 0 {
+            return false;
+        }
+        let (mode, doc) = match checking.pop() {
+            Some(next) => next,
+            None => match pending.next() {
+                Some((_, mode, doc)) => (*mode, *doc),
+                None => return true,
+            },
+        };
+        match doc {
+            Doc::Text(text) => match text.find('\n') {
+                Some(newline) => return width >= text[..newline].chars().count() as isize,
+                None => width -= text.chars().count() as isize,
+            },
+            Doc::Line => match mode {
+                Mode::Flat => width -= 1,
+                Mode::Break => return true,
+            },
+            Doc::Nest(_, doc) | Doc::Align(doc) | Doc::Group(doc) => checking.push((mode, doc)),
+            Doc::Concat(docs) => checking.extend(docs.iter().rev().map(|doc| (mode, doc))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod doc_tests {
+        use crate::clojure_pprint::doc::Doc;
+
+        fn list(items: &[&str]) -> Doc {
+            Doc::group(Doc::Concat(vec![
+                Doc::text("("),
+                Doc::align(Doc::Concat(vec![
+                    Doc::join(
+                        items.iter().map(|item| Doc::text(*item)).collect(),
+                        Doc::Line,
+                    ),
+                    Doc::text(")"),
+                ])),
+            ]))
+        }
+
+        #[test]
+        fn flat_if_it_fits() {
+            assert_eq!("(a b c)", list(&["a", "b", "c"]).pretty(7));
+        }
+
+        #[test]
+        fn breaks_aligned_if_it_does_not() {
+            assert_eq!("(a\n b\n c)", list(&["a", "b", "c"]).pretty(6));
+        }
+
+        #[test]
+        fn counts_what_follows_a_group() {
+            let doc = Doc::Concat(vec![Doc::text("["), list(&["a", "b"]), Doc::text("]")]);
+            assert_eq!("[(a b)]", doc.pretty(7));
+            assert_eq!("[(a\n  b)]", doc.pretty(6));
+        }
+
+        #[test]
+        fn nests_relative_to_indentation() {
+            let doc = Doc::group(Doc::Concat(vec![
+                Doc::text("(when x"),
+                Doc::nest(2, Doc::Concat(vec![Doc::Line, Doc::text("y")])),
+                Doc::text(")"),
+            ]));
+            assert_eq!("(when x y)", doc.pretty(80));
+            assert_eq!("(when x\n  y)", doc.pretty(8));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::stream::{self, Writer};
+use crate::symbol::Symbol;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use crate::var::Var;
+use std::rc::Rc;
+
+/// x as pprint prints it, by *print-pprint-dispatch*;  without the newline after it
+pub fn pprint_str(environment: &Environment, x: &Rc<Value>) -> Result<String, Rc<Value>> {
+    let dispatch = environment.get(&Symbol::intern_with_ns(
+        "clojure.pprint",
+        "*print-pprint-dispatch*",
+    ));
+    let dispatch = match dispatch.try_as_protocol::<protocols::IFn>() {
+        Some(dispatch) => dispatch,
+        None => {
+            return Err(Rc::new(error_message::type_mismatch(
+                TypeTag::IFn,
+                &dispatch,
+            )))
+        }
+    };
+    let out = match environment.resolve_var(&Symbol::intern_with_ns("clojure.core", "*out*")) {
+        Some(out) => out,
+        None => {
+            return Err(Rc::new(error_message::custom(
+                "Unable to resolve var: clojure.core/*out*",
+            )))
+        }
+    };
+    // The dispatch fn writes to *out*, so we have it write to us
+    let pprinted = Writer::buffer();
+    Var::push_thread_bindings(vec![(out, Rc::new(Value::Writer(pprinted.clone())))])?;
+    let dispatched = Rc::new(dispatch.invoke(vec![Rc::clone(x)]));
+    Var::pop_thread_bindings();
+    if let Some(interruption) = dispatched.non_tail() {
+        return Err(interruption);
+    }
+    Ok(pprinted.contents().unwrap_or_default())
+}
+
+/// clojure.pprint/pprint
+/// (pprint object)
+/// (pprint object writer)
+///
+/// Prints object, laid out to fit within *print-right-margin* where it can, followed by a
+/// newline;  to *out*, unless given a writer
+#[derive(Debug, Clone)]
+pub struct PprintFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PprintFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PprintFn {
+        PprintFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PprintFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PprintFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (x, writer) = match args.as_slice() {
+            [x] => (x, stream::out(&self.enclosing_environment)),
+            [x, writer] => match &**writer {
+                Value::Writer(writer) => (x, Ok(writer.clone())),
+                _ => return error_message::type_mismatch(TypeTag::Writer, writer),
+            },
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        match writer.and_then(|writer| {
+            let pprinted = pprint_str(&self.enclosing_environment, x)?;
+            writer.write_str(&pprinted)?;
+            writer.write_str("\n")
+        }) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod pprint_tests {
+        use crate::clojure_pprint::pprint::PprintFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{StrFn, StringWriterFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        /// What pprint writes of source, unevaluated, to a writer of its own
+        fn pprinted_to_writer(source: &str) -> Value {
+            let pprint = PprintFn::new(Environment::clojure_core_environment());
+            let writer = StringWriterFn {}.invoke(vec![]).to_rc_value();
+            assert_eq!(
+                Value::Nil,
+                pprint.invoke(vec![Rc::new(read(source)), Rc::clone(&writer)])
+            );
+            StrFn {}.invoke(vec![writer])
+        }
+
+        /// What source, evaluated, prints;  for pprint with vars bound
+        fn pprinted(source: &str) -> String {
+            match eval_str(&format!("(with-out-str {})", source)) {
+                Value::String(pprinted) => pprinted,
+                value => panic!("expected a string, got {}", value),
+            }
+        }
+
+        #[test]
+        fn pprint_on_one_line_if_it_fits() {
+            assert_eq!(
+                Value::String("[{:a [1 2 3]} \"c\"]\n".to_string()),
+                pprinted_to_writer("[{:a [1 2 3]} \"c\"]")
+            );
+        }
+
+        #[test]
+        fn pprint_within_right_margin() {
+            assert_eq!(
+                "{:name \"pprint\",\n :tags [:layout :printing],\n :deps {:a 1, :b 2}}\n",
+                pprinted(
+                    "(binding [clojure.pprint/*print-right-margin* 30]
+                       (clojure.pprint/pprint
+                         {:name \"pprint\" :tags [:layout :printing] :deps {:a 1 :b 2}}))"
+                )
+            );
+            assert_eq!(
+                "[[1 2 3]\n [4 5 6]]\n",
+                pprinted(
+                    "(binding [clojure.pprint/*print-right-margin* 10]
+                       (clojure.pprint/pprint [[1 2 3] [4 5 6]]))"
+                )
+            );
+        }
+
+        #[test]
+        fn pprint_honors_print_length_and_level() {
+            assert_eq!(
+                "(0 1 2 ...)\n",
+                pprinted("(binding [*print-length* 3] (clojure.pprint/pprint (range)))")
+            );
+            assert_eq!(
+                "[1 [2 #]]\n",
+                pprinted("(binding [*print-level* 2] (clojure.pprint/pprint [1 [2 [3 [4]]]]))")
+            );
+        }
+
+        #[test]
+        fn pprint_code() {
+            assert_eq!(
+                "(defn f [x]\n  (let [y (inc x)\n        z (dec x)]\n    (+ y z)))\n",
+                pprinted(
+                    "(binding [clojure.pprint/*print-right-margin* 20]
+                       (clojure.pprint/with-pprint-dispatch clojure.pprint/code-dispatch
+                         (clojure.pprint/pprint
+                           '(defn f [x] (let [y (inc x) z (dec x)] (+ y z))))))"
+                )
+            );
+            assert_eq!(
+                "(defn\n f\n [x]\n (let\n  [y\n   (inc x)\n   z\n   (dec x)]\n  (+ y z)))\n",
+                pprinted(
+                    "(binding [clojure.pprint/*print-right-margin* 20]
+                       (clojure.pprint/pprint
+                         '(defn f [x] (let [y (inc x) z (dec x)] (+ y z)))))"
+                )
+            );
+        }
+
+        #[test]
+        fn pprint_to_writer() {
+            assert_eq!(
+                Value::String("[:a]\n".to_string()),
+                pprinted_to_writer("[:a]")
+            );
+            let pprint = PprintFn::new(Environment::clojure_core_environment());
+            match pprint.invoke(vec![Rc::new(read("[:a]")), Rc::new(read("1"))]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
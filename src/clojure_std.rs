@@ -1,3 +1,5 @@
 pub(crate) mod env;
+pub(crate) mod process;
+pub(crate) mod shell;
 pub(crate) mod thread;
 pub(crate) mod time;
@@ -0,0 +1,203 @@
+use crate::clojure_java_io::encoding::Encoding;
+use crate::clojure_std::shell;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::process::Process;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::process::Stdio;
+use std::rc::Rc;
+
+/// clojure.java.shell/process ; starts a program, without waiting for it
+/// (process program & args-and-options)
+///
+/// Arguments and options are as for sh, but for :in;  what the program is given and gives
+/// back goes through its stdin, stdout and stderr, as it runs
+#[derive(Debug, Clone)]
+pub struct ProcessFn {}
+impl ToValue for ProcessFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ProcessFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match process(&args) {
+            Ok(process) => Value::Process(Rc::new(process)),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+fn process(args: &[Rc<Value>]) -> Result<Process, Rc<Value>> {
+    let (mut command, options) = shell::command(args)?;
+    let in_enc = Encoding::from_option(&options, "in-enc")?;
+    let out_enc = Encoding::from_option(&options, "out-enc")?;
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = shell::spawn(&mut command)?;
+    // All three were piped, so they're there to be taken
+    let stdin = in_enc.writer(child.stdin.take().unwrap());
+    let stdout = out_enc.reader(child.stdout.take().unwrap());
+    let stderr = out_enc.reader(child.stderr.take().unwrap());
+    Ok(Process::new(child, stdin, stdout, stderr))
+}
+
+/// The process p is;  Err if it isn't one
+fn as_process(p: &Value) -> Result<&Process, Rc<Value>> {
+    match p {
+        Value::Process(process) => Ok(process),
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::Process, p))),
+    }
+}
+
+/// clojure.java.shell/stdin ; a writer to the standard input of process p
+/// (stdin p)
+///
+/// Closing it is how the program is told there's no more to read
+#[derive(Debug, Clone)]
+pub struct StdinFn {}
+impl ToValue for StdinFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for StdinFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match as_process(&args[0]) {
+            Ok(process) => Value::Writer(process.stdin().clone()),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// clojure.java.shell/stdout ; a reader of the standard output of process p
+/// (stdout p)
+#[derive(Debug, Clone)]
+pub struct StdoutFn {}
+impl ToValue for StdoutFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for StdoutFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match as_process(&args[0]) {
+            Ok(process) => Value::Reader(process.stdout().clone()),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// clojure.java.shell/stderr ; a reader of the standard error of process p
+/// (stderr p)
+#[derive(Debug, Clone)]
+pub struct StderrFn {}
+impl ToValue for StderrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for StderrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match as_process(&args[0]) {
+            Ok(process) => Value::Reader(process.stderr().clone()),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// clojure.java.shell/wait ; waits for process p to exit, giving back its exit code
+/// (wait p)
+#[derive(Debug, Clone)]
+pub struct WaitFn {}
+impl ToValue for WaitFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WaitFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match as_process(&args[0]).and_then(Process::wait) {
+            Ok(exit) => Value::I32(exit),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// clojure.java.shell/destroy ; kills process p, if it's still running
+/// (destroy p)
+#[derive(Debug, Clone)]
+pub struct DestroyFn {}
+impl ToValue for DestroyFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DestroyFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match as_process(&args[0]).and_then(Process::destroy) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod process_tests {
+        use crate::clojure_java_io::close::CloseFn;
+        use crate::clojure_std::process::{DestroyFn, ProcessFn, StdinFn, StdoutFn, WaitFn};
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, LineSeqFn, PrintStringFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn stream_through_process() {
+            let p = ProcessFn {}.invoke(read_args(&[r#""cat""#])).to_rc_value();
+            let stdin = StdinFn {}.invoke(vec![Rc::clone(&p)]).to_rc_value();
+            let print_string = PrintStringFn::new(Environment::clojure_core_environment());
+            print_string.invoke(vec![Rc::clone(&stdin), "a\nb\n".to_rc_value()]);
+            CloseFn {}.invoke(vec![stdin]);
+            let stdout = StdoutFn {}.invoke(vec![Rc::clone(&p)]).to_rc_value();
+            let lines = LineSeqFn {}.invoke(vec![stdout]);
+            assert_eq!(
+                read(r#"["a" "b"]"#),
+                IntoFn {}.invoke(vec![Rc::new(read("[]")), lines.to_rc_value()])
+            );
+            assert_eq!(Value::I32(0), WaitFn {}.invoke(vec![p]));
+        }
+
+        #[test]
+        fn destroy_process() {
+            let p = ProcessFn {}
+                .invoke(read_args(&[r#""sleep""#, r#""10""#]))
+                .to_rc_value();
+            DestroyFn {}.invoke(vec![Rc::clone(&p)]);
+            assert_eq!(Value::I32(137), WaitFn {}.invoke(vec![p]));
+        }
+    }
+}
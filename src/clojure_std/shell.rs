@@ -0,0 +1,264 @@
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::clojure_java_io::encoding::Encoding;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::process;
+use crate::seq;
+use crate::stream;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Child, Command, Stdio};
+use std::rc::Rc;
+
+/// clojure.java.shell/sh ; runs a program, waiting for it to exit
+/// (sh program & args-and-options)
+///
+/// Arguments are the strings after program, up to the first option;  options are
+/// * :in, a string or reader, what the program is given as its standard input
+/// * :in-enc, the encoding :in is given in, UTF-8 by default
+/// * :out-enc, the encoding the program writes in, UTF-8 by default
+/// * :env, a map of the program's environment variables, in place of ours
+/// * :dir, the directory the program is run in, in place of ours
+///
+/// Gives back {:exit exit-code :out standard-output :err standard-error}
+#[derive(Debug, Clone)]
+pub struct ShFn {}
+impl ToValue for ShFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ShFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match sh(&args) {
+            Ok(result) => result,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+fn sh(args: &[Rc<Value>]) -> Result<Value, Rc<Value>> {
+    let (mut command, options) = command(args)?;
+    let in_enc = Encoding::from_option(&options, "in-enc")?;
+    let out_enc = Encoding::from_option(&options, "out-enc")?;
+    let input = match options.get("in").as_deref() {
+        None | Some(Value::Nil) => None,
+        Some(Value::String(input)) => Some(in_enc.encode(input)),
+        Some(Value::Reader(input)) => Some(in_enc.encode(&input.read_to_string()?)),
+        Some(input) => {
+            return Err(Rc::new(error_message::type_mismatch(
+                TypeTag::String,
+                input,
+            )))
+        }
+    };
+    command
+        .stdin(match input {
+            Some(_) => Stdio::piped(),
+            None => Stdio::null(),
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    let mut child = spawn(&mut command)?;
+    // Written from another thread, so that the program isn't left unable to write its output
+    // while we're still writing its input
+    let writing = match (input, child.stdin.take()) {
+        (Some(input), Some(mut stdin)) => Some(std::thread::spawn(move || stdin.write_all(&input))),
+        _ => None,
+    };
+    let output = child.wait_with_output().map_err(stream::io_error)?;
+    if let Some(writing) = writing {
+        // The program may well exit without reading all it's given;  that's not our error
+        let _ = writing.join();
+    }
+    Ok(PersistentListMap::Empty
+        .assoc(
+            Keyword::intern("err").to_rc_value(),
+            Rc::new(Value::String(out_enc.decode(&output.stderr))),
+        )
+        .assoc(
+            Keyword::intern("out").to_rc_value(),
+            Rc::new(Value::String(out_enc.decode(&output.stdout))),
+        )
+        .assoc(
+            Keyword::intern("exit").to_rc_value(),
+            Rc::new(Value::I32(process::exit_code(output.status))),
+        )
+        .to_value())
+}
+
+/// The command args ask to be run, as sh and process take them, and the options given after
+/// its arguments;  :env and :dir already applied to it
+pub(crate) fn command(args: &[Rc<Value>]) -> Result<(Command, KeywordOptions<'_>), Rc<Value>> {
+    let strings = args
+        .iter()
+        .take_while(|arg| matches!(&***arg, Value::String(_)))
+        .map(|arg| arg.to_string())
+        .collect::<Vec<String>>();
+    let (program, program_args) = match strings.split_first() {
+        Some(program) => program,
+        None => {
+            return Err(Rc::new(error_message::type_mismatch(
+                TypeTag::String,
+                &args[0],
+            )))
+        }
+    };
+    let options = KeywordOptions::new(&args[strings.len()..])?;
+    let mut command = Command::new(program);
+    command.args(program_args);
+    match options.get("dir").as_deref() {
+        None | Some(Value::Nil) => {}
+        Some(Value::String(dir)) => {
+            command.current_dir(Path::new(dir));
+        }
+        Some(dir) => return Err(Rc::new(error_message::type_mismatch(TypeTag::String, dir))),
+    }
+    if let Some(env) = options
+        .get("env")
+        .filter(|env| !matches!(**env, Value::Nil))
+    {
+        command.env_clear();
+        for entry in seq::iter(&env) {
+            let (key, value) = match &*entry {
+                Value::PersistentVector(entry) => {
+                    let mut entry = entry.to_vec().into_iter();
+                    (entry.next().unwrap(), entry.next().unwrap())
+                }
+                Value::Condition(_) => return Err(entry),
+                _ => {
+                    return Err(Rc::new(error_message::type_mismatch(
+                        TypeTag::PersistentListMap,
+                        &env,
+                    )))
+                }
+            };
+            let key = match &*key {
+                Value::Keyword(keyword) => keyword.name().to_string(),
+                Value::Symbol(symbol) => symbol.name().to_string(),
+                key => key.to_string(),
+            };
+            command.env(key, value.to_string());
+        }
+    }
+    Ok((command, options))
+}
+
+/// Starts the program command runs;  Err, as Java has it, if it can't be
+pub(crate) fn spawn(command: &mut Command) -> Result<Child, Rc<Value>> {
+    command.spawn().map_err(|error| {
+        Rc::new(error_message::condition(
+            ConditionKind::IOException,
+            format!(
+                "Cannot run program \"{}\": {}",
+                command.get_program().to_string_lossy(),
+                stream::described(&error)
+            ),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod shell_tests {
+        use crate::clojure_std::shell::ShFn;
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::rust_core::GetFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        /// What sh gives under key, run with args
+        fn sh(args: &[&str], key: &str) -> Value {
+            let result = ShFn {}.invoke(read_args(args)).to_rc_value();
+            GetFn {}.invoke(vec![result, Rc::new(read(key))])
+        }
+
+        #[test]
+        fn sh_gives_exit_out_and_err() {
+            let args = [r#""sh""#, r#""-c""#, r#""echo out; echo err >&2; exit 3""#];
+            assert_eq!(Value::I32(3), sh(&args, ":exit"));
+            assert_eq!(Value::String("out\n".to_string()), sh(&args, ":out"));
+            assert_eq!(Value::String("err\n".to_string()), sh(&args, ":err"));
+        }
+
+        #[test]
+        fn sh_with_in_env_and_dir() {
+            assert_eq!(
+                Value::String("a\nb".to_string()),
+                sh(&[r#""cat""#, ":in", r#""a\nb""#], ":out")
+            );
+            assert_eq!(
+                Value::String("1 2\n".to_string()),
+                sh(
+                    &[
+                        r#""sh""#,
+                        r#""-c""#,
+                        r#""echo $A $B""#,
+                        ":env",
+                        r#"{:A 1 "B" "2"}"#
+                    ],
+                    ":out"
+                )
+            );
+            let dir = std::env::temp_dir().canonicalize().unwrap();
+            assert_eq!(
+                Value::String(format!("{}\n", dir.display())),
+                sh(
+                    &[
+                        r#""pwd""#,
+                        ":dir",
+                        &Value::String(dir.display().to_string()).to_string_explicit()
+                    ],
+                    ":out"
+                )
+            );
+        }
+
+        #[test]
+        fn sh_in_encodings() {
+            assert_eq!(
+                Value::String("ñ".to_string()),
+                sh(
+                    &[
+                        r#""cat""#,
+                        ":in",
+                        r#""ñ""#,
+                        ":in-enc",
+                        r#""ISO-8859-1""#,
+                        ":out-enc",
+                        r#""ISO-8859-1""#
+                    ],
+                    ":out"
+                )
+            );
+            assert_eq!(
+                Value::String("\u{fffd}".to_string()),
+                sh(
+                    &[r#""cat""#, ":in", r#""ñ""#, ":in-enc", r#""ISO-8859-1""#],
+                    ":out"
+                )
+            );
+        }
+
+        #[test]
+        fn cannot_run_what_is_not_there() {
+            let sh = ShFn {};
+            match sh.invoke(read_args(&[r#""no-such-program-here""#])) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IOException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -1,10 +1,18 @@
 pub(crate) mod blank_qmark_;
+pub(crate) mod capitalize;
 pub(crate) mod ends_with_qmark_;
+pub(crate) mod escape;
 pub(crate) mod includes_qmark_;
+pub(crate) mod index_of;
 pub(crate) mod join;
+pub(crate) mod last_index_of;
 pub(crate) mod lower_case;
+pub(crate) mod re_quote_replacement;
+pub(crate) mod replace;
+pub(crate) mod replace_first;
 pub(crate) mod reverse;
 pub(crate) mod split;
+pub(crate) mod split_lines;
 pub(crate) mod starts_with_qmark_;
 pub(crate) mod trim;
 pub(crate) mod trim_newline;
@@ -0,0 +1,67 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::error_message;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/capitalize ; s with its first character upper case, and the rest lower case
+#[derive(Debug, Clone)]
+pub struct CapitalizeFn {}
+impl ToValue for CapitalizeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CapitalizeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::String(s) => {
+                let mut chars = s.chars();
+                match chars.next() {
+                    Some(first) => Value::String(
+                        first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+                    ),
+                    None => Value::String(String::new()),
+                }
+            }
+            a => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod capitalize_tests {
+        use crate::clojure_string::capitalize::CapitalizeFn;
+        use crate::ifn::IFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn capitalize(s: &str) -> Value {
+            CapitalizeFn {}.invoke(vec![Rc::new(Value::String(String::from(s)))])
+        }
+
+        #[test]
+        fn capitalize_string() {
+            assert_eq!(
+                Value::String(String::from("Hello world")),
+                capitalize("hELLO WORLD")
+            );
+            assert_eq!(Value::String(String::from("")), capitalize(""));
+        }
+
+        #[test]
+        fn capitalize_unicode() {
+            assert_eq!(Value::String(String::from("Éclair")), capitalize("éCLAIR"));
+            assert_eq!(
+                Value::String(String::from("SStrasse")),
+                capitalize("ßTRASSE")
+            );
+            assert_eq!(Value::String(String::from("Οδος")), capitalize("ΟΔΟΣ"));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::error_message;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/escape ; s with each character replaced by what cmap gives for it, if
+/// anything
+/// (escape [s cmap])
+///
+/// cmap is a map or fn, given each character as a string of one
+#[derive(Debug, Clone)]
+pub struct EscapeFn {}
+impl ToValue for EscapeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for EscapeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let s = match &*args[0] {
+            Value::String(s) => s,
+            a => return error_message::type_mismatch(TypeTag::String, a),
+        };
+        let cmap = match args[1].try_as_protocol::<protocols::IFn>() {
+            Some(cmap) => cmap,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[1]),
+        };
+        let mut escaped = String::new();
+        for ch in s.chars() {
+            let replacement = cmap.invoke(vec![Rc::new(Value::String(ch.to_string()))]);
+            match replacement {
+                Value::Nil => escaped.push(ch),
+                Value::String(replacement) => escaped.push_str(&replacement),
+                Value::Condition(_) => return replacement,
+                replacement => escaped.push_str(&replacement.to_string()),
+            }
+        }
+        Value::String(escaped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod escape_tests {
+        use crate::clojure_string::escape::EscapeFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::repl::Repl;
+        use crate::value::{Evaluable, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn escape_with_map() {
+            let escape = EscapeFn {};
+            let cmap =
+                Repl::read_string("{\"<\" \"&lt;\" \">\" \"&gt;\" \"é\" \"&eacute;\"}").unwrap();
+            assert_eq!(
+                Value::String(String::from("&lt;&eacute;!&gt;")),
+                escape.invoke(vec![
+                    Rc::new(Value::String(String::from("<é!>"))),
+                    Rc::new(cmap)
+                ])
+            );
+        }
+
+        #[test]
+        fn escape_with_fn() {
+            let environment = Environment::clojure_core_environment();
+            assert_eq!(
+                Value::String(String::from("a\\-b\\-😀")),
+                Repl::read_string(
+                    "(clojure.string/escape \"a-b-😀\" (fn [c] (if (= c \"-\") \"\\\\-\")))"
+                )
+                .unwrap()
+                .eval(environment)
+            );
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::error_message;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/index-of ; the index of the first value in s, from from-index on, or nil
+/// (index-of [s value] [s value from-index])
+///
+/// Indexes count characters, rather than bytes;  a character being a string of one
+#[derive(Debug, Clone)]
+pub struct IndexOfFn {}
+impl ToValue for IndexOfFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for IndexOfFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 && args.len() != 3 {
+            return error_message::wrong_varg_count(&[2, 3], args.len());
+        }
+        let from = match args.get(2).map(|from| &**from) {
+            None => 0,
+            Some(Value::I32(from)) => (*from).max(0) as usize,
+            Some(from) => return error_message::type_mismatch(TypeTag::I32, from),
+        };
+        match (&*args[0], &*args[1]) {
+            (Value::String(s), Value::String(value)) => {
+                let start = byte_index(s, from);
+                match s[start..].find(value.as_str()) {
+                    Some(found) => Value::I32(char_index(s, start + found) as i32),
+                    None => Value::Nil,
+                }
+            }
+            (Value::String(_), b) => error_message::type_mismatch(TypeTag::String, b),
+            (a, _) => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+/// The byte index of the char_index-th character of s, or the end of s if it's past it
+pub(crate) fn byte_index(s: &str, char_index: usize) -> usize {
+    s.char_indices()
+        .nth(char_index)
+        .map_or(s.len(), |(byte_index, _)| byte_index)
+}
+/// How many characters of s there are before byte_index
+pub(crate) fn char_index(s: &str, byte_index: usize) -> usize {
+    s[..byte_index].chars().count()
+}
+
+#[cfg(test)]
+mod tests {
+    mod index_of_tests {
+        use crate::clojure_string::index_of::IndexOfFn;
+        use crate::ifn::IFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn args(s: &str, value: &str, from: Option<i32>) -> Vec<Rc<Value>> {
+            let mut args = vec![
+                Rc::new(Value::String(String::from(s))),
+                Rc::new(Value::String(String::from(value))),
+            ];
+            args.extend(from.map(|from| Rc::new(Value::I32(from))));
+            args
+        }
+
+        #[test]
+        fn index_of() {
+            let index_of = IndexOfFn {};
+            assert_eq!(Value::I32(2), index_of.invoke(args("abcabc", "ca", None)));
+            assert_eq!(Value::Nil, index_of.invoke(args("abcabc", "x", None)));
+            assert_eq!(Value::I32(0), index_of.invoke(args("abc", "", None)));
+        }
+
+        #[test]
+        fn index_of_from_index() {
+            let index_of = IndexOfFn {};
+            assert_eq!(Value::I32(4), index_of.invoke(args("abcabc", "b", Some(2))));
+            assert_eq!(
+                Value::I32(1),
+                index_of.invoke(args("abcabc", "b", Some(-5)))
+            );
+            assert_eq!(Value::Nil, index_of.invoke(args("abcabc", "b", Some(10))));
+        }
+
+        #[test]
+        fn index_of_counts_characters() {
+            let index_of = IndexOfFn {};
+            assert_eq!(Value::I32(3), index_of.invoke(args("ñé😀x😀", "x", None)));
+            assert_eq!(
+                Value::I32(4),
+                index_of.invoke(args("ñé😀x😀", "😀", Some(3)))
+            );
+        }
+    }
+}
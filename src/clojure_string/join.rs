@@ -1,10 +1,9 @@
 use crate::ifn::IFn;
-use crate::iterable::Iterable;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 use crate::error_message;
-use crate::protocol::ProtocolCastable;
+use crate::seq;
 
 /// clojure.string/join ; joins a coll of items together as a string
 /// (join
@@ -34,16 +33,19 @@ impl IFn for JoinFn {
         } else {
             args.get(1)
         };
-        if let Some(iterable) = coll.unwrap().try_as_protocol::<Iterable>() {
-            Value::String(
-                iterable
+        let coll = coll.unwrap();
+        if !seq::is_seqable(coll) {
+            return Value::String(String::from(""));
+        }
+        match seq::values(coll) {
+            Ok(values) => Value::String(
+                values
                     .iter()
                     .map(|x| x.to_string())
                     .collect::<Vec<std::string::String>>()
                     .join(&separator),
-            )
-        } else {
-            Value::String(String::from(""))
+            ),
+            Err(condition) => (*condition).clone(),
         }
     }
 }
@@ -55,6 +57,7 @@ mod tests {
         use crate::ifn::IFn;
         use crate::persistent_list::PersistentList;
         use crate::persistent_vector::PersistentVector;
+        use crate::test_util::{is_boom, seq_throwing_at_2};
         use crate::value::Value;
         use std::rc::Rc;
 
@@ -141,5 +144,15 @@ mod tests {
                 join.invoke(args)
             );
         }
+
+        #[test]
+        fn join_throws_what_working_out_a_seq_does() {
+            let join = JoinFn {};
+            let args = vec![
+                Rc::new(Value::String(String::from(","))),
+                seq_throwing_at_2(),
+            ];
+            assert!(is_boom(&join.invoke(args)));
+        }
     }
 }
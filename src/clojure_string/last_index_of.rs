@@ -0,0 +1,110 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::clojure_string::index_of::{byte_index, char_index};
+use crate::error_message;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/last-index-of ; the index of the last value in s, starting at or before
+/// from-index, or nil
+/// (last-index-of [s value] [s value from-index])
+///
+/// Indexes count characters, rather than bytes;  a character being a string of one
+#[derive(Debug, Clone)]
+pub struct LastIndexOfFn {}
+impl ToValue for LastIndexOfFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for LastIndexOfFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 && args.len() != 3 {
+            return error_message::wrong_varg_count(&[2, 3], args.len());
+        }
+        let from = match args.get(2).map(|from| &**from) {
+            None => usize::MAX,
+            Some(Value::I32(from)) if *from < 0 => return Value::Nil,
+            Some(Value::I32(from)) => *from as usize,
+            Some(from) => return error_message::type_mismatch(TypeTag::I32, from),
+        };
+        match (&*args[0], &*args[1]) {
+            (Value::String(s), Value::String(value)) => {
+                // Matches starting by from end by here;  any ending in a character ends
+                // before it too
+                let mut end = (byte_index(s, from) + value.len()).min(s.len());
+                while !s.is_char_boundary(end) {
+                    end -= 1;
+                }
+                match s[..end].rfind(value.as_str()) {
+                    Some(found) => Value::I32(char_index(s, found) as i32),
+                    None => Value::Nil,
+                }
+            }
+            (Value::String(_), b) => error_message::type_mismatch(TypeTag::String, b),
+            (a, _) => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod last_index_of_tests {
+        use crate::clojure_string::last_index_of::LastIndexOfFn;
+        use crate::ifn::IFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn args(s: &str, value: &str, from: Option<i32>) -> Vec<Rc<Value>> {
+            let mut args = vec![
+                Rc::new(Value::String(String::from(s))),
+                Rc::new(Value::String(String::from(value))),
+            ];
+            args.extend(from.map(|from| Rc::new(Value::I32(from))));
+            args
+        }
+
+        #[test]
+        fn last_index_of() {
+            let last_index_of = LastIndexOfFn {};
+            assert_eq!(
+                Value::I32(3),
+                last_index_of.invoke(args("abcabc", "ab", None))
+            );
+            assert_eq!(Value::I32(1), last_index_of.invoke(args("aaa", "aa", None)));
+            assert_eq!(Value::Nil, last_index_of.invoke(args("abcabc", "x", None)));
+            assert_eq!(Value::I32(3), last_index_of.invoke(args("abc", "", None)));
+        }
+
+        #[test]
+        fn last_index_of_from_index() {
+            let last_index_of = LastIndexOfFn {};
+            assert_eq!(
+                Value::I32(0),
+                last_index_of.invoke(args("abcabc", "ab", Some(2)))
+            );
+            assert_eq!(
+                Value::I32(3),
+                last_index_of.invoke(args("abcabc", "ab", Some(3)))
+            );
+            assert_eq!(
+                Value::Nil,
+                last_index_of.invoke(args("abcabc", "ab", Some(-1)))
+            );
+        }
+
+        #[test]
+        fn last_index_of_counts_characters() {
+            let last_index_of = LastIndexOfFn {};
+            assert_eq!(
+                Value::I32(4),
+                last_index_of.invoke(args("😀é😀x😀", "😀", None))
+            );
+            assert_eq!(
+                Value::I32(2),
+                last_index_of.invoke(args("😀é😀x😀", "😀", Some(3)))
+            );
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::error_message;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/re-quote-replacement ; replacement with its \ and $ escaped, so that
+/// replace with a regex puts it in as it is, rather than as group references
+#[derive(Debug, Clone)]
+pub struct ReQuoteReplacementFn {}
+impl ToValue for ReQuoteReplacementFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReQuoteReplacementFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::String(replacement) => {
+                let mut quoted = String::new();
+                for ch in replacement.chars() {
+                    if ch == '\\' || ch == '$' {
+                        quoted.push('\\');
+                    }
+                    quoted.push(ch);
+                }
+                Value::String(quoted)
+            }
+            a => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_quote_replacement_tests {
+        use crate::clojure_string::re_quote_replacement::ReQuoteReplacementFn;
+        use crate::clojure_string::replace::ReplaceFn;
+        use crate::ifn::IFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        #[test]
+        fn re_quote_replacement() {
+            let quote = ReQuoteReplacementFn {};
+            let quoted = quote.invoke(vec![Rc::new(Value::String(String::from(r"$1 \ é")))]);
+            assert_eq!(Value::String(String::from(r"\$1 \\ é")), quoted);
+            assert_eq!(
+                Value::String(String::from(r"$1 \ é")),
+                ReplaceFn {}.invoke(vec![
+                    Rc::new(Value::String(String::from("x"))),
+                    Rc::new(Value::Pattern(regex::Regex::new("(x)").unwrap())),
+                    Rc::new(quoted)
+                ])
+            );
+        }
+    }
+}
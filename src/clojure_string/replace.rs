@@ -0,0 +1,281 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::matcher;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/replace ; s with every match replaced
+/// (replace [s match replacement])
+///
+/// match and replacement are strings (a character being a string of one), or match is a
+/// regex, and replacement either a string, where $1 is what the first group matched, ${name}
+/// what the group name matched and \$ a $, or a fn given each match as re-groups has it, for
+/// the string to replace it with
+#[derive(Debug, Clone)]
+pub struct ReplaceFn {}
+impl ToValue for ReplaceFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReplaceFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        replace(&args, None)
+    }
+}
+
+/// s (args being s, match and replacement) with its first limit matches replaced, or all
+/// of them without one;  replace and replace-first
+pub(crate) fn replace(args: &[Rc<Value>], limit: Option<usize>) -> Value {
+    if args.len() != 3 {
+        return error_message::wrong_arg_count(3, args.len());
+    }
+    let s = match &*args[0] {
+        Value::String(s) => s,
+        a => return error_message::type_mismatch(TypeTag::String, a),
+    };
+    let replaced = match (&*args[1], &*args[2]) {
+        (Value::String(found), Value::String(replacement)) => Ok(match limit {
+            Some(limit) => s.replacen(found.as_str(), replacement, limit),
+            None => s.replace(found.as_str(), replacement),
+        }),
+        (Value::String(_), replacement) => {
+            return error_message::type_mismatch(TypeTag::String, replacement)
+        }
+        (Value::Pattern(re), Value::String(replacement)) => {
+            replace_each(s, re, limit, |captures, replaced| {
+                expand(replacement, re, captures, replaced)
+            })
+        }
+        (Value::Pattern(re), _) => match args[2].try_as_protocol::<protocols::IFn>() {
+            Some(f) => replace_each(s, re, limit, |captures, replaced| {
+                let replacement = Rc::new(f.invoke(vec![Rc::new(groups(captures))]));
+                if let Some(interruption) = replacement.non_tail() {
+                    return Err(interruption);
+                }
+                match &*replacement {
+                    Value::String(replacement) => replaced.push_str(replacement),
+                    _ => {
+                        return Err(Rc::new(error_message::type_mismatch(
+                            TypeTag::String,
+                            &replacement,
+                        )))
+                    }
+                }
+                Ok(())
+            }),
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[2]),
+        },
+        (found, _) => return error_message::type_mismatch(TypeTag::Pattern, found),
+    };
+    match replaced {
+        Ok(replaced) => Value::String(replaced),
+        Err(condition) => (*condition).clone(),
+    }
+}
+
+/// s with its first limit matches of re (or all of them) replaced by what replacement
+/// appends for each
+fn replace_each(
+    s: &str,
+    re: &regex::Regex,
+    limit: Option<usize>,
+    mut replacement: impl FnMut(&regex::Captures, &mut String) -> Result<(), Rc<Value>>,
+) -> Result<String, Rc<Value>> {
+    let mut replaced = String::new();
+    let mut last = 0;
+    for captures in re.captures_iter(s).take(limit.unwrap_or(usize::MAX)) {
+        let found = captures.get(0).unwrap();
+        replaced.push_str(&s[last..found.start()]);
+        replacement(&captures, &mut replaced)?;
+        last = found.end();
+    }
+    replaced.push_str(&s[last..]);
+    Ok(replaced)
+}
+
+/// A match as re-groups gives it;  the string matched, or if re has groups, a vector of it
+/// and what each group matched (nil for any that didn't)
+pub(crate) fn groups(captures: &regex::Captures) -> Value {
+    matcher::groups(captures.iter().map(|group| group.map(|group| group.as_str())))
+}
+
+fn illegal_replacement(message: String) -> Rc<Value> {
+    Rc::new(error_message::condition(
+        ConditionKind::IllegalArgumentException,
+        message,
+    ))
+}
+
+/// Appends replacement to replaced, with its group references as what they refer to in
+/// captures, as Java's Matcher.appendReplacement does
+fn expand(
+    replacement: &str,
+    re: &regex::Regex,
+    captures: &regex::Captures,
+    replaced: &mut String,
+) -> Result<(), Rc<Value>> {
+    let mut chars = replacement.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some(escaped) => replaced.push(escaped),
+                None => {
+                    return Err(illegal_replacement(String::from(
+                        "character to be escaped is missing",
+                    )))
+                }
+            },
+            '$' => {
+                let group = match chars.next() {
+                    Some('{') => {
+                        let mut name = String::new();
+                        loop {
+                            match chars.next() {
+                                Some('}') => break,
+                                Some(ch) => name.push(ch),
+                                None => {
+                                    return Err(illegal_replacement(String::from(
+                                        "named capturing group is missing trailing '}'",
+                                    )))
+                                }
+                            }
+                        }
+                        if !re.capture_names().any(|group| group == Some(name.as_str())) {
+                            return Err(illegal_replacement(format!(
+                                "No group with name {{{}}}",
+                                name
+                            )));
+                        }
+                        captures.name(&name)
+                    }
+                    Some(digit) if digit.is_ascii_digit() => {
+                        // As many digits as still refer to a group
+                        let mut group = digit.to_digit(10).unwrap() as usize;
+                        while let Some(digit) = chars.peek().and_then(|ch| ch.to_digit(10)) {
+                            if group * 10 + digit as usize >= captures.len() {
+                                break;
+                            }
+                            group = group * 10 + digit as usize;
+                            chars.next();
+                        }
+                        if group >= captures.len() {
+                            return Err(illegal_replacement(format!("No group {}", group)));
+                        }
+                        captures.get(group)
+                    }
+                    _ => return Err(illegal_replacement(String::from("Illegal group reference"))),
+                };
+                if let Some(group) = group {
+                    replaced.push_str(group.as_str());
+                }
+            }
+            _ => replaced.push(ch),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    mod replace_tests {
+        use crate::clojure_string::replace::ReplaceFn;
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::repl::Repl;
+        use crate::value::{Evaluable, Value};
+        use std::rc::Rc;
+
+        fn string(s: &str) -> Rc<Value> {
+            Rc::new(Value::String(String::from(s)))
+        }
+        fn pattern(re: &str) -> Rc<Value> {
+            Rc::new(Value::Pattern(regex::Regex::new(re).unwrap()))
+        }
+
+        #[test]
+        fn replace_strings() {
+            let replace = ReplaceFn {};
+            assert_eq!(
+                Value::String(String::from("the dog and the dog")),
+                replace.invoke(vec![
+                    string("the cat and the cat"),
+                    string("cat"),
+                    string("dog")
+                ])
+            );
+            assert_eq!(
+                Value::String(String::from("a-b-c")),
+                replace.invoke(vec![string("a b c"), string(" "), string("-")])
+            );
+            assert_eq!(
+                Value::String(String::from("😀$1😀")),
+                replace.invoke(vec![string("ñ$1ñ"), string("ñ"), string("😀")])
+            );
+        }
+
+        #[test]
+        fn replace_regex_with_groups() {
+            let replace = ReplaceFn {};
+            assert_eq!(
+                Value::String(String::from("2-1 4-3")),
+                replace.invoke(vec![
+                    string("1-2 3-4"),
+                    pattern(r"(\d)-(\d)"),
+                    string("$2-$1")
+                ])
+            );
+            assert_eq!(
+                Value::String(String::from("[a] $b")),
+                replace.invoke(vec![
+                    string("a b"),
+                    pattern(r"(?P<x>a) (b)"),
+                    string(r"[${x}] \$$2")
+                ])
+            );
+            assert_eq!(
+                Value::String(String::from("a1")),
+                replace.invoke(vec![string("a"), pattern("(a)"), string("$11")])
+            );
+        }
+
+        #[test]
+        fn replace_regex_with_bad_replacement() {
+            let replace = ReplaceFn {};
+            for replacement in &["$2", "$x", "\\", "${y}"] {
+                match replace.invoke(vec![string("a"), pattern("(a)"), string(replacement)]) {
+                    Value::Condition(_) => {}
+                    replaced => panic!("{} gave {}", replacement, replaced),
+                }
+            }
+        }
+
+        #[test]
+        fn replace_regex_with_fn() {
+            let environment = Environment::clojure_core_environment();
+            let eval_str = |source: &str| {
+                Repl::read_string(source)
+                    .unwrap()
+                    .eval(Rc::clone(&environment))
+            };
+            assert_eq!(
+                Value::String(String::from("<a> <bb>")),
+                eval_str(
+                    "(clojure.string/replace \"a bb\" #\"[a-z]+\" (fn [m] (str \"<\" m \">\")))"
+                )
+            );
+            assert_eq!(
+                Value::String(String::from("b=1, a=2")),
+                eval_str(
+                    "(clojure.string/replace \"1=b, 2=a\" #\"([0-9])=([a-z])\"
+                       (fn [[_ n k]] (str k \"=\" n)))"
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::clojure_string::replace::replace;
+
+/// clojure.string/replace-first ; s with its first match replaced
+/// (replace-first [s match replacement])
+///
+/// Takes the same matches and replacements as replace
+#[derive(Debug, Clone)]
+pub struct ReplaceFirstFn {}
+impl ToValue for ReplaceFirstFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReplaceFirstFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        replace(&args, Some(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod replace_first_tests {
+        use crate::clojure_string::replace_first::ReplaceFirstFn;
+        use crate::ifn::IFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn string(s: &str) -> Rc<Value> {
+            Rc::new(Value::String(String::from(s)))
+        }
+
+        #[test]
+        fn replace_first_string() {
+            let replace_first = ReplaceFirstFn {};
+            assert_eq!(
+                Value::String(String::from("a-b c")),
+                replace_first.invoke(vec![string("a b c"), string(" "), string("-")])
+            );
+        }
+
+        #[test]
+        fn replace_first_regex() {
+            let replace_first = ReplaceFirstFn {};
+            let pattern = Rc::new(Value::Pattern(regex::Regex::new(r"(\w)(\w)").unwrap()));
+            assert_eq!(
+                Value::String(String::from("ba cd")),
+                replace_first.invoke(vec![string("ab cd"), pattern, string("$2$1")])
+            );
+        }
+    }
+}
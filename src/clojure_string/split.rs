@@ -8,6 +8,9 @@ use crate::type_tag::TypeTag;
 
 /// clojure.string/split [s re & [limit]] splits strings by pattern, optionally maximum of limit
 /// amount
+///
+/// As in Clojure, a limit above 0 keeps what's after the last split in the last string, and
+/// without one (or with 0), empty strings at the end are left out
 #[derive(Debug, Clone)]
 pub struct SplitFn {}
 impl ToValue for SplitFn {
@@ -19,29 +22,50 @@ impl IFn for SplitFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
         if args.len() != 2 && args.len() != 3 {
             return error_message::wrong_varg_count(&[2, 3], args.len());
-        } else {
-            match (
-                args.get(0).unwrap().to_value(),
-                args.get(1).unwrap().to_value(),
-            ) {
-                (Value::String(s), Value::Pattern(re)) => {
-                    let splits: Vec<Rc<Value>> = re
-                        .split(&s)
-                        .collect::<Vec<&str>>()
-                        .into_iter()
-                        .filter(|ss| !ss.is_empty())
-                        .map(|ss| Rc::new(Value::String(ss.to_string())))
-                        .collect();
-                    return Value::PersistentVector(
-                        splits.into_iter().collect::<PersistentVector>(),
-                    );
-                }
-                (_a, Value::Pattern(_)) => error_message::type_mismatch(TypeTag::String, &_a),
-                (Value::String(_), _b) => error_message::type_mismatch(TypeTag::Pattern, &_b),
-                (_, _) => error_message::unknown_err(String::from("Unknown error")),
-            }
         }
+        let limit = match args.get(2).map(|limit| &**limit) {
+            None => 0,
+            Some(Value::I32(limit)) => *limit,
+            Some(limit) => return error_message::type_mismatch(TypeTag::I32, limit),
+        };
+        match (&*args[0], &*args[1]) {
+            (Value::String(s), Value::Pattern(re)) => split(s, re, limit)
+                .into_iter()
+                .map(|ss| Rc::new(Value::String(ss.to_string())))
+                .collect::<PersistentVector>()
+                .to_value(),
+            (Value::String(_), b) => error_message::type_mismatch(TypeTag::Pattern, b),
+            (a, _) => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+/// s split around the matches of re, as Java's String.split has it;  with a limit above 0,
+/// into at most limit strings, and with 0, leaving out empty strings at the end
+pub(crate) fn split<'s>(s: &'s str, re: &regex::Regex, limit: i32) -> Vec<&'s str> {
+    let mut splits = vec![];
+    let mut last = 0;
+    for found in re.find_iter(s) {
+        if limit > 0 && splits.len() == limit as usize - 1 {
+            break;
+        }
+        // A match of nothing at the start doesn't split off an empty string
+        if found.end() == 0 {
+            continue;
+        }
+        splits.push(&s[last..found.start()]);
+        last = found.end();
     }
+    if splits.is_empty() {
+        return vec![s];
+    }
+    splits.push(&s[last..]);
+    if limit == 0 {
+        while splits.last() == Some(&"") {
+            splits.pop();
+        }
+    }
+    splits
 }
 
 #[cfg(test)]
@@ -53,6 +77,15 @@ mod tests {
         use crate::value::Value;
         use std::rc::Rc;
 
+        fn strings(strings: &[&str]) -> Value {
+            Value::PersistentVector(
+                strings
+                    .iter()
+                    .map(|s| Rc::new(Value::String(String::from(*s))))
+                    .collect::<PersistentVector>(),
+            )
+        }
+
         #[test]
         fn split_by_comma() {
             let split = SplitFn {};
@@ -116,5 +149,40 @@ mod tests {
                 split.invoke(args)
             );
         }
+
+        #[test]
+        fn split_keeps_empty_strings_but_the_last() {
+            let split = SplitFn {};
+            let args = vec![
+                Rc::new(Value::String(String::from(",a,,b,,"))),
+                Rc::new(Value::Pattern(regex::Regex::new(",").unwrap())),
+            ];
+            assert_eq!(strings(&["", "a", "", "b"]), split.invoke(args));
+        }
+
+        #[test]
+        fn split_with_limit() {
+            let split = SplitFn {};
+            let args = |limit| {
+                vec![
+                    Rc::new(Value::String(String::from("a1b2c3"))),
+                    Rc::new(Value::Pattern(regex::Regex::new(r"\d").unwrap())),
+                    Rc::new(Value::I32(limit)),
+                ]
+            };
+            assert_eq!(strings(&["a", "b2c3"]), split.invoke(args(2)));
+            assert_eq!(strings(&["a", "b", "c", ""]), split.invoke(args(-1)));
+            assert_eq!(strings(&["a", "b", "c"]), split.invoke(args(0)));
+        }
+
+        #[test]
+        fn split_into_characters() {
+            let split = SplitFn {};
+            let args = vec![
+                Rc::new(Value::String(String::from("añ😀"))),
+                Rc::new(Value::Pattern(regex::Regex::new("").unwrap())),
+            ];
+            assert_eq!(strings(&["a", "ñ", "😀"]), split.invoke(args));
+        }
     }
 }
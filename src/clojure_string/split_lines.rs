@@ -0,0 +1,74 @@
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+use crate::clojure_string::split::split;
+use crate::error_message;
+use crate::persistent_vector::PersistentVector;
+use crate::type_tag::TypeTag;
+
+/// clojure.string/split-lines splits s on \n or \r\n, as (split s #"\r?\n") does
+#[derive(Debug, Clone)]
+pub struct SplitLinesFn {}
+impl ToValue for SplitLinesFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SplitLinesFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::String(s) => {
+                let newline = regex::Regex::new(r"\r?\n").unwrap();
+                split(s, &newline, 0)
+                    .into_iter()
+                    .map(|line| Rc::new(Value::String(line.to_string())))
+                    .collect::<PersistentVector>()
+                    .to_value()
+            }
+            a => error_message::type_mismatch(TypeTag::String, a),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod split_lines_tests {
+        use crate::clojure_string::split_lines::SplitLinesFn;
+        use crate::ifn::IFn;
+        use crate::persistent_vector::PersistentVector;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn strings(strings: &[&str]) -> Value {
+            Value::PersistentVector(
+                strings
+                    .iter()
+                    .map(|s| Rc::new(Value::String(String::from(*s))))
+                    .collect::<PersistentVector>(),
+            )
+        }
+
+        #[test]
+        fn split_lines() {
+            let split_lines = SplitLinesFn {};
+            let args = vec![Rc::new(Value::String(String::from(
+                "one\r\ntwo\n\nthree\r\n\n",
+            )))];
+            assert_eq!(
+                strings(&["one", "two", "", "three"]),
+                split_lines.invoke(args)
+            );
+        }
+
+        #[test]
+        fn split_lines_of_one_line() {
+            let split_lines = SplitLinesFn {};
+            let args = vec![Rc::new(Value::String(String::from("one \r two")))];
+            assert_eq!(strings(&["one \r two"]), split_lines.invoke(args));
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Conditions;  what evaluation hands back, in place of a value, when something goes wrong
+//!
+//! A Value::Condition is a condition in flight.  It is handed straight back up through
+//! whatever forms are being evaluated around it, until a (try .. (catch ..)) matching its
+//! kind catches it, or it reaches the top level.  Once caught, or when made with ex-info,
+//! it is held as an ordinary Value::Throwable, which (throw ..) sends off again
+use crate::call_stack::{self, Frame};
+use crate::source_position::SourcePosition;
+use crate::value::Value;
+use std::fmt;
+use std::rc::Rc;
+
+/// What sort of condition something is.  These are named after the JVM exceptions they stand in
+/// for, so that (catch ArityException e ..) and friends read the way they do in Clojure proper
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConditionKind {
+    Throwable,
+    Exception,
+    RuntimeException,
+    IllegalArgumentException,
+    ArityException,
+    ClassCastException,
+    IndexOutOfBoundsException,
+    ArithmeticException,
+    IllegalStateException,
+    UnsupportedOperationException,
+    ExceptionInfo,
+    IOException,
+    FileNotFoundException,
+}
+use ConditionKind::*;
+const CONDITION_KINDS: &[ConditionKind] = &[
+    Throwable,
+    Exception,
+    RuntimeException,
+    IllegalArgumentException,
+    ArityException,
+    ClassCastException,
+    IndexOutOfBoundsException,
+    ArithmeticException,
+    IllegalStateException,
+    UnsupportedOperationException,
+    ExceptionInfo,
+    IOException,
+    FileNotFoundException,
+];
+impl ConditionKind {
+    /// The more general kind this is a case of, if any
+    pub fn parent(self) -> Option<ConditionKind> {
+        match self {
+            Throwable => None,
+            Exception => Some(Throwable),
+            RuntimeException => Some(Exception),
+            ArityException => Some(IllegalArgumentException),
+            IllegalArgumentException
+            | ClassCastException
+            | IndexOutOfBoundsException
+            | ArithmeticException
+            | IllegalStateException
+            | UnsupportedOperationException
+            | ExceptionInfo => Some(RuntimeException),
+            IOException => Some(Exception),
+            FileNotFoundException => Some(IOException),
+        }
+    }
+    /// Whether this kind is other, or a case of it;  ie, ArityException is an Exception
+    pub fn isa(self, other: ConditionKind) -> bool {
+        let mut kind = Some(self);
+        while let Some(k) = kind {
+            if k == other {
+                return true;
+            }
+            kind = k.parent();
+        }
+        false
+    }
+    /// The fully qualified name this kind has in Clojure proper
+    pub fn class_name(self) -> &'static str {
+        match self {
+            Throwable => "java.lang.Throwable",
+            Exception => "java.lang.Exception",
+            RuntimeException => "java.lang.RuntimeException",
+            IllegalArgumentException => "java.lang.IllegalArgumentException",
+            ArityException => "clojure.lang.ArityException",
+            ClassCastException => "java.lang.ClassCastException",
+            IndexOutOfBoundsException => "java.lang.IndexOutOfBoundsException",
+            ArithmeticException => "java.lang.ArithmeticException",
+            IllegalStateException => "java.lang.IllegalStateException",
+            UnsupportedOperationException => "java.lang.UnsupportedOperationException",
+            ExceptionInfo => "clojure.lang.ExceptionInfo",
+            IOException => "java.io.IOException",
+            FileNotFoundException => "java.io.FileNotFoundException",
+        }
+    }
+    /// The kind named, either plainly (ArityException) or fully qualified
+    /// (clojure.lang.ArityException)
+    pub fn from_name(name: &str) -> Option<ConditionKind> {
+        CONDITION_KINDS
+            .iter()
+            .find(|kind| {
+                let class_name = kind.class_name();
+                name == class_name || Some(name) == class_name.rsplit('.').next()
+            })
+            .copied()
+    }
+}
+impl fmt::Display for ConditionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.class_name())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Condition {
+    pub kind: ConditionKind,
+    pub message: String,
+    /// The map given to ex-info, or nil
+    pub data: Rc<Value>,
+    /// The condition that led to this one, if any
+    pub cause: Option<Rc<Condition>>,
+    /// Where the innermost form raising this was read from, if it was read from source
+    pub position: Option<SourcePosition>,
+    /// The fns that were being run when this was created, innermost first
+    pub trace: Vec<Frame>,
+}
+impl Condition {
+    pub fn new(kind: ConditionKind, message: impl Into<String>) -> Condition {
+        Condition {
+            kind,
+            message: message.into(),
+            data: Rc::new(Value::Nil),
+            cause: None,
+            position: None,
+            trace: call_stack::trace(),
+        }
+    }
+    pub fn with_data(self, data: Rc<Value>) -> Condition {
+        Condition { data, ..self }
+    }
+    pub fn with_cause(self, cause: Option<Rc<Condition>>) -> Condition {
+        Condition { cause, ..self }
+    }
+    pub fn with_position(self, position: Option<SourcePosition>) -> Condition {
+        Condition { position, ..self }
+    }
+}
+// Where a condition was raised, and from which fns, doesn't factor into equality, just what it is
+impl PartialEq for Condition {
+    fn eq(&self, other: &Condition) -> bool {
+        self.kind == other.kind
+            && self.message == other.message
+            && self.data == other.data
+            && self.cause == other.cause
+    }
+}
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &*self.data {
+            Value::Nil => write!(f, "#Condition[\"{}\"]", self.message),
+            data => write!(f, "#Condition[\"{}\" {}]", self.message, data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod condition_kind_tests {
+        use crate::condition::ConditionKind;
+
+        #[test]
+        fn isa() {
+            assert!(ConditionKind::ArityException.isa(ConditionKind::IllegalArgumentException));
+            assert!(ConditionKind::ArityException.isa(ConditionKind::Exception));
+            assert!(ConditionKind::ExceptionInfo.isa(ConditionKind::Throwable));
+            assert!(!ConditionKind::Exception.isa(ConditionKind::ExceptionInfo));
+            assert!(!ConditionKind::ClassCastException.isa(ConditionKind::ArityException));
+        }
+
+        #[test]
+        fn from_name() {
+            assert_eq!(
+                Some(ConditionKind::ArityException),
+                ConditionKind::from_name("ArityException")
+            );
+            assert_eq!(
+                Some(ConditionKind::Exception),
+                ConditionKind::from_name("java.lang.Exception")
+            );
+            assert_eq!(None, ConditionKind::from_name("NotAnException"));
+        }
+    }
+
+    mod condition_position_tests {
+        use crate::environment::Environment;
+        use crate::reader::Reader;
+        use crate::value::{Evaluable, Value};
+
+        fn eval_from_file(source: &str) -> Value {
+            Reader::from_file(source.as_bytes(), "script.clj")
+                .read()
+                .unwrap()
+                .eval(Environment::clojure_core_environment())
+        }
+
+        #[test]
+        fn innermost_form_position() {
+            match eval_from_file("(do 1\n    (+ 2\n       (first 1 2)))") {
+                Value::Condition(condition) => assert_eq!(
+                    Some(String::from("script.clj:3:8")),
+                    condition.position.as_ref().map(|position| position.to_string())
+                ),
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn undefined_symbol_position() {
+            match eval_from_file("[1\n  undefined-symbol]") {
+                Value::Condition(condition) => assert_eq!(
+                    Some(String::from("script.clj:2:3")),
+                    condition.position.as_ref().map(|position| position.to_string())
+                ),
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
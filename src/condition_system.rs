@@ -0,0 +1,362 @@
+//! Signals, handlers and restarts;  a condition system in the style of Common Lisp
+//!
+//! Where try can only unwind to a catch and start over from there, here the code that notices
+//! something is wrong offers ways to recover with restart-case, and code further up decides
+//! which one to take with handler-bind, from inside the signal, before anything has unwound
+//!
+//! ```clojure
+//! (defn parse-row [row]
+//!   (restart-case
+//!     (if (valid? row)
+//!       (parse row)
+//!       (error (ex-info "Bad row" {:row row})))
+//!     (use-value [v] v)
+//!     (skip-record [] nil)))
+//!
+//! (handler-bind [ExceptionInfo (fn [e] (invoke-restart 'skip-record))]
+//!   (into [] (map parse-row rows)))
+//! ```
+//!
+//! The rows are parsed inside handler-bind by into;  a lazy (map parse-row rows) would be
+//! given back unrealized, and parsed later, with no handlers around it
+//!
+//! (signal c) runs the handlers matching c, innermost first.  A handler that returns normally
+//! declines, and the next one is tried;  one that invokes a restart unwinds straight to the
+//! restart-case that offered it.  (error c) is the same, save that when every handler declines
+//! it throws c, as (throw c) would
+//!
+//! Only conditions given to signal or error reach handlers.  The errors of our own fns, a type
+//! mismatch or a wrong number of arguments say, are thrown without being signalled;  try can
+//! catch them, but handler-bind never sees them
+use crate::condition::Condition;
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::iterable::Iterable;
+use crate::lambda::{Fn, FnArity};
+use crate::protocol::ProtocolCastable;
+use crate::symbol::Symbol;
+use crate::try_catch::CatchTarget;
+use crate::type_tag::TypeTag;
+use crate::util::IsOdd;
+use crate::value::{Evaluable, Value};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// One handler established by a handler-bind
+struct Handler {
+    target: CatchTarget,
+    handler: Rc<Value>,
+    environment: Rc<Environment>,
+}
+
+/// A restart offered by a restart-case;  the restart-case is known by its id
+struct Restart {
+    id: usize,
+    name: String,
+}
+
+/// A restart that has been invoked, on its way back up to the restart-case that offered it
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct RestartInvocation {
+    id: usize,
+    pub name: String,
+    pub args: Vec<Rc<Value>>,
+}
+impl fmt::Display for RestartInvocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#restart[{}", self.name)?;
+        for arg in self.args.iter() {
+            write!(f, " {}", arg.to_string_explicit())?;
+        }
+        write!(f, "]")
+    }
+}
+
+thread_local! {
+    /// The handlers of each handler-bind we are within, outermost first
+    static HANDLERS: RefCell<Vec<Rc<Vec<Handler>>>> = const { RefCell::new(vec![]) };
+    /// The restarts of each restart-case we are within, outermost first
+    static RESTARTS: RefCell<Vec<Restart>> = const { RefCell::new(vec![]) };
+}
+
+/// Runs the handlers that match condition, innermost first, until one of them invokes a
+/// restart or throws;  returns that interruption, or None if every handler declined
+pub fn signal(condition: &Rc<Condition>) -> Option<Rc<Value>> {
+    let cluster_count = HANDLERS.with(|handlers| handlers.borrow().len());
+    for i in (0..cluster_count).rev() {
+        // While a handler runs, only the handlers established outside of its own
+        // handler-bind are active, so signalling from within a handler can't loop back to it
+        let inner_clusters = HANDLERS.with(|handlers| handlers.borrow_mut().split_off(i));
+        let interruption = run_handlers(&inner_clusters[0], condition);
+        HANDLERS.with(|handlers| handlers.borrow_mut().extend(inner_clusters));
+        if interruption.is_some() {
+            return interruption;
+        }
+    }
+    None
+}
+
+fn run_handlers(cluster: &[Handler], condition: &Rc<Condition>) -> Option<Rc<Value>> {
+    for handler in cluster.iter() {
+        match handler.target.matches(&handler.environment, condition) {
+            Ok(true) => {}
+            Ok(false) => continue,
+            Err(interruption) => return Some(interruption),
+        }
+        if let Value::IFn(ifn) = &*handler.handler {
+            let handled =
+                Rc::new(ifn.invoke(vec![Rc::new(Value::Throwable(Rc::clone(condition)))]));
+            if let Some(interruption) = handled.non_tail() {
+                return Some(interruption);
+            }
+        }
+    }
+    None
+}
+
+/// Unwinds to the innermost restart-case offering a restart named name, to run that restart
+/// on args
+pub fn invoke_restart(name: &str, args: Vec<Rc<Value>>) -> Value {
+    RESTARTS.with(|restarts| {
+        match restarts
+            .borrow()
+            .iter()
+            .rev()
+            .find(|restart| restart.name == name)
+        {
+            Some(restart) => Value::RestartInvocation(Rc::new(RestartInvocation {
+                id: restart.id,
+                name: String::from(name),
+                args,
+            })),
+            None => error_message::no_such_restart(name),
+        }
+    })
+}
+
+/// Evaluates the arguments of a (handler-bind [type handler ..] body..) in environment
+pub fn eval_handler_bind(environment: &Rc<Environment>, args: Vec<Rc<Value>>) -> Rc<Value> {
+    let bindings = match args.first().map(|bindings| &**bindings) {
+        Some(Value::PersistentVector(pvector)) => pvector.to_vec(),
+        _ => {
+            return Rc::new(error_message::custom(
+                "Bindings to handler-bind should be a vector",
+            ))
+        }
+    };
+    if bindings.len().is_odd() {
+        return Rc::new(error_message::custom(
+            "handler-bind requires an even number of forms in its binding vector",
+        ));
+    }
+    let mut cluster = vec![];
+    for pair in bindings.chunks(2) {
+        // Predicates and handlers are evaluated once, here, rather than on each signal
+        let target = match CatchTarget::parse(&pair[0]) {
+            CatchTarget::Predicate(predicate) => {
+                let predicate = predicate.eval_to_rc(Rc::clone(environment));
+                if let Some(interruption) = predicate.non_tail() {
+                    return interruption;
+                }
+                CatchTarget::Predicate(predicate)
+            }
+            target => target,
+        };
+        let handler = pair[1].eval_to_rc(Rc::clone(environment));
+        if let Some(interruption) = handler.non_tail() {
+            return interruption;
+        }
+        if !matches!(&*handler, Value::IFn(_)) {
+            return Rc::new(error_message::type_mismatch(TypeTag::IFn, &handler));
+        }
+        cluster.push(Handler {
+            target,
+            handler,
+            environment: Rc::clone(environment),
+        });
+    }
+    HANDLERS.with(|handlers| handlers.borrow_mut().push(Rc::new(cluster)));
+    let result = Value::implicit_do(&args[1..]).eval_to_rc(Rc::clone(environment));
+    HANDLERS.with(|handlers| handlers.borrow_mut().pop());
+    result
+}
+
+/// Evaluates the arguments of a (restart-case expr (name [params] body..)..) in environment
+pub fn eval_restart_case(environment: &Rc<Environment>, args: Vec<Rc<Value>>) -> Rc<Value> {
+    static RESTART_CASE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let expr = match args.first() {
+        Some(expr) => Rc::clone(expr),
+        None => return Rc::new(error_message::custom("restart-case expects an expression")),
+    };
+    let mut clauses = vec![];
+    for clause in args[1..].iter() {
+        match RestartClause::parse(clause) {
+            Ok(clause) => clauses.push(clause),
+            Err(msg) => return Rc::new(error_message::custom(msg)),
+        }
+    }
+
+    let id = RESTART_CASE_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let restart_count = RESTARTS.with(|restarts| {
+        let mut restarts = restarts.borrow_mut();
+        let restart_count = restarts.len();
+        // Pushed in reverse, so that of two restarts with the same name the first is found
+        for clause in clauses.iter().rev() {
+            restarts.push(Restart {
+                id,
+                name: String::from(clause.name.name()),
+            });
+        }
+        restart_count
+    });
+    let result = expr.eval_to_rc(Rc::clone(environment));
+    RESTARTS.with(|restarts| restarts.borrow_mut().truncate(restart_count));
+
+    match &*result {
+        Value::RestartInvocation(invocation) if invocation.id == id => {
+            match clauses
+                .into_iter()
+                .find(|clause| clause.name.name() == invocation.name)
+            {
+                Some(clause) => clause.invoke(environment, invocation.args.clone()),
+                None => result,
+            }
+        }
+        _ => result,
+    }
+}
+
+/// One (name [params] body..) of a restart-case
+struct RestartClause {
+    name: Symbol,
+    arity: FnArity,
+}
+impl RestartClause {
+    fn parse(form: &Rc<Value>) -> Result<RestartClause, String> {
+        let items = match &**form {
+            Value::PersistentList(_) => form
+                .as_protocol::<Iterable>()
+                .iter()
+                .collect::<Vec<Rc<Value>>>(),
+            _ => vec![],
+        };
+        match (items.first().map(|v| &**v), items.get(1).map(|v| &**v)) {
+            (Some(Value::Symbol(name)), Some(Value::PersistentVector(params))) => {
+                Ok(RestartClause {
+                    name: name.clone(),
+                    arity: FnArity::new(&params.to_vec(), Value::implicit_do(&items[2..]))?,
+                })
+            }
+            _ => Err(format!(
+                "Invalid restart {}; expected (name [params] body..)",
+                form
+            )),
+        }
+    }
+    fn invoke(self, environment: &Rc<Environment>, args: Vec<Rc<Value>>) -> Rc<Value> {
+        let enclosing_environment =
+            Rc::new(Environment::new_local_environment(Rc::clone(environment)));
+        match Fn::new(Some(self.name), vec![self.arity], enclosing_environment) {
+            Ok(restart) => Rc::new(restart.invoke(args)),
+            Err(msg) => Rc::new(error_message::custom(msg)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod condition_system_tests {
+        use crate::condition::ConditionKind;
+        use crate::keyword::Keyword;
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn restart_case_without_restart() {
+            assert_eq!(
+                Value::I32(3),
+                eval_str("(restart-case (+ 1 2) (use-value [v] v))")
+            );
+        }
+
+        #[test]
+        fn invoke_restart_unwinds_to_restart_case() {
+            assert_eq!(
+                Value::I32(5),
+                eval_str("(restart-case (+ 1 (invoke-restart 'use-value 5)) (use-value [v] v))")
+            );
+            assert_eq!(
+                Value::Nil,
+                eval_str("(restart-case (invoke-restart :skip-record) (use-value [v] v) (skip-record [] nil))")
+            );
+        }
+
+        #[test]
+        fn handler_picks_restart() {
+            assert_eq!(
+                eval_str("[10 -2 nil 40]"),
+                eval_str(
+                    "(do
+                       (defn parse-row [row]
+                         (restart-case
+                           (if (lt row 0)
+                             (error (ex-info \"Bad row\" {:row row :skip (= row -3)}))
+                             (* row 10))
+                           (use-value [v] v)
+                           (skip-record [] nil)))
+                       (handler-bind [ExceptionInfo
+                                      (fn [e]
+                                        (if (get (ex-data e) :skip)
+                                          (invoke-restart 'skip-record)
+                                          (invoke-restart 'use-value (get (ex-data e) :row))))]
+                         [(parse-row 1) (parse-row -2) (parse-row -3) (parse-row 4)]))"
+                )
+            );
+        }
+
+        #[test]
+        fn declining_handlers_pass_to_outer_handlers() {
+            assert_eq!(
+                Value::Keyword(Keyword::intern("outer")),
+                eval_str(
+                    "(handler-bind [:default (fn [e] (invoke-restart 'use-value :outer))]
+                       (handler-bind [ArityException (fn [e] (invoke-restart 'use-value :wrong-kind))
+                                      :default (fn [e] nil)]
+                         (restart-case (signal (ex-info \"x\" {})) (use-value [v] v))))"
+                )
+            );
+        }
+
+        #[test]
+        fn signal_does_not_unwind() {
+            assert_eq!(
+                Value::Keyword(Keyword::intern("continued")),
+                eval_str("(handler-bind [:default (fn [e] nil)] (do (signal (ex-info \"x\" {})) :continued))")
+            );
+            assert_eq!(Value::Nil, eval_str("(signal (ex-info \"x\" {}))"));
+        }
+
+        #[test]
+        fn unhandled_error_throws() {
+            assert_eq!(
+                Value::String(String::from("unhandled")),
+                eval_str("(try (error (ex-info \"unhandled\" {})) (catch ExceptionInfo e (ex-message e)))")
+            );
+        }
+
+        #[test]
+        fn no_such_restart() {
+            match eval_str("(restart-case (invoke-restart 'retry) (use-value [v] v))") {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IllegalStateException, condition.kind);
+                    assert_eq!("No restart named retry is active", condition.message);
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! A cons cell, as Clojure's Cons is;  a value put in front of any seq, lazy or not.  Unlike
+//! a PersistentList, what follows our first value needn't be a list, nor be realized yet
+use crate::lazy_seq::LazySeq;
+use crate::meta::Meta;
+use crate::persistent_list::PersistentList;
+use crate::persistent_list_map::PersistentListMap;
+use crate::traits;
+use crate::value::{ToValue, Value};
+
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub struct Cons {
+    first: Rc<Value>,
+    /// The seq following first, or None if there is nothing more
+    more: Option<Rc<Value>>,
+    meta: Meta,
+}
+impl Cons {
+    pub fn new(first: Rc<Value>, more: Rc<Value>) -> Cons {
+        Cons {
+            first,
+            more: match &*more {
+                Value::Nil => None,
+                _ => Some(more),
+            },
+            meta: Meta::default(),
+        }
+    }
+    pub fn first(&self) -> Rc<Value> {
+        Rc::clone(&self.first)
+    }
+    /// The seq following our first value;  the empty list if there is none
+    pub fn more(&self) -> Rc<Value> {
+        match &self.more {
+            Some(more) => Rc::clone(more),
+            None => PersistentList::Empty.to_rc_value(),
+        }
+    }
+}
+impl traits::Seqable for Cons {
+    fn seq(&self) -> Rc<Value> {
+        self.to_rc_value()
+    }
+}
+impl traits::IMeta for Cons {
+    fn meta(&self) -> PersistentListMap {
+        self.meta.get()
+    }
+}
+impl traits::IObj for Cons {
+    fn with_meta(&self, meta: PersistentListMap) -> Cons {
+        Cons {
+            first: Rc::clone(&self.first),
+            more: self.more.clone(),
+            meta: Meta::new(meta),
+        }
+    }
+}
+impl fmt::Display for Cons {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        crate::seq::fmt_seq(&self.to_rc_value(), f)
+    }
+}
+
+// Dropping a long seq would otherwise recurse once per cell;  instead we unlink the cells
+// following us one at a time, for as long as nothing else holds on to them
+impl Drop for Cons {
+    fn drop(&mut self) {
+        let mut next = self.more.take();
+        while let Some(value) = next {
+            next = match Rc::try_unwrap(value) {
+                Ok(Value::Cons(mut cons)) => cons.more.take(),
+                Ok(Value::LazySeq(lazy_seq)) => LazySeq::take_realized(&lazy_seq),
+                _ => None,
+            };
+        }
+    }
+}
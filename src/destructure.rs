@@ -0,0 +1,411 @@
+//! Destructuring of binding forms, as found in let, loop and fn parameters
+//!
+//! A binding form is one of
+//! ```clojure
+//!   x                                   ;; binds the value itself
+//!   [a b & more :as all]                ;; binds by position
+//!   {a :a :keys [b] :strs [c] :syms [d] ;; binds by key
+//!    :or {b 1} :as m}
+//! ```
+//! where each `a`, `more`, etc may itself be any binding form
+use crate::environment::Environment;
+use crate::keyword::Keyword;
+use crate::maps::MapEntry;
+use crate::persistent_hash_map;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap, ToPersistentListMapIter};
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::symbol::Symbol;
+use crate::value::{Evaluable, ToValue, Value};
+use std::rc::Rc;
+
+/// Checks that binding is a well formed binding form, so that, say, a fn with a bad
+/// parameter vector is rejected when it is defined rather than when it is first called
+pub fn validate(binding: &Value) -> Result<(), String> {
+    match binding {
+        Value::Symbol(sym) => validate_symbol(sym),
+        Value::PersistentVector(pvector) => validate_sequential(&pvector.to_vec()),
+        Value::PersistentListMap(pmap) => validate_associative(pmap),
+        Value::PersistentHashMap(phashmap) => validate_associative(&phashmap.to_list_map()),
+        _ => Err(format!("Unsupported binding form: {}", binding)),
+    }
+}
+
+fn validate_symbol(sym: &Symbol) -> Result<(), String> {
+    if sym.ns.is_some() {
+        return Err(format!("Can't bind qualified name: {}", sym));
+    }
+    Ok(())
+}
+
+fn validate_sequential(forms: &[Rc<Value>]) -> Result<(), String> {
+    let mut forms = forms.iter();
+    while let Some(form) = forms.next() {
+        match &**form {
+            Value::Symbol(sym) if is_ampersand(sym) => match forms.next() {
+                Some(rest_form) if !is_as(rest_form) => validate(rest_form)?,
+                _ => {
+                    return Err(String::from(
+                        "Invalid binding form; expected a binding form after '&'",
+                    ))
+                }
+            },
+            _ if is_as(form) => {
+                return match (forms.next().map(|f| &**f), forms.next()) {
+                    (Some(Value::Symbol(sym)), None) => validate_symbol(sym),
+                    _ => Err(String::from(
+                        "Invalid binding form; expected exactly one symbol, last, after :as",
+                    )),
+                };
+            }
+            _ => validate(form)?,
+        }
+    }
+    Ok(())
+}
+
+fn validate_associative(pmap: &PersistentListMap) -> Result<(), String> {
+    for MapEntry { key, val } in pmap.iter() {
+        match &*key {
+            Value::Keyword(kw) if is_directive(kw, "keys") => {
+                validate_key_names(&val, "keys", true)?
+            }
+            Value::Keyword(kw) if is_directive(kw, "strs") || is_directive(kw, "syms") => {
+                validate_key_names(&val, kw.name(), false)?
+            }
+            Value::Keyword(kw) if is_directive(kw, "or") => match &*val {
+                Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {}
+                _ => return Err(String::from("Invalid binding form; :or expects a map")),
+            },
+            Value::Keyword(kw) if is_directive(kw, "as") => match &*val {
+                Value::Symbol(sym) => validate_symbol(sym)?,
+                _ => return Err(String::from("Invalid binding form; :as expects a symbol")),
+            },
+            _ => validate(&key)?,
+        }
+    }
+    Ok(())
+}
+
+/// Checks the vector given to :keys, :strs or :syms
+fn validate_key_names(names: &Value, directive: &str, allow_keywords: bool) -> Result<(), String> {
+    let invalid = || {
+        Err(format!(
+            "Invalid binding form; :{} expects a vector of symbols",
+            directive
+        ))
+    };
+    match names {
+        Value::PersistentVector(pvector) => {
+            for name in pvector.to_vec().iter() {
+                match &**name {
+                    Value::Symbol(_) => {}
+                    Value::Keyword(_) if allow_keywords => {}
+                    _ => return invalid(),
+                }
+            }
+            Ok(())
+        }
+        _ => invalid(),
+    }
+}
+
+/// Binds the parts of value named by binding into environment.  Defaults given with :or
+/// are evaluated in environment too, so they may refer to anything bound before them
+pub fn bind(environment: &Rc<Environment>, binding: &Value, value: Rc<Value>) -> Result<(), String> {
+    match binding {
+        Value::Symbol(sym) => {
+            validate_symbol(sym)?;
+            environment.insert(sym.clone(), value);
+            Ok(())
+        }
+        Value::PersistentVector(pvector) => bind_sequential(environment, &pvector.to_vec(), value),
+        Value::PersistentListMap(pmap) => bind_associative(environment, pmap, value),
+        Value::PersistentHashMap(phashmap) => {
+            bind_associative(environment, &phashmap.to_list_map(), value)
+        }
+        _ => Err(format!("Unsupported binding form: {}", binding)),
+    }
+}
+
+fn bind_sequential(
+    environment: &Rc<Environment>,
+    forms: &[Rc<Value>],
+    value: Rc<Value>,
+) -> Result<(), String> {
+    if !seq::is_seqable(&value) {
+        return Err(format!(
+            "Cannot destructure {} by position; expected a sequential collection or nil",
+            value.type_tag()
+        ));
+    }
+    // What's left to bind, only seq'd as far as the forms take it, so a lazy (even infinite)
+    // seq is realized no further than the forms reach
+    let mut rest = Rc::clone(&value);
+    let mut forms = forms.iter();
+    while let Some(form) = forms.next() {
+        match &**form {
+            Value::Symbol(sym) if is_ampersand(sym) => {
+                let rest_form = forms.next().ok_or_else(|| {
+                    String::from("Invalid binding form; expected a binding form after '&'")
+                })?;
+                // Like Clojure, nothing left over means our rest is nil, not ()
+                bind(environment, rest_form, seq::seq(&rest))?;
+            }
+            _ if is_as(form) => match forms.next().map(|f| &**f) {
+                Some(as_form @ Value::Symbol(_)) => bind(environment, as_form, Rc::clone(&value))?,
+                _ => return Err(String::from("Invalid binding form; :as expects a symbol")),
+            },
+            _ => {
+                let item = seq::first(&rest);
+                rest = seq::more(&rest);
+                bind(environment, form, item)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn bind_associative(
+    environment: &Rc<Environment>,
+    pmap: &PersistentListMap,
+    value: Rc<Value>,
+) -> Result<(), String> {
+    let value = as_map(value)?;
+    let map = value.try_as_protocol::<protocols::IPersistentMap>();
+    let defaults = pmap
+        .get(&Keyword::intern("or").to_rc_value())
+        .try_as_protocol::<protocols::IPersistentMap>();
+    // Looks up key, falling back to what :or says sym defaults to when key is missing
+    let lookup = |sym: &Symbol, key: Rc<Value>| -> Rc<Value> {
+        if let Some(map) = &map {
+            if map.contains_key(&key) {
+                return map.get(&key);
+            }
+        }
+        let default_sym = Symbol::intern(&sym.name).to_rc_value();
+        match &defaults {
+            Some(defaults) if defaults.contains_key(&default_sym) => defaults
+                .get(&default_sym)
+                .eval_to_rc(Rc::clone(environment)),
+            _ => Rc::new(Value::Nil),
+        }
+    };
+
+    if let Value::Symbol(sym) = pmap.get(&Keyword::intern("as").to_rc_value()).as_ref() {
+        bind(environment, &Value::Symbol(sym.clone()), Rc::clone(&value))?;
+    }
+    for MapEntry { key, val } in pmap.iter() {
+        match &*key {
+            Value::Keyword(kw) if is_directive(kw, "keys") => {
+                for name in key_names(&val, "keys")?.iter() {
+                    let sym = Symbol::intern(&name.name);
+                    let key = match &name.ns {
+                        Some(ns) => Keyword::intern_with_ns(ns, &name.name),
+                        None => Keyword::intern(&name.name),
+                    };
+                    environment.insert(sym.clone(), lookup(&sym, key.to_rc_value()));
+                }
+            }
+            Value::Keyword(kw) if is_directive(kw, "strs") => {
+                for name in key_names(&val, "strs")?.iter() {
+                    let sym = Symbol::intern(&name.name);
+                    let key = Value::String(name.to_string()).to_rc_value();
+                    environment.insert(sym.clone(), lookup(&sym, key));
+                }
+            }
+            Value::Keyword(kw) if is_directive(kw, "syms") => {
+                for name in key_names(&val, "syms")?.iter() {
+                    let sym = Symbol::intern(&name.name);
+                    environment.insert(sym.clone(), lookup(&sym, name.to_rc_value()));
+                }
+            }
+            Value::Keyword(kw) if is_directive(kw, "or") || is_directive(kw, "as") => {}
+            // {a :a, [b c] :bc} binds each form to whatever its key expression names
+            _ => {
+                let lookup_key = val.eval_to_rc(Rc::clone(environment));
+                let item = match &*key {
+                    Value::Symbol(sym) => lookup(sym, lookup_key),
+                    _ => match &map {
+                        Some(map) => map.get(&lookup_key),
+                        None => Rc::new(Value::Nil),
+                    },
+                };
+                bind(environment, &key, item)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// What associative destructuring actually looks things up in;  nil is treated as an
+/// empty map, and a seq of alternating keys and values, such as the rest args of
+/// `(fn [& {:keys [a b]}] ..)`, as the map of those keys to those values
+fn as_map(value: Rc<Value>) -> Result<Rc<Value>, String> {
+    match &*value {
+        Value::PersistentListMap(_) | Value::PersistentHashMap(_) | Value::Nil => Ok(value),
+        _ if seq::is_seq(&value) => {
+            let items = seq::values(&value).map_err(|interruption| match &*interruption {
+                Value::Condition(condition) => condition.message.clone(),
+                _ => interruption.to_string(),
+            })?;
+            if items.len() % 2 != 0 {
+                return Err(format!("No value supplied for key: {}", items[items.len() - 1]));
+            }
+            Ok(Rc::new(persistent_hash_map::build_map(items.chunks(2).map(
+                |pair| MapEntry {
+                    key: Rc::clone(&pair[0]),
+                    val: Rc::clone(&pair[1]),
+                },
+            ))))
+        }
+        _ => Err(format!(
+            "Cannot destructure {} by key; expected a map or nil",
+            value.type_tag()
+        )),
+    }
+}
+
+/// The names given to :keys, :strs or :syms, as symbols
+fn key_names(names: &Value, directive: &str) -> Result<Vec<Symbol>, String> {
+    validate_key_names(names, directive, directive == "keys")?;
+    match names {
+        Value::PersistentVector(pvector) => Ok(pvector
+            .to_vec()
+            .iter()
+            .filter_map(|name| match &**name {
+                Value::Symbol(sym) => Some(sym.clone()),
+                Value::Keyword(kw) => Some(kw.sym.clone()),
+                _ => None,
+            })
+            .collect()),
+        _ => Ok(vec![]),
+    }
+}
+
+fn is_ampersand(sym: &Symbol) -> bool {
+    sym.ns.is_none() && sym.name == "&"
+}
+
+fn is_as(form: &Value) -> bool {
+    match form {
+        Value::Keyword(kw) => is_directive(kw, "as"),
+        _ => false,
+    }
+}
+
+fn is_directive(kw: &Keyword, name: &str) -> bool {
+    kw.namespace().is_none() && kw.name() == name
+}
+
+#[cfg(test)]
+mod tests {
+    mod destructure_tests {
+        use crate::error_message;
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn sequential() {
+            assert_eq!(
+                eval_str("[1 2 (quote (3 4)) [1 2 3 4]]"),
+                eval_str("(let [[a b & more :as all] [1 2 3 4]] [a b more all])")
+            );
+            assert_eq!(
+                eval_str("[1 nil nil]"),
+                eval_str("(let [[a b & more] (list 1)] [a b more])")
+            );
+            assert_eq!(eval_str("[nil nil]"), eval_str("(let [[a b] nil] [a b])"));
+        }
+
+        #[test]
+        fn sequential_string() {
+            assert_eq!(
+                eval_str("[\"a\" (quote (\"b\" \"c\"))]"),
+                eval_str("(let [[c & more] \"abc\"] [c more])")
+            );
+        }
+
+        #[test]
+        fn sequential_rest_stays_lazy() {
+            assert_eq!(Value::I32(0), eval_str("(let [[a & r] (range)] a)"));
+            assert_eq!(
+                eval_str("[1 2]"),
+                eval_str("(let [[a & r] (map inc (range))] [a (first r)])")
+            );
+            assert_eq!(
+                Value::I32(3),
+                eval_str("(loop [[x & xs] (range) acc 0] (if (= x 3) acc (recur xs (+ acc x))))")
+            );
+        }
+
+        #[test]
+        fn associative() {
+            assert_eq!(
+                eval_str("[1 2 3 4 5]"),
+                eval_str(
+                    "(let [{a :a :keys [b e] :strs [c] :syms [d] :or {e 5}} {:a 1 :b 2 \"c\" 3 (quote d) 4}] [a b c d e])"
+                )
+            );
+            assert_eq!(
+                eval_str("[nil 2 {:a nil}]"),
+                eval_str("(let [{:keys [a b] :or {a 1 b 2} :as m} {:a nil}] [a b m])")
+            );
+        }
+
+        #[test]
+        fn associative_rest_of_any_seq() {
+            assert_eq!(
+                Value::I32(2),
+                eval_str("(let [[a & {:keys [k]}] [1 :k 2]] k)")
+            );
+            assert_eq!(
+                Value::I32(2),
+                eval_str("(let [[a & {:keys [k]}] (map (fn [x] x) [1 :k 2])] k)")
+            );
+        }
+
+        #[test]
+        fn nested() {
+            assert_eq!(
+                eval_str("[1 2 3 4]"),
+                eval_str(
+                    "(let [{[a {:keys [b]}] :xs {c :c [d] :ds} :m} {:xs [1 {:b 2}] :m {:c 3 :ds [4]}}] [a b c d])"
+                )
+            );
+        }
+
+        #[test]
+        fn in_fn_and_loop() {
+            assert_eq!(
+                Value::I32(6),
+                eval_str("((fn [[a b] {:keys [c]}] (+ a b c)) [1 2] {:c 3})")
+            );
+            assert_eq!(
+                eval_str("[1 2]"),
+                eval_str("((fn [& {:keys [a b]}] [a b]) :b 2 :a 1)")
+            );
+            assert_eq!(
+                Value::I32(10),
+                eval_str("(loop [[x & xs] [1 2 3 4] sum 0] (if x (recur xs (+ sum x)) sum))")
+            );
+            assert_eq!(
+                Value::I32(3),
+                eval_str("(do (defn add-point [{:keys [x y]}] (+ x y)) (add-point {:x 1 :y 2}))")
+            );
+        }
+
+        #[test]
+        fn bad_binding_forms() {
+            assert_eq!(
+                error_message::custom("Unsupported binding form: 1"),
+                eval_str("(fn [1] 1)")
+            );
+            assert_eq!(
+                error_message::custom("Cannot destructure rust.std.i32 by position; expected a sequential collection or nil"),
+                eval_str("(let [[a] 1] a)")
+            );
+        }
+    }
+}
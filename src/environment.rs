@@ -1,10 +1,17 @@
+use crate::clojure_data_json;
+use crate::clojure_edn;
+use crate::clojure_java_io;
 use crate::clojure_std;
+use crate::clojure_pprint;
 use crate::clojure_string;
 use crate::namespace::Namespaces;
+use crate::persistent_list_map::PersistentListMap;
 use crate::repl::Repl;
 use crate::rust_core;
+use crate::stream::{Reader, Writer};
 use crate::symbol::Symbol;
 use crate::value::{ToValue, Value};
+use crate::var::Var;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -61,6 +68,9 @@ impl EnvironmentVal {
     fn get_from_namespace(&self, namespace: &Symbol, sym: &Symbol) -> Rc<Value> {
         self.namespaces.get(namespace, sym)
     }
+    fn try_get_var_from_namespace(&self, namespace: &Symbol, sym: &Symbol) -> Option<Var> {
+        self.namespaces.try_get_var(namespace, sym)
+    }
     fn get_current_namespace(&self) -> Symbol {
         self.curr_ns_sym.borrow().clone()
     }
@@ -96,6 +106,11 @@ pub enum Environment {
     LocalEnvironment(Rc<Environment>, RefCell<HashMap<Symbol, Rc<Value>>>),
 }
 use Environment::*;
+
+/// sym, with the meta of a ^:dynamic def, so that the var it names can be rebound
+fn dynamic_sym(name: &str) -> Symbol {
+    Symbol::intern(name).with_meta(persistent_list_map! { "dynamic" => true })
+}
 impl Environment {
     pub fn has_namespace(&self, symbol: &Symbol) -> bool {
         match self.get_main_environment() {
@@ -243,6 +258,19 @@ impl Environment {
             }
         }
     }
+    /// The Var sym names from the current namespace, ignoring any local bindings;
+    /// for instance, `map` resolves to #'clojure.core/map from most namespaces
+    pub fn resolve_var(&self, sym: &Symbol) -> Option<Var> {
+        match self.get_main_environment() {
+            MainEnvironment(env_val) => {
+                env_val.try_get_var_from_namespace(&env_val.get_current_namespace(), sym)
+            }
+            LocalEnvironment(..) => panic!(
+                "get_main_environment() returns LocalEnvironment,\
+		                 but by definition should only return MainEnvironment"
+            ),
+        }
+    }
     // @TODO refactor to use ^
     // @TODO figure out convention for 'ns' vs 'namespace'
     /// Get closest value "around" us;  try our local environment, then
@@ -270,6 +298,32 @@ impl Environment {
         }
     }
 
+    /// Sets the root of *out*, where everything printed goes unless it's rebound;  so that,
+    /// say, an embedder can capture it with a Writer::buffer()
+    pub fn set_out(&self, out: Writer) {
+        self.insert_into_namespace(
+            &Symbol::intern("clojure.core"),
+            dynamic_sym("*out*"),
+            Rc::new(Value::Writer(out)),
+        );
+    }
+    /// Sets the root of *err*, where errors are reported
+    pub fn set_err(&self, err: Writer) {
+        self.insert_into_namespace(
+            &Symbol::intern("clojure.core"),
+            dynamic_sym("*err*"),
+            Rc::new(Value::Writer(err)),
+        );
+    }
+    /// Sets the root of *in*, where read-line reads from
+    pub fn set_in(&self, input: Reader) {
+        self.insert_into_namespace(
+            &Symbol::intern("clojure.core"),
+            dynamic_sym("*in*"),
+            Rc::new(Value::Reader(input)),
+        );
+    }
+
     pub fn populate_with_clojure_core(environment: Rc<Environment>) {
         // Register our macros / functions ahead of time
         let add_fn = rust_core::AddFn {};
@@ -282,29 +336,152 @@ impl Environment {
         let str_fn = rust_core::StrFn {};
         let do_fn = rust_core::DoFn {};
         let nth_fn = rust_core::NthFn {};
-        let do_macro = rust_core::DoMacro {};
         let concat_fn = rust_core::ConcatFn {};
-        let flush_stdout_fn = rust_core::FlushStdoutFn {};
-        let system_newline_fn = rust_core::SystemNewlineFn {};
-        let print_string_fn = rust_core::PrintStringFn {};
-        let read_line_fn = rust_core::ReadLineFn {};
+        let flush_stdout_fn = rust_core::FlushStdoutFn::new(Rc::clone(&environment));
+        let system_newline_fn = rust_core::SystemNewlineFn::new(Rc::clone(&environment));
+        let print_string_fn = rust_core::PrintStringFn::new(Rc::clone(&environment));
+        let read_line_fn = rust_core::ReadLineFn::new(Rc::clone(&environment));
+        let string_writer_fn = rust_core::StringWriterFn {};
+        let string_reader_fn = rust_core::StringReaderFn {};
+        let pr_str_fn = rust_core::PrStrFn::new(Rc::clone(&environment));
+        let prn_str_fn = rust_core::PrnStrFn::new(Rc::clone(&environment));
+        let print_str_fn = rust_core::PrintStrFn::new(Rc::clone(&environment));
+        let println_str_fn = rust_core::PrintlnStrFn::new(Rc::clone(&environment));
+        let pr_on_fn = rust_core::PrOnFn::new(Rc::clone(&environment));
         let assoc_fn = rust_core::AssocFn {};
+        let conj_fn = rust_core::ConjFn {};
+        let pop_fn = rust_core::PopFn {};
+        let disj_fn = rust_core::DisjFn {};
+        let contains_fn = rust_core::ContainsFn {};
+        let seq_fn = rust_core::SeqFn {};
+        let cons_fn = rust_core::ConsFn {};
+        let lazy_seq_fn = rust_core::LazySeqFn {};
+        let range_fn = rust_core::RangeFn {};
+        let realized_fn = rust_core::RealizedFn {};
+        let compare_fn = rust_core::CompareFn {};
+        let reduce_fn = rust_core::ReduceFn {};
+        let reduced_fn = rust_core::ReducedFn {};
+        let is_reduced_fn = rust_core::IsReducedFn {};
+        let unreduced_fn = rust_core::UnreducedFn {};
+        let filter_fn = rust_core::FilterFn {};
+        let remove_fn = rust_core::RemoveFn {};
+        let take_fn = rust_core::TakeFn {};
+        let drop_fn = rust_core::DropFn {};
+        let take_while_fn = rust_core::TakeWhileFn {};
+        let drop_while_fn = rust_core::DropWhileFn {};
+        let partition_fn = rust_core::PartitionFn {};
+        let partition_by_fn = rust_core::PartitionByFn {};
+        let interleave_fn = rust_core::InterleaveFn {};
+        let distinct_fn = rust_core::DistinctFn {};
+        let frequencies_fn = rust_core::FrequenciesFn {};
+        let group_by_fn = rust_core::GroupByFn {};
+        let sort_fn = rust_core::SortFn {};
+        let sort_by_fn = rust_core::SortByFn {};
+        let seq_reverse_fn = rust_core::ReverseFn {};
+        let some_fn = rust_core::SomeFn {};
+        let every_fn = rust_core::EveryFn {};
+        let mapcat_fn = rust_core::MapcatFn {};
+        let keep_fn = rust_core::KeepFn {};
+        let into_fn = rust_core::IntoFn {};
+        let comp_fn = rust_core::CompFn {};
+        let completing_fn = rust_core::CompletingFn {};
+        let transduce_fn = rust_core::TransduceFn {};
+        let sequence_fn = rust_core::SequenceFn {};
+        let eduction_fn = rust_core::EductionFn {};
+        let partition_all_fn = rust_core::PartitionAllFn {};
+        let dedupe_fn = rust_core::DedupeFn {};
+        let atom_fn = rust_core::AtomFn {};
+        let deref_fn = rust_core::DerefFn {};
+        let swap_fn = rust_core::SwapFn {};
+        let swap_vals_fn = rust_core::SwapValsFn {};
+        let reset_fn = rust_core::ResetFn {};
+        let reset_vals_fn = rust_core::ResetValsFn {};
+        let compare_and_set_fn = rust_core::CompareAndSetFn {};
+        let add_watch_fn = rust_core::AddWatchFn {};
+        let remove_watch_fn = rust_core::RemoveWatchFn {};
+        let with_bindings_fn = rust_core::WithBindingsFn {};
+        let var_set_fn = rust_core::VarSetFn {};
+        let with_redefs_fn = rust_core::WithRedefsFn {};
+        let inst_ms_fn = rust_core::InstMsFn {};
+        let multi_fn_fn = rust_core::MultiFnFn {};
+        let add_method_fn = rust_core::AddMethodFn {};
+        let remove_method_fn = rust_core::RemoveMethodFn {};
+        let get_method_fn = rust_core::GetMethodFn {};
+        let re_pattern_fn = rust_core::RePatternFn {};
+        let re_matcher_fn = rust_core::ReMatcherFn {};
+        let re_find_fn = rust_core::ReFindFn {};
+        let re_matches_fn = rust_core::ReMatchesFn {};
+        let re_seq_fn = rust_core::ReSeqFn {};
+        let re_groups_fn = rust_core::ReGroupsFn {};
+        let re_named_groups_fn = rust_core::ReNamedGroupsFn {};
         let more_fn = rust_core::MoreFn {};
         let first_fn = rust_core::FirstFn {};
         let second_fn = rust_core::SecondFn {};
         let read_string_fn = rust_core::ReadStringFn {};
+        let gensym_fn = rust_core::GensymFn {};
+        let keyword_fn = rust_core::KeywordFn {};
+        let throw_fn = rust_core::ThrowFn {};
+        let ex_info_fn = rust_core::ExInfoFn {};
+        let ex_data_fn = rust_core::ExDataFn {};
+        let ex_trace_fn = rust_core::ExTraceFn {};
+        let ex_message_fn = rust_core::ExMessageFn {};
+        let ex_cause_fn = rust_core::ExCauseFn {};
+        let signal_fn = rust_core::SignalFn {};
+        let error_fn = rust_core::ErrorFn {};
+        let invoke_restart_fn = rust_core::InvokeRestartFn {};
 
         // rust implementations of core functions
         let slurp_fn = rust_core::slurp::SlurpFn {};
+        let spit_fn = rust_core::SpitFn {};
+        let line_seq_fn = rust_core::LineSeqFn {};
+        let file_seq_fn = rust_core::FileSeqFn {};
 
         // clojure.std functions
         let thread_sleep_fn = clojure_std::thread::SleepFn {};
         let nanotime_fn = clojure_std::time::NanoTimeFn {};
         let get_env_fn = clojure_std::env::GetEnvFn {};
+        let sh_fn = clojure_std::shell::ShFn {};
+        let process_fn = clojure_std::process::ProcessFn {};
+        let process_stdin_fn = clojure_std::process::StdinFn {};
+        let process_stdout_fn = clojure_std::process::StdoutFn {};
+        let process_stderr_fn = clojure_std::process::StderrFn {};
+        let process_wait_fn = clojure_std::process::WaitFn {};
+        let process_destroy_fn = clojure_std::process::DestroyFn {};
 
         let get_fn = rust_core::GetFn {};
         let map_fn = rust_core::MapFn {};
 
+        // clojure.data.json
+        let json_read_str_fn = clojure_data_json::read_str::ReadStrFn {};
+        let json_read_fn = clojure_data_json::read::ReadFn {};
+        let json_write_str_fn = clojure_data_json::write_str::WriteStrFn {};
+        let json_write_fn = clojure_data_json::write::WriteFn {};
+
+        // clojure.java.io
+        let io_file_fn = clojure_java_io::file::FileFn {};
+        let io_reader_fn = clojure_java_io::reader::ReaderFn {};
+        let io_writer_fn = clojure_java_io::writer::WriterFn {};
+        let io_close_fn = clojure_java_io::close::CloseFn {};
+        let io_delete_file_fn = clojure_java_io::delete_file::DeleteFileFn {};
+        let io_make_parents_fn = clojure_java_io::make_parents::MakeParentsFn {};
+        let io_exists_fn = clojure_java_io::exists_qmark_::ExistsFn {};
+        let io_is_file_fn = clojure_java_io::file_qmark_::IsFileFn {};
+        let io_directory_fn = clojure_java_io::directory_qmark_::DirectoryFn {};
+        let io_length_fn = clojure_java_io::length::LengthFn {};
+
+        // clojure.edn
+        let edn_read_string_fn = clojure_edn::read_string::ReadStringFn {};
+        let edn_read_fn = clojure_edn::read::ReadFn::new(Rc::clone(&environment));
+        let edn_write_string_fn = clojure_edn::write_string::WriteStringFn {};
+        let edn_write_fn = clojure_edn::write::WriteFn::new(Rc::clone(&environment));
+
+        // clojure.pprint
+        let pprint_fn = clojure_pprint::pprint::PprintFn::new(Rc::clone(&environment));
+        let simple_dispatch_fn =
+            clojure_pprint::dispatch::SimpleDispatchFn::new(Rc::clone(&environment));
+        let code_dispatch_fn =
+            clojure_pprint::dispatch::CodeDispatchFn::new(Rc::clone(&environment));
+
         // clojure.string
         let reverse_fn = clojure_string::reverse::ReverseFn {};
         let join_fn = clojure_string::join::JoinFn {};
@@ -319,6 +496,14 @@ impl Environment {
         let trimr_fn = clojure_string::trimr::TrimRFn {};
         let trim_newline_fn = clojure_string::trim_newline::TrimNewlineFn {};
         let split_fn = clojure_string::split::SplitFn {};
+        let split_lines_fn = clojure_string::split_lines::SplitLinesFn {};
+        let replace_fn = clojure_string::replace::ReplaceFn {};
+        let replace_first_fn = clojure_string::replace_first::ReplaceFirstFn {};
+        let re_quote_replacement_fn = clojure_string::re_quote_replacement::ReQuoteReplacementFn {};
+        let index_of_fn = clojure_string::index_of::IndexOfFn {};
+        let last_index_of_fn = clojure_string::last_index_of::LastIndexOfFn {};
+        let capitalize_fn = clojure_string::capitalize::CapitalizeFn {};
+        let escape_fn = clojure_string::escape::EscapeFn {};
 
         // Hardcoded fns
         let lexical_eval_fn = Value::LexicalEvalFn {};
@@ -329,6 +514,14 @@ impl Environment {
         let fn_macro = Value::FnMacro {};
         let defmacro_macro = Value::DefmacroMacro {};
         let if_macro = Value::IfMacro {};
+        let do_macro = Value::DoMacro {};
+        let loop_macro = Value::LoopMacro {};
+        let recur_macro = Value::RecurMacro {};
+        let syntax_quote_macro = Value::SyntaxQuoteMacro {};
+        let try_macro = Value::TryMacro {};
+        let handler_bind_macro = Value::HandlerBindMacro {};
+        let restart_case_macro = Value::RestartCaseMacro {};
+        let binding_macro = Value::BindingMacro {};
 
         let equals_fn = rust_core::EqualsFn {};
         let type_fn = rust_core::TypeFn {};
@@ -401,9 +594,162 @@ impl Environment {
             get_env_fn.to_rc_value(),
         );
 
+        // clojure.java.shell
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("sh"),
+            sh_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("process"),
+            process_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("stdin"),
+            process_stdin_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("stdout"),
+            process_stdout_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("stderr"),
+            process_stderr_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("wait"),
+            process_wait_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.shell"),
+            Symbol::intern("destroy"),
+            process_destroy_fn.to_rc_value(),
+        );
+
         // core.clj wraps calls to the rust implementations
         // @TODO add this to clojure.rs.core namespace as clojure.rs.core/slurp
         environment.insert(Symbol::intern("rust-slurp"), slurp_fn.to_rc_value());
+        environment.insert(Symbol::intern("spit"), spit_fn.to_rc_value());
+        environment.insert(Symbol::intern("line-seq"), line_seq_fn.to_rc_value());
+        environment.insert(Symbol::intern("file-seq"), file_seq_fn.to_rc_value());
+
+        // clojure.data.json
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.data.json"),
+            Symbol::intern("read-str"),
+            json_read_str_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.data.json"),
+            Symbol::intern("read"),
+            json_read_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.data.json"),
+            Symbol::intern("write-str"),
+            json_write_str_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.data.json"),
+            Symbol::intern("write"),
+            json_write_fn.to_rc_value(),
+        );
+
+        // clojure.java.io
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("file"),
+            io_file_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("reader"),
+            io_reader_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("writer"),
+            io_writer_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("close"),
+            io_close_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("delete-file"),
+            io_delete_file_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("make-parents"),
+            io_make_parents_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("exists?"),
+            io_exists_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("file?"),
+            io_is_file_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("directory?"),
+            io_directory_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.java.io"),
+            Symbol::intern("length"),
+            io_length_fn.to_rc_value(),
+        );
+
+        // clojure.edn
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.edn"),
+            Symbol::intern("read-string"),
+            edn_read_string_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.edn"),
+            Symbol::intern("read"),
+            edn_read_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.edn"),
+            Symbol::intern("write-string"),
+            edn_write_string_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.edn"),
+            Symbol::intern("write"),
+            edn_write_fn.to_rc_value(),
+        );
+
+        // clojure.pprint
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.pprint"),
+            Symbol::intern("pprint"),
+            pprint_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.pprint"),
+            Symbol::intern("simple-dispatch"),
+            simple_dispatch_fn.to_rc_value(),
+        );
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.pprint"),
+            Symbol::intern("code-dispatch"),
+            code_dispatch_fn.to_rc_value(),
+        );
 
         // clojure.string
         environment.insert_into_namespace(
@@ -484,11 +830,66 @@ impl Environment {
             split_fn.to_rc_value(),
         );
 
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("split-lines"),
+            split_lines_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("replace"),
+            replace_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("replace-first"),
+            replace_first_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("re-quote-replacement"),
+            re_quote_replacement_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("index-of"),
+            index_of_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("last-index-of"),
+            last_index_of_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("capitalize"),
+            capitalize_fn.to_rc_value(),
+        );
+
+        environment.insert_into_namespace(
+            &Symbol::intern("clojure.string"),
+            Symbol::intern("escape"),
+            escape_fn.to_rc_value(),
+        );
+
         environment.insert(Symbol::intern("quote"), quote_macro.to_rc_value());
         environment.insert(Symbol::intern("do-fn*"), do_fn.to_rc_value());
         environment.insert(Symbol::intern("do"), do_macro.to_rc_value());
         environment.insert(Symbol::intern("def"), def_macro.to_rc_value());
         environment.insert(Symbol::intern("if"), if_macro.to_rc_value());
+        environment.insert(Symbol::intern("loop"), loop_macro.to_rc_value());
+        environment.insert(Symbol::intern("recur"), recur_macro.to_rc_value());
+        environment.insert(
+            Symbol::intern("syntax-quote"),
+            syntax_quote_macro.to_rc_value(),
+        );
+        environment.insert(Symbol::intern("try"), try_macro.to_rc_value());
         environment.insert(Symbol::intern("ns"), ns_macro.to_rc_value());
         environment.insert(
             Symbol::intern("lexical-eval"),
@@ -498,11 +899,101 @@ impl Environment {
         environment.insert(Symbol::intern("nth"), nth_fn.to_rc_value());
         environment.insert(Symbol::intern("assoc"), assoc_fn.to_rc_value());
         environment.insert(Symbol::intern("get"), get_fn.to_rc_value());
+        environment.insert(Symbol::intern("conj"), conj_fn.to_rc_value());
+        environment.insert(Symbol::intern("pop"), pop_fn.to_rc_value());
+        environment.insert(Symbol::intern("disj"), disj_fn.to_rc_value());
+        environment.insert(Symbol::intern("contains?"), contains_fn.to_rc_value());
+        environment.insert(Symbol::intern("seq"), seq_fn.to_rc_value());
+        environment.insert(Symbol::intern("cons"), cons_fn.to_rc_value());
+        environment.insert(Symbol::intern("lazy-seq*"), lazy_seq_fn.to_rc_value());
+        environment.insert(Symbol::intern("range"), range_fn.to_rc_value());
+        environment.insert(Symbol::intern("realized?"), realized_fn.to_rc_value());
+        environment.insert(Symbol::intern("compare"), compare_fn.to_rc_value());
+        environment.insert(Symbol::intern("reduce"), reduce_fn.to_rc_value());
+        environment.insert(Symbol::intern("reduced"), reduced_fn.to_rc_value());
+        environment.insert(Symbol::intern("reduced?"), is_reduced_fn.to_rc_value());
+        environment.insert(Symbol::intern("unreduced"), unreduced_fn.to_rc_value());
+        environment.insert(Symbol::intern("filter"), filter_fn.to_rc_value());
+        environment.insert(Symbol::intern("remove"), remove_fn.to_rc_value());
+        environment.insert(Symbol::intern("take"), take_fn.to_rc_value());
+        environment.insert(Symbol::intern("drop"), drop_fn.to_rc_value());
+        environment.insert(Symbol::intern("take-while"), take_while_fn.to_rc_value());
+        environment.insert(Symbol::intern("drop-while"), drop_while_fn.to_rc_value());
+        environment.insert(Symbol::intern("partition"), partition_fn.to_rc_value());
+        environment.insert(Symbol::intern("partition-by"), partition_by_fn.to_rc_value());
+        environment.insert(Symbol::intern("interleave"), interleave_fn.to_rc_value());
+        environment.insert(Symbol::intern("distinct"), distinct_fn.to_rc_value());
+        environment.insert(Symbol::intern("frequencies"), frequencies_fn.to_rc_value());
+        environment.insert(Symbol::intern("group-by"), group_by_fn.to_rc_value());
+        environment.insert(Symbol::intern("sort"), sort_fn.to_rc_value());
+        environment.insert(Symbol::intern("sort-by"), sort_by_fn.to_rc_value());
+        environment.insert(Symbol::intern("reverse"), seq_reverse_fn.to_rc_value());
+        environment.insert(Symbol::intern("some"), some_fn.to_rc_value());
+        environment.insert(Symbol::intern("every?"), every_fn.to_rc_value());
+        environment.insert(Symbol::intern("mapcat"), mapcat_fn.to_rc_value());
+        environment.insert(Symbol::intern("keep"), keep_fn.to_rc_value());
+        environment.insert(Symbol::intern("into"), into_fn.to_rc_value());
+        environment.insert(Symbol::intern("comp"), comp_fn.to_rc_value());
+        environment.insert(Symbol::intern("completing"), completing_fn.to_rc_value());
+        environment.insert(Symbol::intern("transduce"), transduce_fn.to_rc_value());
+        environment.insert(Symbol::intern("sequence"), sequence_fn.to_rc_value());
+        environment.insert(Symbol::intern("eduction"), eduction_fn.to_rc_value());
+        environment.insert(Symbol::intern("partition-all"), partition_all_fn.to_rc_value());
+        environment.insert(Symbol::intern("dedupe"), dedupe_fn.to_rc_value());
+        environment.insert(Symbol::intern("atom"), atom_fn.to_rc_value());
+        environment.insert(Symbol::intern("deref"), deref_fn.to_rc_value());
+        environment.insert(Symbol::intern("swap!"), swap_fn.to_rc_value());
+        environment.insert(Symbol::intern("swap-vals!"), swap_vals_fn.to_rc_value());
+        environment.insert(Symbol::intern("reset!"), reset_fn.to_rc_value());
+        environment.insert(Symbol::intern("reset-vals!"), reset_vals_fn.to_rc_value());
+        environment.insert(Symbol::intern("compare-and-set!"), compare_and_set_fn.to_rc_value());
+        environment.insert(Symbol::intern("add-watch"), add_watch_fn.to_rc_value());
+        environment.insert(Symbol::intern("remove-watch"), remove_watch_fn.to_rc_value());
+        environment.insert(Symbol::intern("with-bindings*"), with_bindings_fn.to_rc_value());
+        environment.insert(Symbol::intern("binding*"), binding_macro.to_rc_value());
+        environment.insert(Symbol::intern("var-set"), var_set_fn.to_rc_value());
+        environment.insert(Symbol::intern("with-redefs-fn"), with_redefs_fn.to_rc_value());
+        environment.insert(Symbol::intern("inst-ms"), inst_ms_fn.to_rc_value());
+        environment.insert(Symbol::intern("multi-fn*"), multi_fn_fn.to_rc_value());
+        environment.insert(Symbol::intern("add-method*"), add_method_fn.to_rc_value());
+        environment.insert(Symbol::intern("remove-method"), remove_method_fn.to_rc_value());
+        environment.insert(Symbol::intern("get-method"), get_method_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-pattern"), re_pattern_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-matcher"), re_matcher_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-find"), re_find_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-matches"), re_matches_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-seq"), re_seq_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-groups"), re_groups_fn.to_rc_value());
+        environment.insert(Symbol::intern("re-named-groups"), re_named_groups_fn.to_rc_value());
         environment.insert(Symbol::intern("concat"), concat_fn.to_rc_value());
         environment.insert(Symbol::intern("more"), more_fn.to_rc_value());
         environment.insert(Symbol::intern("first"), first_fn.to_rc_value());
         environment.insert(Symbol::intern("second"), second_fn.to_rc_value());
         environment.insert(Symbol::intern("read-string"), read_string_fn.to_rc_value());
+        environment.insert(Symbol::intern("gensym"), gensym_fn.to_rc_value());
+        environment.insert(Symbol::intern("keyword"), keyword_fn.to_rc_value());
+        // exceptions
+        environment.insert(Symbol::intern("throw"), throw_fn.to_rc_value());
+        environment.insert(Symbol::intern("ex-info"), ex_info_fn.to_rc_value());
+        environment.insert(Symbol::intern("ex-data"), ex_data_fn.to_rc_value());
+        environment.insert(Symbol::intern("ex-trace"), ex_trace_fn.to_rc_value());
+        environment.insert(Symbol::intern("ex-message"), ex_message_fn.to_rc_value());
+        environment.insert(Symbol::intern("ex-cause"), ex_cause_fn.to_rc_value());
+        // conditions and restarts
+        environment.insert(Symbol::intern("signal"), signal_fn.to_rc_value());
+        environment.insert(Symbol::intern("error"), error_fn.to_rc_value());
+        environment.insert(
+            Symbol::intern("invoke-restart"),
+            invoke_restart_fn.to_rc_value(),
+        );
+        environment.insert(
+            Symbol::intern("handler-bind"),
+            handler_bind_macro.to_rc_value(),
+        );
+        environment.insert(
+            Symbol::intern("restart-case"),
+            restart_case_macro.to_rc_value(),
+        );
         // input and output
         environment.insert(
             Symbol::intern("system-newline"),
@@ -517,6 +1008,16 @@ impl Environment {
             print_string_fn.to_rc_value(),
         );
         environment.insert(Symbol::intern("read-line"), read_line_fn.to_rc_value());
+        environment.insert(Symbol::intern("string-writer"), string_writer_fn.to_rc_value());
+        environment.insert(Symbol::intern("string-reader"), string_reader_fn.to_rc_value());
+        environment.insert(Symbol::intern("pr-str"), pr_str_fn.to_rc_value());
+        environment.insert(Symbol::intern("prn-str"), prn_str_fn.to_rc_value());
+        environment.insert(Symbol::intern("print-str"), print_str_fn.to_rc_value());
+        environment.insert(Symbol::intern("println-str"), println_str_fn.to_rc_value());
+        environment.insert(Symbol::intern("pr-on"), pr_on_fn.to_rc_value());
+        environment.set_out(Writer::stdout());
+        environment.set_err(Writer::stderr());
+        environment.set_in(Reader::stdin());
 
         environment.insert(Symbol::intern("="), equals_fn.to_rc_value());
         environment.insert(Symbol::intern("type"), type_fn.to_rc_value());
@@ -529,7 +1030,7 @@ impl Environment {
         // TODO: should read into namespace if (ns ..) is given in source file
         for file in [
             "core",
-            "string",
+            "pprint",
         ] {
             let file = format!(
                 "{}/src/clojure/{}.clj",
@@ -1,52 +1,129 @@
+use crate::condition::{Condition, ConditionKind};
 use crate::type_tag::TypeTag;
 use crate::value::Value;
 use std::error::Error;
+use std::rc::Rc;
+
+/// A condition of the given kind;  the functions below are all built on this
+pub fn condition(kind: ConditionKind, message: impl Into<String>) -> Value {
+    Value::Condition(Rc::new(Condition::new(kind, message)))
+}
 
 pub fn type_mismatch(expected: TypeTag, got: &Value) -> Value {
-    Value::Condition(format!(
-        "Type mismatch; Expected instance of {},  Recieved type {}",
-        expected, got.type_tag()
-    ))
+    condition(
+        ConditionKind::ClassCastException,
+        format!(
+            "Type mismatch; Expected instance of {},  Recieved type {}",
+            expected,
+            got.type_tag()
+        ),
+    )
 }
 
 pub fn wrong_arg_count(expected: usize, got: usize) -> Value {
-    Value::Condition(format!(
-        "Wrong number of arguments given to function (Given: {}, Expected: {})",
-        got, expected
-    ))
+    condition(
+        ConditionKind::ArityException,
+        format!(
+            "Wrong number of arguments given to function (Given: {}, Expected: {})",
+            got, expected
+        ),
+    )
 }
 
 pub fn wrong_varg_count(expected: &[usize], got: usize) -> Value {
-    Value::Condition(format!(
-        "Wrong number of arguments given to function (Given: {}, Expected: {:?})",
-        got, expected
-    ))
+    condition(
+        ConditionKind::ArityException,
+        format!(
+            "Wrong number of arguments given to function (Given: {}, Expected: {:?})",
+            got, expected
+        ),
+    )
+}
+
+/// For fns that also take any number of arguments past some minimum;
+/// reports, say, `Expected: [0, 1, >=3]`
+pub fn wrong_variadic_arg_count(fixed: &[usize], variadic_min: usize, got: usize) -> Value {
+    let expected = fixed
+        .iter()
+        .map(|argc| argc.to_string())
+        .chain(std::iter::once(format!(">={}", variadic_min)))
+        .collect::<Vec<String>>()
+        .join(", ");
+    condition(
+        ConditionKind::ArityException,
+        format!(
+            "Wrong number of arguments given to function (Given: {}, Expected: [{}])",
+            got, expected
+        ),
+    )
 }
 
 pub fn zero_arg_count(got: usize) -> Value {
-    Value::Condition(format!(
-        "Wrong number of arguments given to function (Given: {})",
-        got
-    ))
+    condition(
+        ConditionKind::ArityException,
+        format!(
+            "Wrong number of arguments given to function (Given: {})",
+            got
+        ),
+    )
+}
+
+pub fn recur_arg_count(expected: usize, got: usize) -> Value {
+    condition(
+        ConditionKind::IllegalArgumentException,
+        format!(
+            "Mismatched argument count to recur (Given: {}, Expected: {})",
+            got, expected
+        ),
+    )
+}
+
+pub fn non_tail_recur() -> Value {
+    condition(
+        ConditionKind::UnsupportedOperationException,
+        String::from("Can only recur from tail position"),
+    )
+}
+
+/// A recur whose loop or fn is outside of form, which it can't be carried up through
+pub fn recur_across(form: &str) -> Value {
+    condition(
+        ConditionKind::UnsupportedOperationException,
+        format!("Cannot recur across {}", form),
+    )
+}
+
+pub fn no_such_restart(name: &str) -> Value {
+    condition(
+        ConditionKind::IllegalStateException,
+        format!("No restart named {} is active", name),
+    )
+}
+
+pub fn divide_by_zero() -> Value {
+    condition(ConditionKind::ArithmeticException, "Divide by zero")
 }
 
 pub fn index_out_of_bounds(ind: usize, count: usize) -> Value {
-    Value::Condition(format!(
-        "Index out of bounds: Index ({}), Length: ({})",
-        ind, count
-    ))
+    condition(
+        ConditionKind::IndexOutOfBoundsException,
+        format!("Index out of bounds: Index ({}), Length: ({})", ind, count),
+    )
 }
 
 pub fn index_cannot_be_negative(ind: usize) -> Value {
-    Value::Condition(format!("Index cannot be negative; Index ({})", ind))
+    condition(
+        ConditionKind::IndexOutOfBoundsException,
+        format!("Index cannot be negative; Index ({})", ind),
+    )
 }
 
 pub fn generic_err(error: Box<dyn Error>) -> Value {
-    Value::Condition(error.to_string())
+    condition(ConditionKind::Exception, error.to_string())
 }
 
 pub fn unknown_err(error: String) -> Value {
-    Value::Condition(error)
+    condition(ConditionKind::Exception, error)
 }
 //
 // This module will likely be rewritten to look like everything below this line
@@ -63,7 +140,7 @@ pub fn unknown_err(error: String) -> Value {
 // (or, if we wish to be a bit more flexible and basically forgo the type system, we can use a plain string).
 // In my case, I think really expressing either as either is fine, as long as the same error produces
 // the same message each time, which can be enforced with functions; ie
-// 
+//
 // pub weird_error() -> String {
 //    "This always returns the same weird error"
 // }
@@ -73,11 +150,14 @@ pub fn unknown_err(error: String) -> Value {
 
 // We currently don't have any type that represents an interface type name, so we
 pub struct Cast<'a>(pub &'a str);
-pub fn cast(expected: Cast,found: TypeTag) -> Value {
-    Value::Condition(format!("Cannot cast {} to {}",found,expected.0))
+pub fn cast(expected: Cast, found: TypeTag) -> Value {
+    condition(
+        ConditionKind::ClassCastException,
+        format!("Cannot cast {} to {}", found, expected.0),
+    )
 }
 
 /// For one off errors
-pub fn custom(message: &str) -> Value {
-    Value::Condition(String::from(message))
+pub fn custom(message: impl Into<String>) -> Value {
+    condition(ConditionKind::RuntimeException, message)
 }
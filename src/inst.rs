@@ -0,0 +1,230 @@
+//! Instants in time, as #inst reads and prints them;  what java.util.Date is to Clojure
+//!
+//! We keep only milliseconds since the Unix epoch, so an offset given in a timestamp is
+//! applied as it's read, and we print ourselves back in UTC
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Inst {
+    millis: i64,
+}
+impl Inst {
+    pub fn from_millis(millis: i64) -> Inst {
+        Inst { millis }
+    }
+    /// Milliseconds since 1970-01-01T00:00:00Z, as inst-ms gives
+    pub fn millis(&self) -> i64 {
+        self.millis
+    }
+    /// Parses an RFC 3339 timestamp, as #inst takes one;
+    /// yyyy[-MM[-dd[Thh[:mm[:ss[.fff]]]]]][Z|(+|-)hh:mm], where what's left out is its least
+    pub fn parse(timestamp: &str) -> Result<Inst, String> {
+        let invalid = || format!("Unrecognized date/time syntax: {}", timestamp);
+        let (date_time, offset_minutes) = split_offset(timestamp).ok_or_else(invalid)?;
+        let (date, time) = match date_time.find('T') {
+            Some(t) => (&date_time[..t], Some(&date_time[t + 1..])),
+            None => (date_time, None),
+        };
+
+        let mut date = date.split('-');
+        let year = digits(date.next(), 4).ok_or_else(invalid)?;
+        let month = optional_digits(date.next(), 2, 1).ok_or_else(invalid)?;
+        let day = optional_digits(date.next(), 2, 1).ok_or_else(invalid)?;
+        if date.next().is_some() || !(1..=12).contains(&month) {
+            return Err(invalid());
+        }
+        if day < 1 || day > days_in_month(year, month) {
+            return Err(invalid());
+        }
+
+        let (mut hour, mut minute, mut second, mut millis) = (0, 0, 0, 0);
+        if let Some(time) = time {
+            let (time, fraction) = match time.find('.') {
+                Some(point) => (&time[..point], Some(&time[point + 1..])),
+                None => (time, None),
+            };
+            let mut time = time.split(':');
+            hour = digits(time.next(), 2).ok_or_else(invalid)?;
+            minute = optional_digits(time.next(), 2, 0).ok_or_else(invalid)?;
+            second = optional_digits(time.next(), 2, 0).ok_or_else(invalid)?;
+            if time.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+                return Err(invalid());
+            }
+            if let Some(fraction) = fraction {
+                if fraction.is_empty() || !fraction.chars().all(|ch| ch.is_ascii_digit()) {
+                    return Err(invalid());
+                }
+                // Only milliseconds are kept;  anything finer is dropped
+                let fraction = format!("{:0<3}", &fraction[..fraction.len().min(3)]);
+                millis = fraction.parse().map_err(|_| invalid())?;
+            }
+        }
+
+        let days = days_from_civil(year, month, day);
+        let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second - offset_minutes * 60;
+        Ok(Inst::from_millis(seconds * 1_000 + millis))
+    }
+    /// This as an RFC 3339 timestamp in UTC, as #inst prints it
+    pub fn timestamp(&self) -> String {
+        let days = self.millis.div_euclid(86_400_000);
+        let millis_of_day = self.millis.rem_euclid(86_400_000);
+        let (year, month, day) = civil_from_days(days);
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}-00:00",
+            year,
+            month,
+            day,
+            millis_of_day / 3_600_000,
+            millis_of_day / 60_000 % 60,
+            millis_of_day / 1_000 % 60,
+            millis_of_day % 1_000
+        )
+    }
+}
+impl fmt::Display for Inst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#inst \"{}\"", self.timestamp())
+    }
+}
+
+/// timestamp without its offset, and its offset in minutes;  a timestamp without one is UTC
+fn split_offset(timestamp: &str) -> Option<(&str, i64)> {
+    if let Some(date_time) = timestamp.strip_suffix('Z') {
+        return Some((date_time, 0));
+    }
+    // An offset can only follow a time, so we look for its sign after the T
+    let t = match timestamp.find('T') {
+        Some(t) => t,
+        None => return Some((timestamp, 0)),
+    };
+    match timestamp[t..].rfind(['+', '-']) {
+        Some(sign) => {
+            let sign = t + sign;
+            let mut offset = timestamp[sign + 1..].split(':');
+            let hours = digits(offset.next(), 2)?;
+            let minutes = digits(offset.next(), 2)?;
+            if offset.next().is_some() || hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = hours * 60 + minutes;
+            match &timestamp[sign..sign + 1] {
+                "-" => Some((&timestamp[..sign], -offset)),
+                _ => Some((&timestamp[..sign], offset)),
+            }
+        }
+        None => Some((timestamp, 0)),
+    }
+}
+
+/// field as a number, if it's exactly count digits
+fn digits(field: Option<&str>, count: usize) -> Option<i64> {
+    match field {
+        Some(field) if field.len() == count && field.chars().all(|ch| ch.is_ascii_digit()) => {
+            field.parse().ok()
+        }
+        _ => None,
+    }
+}
+/// As digits, but default if field was left out
+fn optional_digits(field: Option<&str>, count: usize, default: i64) -> Option<i64> {
+    match field {
+        None => Some(default),
+        field => digits(field, count),
+    }
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// These two are Howard Hinnant's algorithms, for the proleptic Gregorian calendar;
+// see http://howardhinnant.github.io/date_algorithms.html
+
+/// Days since 1970-01-01 of the given date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+/// The year, month and day of days since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    mod inst_tests {
+        use crate::inst::Inst;
+
+        #[test]
+        fn parse_timestamps() {
+            assert_eq!(
+                Ok(Inst::from_millis(0)),
+                Inst::parse("1970-01-01T00:00:00.000-00:00")
+            );
+            assert_eq!(Ok(Inst::from_millis(0)), Inst::parse("1970"));
+            assert_eq!(
+                Ok(Inst::from_millis(1_577_934_245_500)),
+                Inst::parse("2020-01-02T03:04:05.5Z")
+            );
+            assert_eq!(
+                Inst::parse("2020-01-02T03:04:05Z"),
+                Inst::parse("2020-01-02T05:34:05+02:30")
+            );
+            assert_eq!(
+                Ok(Inst::from_millis(-86_400_000)),
+                Inst::parse("1969-12-31")
+            );
+        }
+
+        #[test]
+        fn reject_invalid_timestamps() {
+            for timestamp in &[
+                "",
+                "70",
+                "2020-13",
+                "2021-02-29",
+                "2020-01-01T24:00",
+                "2020-01-01T",
+            ] {
+                assert!(Inst::parse(timestamp).is_err(), "{}", timestamp);
+            }
+        }
+
+        #[test]
+        fn print_in_utc() {
+            assert_eq!(
+                "#inst \"2020-02-29T23:59:59.007-00:00\"",
+                Inst::parse("2020-03-01T01:59:59.007+02:00")
+                    .unwrap()
+                    .to_string()
+            );
+            assert_eq!(
+                "#inst \"1969-12-31T23:59:59.999-00:00\"",
+                Inst::from_millis(-1).to_string()
+            );
+        }
+    }
+}
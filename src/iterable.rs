@@ -1,5 +1,8 @@
 use crate::persistent_list::PersistentListIter;
 use crate::persistent_list::ToPersistentListIter;
+use crate::persistent_hash_map::PersistentHashMapIter;
+use crate::persistent_hash_set::PersistentHashSetIter;
+use crate::seq::{self, SeqIter};
 use crate::persistent_list_map::PersistentListMapIter;
 use crate::persistent_list_map::ToPersistentListMapIter;
 use crate::persistent_vector::PersistentVectorIter;
@@ -11,12 +14,15 @@ use crate::value::Value;
 use std::rc::Rc;
 // @TODO move to protocols::iterable
 
-define_protocol!(Iterable,PersistentList,PersistentListMap,PersistentVector);
+define_protocol!(Iterable,PersistentList,PersistentListMap,PersistentHashMap,PersistentHashSet,PersistentVector,LazySeq,Cons);
 
 pub enum IterableIter {
     PersistentList(PersistentListIter),
     PersistentVector(PersistentVectorIter),
     PersistentListMap(PersistentListMapIter),
+    PersistentHashMap(PersistentHashMapIter),
+    PersistentHashSet(PersistentHashSetIter),
+    Seq(SeqIter),
 }
 impl Iterator for IterableIter {
     type Item = Rc<Value>;
@@ -36,6 +42,13 @@ impl Iterator for IterableIter {
                 }
                 None
             }
+            IterableIter::PersistentHashMap(phash_map_iter) => {
+                phash_map_iter.next().map(|map_entry| {
+                    vec![map_entry.key, map_entry.val].into_vector().to_rc_value()
+                })
+            }
+            IterableIter::PersistentHashSet(pset_iter) => pset_iter.next(),
+            IterableIter::Seq(seq_iter) => seq_iter.next(),
         }
     }
 }
@@ -51,6 +64,9 @@ impl Iterable {
             Value::PersistentListMap(pmap) => {
                 IterableIter::PersistentListMap(Rc::new(pmap.clone()).iter())
             }
+            Value::PersistentHashMap(phashmap) => IterableIter::PersistentHashMap(phashmap.iter()),
+            Value::PersistentHashSet(pset) => IterableIter::PersistentHashSet(pset.iter()),
+            Value::LazySeq(_) | Value::Cons(_) => IterableIter::Seq(seq::iter(&self.value)),
             // We are ok panicking in this case because an invariant on the type is the assumption
             // that we only have an Iterable if we were able to convert
             _ => panic!("Called Iterable iter on non-iterable"),
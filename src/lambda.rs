@@ -1,63 +1,239 @@
+use crate::call_stack;
+use crate::destructure;
 use crate::environment::Environment;
+use crate::error_message;
 use crate::ifn::IFn;
 use crate::persistent_list::ToPersistentList;
 use crate::symbol::Symbol;
 use crate::value::{Evaluable, ToValue, Value};
 use std::rc::Rc;
 
+/// One parameter list and body of a fn;  for instance,
+/// ```clojure
+/// (fn ([x] ..) ([x y] ..) ([x y & more] ..))
+/// ```
+/// has three, the last of which is variadic
 #[derive(Debug, Clone)]
-pub struct Fn {
+pub struct FnArity {
+    /// The binding forms of the parameters before any `&`
+    pub params: Vec<Rc<Value>>,
+    /// The binding form after the `&`, if any, which is bound to the rest of the arguments
+    pub rest_param: Option<Rc<Value>>,
     pub body: Rc<Value>,
+}
+impl FnArity {
+    /// Builds an arity from its parameter vector, like [x [y z] & more], and its body
+    pub fn new(params: &[Rc<Value>], body: Rc<Value>) -> Result<FnArity, String> {
+        let mut fixed_params = vec![];
+        let mut params = params.iter();
+        while let Some(param) = params.next() {
+            match &**param {
+                Value::Symbol(sym) if sym.to_string() == "&" => {
+                    return match (params.next(), params.next()) {
+                        (Some(rest_param), None) => {
+                            destructure::validate(rest_param)?;
+                            Ok(FnArity {
+                                params: fixed_params,
+                                rest_param: Some(Rc::clone(rest_param)),
+                                body,
+                            })
+                        }
+                        _ => Err(String::from(
+                            "Invalid parameter list; expected exactly one binding form after '&'",
+                        )),
+                    };
+                }
+                _ => {
+                    destructure::validate(param)?;
+                    fixed_params.push(Rc::clone(param));
+                }
+            }
+        }
+        Ok(FnArity {
+            params: fixed_params,
+            rest_param: None,
+            body,
+        })
+    }
+    pub fn is_variadic(&self) -> bool {
+        self.rest_param.is_some()
+    }
+    /// Number of arguments this arity requires
+    pub fn required_arg_count(&self) -> usize {
+        self.params.len()
+    }
+    /// Binds the arguments given to this arity into local_environment
+    fn bind_args(
+        &self,
+        local_environment: &Rc<Environment>,
+        args: Vec<Rc<Value>>,
+    ) -> Result<(), Rc<Value>> {
+        let mut args = args.into_iter();
+        for param in self.params.iter() {
+            destructure::bind(local_environment, param, args.next().unwrap())
+                .map_err(|msg| Rc::new(error_message::custom(msg)))?;
+        }
+        if let Some(rest_param) = &self.rest_param {
+            let rest_args = args.collect::<Vec<Rc<Value>>>();
+            // Like Clojure, no rest arguments means our rest is nil, not ()
+            let rest_val = if rest_args.is_empty() {
+                Rc::new(Value::Nil)
+            } else {
+                rest_args.into_list().to_rc_value()
+            };
+            destructure::bind(local_environment, rest_param, rest_val)
+                .map_err(|msg| Rc::new(error_message::custom(msg)))?;
+        }
+        Ok(())
+    }
+    /// Binds the arguments of a (recur ..) into local_environment; unlike a
+    /// normal call, a variadic arity's rest argument is given directly as a seq
+    fn bind_recur_args(
+        &self,
+        local_environment: &Rc<Environment>,
+        args: Vec<Rc<Value>>,
+    ) -> Result<(), Rc<Value>> {
+        let params = self
+            .params
+            .iter()
+            .chain(self.rest_param.iter())
+            .collect::<Vec<&Rc<Value>>>();
+        if params.len() != args.len() {
+            return Err(Rc::new(error_message::recur_arg_count(params.len(), args.len())));
+        }
+        for (param, arg) in params.into_iter().zip(args) {
+            destructure::bind(local_environment, param, arg)
+                .map_err(|msg| Rc::new(error_message::custom(msg)))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Fn {
+    /// Given in (fn name [..] ..);  within the fn's body, name refers to the fn itself
+    pub name: Option<Symbol>,
+    /// Fixed arities, at most one per argument count
+    pub arities: Vec<FnArity>,
+    pub variadic_arity: Option<FnArity>,
     // Closed over variables
     pub enclosing_environment: Rc<Environment>,
-    pub arg_syms: Vec<Symbol>,
 }
 impl ToValue for Fn {
     fn to_value(&self) -> Value {
         Value::IFn(Rc::new(self.clone()))
     }
 }
-impl IFn for Fn {
-    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+impl Fn {
+    /// Sorts arities into fixed and variadic, enforcing the same rules Clojure does about
+    /// which overloads may live together
+    pub fn new(
+        name: Option<Symbol>,
+        all_arities: Vec<FnArity>,
+        enclosing_environment: Rc<Environment>,
+    ) -> Result<Fn, String> {
+        let mut arities: Vec<FnArity> = vec![];
+        let mut variadic_arity = None;
+        for arity in all_arities.into_iter() {
+            if arity.is_variadic() {
+                if variadic_arity.is_some() {
+                    return Err(String::from("Can't have more than 1 variadic overload"));
+                }
+                variadic_arity = Some(arity);
+            } else {
+                if arities
+                    .iter()
+                    .any(|other| other.required_arg_count() == arity.required_arg_count())
+                {
+                    return Err(String::from("Can't have 2 overloads with same arity"));
+                }
+                arities.push(arity);
+            }
+        }
+        if let Some(variadic_arity) = &variadic_arity {
+            if arities
+                .iter()
+                .any(|arity| arity.required_arg_count() > variadic_arity.required_arg_count())
+            {
+                return Err(String::from(
+                    "Can't have fixed arity function with more params than variadic function",
+                ));
+            }
+        }
+        arities.sort_by_key(|arity| arity.required_arg_count());
+        Ok(Fn {
+            name,
+            arities,
+            variadic_arity,
+            enclosing_environment,
+        })
+    }
+    /// Picks the arity to invoke for a given number of arguments
+    fn arity_for(&self, argc: usize) -> Option<&FnArity> {
+        self.arities
+            .iter()
+            .find(|arity| arity.required_arg_count() == argc)
+            .or_else(|| {
+                self.variadic_arity
+                    .as_ref()
+                    .filter(|arity| argc >= arity.required_arg_count())
+            })
+    }
+    fn wrong_arg_count(&self, got: usize) -> Value {
+        let fixed_arities = self
+            .arities
+            .iter()
+            .map(|arity| arity.required_arg_count())
+            .collect::<Vec<usize>>();
+        match (&self.variadic_arity, fixed_arities.as_slice()) {
+            (Some(variadic_arity), _) => error_message::wrong_variadic_arg_count(
+                &fixed_arities,
+                variadic_arity.required_arg_count(),
+                got,
+            ),
+            (None, [expected]) => error_message::wrong_arg_count(*expected, got),
+            (None, _) => error_message::wrong_varg_count(&fixed_arities, got),
+        }
+    }
+    /// A new local environment for one run of our body, which knows our name, if we have one
+    fn new_local_environment(&self) -> Rc<Environment> {
         let local_environment = Rc::new(Environment::new_local_environment(Rc::clone(
             &self.enclosing_environment,
         )));
-
-        let argc = self.arg_syms.len();
-
-        let mut var_args = false;
-        if argc >= 2 {
-            if let Some(sym) = self.arg_syms.get(argc - 2) {
-                if sym.to_string() == "&" {
-                    var_args = true;
-                    let last_sym = self.arg_syms.get(argc - 1).unwrap();
-                    local_environment.insert(last_sym.clone(), Rc::new(Value::Nil));
-                }
-            }
+        if let Some(name) = &self.name {
+            local_environment.insert(name.clone(), self.to_rc_value());
         }
-
-        if !var_args && args.len() != argc {
-            return Value::Condition(format!(
-                "Wrong number of arguments given to function (Given: {}, Expected: {})",
-                args.len(),
-                argc
-            ));
+        local_environment
+    }
+}
+impl IFn for Fn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        call_stack::in_call(self.name.as_ref(), || self.run(args))
+    }
+}
+impl Fn {
+    /// Runs the arity args are for, as invoke does, without recording the call
+    fn run(&self, args: Vec<Rc<Value>>) -> Value {
+        let arity = match self.arity_for(args.len()) {
+            Some(arity) => arity,
+            None => return self.wrong_arg_count(args.len()),
+        };
+        let mut local_environment = self.new_local_environment();
+        if let Err(condition) = arity.bind_args(&local_environment, args) {
+            return condition.to_value();
         }
-
-        for (i, arg) in args.iter().enumerate() {
-            let curr_sym = self.arg_syms.get(i).unwrap();
-            // We can bind the rest of the arguments, then, to the next variable and blow this popsicle stand
-            if curr_sym.to_string() == "&" {
-                if !var_args {
-                    return Value::Condition(String::from("Invalid function argument '&' in non-variable-argument function definition"));
+        // A (recur ..) in tail position of our body comes back to us as a Value::Recur,
+        // and we run the same arity's body again rather than growing the stack
+        loop {
+            match arity.body.eval(local_environment) {
+                Value::Recur(recur_args) => {
+                    local_environment = self.new_local_environment();
+                    if let Err(condition) = arity.bind_recur_args(&local_environment, recur_args) {
+                        return condition.to_value();
+                    }
                 }
-                let last_sym = self.arg_syms.get(i + 1).unwrap();
-                let rest_args = args.get(i..).unwrap().to_vec().into_list().to_rc_value();
-                local_environment.insert(last_sym.clone(), rest_args);
-                break;
+                result => return result,
             }
-            local_environment.insert(curr_sym.clone(), arg.to_rc_value());
         }
-        self.body.eval(local_environment)
     }
 }
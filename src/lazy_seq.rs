@@ -0,0 +1,178 @@
+//! A lazy seq, as Clojure's LazySeq is;  a seq whose values aren't worked out until they're
+//! first asked for.  The thunk working them out is run at most once, and what it gives back
+//! is kept for every later use
+//!
+//! ```clojure
+//! (lazy-seq (cons 1 (f)))  ; (f) is only called once we ask for more than the 1
+//! ```
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::meta::Meta;
+use crate::persistent_list_map::PersistentListMap;
+use crate::seq;
+use crate::traits;
+use crate::value::{ToValue, Value};
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Works out a lazy seq;  giving back any seqable value, or nil
+type Thunk = Box<dyn FnOnce() -> Rc<Value>>;
+
+enum LazySeqState {
+    Unrealized(Thunk),
+    /// Our thunk is running right now
+    Realizing,
+    /// What our thunk gave back;  once we've been seq'd, nil or a nonempty seq
+    Realized(Rc<Value>),
+}
+
+#[derive(Clone)]
+pub struct LazySeq {
+    state: Rc<RefCell<LazySeqState>>,
+    meta: Meta,
+}
+impl LazySeq {
+    pub fn new(thunk: impl FnOnce() -> Rc<Value> + 'static) -> LazySeq {
+        LazySeq {
+            state: Rc::new(RefCell::new(LazySeqState::Unrealized(Box::new(thunk)))),
+            meta: Meta::default(),
+        }
+    }
+    /// The lazy seq of what calling ifn, a fn of no arguments, gives back;  as lazy-seq makes
+    pub fn from_ifn(ifn: Rc<dyn IFn>) -> LazySeq {
+        LazySeq::new(move || Rc::new(ifn.invoke(vec![])))
+    }
+    /// The lazy seq of the values of iter, each only taken from iter when it's asked for.
+    /// Should iter give a condition, that's where the seq ends;  it's what the rest of the
+    /// seq realizes to, so walking it hands the condition back up
+    pub fn from_iter<I: Iterator<Item = Rc<Value>> + 'static>(mut iter: I) -> LazySeq {
+        LazySeq::new(move || match iter.next() {
+            Some(first) if first.non_tail().is_some() => first,
+            Some(first) => {
+                crate::cons::Cons::new(first, LazySeq::from_iter(iter).to_rc_value()).to_rc_value()
+            }
+            None => Rc::new(Value::Nil),
+        })
+    }
+    pub fn is_realized(&self) -> bool {
+        matches!(&*self.state.borrow(), LazySeqState::Realized(_))
+    }
+    /// Runs our thunk if it hasn't been run, giving back what it gave back
+    fn sval(&self) -> Rc<Value> {
+        let state = std::mem::replace(&mut *self.state.borrow_mut(), LazySeqState::Realizing);
+        let value = match state {
+            LazySeqState::Unrealized(thunk) => thunk(),
+            LazySeqState::Realized(value) => value,
+            LazySeqState::Realizing => {
+                return Rc::new(error_message::custom(
+                    "Lazy seq asked for its own values while working them out",
+                ))
+            }
+        };
+        *self.state.borrow_mut() = LazySeqState::Realized(Rc::clone(&value));
+        value
+    }
+    /// For Cons's Drop;  if nothing else holds on to us, what we were realized to, taken out
+    /// of us so it can be dropped without recursing into us
+    pub(crate) fn take_realized(&self) -> Option<Rc<Value>> {
+        if Rc::strong_count(&self.state) != 1 {
+            return None;
+        }
+        match std::mem::replace(&mut *self.state.borrow_mut(), LazySeqState::Realizing) {
+            LazySeqState::Realized(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+impl traits::Seqable for LazySeq {
+    /// Realizes us, giving back nil or a nonempty seq
+    fn seq(&self) -> Rc<Value> {
+        let mut value = self.sval();
+        // A thunk giving back another lazy seq is unwrapped in a loop rather than by recursing,
+        // as a long run of them (like filter makes, skipping values) would blow our stack
+        while let Value::LazySeq(lazy_seq) = &*value {
+            let next = lazy_seq.sval();
+            value = next;
+        }
+        let value = seq::seq(&value);
+        *self.state.borrow_mut() = LazySeqState::Realized(Rc::clone(&value));
+        value
+    }
+}
+impl fmt::Debug for LazySeq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &*self.state.borrow() {
+            LazySeqState::Realized(value) => write!(f, "LazySeq({:?})", value),
+            _ => write!(f, "LazySeq(..)"),
+        }
+    }
+}
+impl traits::IMeta for LazySeq {
+    fn meta(&self) -> PersistentListMap {
+        self.meta.get()
+    }
+}
+impl traits::IObj for LazySeq {
+    fn with_meta(&self, meta: PersistentListMap) -> LazySeq {
+        LazySeq {
+            meta: Meta::new(meta),
+            ..self.clone()
+        }
+    }
+}
+impl fmt::Display for LazySeq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        seq::fmt_seq(&self.to_rc_value(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod lazy_seq_tests {
+        use crate::lazy_seq::LazySeq;
+        use crate::seq;
+        use crate::traits::Seqable;
+        use crate::value::{ToValue, Value};
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        #[test]
+        fn thunk_runs_once() {
+            let calls = Rc::new(Cell::new(0));
+            let thunk_calls = Rc::clone(&calls);
+            let lazy_seq = LazySeq::new(move || {
+                thunk_calls.set(thunk_calls.get() + 1);
+                list_val!(1 2).to_rc_value()
+            });
+            assert_eq!(0, calls.get());
+            assert_eq!(list_val!(1 2).to_rc_value(), lazy_seq.seq());
+            assert_eq!(list_val!(1 2).to_rc_value(), lazy_seq.clone().seq());
+            assert_eq!(1, calls.get());
+        }
+
+        #[test]
+        fn from_iter_takes_only_whats_asked_for() {
+            let taken = Rc::new(Cell::new(0));
+            let iter_taken = Rc::clone(&taken);
+            let ints = (0..).map(move |i| {
+                iter_taken.set(iter_taken.get() + 1);
+                Value::I32(i).to_rc_value()
+            });
+            let lazy_seq = LazySeq::from_iter(ints).to_rc_value();
+            assert_eq!(
+                vec![Value::I32(0).to_rc_value(), Value::I32(1).to_rc_value()],
+                seq::iter(&lazy_seq).take(2).collect::<Vec<Rc<Value>>>()
+            );
+            assert_eq!(2, taken.get());
+        }
+
+        #[test]
+        fn long_seqs_drop_without_overflowing() {
+            let lazy_seq = LazySeq::from_iter((0..1_000_000).map(|i| Value::I32(i).to_rc_value()))
+                .to_rc_value();
+            assert_eq!(1_000_000, seq::iter(&lazy_seq).count());
+        }
+    }
+}
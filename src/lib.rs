@@ -6,22 +6,49 @@
 #[macro_use] pub mod protocol;
 #[macro_use] pub mod symbol;
 #[macro_use] pub mod var;
+             pub mod atom;
+             pub mod call_stack;
+             pub mod clojure_data_json;
+             pub mod clojure_edn;
+             pub mod clojure_java_io;
+             pub mod clojure_pprint;
              pub mod clojure_std;
              pub mod clojure_string;
+             pub mod condition;
+             pub mod condition_system;
+             pub mod cons;
+             pub mod destructure;
              pub mod environment;
              pub mod error_message;
              pub mod ifn;
+             pub mod inst;
              pub mod iterable;
              pub mod keyword;
+             pub mod lazy_seq;
              pub mod lambda;
              pub mod maps;
+             pub mod matcher;
+             pub mod meta;
+             pub mod multi_fn;
              pub mod namespace;
+             pub mod persistent_hash_map;
+             pub mod persistent_hash_set;
              pub mod persistent_vector;
+             pub mod printer;
+             pub mod process;
              pub mod reader;
              pub mod repl;
+             pub mod seq;
              pub mod rust_core;
+             pub mod source_position;
+             pub mod stream;
+             pub mod syntax_quote;
+#[cfg(test)]      mod test_util;
+             pub mod transducer;
+             pub mod try_catch;
              pub mod type_tag;
              pub mod user_action;
+             pub mod uuid;
                  mod util;
              pub mod value;
              pub mod protocols;
@@ -1,14 +1,17 @@
 use std::io;
 use rust_clojure::{
-    user_action::{Action, parse_args},
+    user_action::{Action, parse_args, take_flag},
     repl::Repl,
 };
 
 fn main() {
-    let cli_arg: Action = parse_args(std::env::args().collect());
+    let mut arguments: Vec<String> = std::env::args().collect();
+    // pretty print results, as clojure.pprint/pprint would
+    let pretty_print = take_flag(&mut arguments, "--pprint");
+    let cli_arg: Action = parse_args(arguments);
 
     // instantiate the core environment
-    let repl = Repl::default();
+    let repl = Repl::default().with_pretty_print(pretty_print);
 
     // do the work
     act(&repl, cli_arg);
@@ -19,14 +22,14 @@ fn act(repl: &Repl, a: Action) {
         // eval the file/script
         Action::RunScript(script_filepath) => {
             if let Some(val) = repl.eval_file(script_filepath.as_str()) {
-                println!("{}", val);
+                println!("{}", repl.pr_str(&val));
             }
         }
 
         // eval the expression
         Action::Evaluate(expr_str) => {
             if let Some(last_val) = repl.eval_readable(expr_str.as_bytes()) {
-                println!("{}", last_val);
+                println!("{}", repl.pr_str(&last_val));
             }
         }
 
@@ -1,5 +1,7 @@
 //! General map utilities
 use crate::value::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
 #[derive(Debug, Clone, PartialEq, Hash)]
@@ -10,3 +12,22 @@ pub struct MapEntry {
     pub key: Rc<Value>,
     pub val: Rc<Value>,
 }
+
+/// Hashes the entries of a map into state, such that the same entries in any order, and in
+/// either of our map types, hash the same
+pub fn hash_entries<I: Iterator<Item = MapEntry>, H: Hasher>(entries: I, state: &mut H) {
+    hash_unordered(entries, state)
+}
+
+/// Hashes items into state, such that the same items in any order hash the same;  for
+/// collections, like maps and sets, whose equality doesn't care for order
+pub fn hash_unordered<T: Hash, I: Iterator<Item = T>, H: Hasher>(items: I, state: &mut H) {
+    let items_hash = items
+        .map(|item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0_u64, u64::wrapping_add);
+    items_hash.hash(state)
+}
@@ -0,0 +1,175 @@
+//! Matchers, as re-matcher makes them;  a regex and a string it's run over, one match at a
+//! time, as java.util.regex.Matcher is to Clojure
+//!
+//! Each find carries on from where the last left off, and the groups of the last match are
+//! kept for re-groups
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::keyword::Keyword;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::persistent_vector::PersistentVector;
+use crate::value::{ToValue, Value};
+use regex::{CaptureLocations, Regex};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Matcher {
+    re: Regex,
+    s: String,
+    /// Where the next find starts looking
+    next: Cell<usize>,
+    /// The groups of the last match found, if there is one
+    last_match: RefCell<Option<CaptureLocations>>,
+}
+impl Matcher {
+    pub fn new(re: Regex, s: String) -> Matcher {
+        Matcher {
+            re,
+            s,
+            next: Cell::new(0),
+            last_match: RefCell::new(None),
+        }
+    }
+    /// Looks for the next match, as Matcher.find does;  whether there was one.  After a match
+    /// of nothing, the next find starts a character further on, so it doesn't find it again
+    pub fn find(&self) -> bool {
+        let start = self.next.get();
+        if start > self.s.len() {
+            self.last_match.replace(None);
+            return false;
+        }
+        let mut locations = self.re.capture_locations();
+        match self.re.captures_read_at(&mut locations, &self.s, start) {
+            Some(found) => {
+                let next = if found.start() == found.end() {
+                    found.end()
+                        + self.s[found.end()..]
+                            .chars()
+                            .next()
+                            .map_or(1, char::len_utf8)
+                } else {
+                    found.end()
+                };
+                self.next.set(next);
+                self.last_match.replace(Some(locations));
+                true
+            }
+            None => {
+                self.next.set(self.s.len() + 1);
+                self.last_match.replace(None);
+                false
+            }
+        }
+    }
+    /// The groups of the last match, as re-groups gives them;  Err if there wasn't one
+    pub fn groups(&self) -> Result<Value, Rc<Value>> {
+        let last_match = self.last_match.borrow();
+        let locations = last_match.as_ref().ok_or_else(no_match_found)?;
+        Ok(groups((0..locations.len()).map(|i| {
+            locations.get(i).map(|(start, end)| &self.s[start..end])
+        })))
+    }
+    /// The named groups of the last match;  Err if there wasn't one
+    pub fn named_groups(&self) -> Result<Value, Rc<Value>> {
+        let last_match = self.last_match.borrow();
+        let locations = last_match.as_ref().ok_or_else(no_match_found)?;
+        Ok(named_groups(&self.re, |i| {
+            locations.get(i).map(|(start, end)| &self.s[start..end])
+        }))
+    }
+}
+impl fmt::Display for Matcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "#matcher[{} {}]",
+            Value::Pattern(self.re.clone()).to_string_explicit(),
+            Value::String(self.s.clone()).to_string_explicit()
+        )
+    }
+}
+
+fn no_match_found() -> Rc<Value> {
+    Rc::new(error_message::condition(
+        ConditionKind::IllegalStateException,
+        "No match found",
+    ))
+}
+
+/// A match's groups, the whole match first, as Clojure gives them;  just the match if its regex
+/// has no groups of its own, or else all of them in a vector, with nil for any that didn't
+/// take part
+pub fn groups<'s>(mut groups: impl ExactSizeIterator<Item = Option<&'s str>>) -> Value {
+    let group = |group: Option<&str>| Rc::new(group.map_or(Value::Nil, |group| group.to_value()));
+    if groups.len() == 1 {
+        return (*group(groups.next().unwrap())).clone();
+    }
+    groups.map(group).collect::<PersistentVector>().to_value()
+}
+
+/// The named groups of re in a match, as a map of keywords of their names to what they matched
+/// (or nil, for any that didn't take part), given by group what the group at each index matched
+pub fn named_groups<'s>(re: &Regex, group: impl Fn(usize) -> Option<&'s str>) -> Value {
+    let mut named_groups = PersistentListMap::Empty;
+    // Our maps print newest first, so these go in backwards to print in order
+    let names = re.capture_names().enumerate().collect::<Vec<_>>();
+    for (i, name) in names.into_iter().rev() {
+        if let Some(name) = name {
+            named_groups = named_groups.assoc(
+                Keyword::intern(name).to_rc_value(),
+                Rc::new(group(i).map_or(Value::Nil, |group| group.to_value())),
+            );
+        }
+    }
+    named_groups.to_value()
+}
+
+#[cfg(test)]
+mod tests {
+    mod matcher_tests {
+        use crate::matcher::Matcher;
+        use regex::Regex;
+
+        fn found(matcher: &Matcher) -> Vec<String> {
+            let mut found = vec![];
+            while matcher.find() {
+                found.push(matcher.groups().unwrap().to_string());
+            }
+            found
+        }
+
+        #[test]
+        fn find_one_match_after_another() {
+            let matcher = Matcher::new(Regex::new("[0-9]+").unwrap(), "a1b22c333".to_string());
+            assert_eq!(vec!["1", "22", "333"], found(&matcher));
+            assert!(matcher.groups().is_err());
+        }
+
+        #[test]
+        fn find_matches_of_nothing_once_each() {
+            let matcher = Matcher::new(Regex::new("b*").unwrap(), "abñ".to_string());
+            assert_eq!(vec!["", "b", "", ""], found(&matcher));
+        }
+
+        #[test]
+        fn groups_of_last_match() {
+            let matcher = Matcher::new(
+                Regex::new("(?P<key>[a-z]+)=([0-9]+)?").unwrap(),
+                "a=1 b=".to_string(),
+            );
+            assert!(matcher.find());
+            assert_eq!(
+                "[\"a=1\" \"a\" \"1\"]",
+                matcher.groups().unwrap().to_string()
+            );
+            assert!(matcher.find());
+            assert_eq!("[\"b=\" \"b\" nil]", matcher.groups().unwrap().to_string());
+            assert_eq!(
+                "{:key \"b\"}",
+                matcher.named_groups().unwrap().to_string_explicit()
+            );
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Metadata, as carried by our collections
+use crate::persistent_list_map::PersistentListMap;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// The metadata map of a list, vector or map.  As in Clojure, metadata never factors into
+/// equality or hashing, so any two Metas are equal, and a Meta hashes to nothing
+#[derive(Debug, Clone, Default)]
+pub struct Meta(Option<Rc<PersistentListMap>>);
+impl Meta {
+    pub fn new(meta: PersistentListMap) -> Meta {
+        match meta {
+            PersistentListMap::Empty => Meta(None),
+            meta => Meta(Some(Rc::new(meta))),
+        }
+    }
+    pub fn get(&self) -> PersistentListMap {
+        match &self.0 {
+            Some(meta) => (**meta).clone(),
+            None => PersistentListMap::Empty,
+        }
+    }
+}
+impl PartialEq for Meta {
+    fn eq(&self, _other: &Meta) -> bool {
+        true
+    }
+}
+impl Hash for Meta {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
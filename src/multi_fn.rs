@@ -0,0 +1,96 @@
+//! Multimethods, as in Clojure;  a fn that calls one of its methods, chosen by what its
+//! dispatch fn gives back for its arguments.  Made by defmulti, and added to by defmethod
+//!
+//! Unlike Clojure, a dispatch value is only matched by equality;  there's no hierarchy
+//! for isa? to look up
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::protocols;
+use crate::symbol::Symbol;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Each dispatch value with the method added for it
+type Methods = Vec<(Rc<Value>, protocols::IFn)>;
+
+// Clones share their methods, as they're the same multimethod
+#[derive(Debug, Clone)]
+pub struct MultiFn {
+    pub name: Symbol,
+    dispatch_fn: protocols::IFn,
+    /// The method for each dispatch value, in the order they were added
+    methods: Rc<RefCell<Methods>>,
+}
+impl MultiFn {
+    pub fn new(name: Symbol, dispatch_fn: protocols::IFn) -> MultiFn {
+        MultiFn {
+            name,
+            dispatch_fn,
+            methods: Rc::new(RefCell::new(vec![])),
+        }
+    }
+    /// The dispatch value that stands for any other, :default
+    pub fn default_dispatch_value() -> Rc<Value> {
+        Keyword::intern("default").to_rc_value()
+    }
+    /// Adds method for dispatch_value, replacing any there already
+    pub fn add_method(&self, dispatch_value: Rc<Value>, method: protocols::IFn) {
+        let mut methods = self.methods.borrow_mut();
+        match methods
+            .iter_mut()
+            .find(|(added_for, _)| *added_for == dispatch_value)
+        {
+            Some(added) => added.1 = method,
+            None => methods.push((dispatch_value, method)),
+        }
+    }
+    pub fn remove_method(&self, dispatch_value: &Rc<Value>) {
+        self.methods
+            .borrow_mut()
+            .retain(|(added_for, _)| added_for != dispatch_value);
+    }
+    /// The method added for dispatch_value itself, not counting the :default method
+    pub fn get_own_method(&self, dispatch_value: &Rc<Value>) -> Option<protocols::IFn> {
+        self.methods
+            .borrow()
+            .iter()
+            .find(|(added_for, _)| added_for == dispatch_value)
+            .map(|(_, method)| method.clone())
+    }
+    /// The method that would be called for dispatch_value;  its own, or else the :default
+    pub fn get_method(&self, dispatch_value: &Rc<Value>) -> Option<protocols::IFn> {
+        self.get_own_method(dispatch_value)
+            .or_else(|| self.get_own_method(&MultiFn::default_dispatch_value()))
+    }
+    /// Whether any methods other than the :default have been added
+    pub fn has_own_methods(&self) -> bool {
+        let default = MultiFn::default_dispatch_value();
+        self.methods
+            .borrow()
+            .iter()
+            .any(|(added_for, _)| *added_for != default)
+    }
+}
+impl IFn for MultiFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let dispatch_value = Rc::new(self.dispatch_fn.invoke(args.clone()));
+        if let Some(interruption) = dispatch_value.non_tail() {
+            return (*interruption).clone();
+        }
+        match self.get_method(&dispatch_value) {
+            Some(method) => method.invoke(args),
+            None => error_message::custom(format!(
+                "No method in multimethod '{}' for dispatch value: {}",
+                self.name, dispatch_value
+            )),
+        }
+    }
+}
+impl fmt::Display for MultiFn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#multifn[{}]", self.name)
+    }
+}
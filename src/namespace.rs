@@ -1,3 +1,4 @@
+use crate::error_message;
 use crate::symbol::Symbol;
 use crate::var::Var;
 use crate::value::Value;
@@ -187,21 +188,22 @@ impl Namespace {
     pub fn get_var(&self, sym: &Symbol) -> Rc<Value> {
         match self.mappings.borrow_mut().get(&sym.unqualified()).map(|var| var.clone()) {
             Some(var) => Rc::new(Value::Var(var)),
-            None => Rc::new(Value::Condition(format!("Undefined symbol {}", sym))),
+            None => Rc::new(error_message::custom(format!("Undefined symbol {}", sym))),
         }
     }
 
     pub fn try_get(&self, sym: &Symbol) -> Option<Rc<Value>> {
-        match self.mappings.borrow_mut().get(&sym.unqualified()) {
-            Some(var) => Some(var.deref()),
-            None => None,
-        }
+        self.try_get_var(sym).map(|var| var.deref())
+    }
+
+    pub fn try_get_var(&self, sym: &Symbol) -> Option<Var> {
+        self.mappings.borrow_mut().get(&sym.unqualified()).cloned()
     }
 
     pub fn get(&self, sym: &Symbol) -> Rc<Value> {
         match self.try_get(sym) {
             Some(val) => val,
-            None => Rc::new(Value::Condition(format!("Undefined symbol {}", sym))),
+            None => Rc::new(error_message::custom(format!("Undefined symbol {}", sym))),
         }
     }
 }
@@ -310,12 +312,17 @@ impl Namespaces {
         match namespace {
             Some(namespace) => namespace.get_var(&sym),
             // @TODO should this be a condition or nil?
-            _ => Rc::new(Value::Condition(format!("Undefined symbol {}", sym))),
+            _ => Rc::new(error_message::custom(format!("Undefined symbol {}", sym))),
         }
     }
     /// Like get, but slightly lower level; returns a None on failure rather than a
     /// Value::Condition. See docs for get
     pub fn try_get(&self, namespace_sym: &Symbol, sym: &Symbol) -> Option<Rc<Value>> {
+        self.try_get_var(namespace_sym, sym).map(|var| var.deref())
+    }
+    /// Like try_get, but returns the Var sym resolves to rather than its value; the Var
+    /// knows which namespace it actually lives in, which may be one namespace_sym refers
+    pub fn try_get_var(&self, namespace_sym: &Symbol, sym: &Symbol) -> Option<Var> {
         // When storing / retrieving from namespaces, we want
         // namespace_sym unqualified keys
         let mut namespace_sym = namespace_sym.unqualified();
@@ -334,7 +341,7 @@ impl Namespaces {
 
         // If we cannot find the symbol, and its not a direct grab from a specific namespace,
         // we should see if we can find it in one of our referred namespaces or symbols
-        let val = namespace.try_get(&sym);
+        let val = namespace.try_get_var(&sym);
         match val {
             Some(_) => val,
             None => {
@@ -351,7 +358,7 @@ impl Namespaces {
                         continue;
                     }
                     // Ex: let's try to get, then, from "clojure.core or "clojure.string"
-                    let try_get_sym_from_other_ns = self.try_get(&referred_namespace_sym, &sym);
+                    let try_get_sym_from_other_ns = self.try_get_var(&referred_namespace_sym, &sym);
                     // And if we get a value, return it
                     if let Some(_) = &try_get_sym_from_other_ns {
                         return try_get_sym_from_other_ns;
@@ -381,7 +388,7 @@ impl Namespaces {
                     // If we *have* referred the sym we're looking for from this ns
                     // let's try to get it
                     // Ex:  try_get('clojure.string, '+)
-                    let try_get_sym_from_other_ns = self.try_get(&referred_namespace_sym, &sym);
+                    let try_get_sym_from_other_ns = self.try_get_var(&referred_namespace_sym, &sym);
                     // And if we get a value, return it
                     if let Some(_) = &try_get_sym_from_other_ns {
                         return try_get_sym_from_other_ns;
@@ -418,7 +425,7 @@ impl Namespaces {
         match self.try_get(namespace_sym, sym) {
             Some(val) => val,
             // @TODO should this be a condition or nil?
-            None => Rc::new(Value::Condition(format!("Undefined symbol {}", sym))),
+            None => Rc::new(error_message::custom(format!("Undefined symbol {}", sym))),
         }
     }
 }
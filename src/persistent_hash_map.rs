@@ -0,0 +1,594 @@
+//! For larger Persistent maps;  a hash array mapped trie, as Clojure's PersistentHashMap is.
+//! Each level of the trie is indexed by the next 5 bits of a key's hash, so that a lookup
+//! or assoc only walks a handful of nodes however many entries there are, and an assoc only
+//! copies that handful of nodes, sharing the rest with the map it was assoc'd onto
+//!
+//! Small maps stay PersistentListMaps, which are cheaper to build and walk when tiny;  see
+//! LIST_MAP_MAX_COUNT and build_map
+use crate::maps::MapEntry;
+use crate::lazy_seq::LazySeq;
+use crate::meta::Meta;
+use crate::persistent_vector::ToPersistentVector;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap, ToPersistentListMapIter};
+use crate::traits;
+use crate::value::{ToValue, Value};
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+/// Maps with more entries than this are PersistentHashMaps, and those with this many or less
+/// are PersistentListMaps;  like the threshold Clojure keeps its PersistentArrayMaps under
+pub const LIST_MAP_MAX_COUNT: usize = 8;
+
+/// How many bits of a hash index each level of the trie
+const BITS_PER_LEVEL: u32 = 5;
+const LEVEL_MASK: u32 = (1 << BITS_PER_LEVEL) - 1;
+
+#[derive(Debug, Clone, Default)]
+pub struct PersistentHashMap {
+    count: usize,
+    root: Option<Rc<Node>>,
+    meta: Meta,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    /// Up to 32 slots, one for each value of this level's bits of the hash;  only the slots in
+    /// use are stored, with bitmap telling which those are
+    Branch { bitmap: u32, slots: Vec<Slot> },
+    /// Entries whose keys' hashes are entirely the same
+    Collision { hash: u32, entries: Vec<MapEntry> },
+}
+#[derive(Debug, Clone)]
+enum Slot {
+    Entry(u32, MapEntry),
+    Node(Rc<Node>),
+}
+
+fn hash_of(key: &Value) -> u32 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let hash = hasher.finish();
+    (hash ^ (hash >> 32)) as u32
+}
+/// The bit standing for hash's slot in a branch at shift
+fn bit_for(hash: u32, shift: u32) -> u32 {
+    1 << ((hash >> shift) & LEVEL_MASK)
+}
+/// Where the slot for bit is in a branch's slots
+fn index_for(bitmap: u32, bit: u32) -> usize {
+    (bitmap & (bit - 1)).count_ones() as usize
+}
+
+impl Node {
+    fn get(&self, hash: u32, shift: u32, key: &Rc<Value>) -> Option<&MapEntry> {
+        match self {
+            Node::Branch { bitmap, slots } => {
+                let bit = bit_for(hash, shift);
+                if bitmap & bit == 0 {
+                    return None;
+                }
+                match &slots[index_for(*bitmap, bit)] {
+                    Slot::Entry(entry_hash, entry) if *entry_hash == hash && entry.key == *key => {
+                        Some(entry)
+                    }
+                    Slot::Entry(..) => None,
+                    Slot::Node(node) => node.get(hash, shift + BITS_PER_LEVEL, key),
+                }
+            }
+            Node::Collision { entries, .. } => entries.iter().find(|entry| entry.key == *key),
+        }
+    }
+    /// This node with entry assoc'd onto it, and whether that added an entry rather than
+    /// replacing one
+    fn assoc(&self, hash: u32, shift: u32, entry: MapEntry) -> (Node, bool) {
+        match self {
+            Node::Branch { bitmap, slots } => {
+                let bit = bit_for(hash, shift);
+                let index = index_for(*bitmap, bit);
+                let mut slots = slots.clone();
+                if bitmap & bit == 0 {
+                    slots.insert(index, Slot::Entry(hash, entry));
+                    return (
+                        Node::Branch {
+                            bitmap: bitmap | bit,
+                            slots,
+                        },
+                        true,
+                    );
+                }
+                let added = match &slots[index] {
+                    Slot::Entry(entry_hash, old_entry) if old_entry.key == entry.key => {
+                        slots[index] = Slot::Entry(*entry_hash, entry);
+                        false
+                    }
+                    Slot::Entry(entry_hash, old_entry) => {
+                        slots[index] = Slot::Node(Rc::new(Node::pair(
+                            shift + BITS_PER_LEVEL,
+                            (*entry_hash, old_entry.clone()),
+                            (hash, entry),
+                        )));
+                        true
+                    }
+                    Slot::Node(node) => {
+                        let (node, added) = node.assoc(hash, shift + BITS_PER_LEVEL, entry);
+                        slots[index] = Slot::Node(Rc::new(node));
+                        added
+                    }
+                };
+                (
+                    Node::Branch {
+                        bitmap: *bitmap,
+                        slots,
+                    },
+                    added,
+                )
+            }
+            Node::Collision {
+                hash: collision_hash,
+                entries,
+            } if *collision_hash == hash => {
+                let mut entries = entries.clone();
+                match entries
+                    .iter()
+                    .position(|old_entry| old_entry.key == entry.key)
+                {
+                    Some(index) => {
+                        entries[index] = entry;
+                        (Node::Collision { hash, entries }, false)
+                    }
+                    None => {
+                        entries.push(entry);
+                        (Node::Collision { hash, entries }, true)
+                    }
+                }
+            }
+            // A different hash, so we can no longer be a leaf;  we become a branch holding
+            // ourselves, and let it sort out where the new entry goes
+            Node::Collision {
+                hash: collision_hash,
+                ..
+            } => Node::Branch {
+                bitmap: bit_for(*collision_hash, shift),
+                slots: vec![Slot::Node(Rc::new(self.clone()))],
+            }
+            .assoc(hash, shift, entry),
+        }
+    }
+    /// This node without the entry for key;  None if key isn't here, and Some(None) if
+    /// removing it leaves us with no entries
+    fn dissoc(&self, hash: u32, shift: u32, key: &Rc<Value>) -> Option<Option<Node>> {
+        match self {
+            Node::Branch { bitmap, slots } => {
+                let bit = bit_for(hash, shift);
+                if bitmap & bit == 0 {
+                    return None;
+                }
+                let index = index_for(*bitmap, bit);
+                let mut slots = slots.clone();
+                let emptied = match &slots[index] {
+                    Slot::Entry(_, entry) if entry.key == *key => true,
+                    Slot::Entry(..) => return None,
+                    Slot::Node(node) => match node.dissoc(hash, shift + BITS_PER_LEVEL, key)? {
+                        Some(node) => {
+                            slots[index] = Slot::Node(Rc::new(node));
+                            false
+                        }
+                        None => true,
+                    },
+                };
+                if !emptied {
+                    return Some(Some(Node::Branch {
+                        bitmap: *bitmap,
+                        slots,
+                    }));
+                }
+                slots.remove(index);
+                if slots.is_empty() {
+                    return Some(None);
+                }
+                let bitmap = bitmap & !bit;
+                Some(Some(Node::Branch { bitmap, slots }))
+            }
+            Node::Collision { hash, entries } => {
+                let index = entries.iter().position(|entry| entry.key == *key)?;
+                let mut entries = entries.clone();
+                entries.remove(index);
+                if entries.is_empty() {
+                    return Some(None);
+                }
+                Some(Some(Node::Collision {
+                    hash: *hash,
+                    entries,
+                }))
+            }
+        }
+    }
+    /// The node holding just the two entries given, which fell into the same slot above shift
+    fn pair(shift: u32, first: (u32, MapEntry), second: (u32, MapEntry)) -> Node {
+        let (first_hash, second_hash) = (first.0, second.0);
+        if first_hash == second_hash {
+            return Node::Collision {
+                hash: first_hash,
+                entries: vec![first.1, second.1],
+            };
+        }
+        let (first_bit, second_bit) = (bit_for(first_hash, shift), bit_for(second_hash, shift));
+        if first_bit == second_bit {
+            return Node::Branch {
+                bitmap: first_bit,
+                slots: vec![Slot::Node(Rc::new(Node::pair(
+                    shift + BITS_PER_LEVEL,
+                    first,
+                    second,
+                )))],
+            };
+        }
+        let slots = if first_bit < second_bit {
+            vec![
+                Slot::Entry(first.0, first.1),
+                Slot::Entry(second.0, second.1),
+            ]
+        } else {
+            vec![
+                Slot::Entry(second.0, second.1),
+                Slot::Entry(first.0, first.1),
+            ]
+        };
+        Node::Branch {
+            bitmap: first_bit | second_bit,
+            slots,
+        }
+    }
+}
+
+impl PersistentHashMap {
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    fn get_entry(&self, key: &Rc<Value>) -> Option<&MapEntry> {
+        self.root
+            .as_ref()
+            .and_then(|root| root.get(hash_of(key), 0, key))
+    }
+    /// Whether we have the same entries as the PersistentListMap plistmap
+    pub fn equals_list_map(&self, plistmap: &PersistentListMap) -> bool {
+        self.count == plistmap.count()
+            && plistmap
+                .iter()
+                .all(|entry| self.get_entry(&entry.key).map(|ours| &ours.val) == Some(&entry.val))
+    }
+    /// Our entries, as a PersistentListMap, whatever our size
+    pub fn to_list_map(&self) -> PersistentListMap {
+        self.iter().collect()
+    }
+    /// This map without an entry for key
+    pub fn dissoc(&self, key: &Rc<Value>) -> PersistentHashMap {
+        let root = match &self.root {
+            Some(root) => root.dissoc(hash_of(key), 0, key),
+            None => None,
+        };
+        match root {
+            Some(root) => PersistentHashMap {
+                count: self.count - 1,
+                root: root.map(Rc::new),
+                meta: self.meta.clone(),
+            },
+            None => self.clone(),
+        }
+    }
+    pub fn iter(&self) -> PersistentHashMapIter {
+        PersistentHashMapIter {
+            stack: self.root.iter().map(|root| (Rc::clone(root), 0)).collect(),
+        }
+    }
+}
+impl IPersistentMap for PersistentHashMap {
+    fn get(&self, key: &Rc<Value>) -> Rc<Value> {
+        match self.get_entry(key) {
+            Some(entry) => Rc::clone(&entry.val),
+            None => Rc::new(Value::Nil),
+        }
+    }
+    fn get_with_default(&self, key: &Rc<Value>, default: &Rc<Value>) -> Rc<Value> {
+        match self.get_entry(key) {
+            Some(entry) => Rc::clone(&entry.val),
+            None => Rc::clone(default),
+        }
+    }
+    fn assoc(&self, key: Rc<Value>, val: Rc<Value>) -> PersistentHashMap {
+        let hash = hash_of(&key);
+        let entry = MapEntry { key, val };
+        let (root, added) = match &self.root {
+            Some(root) => root.assoc(hash, 0, entry),
+            None => (
+                Node::Branch {
+                    bitmap: bit_for(hash, 0),
+                    slots: vec![Slot::Entry(hash, entry)],
+                },
+                true,
+            ),
+        };
+        PersistentHashMap {
+            count: if added { self.count + 1 } else { self.count },
+            root: Some(Rc::new(root)),
+            meta: self.meta.clone(),
+        }
+    }
+    fn contains_key(&self, key: &Rc<Value>) -> bool {
+        self.get_entry(key).is_some()
+    }
+}
+
+/// The map of entries, later entries replacing earlier ones with the same key;  a
+/// PersistentListMap if there are few enough, and otherwise a PersistentHashMap
+pub fn build_map<I: IntoIterator<Item = MapEntry>>(entries: I) -> Value {
+    let entries = entries.into_iter().collect::<Vec<MapEntry>>();
+    if entries.len() <= LIST_MAP_MAX_COUNT {
+        return entries
+            .into_iter()
+            .collect::<PersistentListMap>()
+            .to_value();
+    }
+    let hash_map = entries.into_iter().collect::<PersistentHashMap>();
+    if hash_map.count() <= LIST_MAP_MAX_COUNT {
+        return hash_map.to_list_map().to_value();
+    }
+    hash_map.to_value()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// Iterating
+//
+////////////////////////////////////////////////////////////////////////////////////////////////////
+pub struct PersistentHashMapIter {
+    /// The nodes we're partway through, with the index of the next slot or entry to visit
+    stack: Vec<(Rc<Node>, usize)>,
+}
+impl Iterator for PersistentHashMapIter {
+    type Item = MapEntry;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, index) = self.stack.last_mut()?;
+            let node = Rc::clone(node);
+            let slot_index = *index;
+            *index += 1;
+            match &*node {
+                Node::Branch { slots, .. } => match slots.get(slot_index) {
+                    Some(Slot::Entry(_, entry)) => return Some(entry.clone()),
+                    Some(Slot::Node(child)) => self.stack.push((Rc::clone(child), 0)),
+                    None => {
+                        self.stack.pop();
+                    }
+                },
+                Node::Collision { entries, .. } => match entries.get(slot_index) {
+                    Some(entry) => return Some(entry.clone()),
+                    None => {
+                        self.stack.pop();
+                    }
+                },
+            }
+        }
+    }
+}
+impl FromIterator<MapEntry> for PersistentHashMap {
+    fn from_iter<I: IntoIterator<Item = MapEntry>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(PersistentHashMap::default(), |map, entry| {
+                map.assoc(entry.key, entry.val)
+            })
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+// End Iteration
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl From<&PersistentListMap> for PersistentHashMap {
+    fn from(plistmap: &PersistentListMap) -> Self {
+        PersistentHashMap {
+            meta: Meta::new(traits::IMeta::meta(plistmap)),
+            ..plistmap.iter().collect()
+        }
+    }
+}
+
+// Like a PersistentListMap, we're equal to any map with the same entries, in whatever order
+impl PartialEq for PersistentHashMap {
+    fn eq(&self, other: &PersistentHashMap) -> bool {
+        self.count == other.count
+            && other
+                .iter()
+                .all(|entry| self.get_entry(&entry.key).map(|ours| &ours.val) == Some(&entry.val))
+    }
+}
+impl Eq for PersistentHashMap {}
+impl Hash for PersistentHashMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::maps::hash_entries(self.iter(), state)
+    }
+}
+
+// Seq'd as its entries, each a [key val] vector
+impl traits::Seqable for PersistentHashMap {
+    fn seq(&self) -> Rc<Value> {
+        let entries = self
+            .iter()
+            .map(|entry| vec![entry.key, entry.val].into_vector().to_rc_value());
+        LazySeq::from_iter(entries).seq()
+    }
+}
+impl traits::IMeta for PersistentHashMap {
+    fn meta(&self) -> PersistentListMap {
+        self.meta.get()
+    }
+}
+impl traits::IObj for PersistentHashMap {
+    fn with_meta(&self, meta: PersistentListMap) -> PersistentHashMap {
+        PersistentHashMap {
+            meta: Meta::new(meta),
+            ..self.clone()
+        }
+    }
+}
+impl fmt::Display for PersistentHashMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let str = self
+            .iter()
+            .map(|mapentry| {
+                format!(
+                    "{} {}",
+                    mapentry.key.to_string_explicit(),
+                    mapentry.val.to_string_explicit()
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        write!(f, "{{{}}}", str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod persistent_hash_map_tests {
+        use crate::keyword::Keyword;
+        use crate::maps::MapEntry;
+        use crate::persistent_hash_map::*;
+        use crate::value::ToValue;
+
+        fn entry(i: i32) -> MapEntry {
+            MapEntry {
+                key: i.to_rc_value(),
+                val: (i * 10).to_rc_value(),
+            }
+        }
+
+        #[test]
+        fn assoc_and_get_many() {
+            let map = (0..5000).map(entry).collect::<PersistentHashMap>();
+            assert_eq!(5000, map.count());
+            for i in 0..5000 {
+                assert_eq!((i * 10).to_rc_value(), map.get(&i.to_rc_value()));
+            }
+            assert_eq!(Value::Nil, *map.get(&5000.to_rc_value()));
+            assert!(!map.contains_key(&Keyword::intern("a").to_rc_value()));
+        }
+
+        #[test]
+        fn assoc_replaces() {
+            let map = (0..100).map(entry).collect::<PersistentHashMap>();
+            let replaced = map.assoc(7.to_rc_value(), Keyword::intern("seven").to_rc_value());
+            assert_eq!(100, replaced.count());
+            assert_eq!(
+                Keyword::intern("seven").to_rc_value(),
+                replaced.get(&7.to_rc_value())
+            );
+            // The map we assoc'd onto is untouched
+            assert_eq!(70.to_rc_value(), map.get(&7.to_rc_value()));
+        }
+
+        #[test]
+        fn dissoc_many() {
+            let map = (0..2000).map(entry).collect::<PersistentHashMap>();
+            let halved = (0..2000)
+                .step_by(2)
+                .fold(map.clone(), |map, i| map.dissoc(&i.to_rc_value()));
+            assert_eq!(1000, halved.count());
+            for i in 0..2000 {
+                assert_eq!(i % 2 == 1, halved.contains_key(&i.to_rc_value()));
+            }
+            assert_eq!(halved, halved.dissoc(&Keyword::intern("a").to_rc_value()));
+            let emptied = (0..2000).fold(map.clone(), |map, i| map.dissoc(&i.to_rc_value()));
+            assert_eq!(0, emptied.count());
+            assert_eq!(0, emptied.iter().count());
+            assert_eq!(2000, map.count());
+        }
+
+        #[test]
+        fn iterates_every_entry_once() {
+            let map = (0..1000).map(entry).collect::<PersistentHashMap>();
+            let mut keys = map
+                .iter()
+                .map(|entry| match &*entry.key {
+                    Value::I32(i) => *i,
+                    _ => panic!("expected an i32 key"),
+                })
+                .collect::<Vec<i32>>();
+            keys.sort_unstable();
+            assert_eq!((0..1000).collect::<Vec<i32>>(), keys);
+        }
+
+        #[test]
+        fn colliding_hashes() {
+            // Entries whose hashes are forced to collide entirely, and then diverge
+            let root = Node::pair(0, (1, entry(1)), (1, entry(2)));
+            let (root, added) = root.assoc(1, 0, entry(3));
+            assert!(added);
+            let (root, added) = root.assoc(2, 0, entry(4));
+            assert!(added);
+            let (root, added) = root.assoc(1, 0, entry(3));
+            assert!(!added);
+            for (hash, i) in [(1, 1), (1, 2), (1, 3), (2, 4)].iter() {
+                assert_eq!(Some(&entry(*i)), root.get(*hash, 0, &i.to_rc_value()));
+            }
+            assert_eq!(None, root.get(1, 0, &4.to_rc_value()));
+        }
+
+        #[test]
+        fn equals_across_representations() {
+            let hash_map = (0..20).map(entry).collect::<PersistentHashMap>();
+            let list_map = (0..20).rev().map(entry).collect::<PersistentListMap>();
+            assert!(hash_map.equals_list_map(&list_map));
+            assert_eq!(hash_map.to_value(), list_map.to_value());
+            assert_eq!(list_map.to_value(), hash_map.to_value());
+            assert!(!hash_map.equals_list_map(&PersistentListMap::Empty));
+        }
+
+        #[test]
+        fn build_map_by_size() {
+            match build_map((0..LIST_MAP_MAX_COUNT as i32).map(entry)) {
+                Value::PersistentListMap(_) => {}
+                map => panic!("expected a PersistentListMap, got {}", map),
+            }
+            match build_map((0..=LIST_MAP_MAX_COUNT as i32).map(entry)) {
+                Value::PersistentHashMap(_) => {}
+                map => panic!("expected a PersistentHashMap, got {}", map),
+            }
+        }
+    }
+
+    mod hash_map_value_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn assoc_grows_into_hash_map() {
+            assert_eq!(
+                eval_str("[100 49 true \"clojure.lang.PersistentHashMap\"]"),
+                eval_str(
+                    "(let [m (loop [m {} i 0]
+                               (if (= i 100) m (recur (assoc m i (+ i 1)) (+ i 1))))]
+                       [(count m) (get m 48) (= m (assoc m 0 1)) (str (type m))])"
+                )
+            );
+        }
+
+        #[test]
+        fn map_literals_by_size() {
+            assert_eq!(
+                Value::Boolean(true),
+                eval_str("(= {:a 1 :b 2} {:b 2 :a 1})")
+            );
+            assert_eq!(
+                eval_str("[10 10 11]"),
+                eval_str(
+                    "(let [m {:a 1 :b 2 :c 3 :d 4 :e 5 :f 6 :g 7 :h 8 :i 9 :j 10}]
+                       [(count m) (get m :j) (count (assoc m :k 11))])"
+                )
+            );
+            assert_eq!(Value::I32(1), eval_str("(count (assoc {:a 1} :a 2))"));
+        }
+    }
+}
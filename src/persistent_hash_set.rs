@@ -0,0 +1,191 @@
+//! A persistent set, as Clojure's PersistentHashSet is;  a PersistentHashMap of each of our
+//! values to itself, so that it shares that map's trie, and how cheap it is to add, remove
+//! and look up values
+use crate::lazy_seq::LazySeq;
+use crate::meta::Meta;
+use crate::persistent_hash_map::{PersistentHashMap, PersistentHashMapIter};
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::traits;
+use crate::value::Value;
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, Default)]
+pub struct PersistentHashSet {
+    map: PersistentHashMap,
+    meta: Meta,
+}
+
+impl PersistentHashSet {
+    pub fn count(&self) -> usize {
+        self.map.count()
+    }
+    pub fn contains(&self, val: &Rc<Value>) -> bool {
+        self.map.contains_key(val)
+    }
+    /// Our value equal to val, or nil;  what calling a set as a fn does
+    pub fn get(&self, val: &Rc<Value>) -> Rc<Value> {
+        self.map.get(val)
+    }
+    /// This set with val added
+    pub fn conj(&self, val: Rc<Value>) -> PersistentHashSet {
+        if self.contains(&val) {
+            return self.clone();
+        }
+        PersistentHashSet {
+            map: self.map.assoc(Rc::clone(&val), val),
+            meta: self.meta.clone(),
+        }
+    }
+    /// This set without val
+    pub fn disj(&self, val: &Rc<Value>) -> PersistentHashSet {
+        PersistentHashSet {
+            map: self.map.dissoc(val),
+            meta: self.meta.clone(),
+        }
+    }
+    pub fn iter(&self) -> PersistentHashSetIter {
+        PersistentHashSetIter {
+            entries: self.map.iter(),
+        }
+    }
+}
+
+pub struct PersistentHashSetIter {
+    entries: PersistentHashMapIter,
+}
+impl Iterator for PersistentHashSetIter {
+    type Item = Rc<Value>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entries.next().map(|entry| entry.key)
+    }
+}
+impl FromIterator<Rc<Value>> for PersistentHashSet {
+    fn from_iter<I: IntoIterator<Item = Rc<Value>>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(PersistentHashSet::default(), |set, val| set.conj(val))
+    }
+}
+
+// Like our maps, we're equal to any set with the same values, in whatever order
+impl PartialEq for PersistentHashSet {
+    fn eq(&self, other: &PersistentHashSet) -> bool {
+        self.count() == other.count() && other.iter().all(|val| self.contains(&val))
+    }
+}
+impl Eq for PersistentHashSet {}
+impl Hash for PersistentHashSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::maps::hash_unordered(self.iter(), state)
+    }
+}
+
+impl traits::Seqable for PersistentHashSet {
+    fn seq(&self) -> Rc<Value> {
+        LazySeq::from_iter(self.iter()).seq()
+    }
+}
+impl traits::IMeta for PersistentHashSet {
+    fn meta(&self) -> PersistentListMap {
+        self.meta.get()
+    }
+}
+impl traits::IObj for PersistentHashSet {
+    fn with_meta(&self, meta: PersistentListMap) -> PersistentHashSet {
+        PersistentHashSet {
+            meta: Meta::new(meta),
+            ..self.clone()
+        }
+    }
+}
+impl fmt::Display for PersistentHashSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let str = self
+            .iter()
+            .map(|val| val.to_string_explicit())
+            .collect::<Vec<String>>()
+            .join(" ");
+        write!(f, "#{{{}}}", str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod persistent_hash_set_tests {
+        use crate::persistent_hash_set::PersistentHashSet;
+        use crate::value::{ToValue, Value};
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(set: &PersistentHashSet) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            set.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        #[test]
+        fn conj_disj_and_contains() {
+            let set = (0..100)
+                .map(|i| i.to_rc_value())
+                .collect::<PersistentHashSet>();
+            assert_eq!(100, set.count());
+            assert_eq!(set, set.conj(5.to_rc_value()));
+            let without_five = set.disj(&5.to_rc_value());
+            assert_eq!(99, without_five.count());
+            assert!(!without_five.contains(&5.to_rc_value()));
+            assert!(set.contains(&5.to_rc_value()));
+            assert_eq!(Value::Nil, *without_five.get(&5.to_rc_value()));
+            assert_eq!(Value::I32(6), *without_five.get(&6.to_rc_value()));
+        }
+
+        #[test]
+        fn equal_and_hashed_the_same_in_any_order() {
+            let forwards = (0..50)
+                .map(|i| i.to_rc_value())
+                .collect::<PersistentHashSet>();
+            let backwards = (0..50)
+                .rev()
+                .map(|i| i.to_rc_value())
+                .collect::<PersistentHashSet>();
+            assert_eq!(forwards, backwards);
+            assert_eq!(hash_of(&forwards), hash_of(&backwards));
+            assert_ne!(forwards, backwards.disj(&0.to_rc_value()));
+        }
+    }
+    mod hash_set_value_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn set_literals_evaluate_their_values() {
+            assert_eq!(eval_str("#{1 2 3}"), eval_str("(let [x 3] #{(+ 0 1) 2 x})"));
+            assert_eq!(
+                Value::Boolean(true),
+                eval_str("(= #{[1 2] {:a 1}} #{{:a 1} [1 2]})")
+            );
+            assert_eq!(
+                eval_str("\"clojure.lang.PersistentHashSet\""),
+                eval_str("(str (type #{}))")
+            );
+        }
+
+        #[test]
+        fn sets_as_fns() {
+            assert_eq!(
+                eval_str("[:a nil]"),
+                eval_str("[(#{:a :b} :a) (#{:a :b} :c)]")
+            );
+        }
+
+        #[test]
+        fn sets_are_iterable() {
+            assert_eq!(
+                eval_str("[3 1 '(2)]"),
+                eval_str("[(count #{1 2 3}) (first #{1}) (map (fn [x] (+ x 1)) #{1})]")
+            );
+        }
+    }
+}
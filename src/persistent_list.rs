@@ -3,15 +3,16 @@ use std::fmt::Debug;
 use std::iter::FromIterator;
 use std::rc::Rc;
 
+use crate::meta::Meta;
 use crate::value::{ToValue, Value};
 use crate::persistent_list_map::PersistentListMap;
 use crate::traits;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum PersistentList {
     // @TODO refactor i32 (our len) into a usize
-    Cons(Rc<Value>, Rc<PersistentList>, i32),
+    Cons(Rc<Value>, Rc<PersistentList>, i32, Meta),
     Empty,
 }
 // Experimental
@@ -44,16 +45,12 @@ macro_rules! list_val {
         }
     };
 }
-// @TODO definitely don't do this
-#[derive(Debug, Clone, Hash)]
-struct EmptyHash {}
-
 use crate::persistent_list::PersistentList::{Cons, Empty};
 pub fn cons_rc(head: Rc<Value>, tail: Rc<PersistentList>) -> PersistentList {
-    if let Cons(_, _, old_count) = &*tail {
-        Cons(Rc::clone(&head), Rc::clone(&tail), old_count + 1)
+    if let Cons(_, _, old_count, _) = &*tail {
+        Cons(Rc::clone(&head), Rc::clone(&tail), old_count + 1, Meta::default())
     } else {
-        Cons(Rc::clone(&head), Rc::clone(&tail), 1)
+        Cons(Rc::clone(&head), Rc::clone(&tail), 1, Meta::default())
     }
 }
 
@@ -64,7 +61,7 @@ pub fn cons(head: Value, tail: PersistentList) -> PersistentList {
 impl PersistentList {
     pub fn len(&self) -> i32 {
         match self {
-            Cons(_, _, count) => *count,
+            Cons(_, _, count, _) => *count,
             _ => 0,
         }
     }
@@ -98,22 +95,44 @@ impl ToPersistentList for Vec<Rc<Value>> {
         self.into_iter().collect::<PersistentList>()
     }
 }
+// A nonempty list is already a seq
+impl traits::Seqable for PersistentList {
+    fn seq(&self) -> Rc<Value> {
+        match self {
+            Cons(..) => self.to_rc_value(),
+            Empty => Rc::new(Value::Nil),
+        }
+    }
+}
+// Hashed as any seq of the same values is
+impl Hash for PersistentList {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::seq::hash_ordered(Rc::new(self.clone()).iter(), state)
+    }
+}
 impl traits::IMeta for PersistentList {
     fn meta(&self) -> PersistentListMap {
-        // @TODO implement
-        PersistentListMap::Empty
+        match self {
+            Cons(_, _, _, meta) => meta.get(),
+            Empty => PersistentListMap::Empty,
+        }
     }
 }
 impl traits::IObj for PersistentList {
-    fn with_meta(&self, _meta: PersistentListMap) -> PersistentList {
-        // @TODO implement
-        self.clone()
+    // @TODO let () carry metadata too
+    fn with_meta(&self, meta: PersistentListMap) -> PersistentList {
+        match self {
+            Cons(head, tail, count, _) => {
+                Cons(Rc::clone(head), Rc::clone(tail), *count, Meta::new(meta))
+            }
+            Empty => Empty,
+        }
     }
 }
 impl fmt::Display for PersistentList {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str = match self {
-            Cons(head, tail, count) => {
+            Cons(head, tail, count, _) => {
                 let tail_str = tail
                     .iter()
                     .map(|rc_arg| rc_arg.to_string_explicit())
@@ -173,7 +192,7 @@ impl Iterator for PersistentListIter {
     type Item = Rc<Value>;
     fn next(&mut self) -> Option<Self::Item> {
         match &*(self.list.clone()) {
-            Cons(first, rest, _) => {
+            Cons(first, rest, _, _) => {
                 self.list = Rc::clone(&rest);
                 Some(Rc::clone(&first))
             }
@@ -194,7 +213,7 @@ impl FromIterator<Rc<Value>> for PersistentList {
         }
         for i in coll_as_vec.iter().rev() {
             count += 1;
-            retval = Cons(Rc::clone(i), Rc::new(retval), count);
+            retval = Cons(Rc::clone(i), Rc::new(retval), count, Meta::default());
         }
 
         retval
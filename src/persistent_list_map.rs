@@ -14,21 +14,40 @@
 //! b => {:a 1 :b 3}
 
 use crate::maps::MapEntry;
+use crate::lazy_seq::LazySeq;
+use crate::meta::Meta;
+use crate::persistent_vector::ToPersistentVector;
+use crate::value::ToValue;
 use crate::value::Value;
 use crate::traits;
 
 use std::collections::HashMap;
 use std::convert::From;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::rc::Rc;
 
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone)]
 pub enum PersistentListMap {
-    Map(Rc<PersistentListMap>, MapEntry),
+    Map(Rc<PersistentListMap>, MapEntry, Meta),
     Empty,
 }
+// Equal to any map with the same entries, in whatever order they were assoc'd
+impl PartialEq for PersistentListMap {
+    fn eq(&self, other: &PersistentListMap) -> bool {
+        self.count() == other.count()
+            && self.iter().all(|entry| {
+                other.contains_key(&entry.key) && other.get(&entry.key) == entry.val
+            })
+    }
+}
 impl Eq for PersistentListMap {}
+impl Hash for PersistentListMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        crate::maps::hash_entries(self.iter(), state)
+    }
+}
 
 /// ```rust,no_run
 /// # #[macro_use] extern crate rust_clojure; use rust_clojure::*;
@@ -137,7 +156,7 @@ impl IPersistentMap for PersistentListMap {
     // @TODO make fn of ILookup
     fn get(&self, key: &Rc<Value>) -> Rc<Value> {
         match self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return Rc::clone(&entry.val);
                 }
@@ -148,7 +167,7 @@ impl IPersistentMap for PersistentListMap {
     }
     fn get_with_default(&self, key: &Rc<Value>, default: &Rc<Value>) -> Rc<Value> {
         match self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return Rc::clone(&entry.val);
                 }
@@ -162,11 +181,14 @@ impl IPersistentMap for PersistentListMap {
         }
     }
     fn assoc(&self, key: Rc<Value>, val: Rc<Value>) -> PersistentListMap {
-        PersistentListMap::Map(Rc::new(self.clone()), MapEntry { key, val })
+        if self.contains_key(&key) {
+            return self.replace(key, val);
+        }
+        PersistentListMap::Map(Rc::new(self.clone()), MapEntry { key, val }, self.carried_meta())
     }
     fn contains_key(&self,key: &Rc<Value>) -> bool {
         match self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return true;
                 }
@@ -181,7 +203,7 @@ impl IPersistentMap for Rc<PersistentListMap> {
     // @TODO make fn of ILookup
     fn get(&self, key: &Rc<Value>) -> Rc<Value> {
         match &**self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return Rc::clone(&entry.val);
                 }
@@ -192,7 +214,7 @@ impl IPersistentMap for Rc<PersistentListMap> {
     }
     fn get_with_default(&self, key: &Rc<Value>, default: &Rc<Value>) -> Rc<Value> {
         match &**self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return Rc::clone(&entry.val);
                 }
@@ -206,14 +228,18 @@ impl IPersistentMap for Rc<PersistentListMap> {
         }
     }
     fn assoc(&self, key: Rc<Value>, val: Rc<Value>) -> Rc<PersistentListMap> {
+        if self.contains_key(&key) {
+            return Rc::new(self.replace(key, val));
+        }
         Rc::new(PersistentListMap::Map(
             Rc::clone(self),
             MapEntry { key, val },
+            self.carried_meta(),
         ))
     }
     fn contains_key(&self,key: &Rc<Value>) -> bool {
         match &**self {
-            PersistentListMap::Map(parent, entry) => {
+            PersistentListMap::Map(parent, entry, _) => {
                 if entry.key == *key {
                     return true;
                 }
@@ -257,7 +283,7 @@ impl Iterator for PersistentListMapIter {
     type Item = MapEntry;
     fn next(&mut self) -> Option<Self::Item> {
         match &*(Rc::clone(&self.node)) {
-            PersistentListMap::Map(parent, mapentry) => {
+            PersistentListMap::Map(parent, mapentry, _) => {
                 self.node = Rc::clone(parent);
                 if self.seen.contains_key(&mapentry.key) {
                     return self.next();
@@ -289,7 +315,7 @@ impl FromIterator<MapEntry> for PersistentListMap {
         let mut map_so_far = PersistentListMap::Empty;
 
         for i in iter {
-            map_so_far = PersistentListMap::Map(Rc::new(map_so_far), i.clone());
+            map_so_far = PersistentListMap::Map(Rc::new(map_so_far), i.clone(), Meta::default());
         }
         map_so_far
     }
@@ -297,16 +323,60 @@ impl FromIterator<MapEntry> for PersistentListMap {
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // End Iteration
 ////////////////////////////////////////////////////////////////////////////////////////////////////
+impl PersistentListMap {
+    pub fn count(&self) -> usize {
+        self.iter().count()
+    }
+    /// This map, with key's val replaced in place, rather than shadowed by a new entry
+    /// (which would grow us forever as the same key is assoc'd over and over)
+    fn replace(&self, key: Rc<Value>, val: Rc<Value>) -> PersistentListMap {
+        let entries = self
+            .iter()
+            .map(|entry| {
+                if entry.key == key {
+                    MapEntry { key: Rc::clone(&key), val: Rc::clone(&val) }
+                } else {
+                    entry
+                }
+            })
+            .collect::<Vec<MapEntry>>();
+        // We iterate newest entry first, so we rebuild from the oldest to keep our order
+        traits::IObj::with_meta(
+            &entries.into_iter().rev().collect::<PersistentListMap>(),
+            traits::IMeta::meta(self),
+        )
+    }
+    /// Our metadata, as carried over to what's assoc'd onto us
+    fn carried_meta(&self) -> Meta {
+        match self {
+            PersistentListMap::Map(_, _, meta) => meta.clone(),
+            PersistentListMap::Empty => Meta::default(),
+        }
+    }
+}
+// Seq'd as its entries, each a [key val] vector
+impl traits::Seqable for PersistentListMap {
+    fn seq(&self) -> Rc<Value> {
+        let entries = Rc::new(self.clone())
+            .iter()
+            .map(|entry| vec![entry.key, entry.val].into_vector().to_rc_value());
+        LazySeq::from_iter(entries).seq()
+    }
+}
 impl traits::IMeta for PersistentListMap {
     fn meta(&self) -> PersistentListMap {
-        // @TODO implement
-        PersistentListMap::Empty
+        self.carried_meta().get()
     }
 }
 impl traits::IObj for PersistentListMap {
-    fn with_meta(&self, _meta: PersistentListMap) -> PersistentListMap {
-        // @TODO implement
-        self.clone()
+    // @TODO let {} carry metadata too
+    fn with_meta(&self, meta: PersistentListMap) -> PersistentListMap {
+        match self {
+            PersistentListMap::Map(parent, entry, _) => {
+                PersistentListMap::Map(Rc::clone(parent), entry.clone(), Meta::new(meta))
+            }
+            PersistentListMap::Empty => PersistentListMap::Empty,
+        }
     }
 }
 impl fmt::Display for PersistentListMap {
@@ -1,33 +1,313 @@
+//! A persistent vector, as in Clojure;  a bit-partitioned trie, 32 values wide at each node,
+//! plus a tail of up to 32 values not yet pushed into the trie.  nth, assoc, conj and pop
+//! each touch only the O(log32 n) nodes on the path to one index, and copy only those,
+//! sharing the rest of the trie with the vector they were made from
 use std::convert::From;
 use std::fmt;
 use std::fmt::Debug;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::iter::FromIterator;
 use std::rc::Rc;
 
-use crate::value::{ToValue, Value};
+use crate::lazy_seq::LazySeq;
+use crate::meta::Meta;
 use crate::persistent_list_map::PersistentListMap;
 use crate::traits;
-#[derive(Debug, Clone, PartialEq, Hash)]
+use crate::value::{ToValue, Value};
+
+/// How many bits of an index each level of the trie covers
+const BITS: u32 = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+#[derive(Debug, Clone)]
 pub struct PersistentVector {
-    pub vals: Vec<Rc<Value>>,
+    count: usize,
+    /// How far the index is shifted to get the root's part of it;  a multiple of BITS
+    shift: u32,
+    root: Rc<VectorNode>,
+    tail: Rc<Vec<Rc<Value>>>,
+    pub meta: Meta,
+}
+#[derive(Debug)]
+enum VectorNode {
+    Branch(Vec<Rc<VectorNode>>),
+    Leaf(Vec<Rc<Value>>),
+}
+impl VectorNode {
+    fn children(&self) -> &[Rc<VectorNode>] {
+        match self {
+            VectorNode::Branch(children) => children,
+            VectorNode::Leaf(_) => &[],
+        }
+    }
+    fn values(&self) -> &[Rc<Value>] {
+        match self {
+            VectorNode::Leaf(values) => values,
+            VectorNode::Branch(_) => &[],
+        }
+    }
+    /// node, under enough branches to sit at level
+    fn new_path(level: u32, node: Rc<VectorNode>) -> Rc<VectorNode> {
+        if level == 0 {
+            return node;
+        }
+        Rc::new(VectorNode::Branch(vec![VectorNode::new_path(
+            level - BITS,
+            node,
+        )]))
+    }
+}
+
+impl PersistentVector {
+    pub fn empty() -> PersistentVector {
+        PersistentVector {
+            count: 0,
+            shift: BITS,
+            root: Rc::new(VectorNode::Branch(vec![])),
+            tail: Rc::new(vec![]),
+            meta: Meta::default(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.count
+    }
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Index of the first value in our tail, rather than our trie
+    fn tail_offset(&self) -> usize {
+        if self.count < WIDTH {
+            0
+        } else {
+            ((self.count - 1) >> BITS) << BITS
+        }
+    }
+    /// The values of the leaf (or tail) holding index, which must be in range
+    fn leaf_for(&self, index: usize) -> &[Rc<Value>] {
+        if index >= self.tail_offset() {
+            return &self.tail;
+        }
+        let mut node = &self.root;
+        let mut level = self.shift;
+        while level > 0 {
+            node = &node.children()[(index >> level) & MASK];
+            level -= BITS;
+        }
+        node.values()
+    }
+    pub fn nth(&self, index: usize) -> Option<Rc<Value>> {
+        if index >= self.count {
+            return None;
+        }
+        Some(Rc::clone(&self.leaf_for(index)[index & MASK]))
+    }
+    /// This vector with val added to the end
+    pub fn conj(&self, val: Rc<Value>) -> PersistentVector {
+        // Room in the tail
+        if self.count - self.tail_offset() < WIDTH {
+            let mut tail = (*self.tail).clone();
+            tail.push(val);
+            return PersistentVector {
+                count: self.count + 1,
+                tail: Rc::new(tail),
+                ..self.clone()
+            };
+        }
+        // Otherwise our full tail goes into the trie, and val starts a new one
+        let tail_node = Rc::new(VectorNode::Leaf((*self.tail).clone()));
+        let (root, shift) = if (self.count >> BITS) > (1 << self.shift) {
+            // The trie is full, so it grows a level
+            let root = VectorNode::Branch(vec![
+                Rc::clone(&self.root),
+                VectorNode::new_path(self.shift, tail_node),
+            ]);
+            (Rc::new(root), self.shift + BITS)
+        } else {
+            (
+                self.push_tail(self.shift, &self.root, tail_node),
+                self.shift,
+            )
+        };
+        PersistentVector {
+            count: self.count + 1,
+            shift,
+            root,
+            tail: Rc::new(vec![val]),
+            meta: self.meta.clone(),
+        }
+    }
+    fn push_tail(
+        &self,
+        level: u32,
+        parent: &Rc<VectorNode>,
+        tail_node: Rc<VectorNode>,
+    ) -> Rc<VectorNode> {
+        let index = ((self.count - 1) >> level) & MASK;
+        let mut children = parent.children().to_vec();
+        let child = if level == BITS {
+            tail_node
+        } else {
+            match children.get(index) {
+                Some(child) => self.push_tail(level - BITS, child, tail_node),
+                None => VectorNode::new_path(level - BITS, tail_node),
+            }
+        };
+        if index < children.len() {
+            children[index] = child;
+        } else {
+            children.push(child);
+        }
+        Rc::new(VectorNode::Branch(children))
+    }
+    /// This vector with the value at index replaced by val;  an index of our length adds
+    /// val to the end, like conj.  None if index is past that
+    pub fn assoc(&self, index: usize, val: Rc<Value>) -> Option<PersistentVector> {
+        if index == self.count {
+            return Some(self.conj(val));
+        }
+        if index > self.count {
+            return None;
+        }
+        if index >= self.tail_offset() {
+            let mut tail = (*self.tail).clone();
+            tail[index & MASK] = val;
+            return Some(PersistentVector {
+                tail: Rc::new(tail),
+                ..self.clone()
+            });
+        }
+        Some(PersistentVector {
+            root: PersistentVector::assoc_in_node(self.shift, &self.root, index, val),
+            ..self.clone()
+        })
+    }
+    fn assoc_in_node(
+        level: u32,
+        node: &Rc<VectorNode>,
+        index: usize,
+        val: Rc<Value>,
+    ) -> Rc<VectorNode> {
+        if level == 0 {
+            let mut values = node.values().to_vec();
+            values[index & MASK] = val;
+            return Rc::new(VectorNode::Leaf(values));
+        }
+        let mut children = node.children().to_vec();
+        let child_index = (index >> level) & MASK;
+        children[child_index] =
+            PersistentVector::assoc_in_node(level - BITS, &children[child_index], index, val);
+        Rc::new(VectorNode::Branch(children))
+    }
+    /// This vector without its last value;  None if we're empty
+    pub fn pop(&self) -> Option<PersistentVector> {
+        match self.count {
+            0 => return None,
+            1 => {
+                return Some(PersistentVector {
+                    meta: self.meta.clone(),
+                    ..PersistentVector::empty()
+                })
+            }
+            _ => {}
+        }
+        if self.count - self.tail_offset() > 1 {
+            let mut tail = (*self.tail).clone();
+            tail.pop();
+            return Some(PersistentVector {
+                count: self.count - 1,
+                tail: Rc::new(tail),
+                ..self.clone()
+            });
+        }
+        // Our tail is emptied, so the last leaf of the trie comes out to be our new tail
+        let tail = self.leaf_for(self.count - 2).to_vec();
+        let mut root = self
+            .pop_tail(self.shift, &self.root)
+            .unwrap_or_else(|| Rc::new(VectorNode::Branch(vec![])));
+        let mut shift = self.shift;
+        if shift > BITS && root.children().len() == 1 {
+            root = Rc::clone(&root.children()[0]);
+            shift -= BITS;
+        }
+        Some(PersistentVector {
+            count: self.count - 1,
+            shift,
+            root,
+            tail: Rc::new(tail),
+            meta: self.meta.clone(),
+        })
+    }
+    /// node without its last leaf, or None if that leaves it with nothing
+    fn pop_tail(&self, level: u32, node: &Rc<VectorNode>) -> Option<Rc<VectorNode>> {
+        let index = ((self.count - 2) >> level) & MASK;
+        let mut children = node.children().to_vec();
+        if level > BITS {
+            match self.pop_tail(level - BITS, &children[index]) {
+                Some(child) => children[index] = child,
+                None if index == 0 => return None,
+                None => {
+                    children.truncate(index);
+                }
+            }
+        } else if index == 0 {
+            return None;
+        } else {
+            children.truncate(index);
+        }
+        Some(Rc::new(VectorNode::Branch(children)))
+    }
+    /// Our values, copied out into a Vec
+    pub fn to_vec(&self) -> Vec<Rc<Value>> {
+        let mut vals = Vec::with_capacity(self.count);
+        let mut index = 0;
+        while index < self.count {
+            let leaf = self.leaf_for(index);
+            vals.extend(leaf.iter().cloned());
+            index += leaf.len();
+        }
+        vals
+    }
+}
+impl Default for PersistentVector {
+    fn default() -> PersistentVector {
+        PersistentVector::empty()
+    }
+}
+impl PartialEq for PersistentVector {
+    fn eq(&self, other: &PersistentVector) -> bool {
+        self.count == other.count
+            && (0..self.count).all(|index| self.nth(index) == other.nth(index))
+    }
+}
+impl Hash for PersistentVector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // As a list is, so that a vector and a seq of the same values hash the same
+        crate::seq::hash_ordered(Rc::new(self.clone()).iter(), state)
+    }
+}
+impl traits::Seqable for PersistentVector {
+    fn seq(&self) -> Rc<Value> {
+        LazySeq::from_iter(Rc::new(self.clone()).iter()).seq()
+    }
 }
 impl traits::IMeta for PersistentVector {
     fn meta(&self) -> PersistentListMap {
-        // @TODO implement
-        PersistentListMap::Empty
+        self.meta.get()
     }
 }
 impl traits::IObj for PersistentVector {
-    fn with_meta(&self, _meta: PersistentListMap) -> PersistentVector {
-        // @TODO implement
-        self.clone()
+    fn with_meta(&self, meta: PersistentListMap) -> PersistentVector {
+        PersistentVector {
+            meta: Meta::new(meta),
+            ..self.clone()
+        }
     }
 }
 impl fmt::Display for PersistentVector {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str = self
-            .vals
+            .to_vec()
             .iter()
             .map(|rc_arg| rc_arg.to_string_explicit())
             .collect::<Vec<std::string::String>>()
@@ -41,12 +321,6 @@ impl From<Vec<Rc<Value>>> for PersistentVector {
         item.into_iter().collect::<PersistentVector>()
     }
 }
-// impl Hash for PersistentVector {
-//     fn hash<H: Hasher>(&self, state: &mut H) {
-// 	let as_vec = Rc::new(self.clone()).iter().collect::<Vec<Rc<Value>>>();
-// 	as_vec.hash(state)
-//     }
-// }
 //
 // Mostly to just make some code more concise
 // @TODO ~lookup proper rust conversion traits~
@@ -87,25 +361,76 @@ pub struct PersistentVectorIter {
 impl Iterator for PersistentVectorIter {
     type Item = Rc<Value>;
     fn next(&mut self) -> Option<Self::Item> {
-        let retval = (&*self.vector.vals)
-            .get(self.ind)
-            .map(|rc_val| Rc::clone(rc_val));
+        let retval = self.vector.nth(self.ind);
         self.ind += 1;
         retval
     }
 }
 impl FromIterator<Rc<Value>> for PersistentVector {
-    //
-    // @TODO Surely we can just forward Vec's from_iter and wrap it
-    //
     fn from_iter<I: IntoIterator<Item = Rc<Value>>>(iter: I) -> Self {
-        // @TODO see if we can directly loop through our original iter backwards, and avoid
-        // dumping into this vector just to loop through again backwards
-        let mut coll_as_vec = vec![];
+        iter.into_iter()
+            .fold(PersistentVector::empty(), |vector, val| vector.conj(val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod persistent_vector_tests {
+        use crate::persistent_vector::PersistentVector;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn vector_of(count: i32) -> PersistentVector {
+            (0..count).map(|i| Rc::new(Value::I32(i))).collect()
+        }
+
+        #[test]
+        fn nth_across_levels() {
+            // One leaf, a full root, and past the root growing a level or two
+            for count in [0, 1, 32, 33, 1056, 1057, 33_000] {
+                let vector = vector_of(count);
+                assert_eq!(count as usize, vector.len());
+                for i in 0..count {
+                    assert_eq!(Some(Rc::new(Value::I32(i))), vector.nth(i as usize));
+                }
+                assert_eq!(None, vector.nth(count as usize));
+            }
+        }
+
+        #[test]
+        fn assoc_leaves_the_original() {
+            let vector = vector_of(2000);
+            let assoced = vector
+                .assoc(5, Rc::new(Value::I32(-5)))
+                .and_then(|v| v.assoc(1999, Rc::new(Value::I32(-1999))))
+                .unwrap();
+            assert_eq!(Some(Rc::new(Value::I32(-5))), assoced.nth(5));
+            assert_eq!(Some(Rc::new(Value::I32(-1999))), assoced.nth(1999));
+            assert_eq!(Some(Rc::new(Value::I32(5))), vector.nth(5));
+            assert_eq!(Some(Rc::new(Value::I32(1999))), vector.nth(1999));
+            // An index of our length is a conj, past it is nothing
+            assert_eq!(2001, vector.assoc(2000, Rc::new(Value::Nil)).unwrap().len());
+            assert_eq!(None, vector.assoc(2001, Rc::new(Value::Nil)));
+        }
+
+        #[test]
+        fn pop_back_down_to_empty() {
+            let mut vector = vector_of(1100);
+            let original = vector.clone();
+            for count in (0..1100).rev() {
+                vector = vector.pop().unwrap();
+                assert_eq!(vector_of(count), vector);
+                assert_eq!(count as usize, vector.to_vec().len());
+            }
+            assert_eq!(None, vector.pop());
+            assert_eq!(vector_of(1100), original);
+        }
 
-        for i in iter {
-            coll_as_vec.push(i);
+        #[test]
+        fn equality_ignores_structure() {
+            let popped = vector_of(33).pop().unwrap();
+            assert_eq!(vector_of(32), popped);
+            assert_ne!(vector_of(31), popped);
         }
-        PersistentVector { vals: coll_as_vec }
     }
 }
@@ -0,0 +1,222 @@
+//! Printing values as pr and print do;  readably, so that what's printed reads back as the
+//! same value, or for people, with strings as they are
+//!
+//! This is apart from Display, which is what str gives, as in Clojure;  mostly the same,
+//! but a string is itself, a float needn't show it's one, and nothing here is asked of
+//! print-method
+use crate::environment::Environment;
+use crate::ifn::IFn;
+use crate::multi_fn::MultiFn;
+use crate::rust_core::type_of;
+use crate::seq;
+use crate::stream::Writer;
+use crate::symbol::Symbol;
+use crate::value::Value;
+use std::rc::Rc;
+
+/// x as pr prints it if readably, and as print does otherwise.  Anything with a method of
+/// its own in environment's print-method (by its type) is printed by that
+pub fn print_str(
+    environment: &Environment,
+    x: &Rc<Value>,
+    readably: bool,
+) -> Result<String, Rc<Value>> {
+    let print_method = environment.get(&Symbol::intern_with_ns("clojure.core", "print-method"));
+    let printer = Printer {
+        readably,
+        print_method: match &*print_method {
+            Value::MultiFn(print_method) if print_method.has_own_methods() => {
+                Some(Rc::clone(print_method))
+            }
+            _ => None,
+        },
+        length: print_limit(environment, "*print-length*"),
+        level: print_limit(environment, "*print-level*"),
+    };
+    let mut printed = String::new();
+    printer.print(x, 0, &mut printed)?;
+    Ok(printed)
+}
+
+/// Whether *print-readably* is currently true in environment, as it is unless rebound
+pub fn print_readably(environment: &Environment) -> bool {
+    environment
+        .get(&Symbol::intern_with_ns("clojure.core", "*print-readably*"))
+        .is_truthy()
+}
+
+/// What *print-length* or *print-level* (as named) is currently bound to in environment;
+/// None if it's nil, as it is unless rebound, for no limit
+pub fn print_limit(environment: &Environment, name: &str) -> Option<usize> {
+    match &*environment.get(&Symbol::intern_with_ns("clojure.core", name)) {
+        Value::I32(limit) if *limit >= 0 => Some(*limit as usize),
+        _ => None,
+    }
+}
+
+/// Whether x has a print-method of its own in environment, that it should be printed by
+/// rather than as we would
+pub fn has_print_method(environment: &Environment, x: &Rc<Value>) -> bool {
+    match &*environment.get(&Symbol::intern_with_ns("clojure.core", "print-method")) {
+        Value::MultiFn(print_method) => print_method.get_own_method(&type_of(x)).is_some(),
+        _ => false,
+    }
+}
+
+/// string as a string literal;  quoted, with what would end it or not read back escaped
+pub fn escape_string(string: &str) -> String {
+    let mut escaped = String::with_capacity(string.len() + 2);
+    escaped.push('"');
+    for ch in string.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// x as Clojure prints a double;  always with a decimal point, so it reads back as one
+pub fn format_f64(x: f64) -> String {
+    if x.is_nan() {
+        String::from("##NaN")
+    } else if x.is_infinite() {
+        String::from(if x > 0.0 { "##Inf" } else { "##-Inf" })
+    } else {
+        let formatted = x.to_string();
+        if formatted.contains(['.', 'e']) {
+            formatted
+        } else {
+            formatted + ".0"
+        }
+    }
+}
+
+/// Whether x prints as a collection, and so counts towards *print-level*
+pub fn is_collection(x: &Value) -> bool {
+    matches!(
+        x,
+        Value::PersistentList(_)
+            | Value::LazySeq(_)
+            | Value::Cons(_)
+            | Value::PersistentVector(_)
+            | Value::PersistentHashSet(_)
+            | Value::PersistentListMap(_)
+            | Value::PersistentHashMap(_)
+    )
+}
+
+struct Printer {
+    readably: bool,
+    /// print-method, if anything's been added to it;  otherwise we needn't ask it
+    print_method: Option<Rc<MultiFn>>,
+    /// How many elements of a collection to print before eliding the rest with ...
+    length: Option<usize>,
+    /// How deeply collections may nest before one is printed as #
+    level: Option<usize>,
+}
+impl Printer {
+    /// Prints x, depth collections in, to printed
+    fn print(&self, x: &Rc<Value>, depth: usize, printed: &mut String) -> Result<(), Rc<Value>> {
+        if let Some(print_method) = &self.print_method {
+            if let Some(method) = print_method.get_own_method(&type_of(x)) {
+                let writer = Writer::buffer();
+                let result = Rc::new(
+                    method.invoke(vec![Rc::clone(x), Rc::new(Value::Writer(writer.clone()))]),
+                );
+                if let Some(interruption) = result.non_tail() {
+                    return Err(interruption);
+                }
+                printed.push_str(&writer.contents().unwrap_or_default());
+                return Ok(());
+            }
+        }
+        if is_collection(x) && self.level.is_some_and(|level| depth >= level) {
+            printed.push('#');
+            return Ok(());
+        }
+        match &**x {
+            Value::String(string) if self.readably => printed.push_str(&escape_string(string)),
+            Value::String(string) => printed.push_str(string),
+            Value::F64(float) => printed.push_str(&format_f64(*float)),
+            Value::PersistentList(_) | Value::LazySeq(_) | Value::Cons(_) => {
+                self.print_all("(", seq::iter(x), ")", depth, printed)?
+            }
+            Value::PersistentVector(vector) => {
+                self.print_all("[", vector.to_vec().into_iter(), "]", depth, printed)?
+            }
+            Value::PersistentHashSet(_) => {
+                self.print_all("#{", seq::iter(x), "}", depth, printed)?
+            }
+            Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {
+                printed.push('{');
+                for (i, entry) in seq::iter(x).enumerate() {
+                    if i > 0 {
+                        printed.push_str(", ");
+                    }
+                    if self.length == Some(i) {
+                        printed.push_str("...");
+                        break;
+                    }
+                    match &*entry {
+                        Value::PersistentVector(entry) => {
+                            for (j, key_or_val) in entry.to_vec().iter().enumerate() {
+                                if j > 0 {
+                                    printed.push(' ');
+                                }
+                                self.print(key_or_val, depth + 1, printed)?;
+                            }
+                        }
+                        _ => return Err(entry),
+                    }
+                }
+                printed.push('}');
+            }
+            Value::Var(var) => printed.push_str(&format!("#'{}/{}", var.ns, var.sym)),
+            Value::Atom(atom) => {
+                printed.push_str("#atom[");
+                self.print(&atom.deref(), depth + 1, printed)?;
+                printed.push(']');
+            }
+            Value::Reduced(reduced) => {
+                printed.push_str("#reduced[");
+                self.print(reduced, depth + 1, printed)?;
+                printed.push(']');
+            }
+            // A condition in flight, say one raised realizing a seq, is passed on rather than
+            // printed
+            Value::Condition(_) => return Err(Rc::clone(x)),
+            _ => printed.push_str(&x.to_string()),
+        }
+        Ok(())
+    }
+    fn print_all(
+        &self,
+        open: &str,
+        values: impl Iterator<Item = Rc<Value>>,
+        close: &str,
+        depth: usize,
+        printed: &mut String,
+    ) -> Result<(), Rc<Value>> {
+        printed.push_str(open);
+        for (i, value) in values.enumerate() {
+            if i > 0 {
+                printed.push(' ');
+            }
+            if self.length == Some(i) {
+                printed.push_str("...");
+                break;
+            }
+            self.print(&value, depth + 1, printed)?;
+        }
+        printed.push_str(close);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+//! Processes, as clojure.java.shell/process starts them;  another program running alongside
+//! us, what java.lang.Process is to Clojure
+//!
+//! Its standard input is a writer, and its standard output and error readers, so that they can
+//! be written and read a line at a time as it runs, as any other stream is
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::stream::{self, Reader, Writer};
+use crate::value::Value;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::process::{Child, ExitStatus};
+use std::rc::Rc;
+
+#[derive(Debug)]
+pub struct Process {
+    /// The process itself, its streams taken out of it
+    child: RefCell<Child>,
+    stdin: Writer,
+    stdout: Reader,
+    stderr: Reader,
+    /// Its exit code, once it's been waited for
+    exit: Cell<Option<i32>>,
+}
+impl Process {
+    pub fn new(child: Child, stdin: Writer, stdout: Reader, stderr: Reader) -> Process {
+        Process {
+            child: RefCell::new(child),
+            stdin,
+            stdout,
+            stderr,
+            exit: Cell::new(None),
+        }
+    }
+    pub fn stdin(&self) -> &Writer {
+        &self.stdin
+    }
+    pub fn stdout(&self) -> &Reader {
+        &self.stdout
+    }
+    pub fn stderr(&self) -> &Reader {
+        &self.stderr
+    }
+    /// Waits for the process to exit, if it hasn't;  its exit code
+    pub fn wait(&self) -> Result<i32, Rc<Value>> {
+        if let Some(exit) = self.exit.get() {
+            return Ok(exit);
+        }
+        let status = self.child.borrow_mut().wait().map_err(stream::io_error)?;
+        let exit = exit_code(status);
+        self.exit.set(Some(exit));
+        Ok(exit)
+    }
+    /// Kills the process, if it's still running
+    pub fn destroy(&self) -> Result<(), Rc<Value>> {
+        let mut child = self.child.borrow_mut();
+        // Only a process that's already exited can't be killed, and that's as good as done
+        if child.try_wait().map_err(stream::io_error)?.is_some() {
+            return Ok(());
+        }
+        child.kill().map_err(|error| {
+            Rc::new(error_message::condition(
+                ConditionKind::IOException,
+                stream::described(&error),
+            ))
+        })
+    }
+}
+impl fmt::Display for Process {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#process[{}]", self.child.borrow().id())
+    }
+}
+
+/// The exit code of a process that exited with status;  as Java has it, one killed by a
+/// signal exits with 128 plus the signal's number
+pub fn exit_code(status: ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+    status.code().unwrap_or(-1)
+}
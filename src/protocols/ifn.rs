@@ -1,9 +1,12 @@
 use crate::value::Value;
 use std::rc::Rc;
+use crate::error_message;
 use crate::ifn;
+use crate::rust_core::GetFn;
 
-// Let's keep it simple for now, but we will expand this 
-define_protocol!(IFn,IFn);
+// Anything that can be called like a fn;  fns and multimethods themselves, and keywords, sets and maps,
+// which look up their argument (or look themselves up in it, for a keyword)
+define_protocol!(IFn = IFn | MultiFn | Keyword | PersistentHashSet | PersistentListMap | PersistentHashMap);
 
 impl ifn::IFn for IFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
@@ -11,7 +14,31 @@ impl ifn::IFn for IFn {
             Value::IFn(ifn) => {
                 ifn.invoke(args)
             },
-            _ => panic!("Called Iterable iter on non-iterable"),
+            Value::MultiFn(multi_fn) => ifn::IFn::invoke(&**multi_fn, args),
+            // (:k m) and (:k m not-found)
+            Value::Keyword(_) => match args.as_slice() {
+                [coll] => ifn::IFn::invoke(&GetFn {}, vec![Rc::clone(coll), Rc::clone(&self.value)]),
+                [coll, not_found] => ifn::IFn::invoke(&GetFn {}, vec![
+                    Rc::clone(coll),
+                    Rc::clone(&self.value),
+                    Rc::clone(not_found),
+                ]),
+                _ => error_message::wrong_varg_count(&[1, 2], args.len()),
+            },
+            Value::PersistentHashSet(pset) => match args.as_slice() {
+                [val] => (*pset.get(val)).clone(),
+                _ => error_message::wrong_arg_count(1, args.len()),
+            },
+            // ({:k v} k) and ({:k v} k not-found)
+            Value::PersistentListMap(_) | Value::PersistentHashMap(_) => match args.len() {
+                1 | 2 => {
+                    let mut get_args = vec![Rc::clone(&self.value)];
+                    get_args.extend(args);
+                    ifn::IFn::invoke(&GetFn {}, get_args)
+                }
+                _ => error_message::wrong_varg_count(&[1, 2], args.len()),
+            },
+            _ => panic!("Called IFn invoke on non-fn"),
         }
     }
 }
@@ -10,6 +10,10 @@ define_protocol!(
             PersistentList     |
             PersistentVector   |
             PersistentListMap  |
+            PersistentHashMap  |
+            PersistentHashSet  |
+            LazySeq            |
+            Cons               |
             Symbol //             |
             // IFn
 );
@@ -25,6 +29,18 @@ impl traits::IMeta for IMeta {
             Value::PersistentListMap(val) => {
                 val.meta()
             }
+            Value::PersistentHashMap(val) => {
+                val.meta()
+            }
+            Value::PersistentHashSet(val) => {
+                val.meta()
+            }
+            Value::LazySeq(val) => {
+                val.meta()
+            }
+            Value::Cons(val) => {
+                val.meta()
+            }
             Value::Symbol(val) => {
                 val.meta()
             }
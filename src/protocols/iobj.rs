@@ -9,6 +9,10 @@ define_protocol!(
     IObj = PersistentList     |
            PersistentVector   |
            PersistentListMap  |
+           PersistentHashMap  |
+           PersistentHashSet  |
+           LazySeq            |
+           Cons               |
            Symbol //              |
            // IFn
 );
@@ -24,6 +28,18 @@ impl traits::IMeta for IObj {
             Value::PersistentListMap(val) => {
                 val.meta()
             }
+            Value::PersistentHashMap(val) => {
+                val.meta()
+            }
+            Value::PersistentHashSet(val) => {
+                val.meta()
+            }
+            Value::LazySeq(val) => {
+                val.meta()
+            }
+            Value::Cons(val) => {
+                val.meta()
+            }
             Value::Symbol(val) => {
                 val.meta()
             }
@@ -49,6 +65,18 @@ impl traits::IObj for IObj {
             Value::PersistentListMap(val) => {
                 val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
             }
+            Value::PersistentHashMap(val) => {
+                val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
+            }
+            Value::PersistentHashSet(val) => {
+                val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
+            }
+            Value::LazySeq(val) => {
+                val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
+            }
+            Value::Cons(val) => {
+                val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
+            }
             Value::Symbol(val) => {
                 val.with_meta(meta).to_rc_value().as_protocol::<IObj>()
             }
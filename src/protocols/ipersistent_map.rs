@@ -1,9 +1,10 @@
 use crate::value::{Value,ToValue};
 use std::rc::Rc;
+use crate::persistent_hash_map::{self, PersistentHashMap};
 use crate::persistent_list_map;
 use crate::protocol::ProtocolCastable;
 
-define_protocol!(IPersistentMap = PersistentListMap);
+define_protocol!(IPersistentMap = PersistentListMap | PersistentHashMap);
 
 impl persistent_list_map::IPersistentMap for IPersistentMap {
     fn get(&self, key: &Rc<Value>) -> Rc<Value> {
@@ -11,6 +12,9 @@ impl persistent_list_map::IPersistentMap for IPersistentMap {
             Value::PersistentListMap(plist_map) => {
                 plist_map.get(key)
             },
+            Value::PersistentHashMap(phash_map) => {
+                phash_map.get(key)
+            },
             _ => panic!("Called Iterable iter on non-iterable"),
         }
     }
@@ -19,13 +23,25 @@ impl persistent_list_map::IPersistentMap for IPersistentMap {
             Value::PersistentListMap(plist_map) => {
                 plist_map.get_with_default(key, default)
             },
+            Value::PersistentHashMap(phash_map) => {
+                phash_map.get_with_default(key, default)
+            },
             _ => panic!("Called Iterable iter on non-iterable"),
         }
     }
+    /// Once a PersistentListMap grows past LIST_MAP_MAX_COUNT entries, what it's
+    /// assoc'd into is a PersistentHashMap
     fn assoc(&self, key: Rc<Value>, value: Rc<Value>) -> IPersistentMap {
         match &*self.value {
             Value::PersistentListMap(plist_map) => {
-                plist_map.assoc(key,value).to_rc_value().as_protocol::<IPersistentMap>()
+                let plist_map = plist_map.assoc(key,value);
+                if plist_map.count() > persistent_hash_map::LIST_MAP_MAX_COUNT {
+                    return PersistentHashMap::from(&plist_map).to_rc_value().as_protocol::<IPersistentMap>();
+                }
+                plist_map.to_rc_value().as_protocol::<IPersistentMap>()
+            },
+            Value::PersistentHashMap(phash_map) => {
+                phash_map.assoc(key,value).to_rc_value().as_protocol::<IPersistentMap>()
             },
             _ => panic!("Called Iterable iter on non-iterable"),
         }
@@ -35,6 +51,9 @@ impl persistent_list_map::IPersistentMap for IPersistentMap {
             Value::PersistentListMap(plist_map) => {
                 plist_map.contains_key(key)
             },
+            Value::PersistentHashMap(phash_map) => {
+                phash_map.contains_key(key)
+            },
             _ => panic!("Called Iterable iter on non-iterable"),
         }
     }
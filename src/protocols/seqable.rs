@@ -0,0 +1,29 @@
+use crate::traits;
+use crate::value::Value;
+use std::rc::Rc;
+
+define_protocol!(
+    Seqable = PersistentList    |
+              PersistentVector  |
+              PersistentListMap |
+              PersistentHashMap |
+              PersistentHashSet |
+              String            |
+              LazySeq           |
+              Cons
+);
+impl traits::Seqable for Seqable {
+    fn seq(&self) -> Rc<Value> {
+        match &*self.value {
+            Value::PersistentList(val) => val.seq(),
+            Value::PersistentVector(val) => val.seq(),
+            Value::PersistentListMap(val) => val.seq(),
+            Value::PersistentHashMap(val) => val.seq(),
+            Value::PersistentHashSet(val) => val.seq(),
+            Value::String(val) => val.seq(),
+            Value::LazySeq(val) => val.seq(),
+            Value::Cons(val) => val.seq(),
+            _ => panic!("protocols::Seqable was wrapping an invalid type {} when calling seq()", self.value.type_tag()),
+        }
+    }
+}
@@ -17,17 +17,21 @@ use nom::{
 use crate::keyword::Keyword;
 use crate::maps::MapEntry;
 use crate::persistent_list::ToPersistentList;
-use crate::persistent_list_map::{PersistentListMap,ToPersistentListMap, ToPersistentListMapIter};
+use crate::persistent_hash_map;
+use crate::persistent_hash_set::PersistentHashSet;
+use crate::persistent_list_map::{PersistentListMap, ToPersistentListMapIter};
 use crate::persistent_vector::ToPersistentVector;
 use crate::protocol::ProtocolCastable;
 use crate::protocol::Protocol;
 use crate::symbol::Symbol;
 use crate::error_message;
+use crate::source_position::SourcePosition;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 use crate::protocols;
 use crate::traits::IObj;
 use crate::traits::IMeta;
+use std::cell::RefCell;
 use std::io::BufRead;
 //
 // Note; the difference between ours 'parsers'
@@ -38,6 +42,7 @@ use std::io::BufRead;
 //   try_read_i32
 //   try_read_string
 //   try_read_map
+//   try_read_set
 //   try_read_list
 //   try_read_vector
 //
@@ -78,6 +83,83 @@ fn cons_str(head: char, tail: &str) -> String {
 //     End Utils
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+////////////////////////////////////////////////////////////////////////////////////////////////////
+//
+//     Source Positions
+//
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The text a Reader is in the middle of reading.  Our try-readers only ever see what's left of
+/// the text to read, which is always some suffix of it, so with this they can work out the
+/// line and column of what they read
+struct ReadSource {
+    text: String,
+    file: Option<String>,
+    /// The line and column text starts at
+    line: usize,
+    column: usize,
+}
+thread_local! {
+    static READ_SOURCE: RefCell<Option<ReadSource>> = const { RefCell::new(None) };
+}
+
+/// Where the form at the start of input, what's left of the text being read, was read from
+fn source_position(input: &str) -> Option<SourcePosition> {
+    READ_SOURCE.with(|read_source| {
+        let read_source = read_source.borrow();
+        let read_source = read_source.as_ref()?;
+        let offset = read_source.text.len().checked_sub(input.len())?;
+        let read_text = read_source.text.get(..offset)?;
+        let (line, column) = position_after(read_source.line, read_source.column, read_text);
+        Some(SourcePosition {
+            file: read_source.file.clone(),
+            line,
+            column,
+        })
+    })
+}
+
+/// The line and column we are at after reading text, starting at line and column
+fn position_after(line: usize, column: usize, text: &str) -> (usize, usize) {
+    match text.rfind('\n') {
+        Some(last_newline) => (
+            line + text.matches('\n').count(),
+            text[last_newline + 1..].chars().count() + 1,
+        ),
+        None => (line, column + text.chars().count()),
+    }
+}
+
+/// value, with the position of input (the text it was read from) added to its metadata
+fn with_source_position(input: &str, value: Value) -> Value {
+    let position = match source_position(input) {
+        Some(position) => position,
+        None => return value,
+    };
+    match value {
+        Value::PersistentList(plist) => {
+            plist.with_meta(position.add_to_meta(plist.meta())).to_value()
+        }
+        Value::PersistentVector(pvector) => {
+            pvector.with_meta(position.add_to_meta(pvector.meta())).to_value()
+        }
+        Value::PersistentListMap(plistmap) => {
+            plistmap.with_meta(position.add_to_meta(plistmap.meta())).to_value()
+        }
+        Value::PersistentHashMap(phashmap) => {
+            phashmap.with_meta(position.add_to_meta(phashmap.meta())).to_value()
+        }
+        Value::PersistentHashSet(pset) => {
+            pset.with_meta(position.add_to_meta(pset.meta())).to_value()
+        }
+        Value::Symbol(symbol) => symbol.with_meta(position.add_to_meta(symbol.meta())).to_value(),
+        value => value,
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////
+//     End Source Positions
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 //
 //     Predicates
@@ -104,8 +186,9 @@ fn cons_str(head: char, tail: &str) -> String {
 ///   - `$`,
 ///   - `*`,
 ///   - `!`,
+///   - `#`, (so that `foo#` can be auto-gensymed in a syntax-quote)
 fn is_identifier_char(ch: char) -> bool {
-    ch.is_alphanumeric() || "|?<>+-_=^%&$*!.".contains(ch)
+    ch.is_alphanumeric() || "|?<>+-_=^%&$*!.#".contains(ch)
 }
 
 /// Returns true if a character is an acceptable (non numeric) identifier char
@@ -324,6 +407,29 @@ pub fn string_parser(input: &str) -> IResult<&str, String> {
     string_parser(input)
 }
 
+/// Parses the string of a regex literal;  unlike a string, its escapes are left to the regex,
+/// so #"\d" is the regex \d, and only \" is read as a quote that doesn't end it
+pub fn regex_string_parser(input: &str) -> IResult<&str, String> {
+    let (rest_input, _) = tag("\"")(input)?;
+    let mut regex_string = String::new();
+    let mut chars = rest_input.char_indices();
+    while let Some((i, ch)) = chars.next() {
+        match ch {
+            '"' => return Ok((&rest_input[i + 1..], regex_string)),
+            '\\' => match chars.next() {
+                Some((_, '"')) => regex_string.push('"'),
+                Some((_, escaped)) => {
+                    regex_string.push('\\');
+                    regex_string.push(escaped);
+                }
+                None => break,
+            },
+            _ => regex_string.push(ch),
+        }
+    }
+    Err(Incomplete(nom::Needed::Size(1)))
+}
+
 // Helper function to integer_parser for same reason as
 // identifier_tail. See comment above said function for explanation
 
@@ -452,7 +558,8 @@ pub fn try_read_keyword(input: &str) -> IResult<&str, Value> {
 /// Example Failures:
 ///    12cat,  'quoted,  @at-is-for-references
 pub fn try_read_symbol(input: &str) -> IResult<&str, Value> {
-    to_value_parser(symbol_parser)(input)
+    let (rest_input, symbol) = to_value_parser(symbol_parser)(input)?;
+    Ok((rest_input, with_source_position(input, symbol)))
 }
 
 /// Tries to parse a &str that says 'nil' into Value::Nil
@@ -475,7 +582,7 @@ pub fn try_read_pattern(input: &str) -> IResult<&str, Value> {
     named!(hash_parser<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("#")));
 
     let (rest_input, _) = hash_parser(input)?;
-    let (rest_input, regex_string) = string_parser(rest_input)?;
+    let (rest_input, regex_string) = regex_string_parser(rest_input)?;
 
     // If an error is thrown,  this will be coerced into a condition
     let regex = regex::Regex::new(regex_string.as_str()).to_value();
@@ -493,7 +600,8 @@ pub fn try_read_var(input: &str) -> IResult<&str, Value> {
 }
 
 // @TODO Perhaps generalize this, or even generalize it as a reader macro
-/// Tries to parse &str into Value::PersistentListMap, or some other Value::..Map
+/// Tries to parse &str into Value::PersistentListMap, or, if it has more than
+/// LIST_MAP_MAX_COUNT entries, Value::PersistentHashMap
 /// Example Successes:
 ///    {:a 1} => Value::PersistentListMap {PersistentListMap { MapEntry { :a, 1} .. ]})
 pub fn try_read_map(input: &str) -> IResult<&str, Value> {
@@ -505,7 +613,8 @@ pub fn try_read_map(input: &str) -> IResult<&str, Value> {
     loop {
         let right_brace = rbracep(rest_input);
         if let Ok((after_map_input, _)) = right_brace {
-            return Ok((after_map_input, map_as_vec.into_list_map().to_value()));
+            let map = persistent_hash_map::build_map(map_as_vec);
+            return Ok((after_map_input, with_source_position(input, map)));
         }
         let (_rest_input, next_key) = try_read(rest_input)?;
         let (_rest_input, next_val) = try_read(_rest_input)?;
@@ -517,6 +626,37 @@ pub fn try_read_map(input: &str) -> IResult<&str, Value> {
     }
 }
 
+/// Tries to parse &str into Value::PersistentHashSet
+/// Example Successes:
+///    #{:a 1} => Value::PersistentHashSet(#{:a 1})
+/// Reading the same value twice, as in #{1 1}, is an error, as it is in Clojure
+pub fn try_read_set(input: &str) -> IResult<&str, Value> {
+    named!(lsetp<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("#{")));
+    named!(rbracep<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("}")));
+    let (set_inner_input, _) = lsetp(input)?;
+    let mut set = PersistentHashSet::default();
+    let mut duplicate = None;
+    let mut rest_input = set_inner_input;
+    loop {
+        if let Ok((after_set_input, _)) = rbracep(rest_input) {
+            if let Some(duplicate) = duplicate {
+                return Ok((
+                    after_set_input,
+                    error_message::custom(format!("Duplicate key: {}", duplicate)),
+                ));
+            }
+            return Ok((after_set_input, with_source_position(input, set.to_value())));
+        }
+        let (_rest_input, form) = try_read(rest_input)?;
+        let form = form.to_rc_value();
+        if duplicate.is_none() && set.contains(&form) {
+            duplicate = Some(form.to_string_explicit());
+        }
+        set = set.conj(form);
+        rest_input = _rest_input;
+    }
+}
+
 pub fn try_read_meta(input: &str) -> IResult<&str, Value> {
     named!(meta_start<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("^")));
     let (rest_input, _) = meta_start(input)?;
@@ -543,6 +683,9 @@ pub fn try_read_meta(input: &str) -> IResult<&str, Value> {
             plist_map.clone()
          // Then we're already set
         }
+        Value::PersistentHashMap(phash_map) => {
+            phash_map.to_list_map()
+        }
         _ => {
             // @TODO check instanceof IPersistentMap here instead
             // @TODO Clojure has basically this one off error here, but another thing we wish to do
@@ -554,16 +697,9 @@ pub fn try_read_meta(input: &str) -> IResult<&str, Value> {
 
     // Extra clone, implement these functions for plain Values 
     if let Some(iobj_value) =  iobj_value.to_rc_value().try_as_protocol::<protocols::IObj>() {
-        // @TODO get actual line and column info
-        let line = 1;
-        let column = 1;
-        // @TODO merge the meta iobj_value *already* has
+        // Merged in, rather than over, the meta iobj_value already has, which includes
+        // the line and column it was read from
         // @TODO define some better macros and / or functions for map handling 
-        meta = conj!(
-            meta,
-            map_entry!("line",line),
-            map_entry!("column",column)
-        );
         meta = merge!(meta,iobj_value.meta());
         Ok((rest_input,iobj_value.with_meta(meta).unwrap().to_value()))
     }
@@ -574,9 +710,9 @@ pub fn try_read_meta(input: &str) -> IResult<&str, Value> {
 // @TODO use nom functions in place of macro
 /// Tries to parse &str into Value::PersistentVector
 /// Example Successes:
-///    [1 2 3] => Value::PersistentVector(PersistentVector { vals: [Rc(Value::I32(1) ... ]})
+///    [1 2 3] => Value::PersistentVector([Rc(Value::I32(1) ... ])
 ///    [1 2 [5 10 15] 3]
-///      => Value::PersistentVector(PersistentVector { vals: [Rc(Value::I32(1) .. Rc(Value::PersistentVector..)]})
+///      => Value::PersistentVector([Rc(Value::I32(1) .. Rc(Value::PersistentVector..)])
 pub fn try_read_vector(input: &str) -> IResult<&str, Value> {
     named!(lbracketp<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("[")));
     named!(rbracketp<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("]")));
@@ -588,7 +724,8 @@ pub fn try_read_vector(input: &str) -> IResult<&str, Value> {
         // Try parse end of vector
         // If we succeeded,  we can convert our vector of values into a PersistentVector and return our success
         if let Ok((after_vector_input, _)) = rbracketp(rest_input) {
-            return Ok((after_vector_input, vector_as_vec.into_vector().to_value()));
+            let vector = vector_as_vec.into_vector().to_value();
+            return Ok((after_vector_input, with_source_position(input, vector)));
         }
 
         // Otherwise, we need to keep reading until we get that closing bracket letting us know we're finished
@@ -607,7 +744,8 @@ pub fn try_read_list(input: &str) -> IResult<&str, Value> {
     let mut rest_input = list_inner_input;
     loop {
         if let Ok((after_list_input, _)) = rparenp(rest_input) {
-            return Ok((after_list_input, list_as_vec.into_list().to_value()));
+            let list = list_as_vec.into_list().to_value();
+            return Ok((after_list_input, with_source_position(input, list)));
         }
         let (_rest_input, form) = try_read(rest_input)?;
         list_as_vec.push(form.to_rc_value());
@@ -626,14 +764,71 @@ pub fn try_read_quoted(input: &str) -> IResult<&str, Value> {
     Ok((rest_input, list_val!(sym!("quote") quoted_form_value)))
 }
 
+/// `form => (syntax-quote form),  like 'form => (quote form)
+///
+/// The syntax-quote special form then does the work of qualifying symbols, auto-gensyming
+/// foo# and filling in what's unquoted, when it's evaluated
+pub fn try_read_syntax_quoted(input: &str) -> IResult<&str, Value> {
+    named!(syntax_quote<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("`")));
+
+    let (form, _) = syntax_quote(input)?;
+
+    let (rest_input, quoted_form_value) = try_read(form)?;
+
+    // (syntax-quote value)
+    Ok((rest_input, list_val!(sym!("syntax-quote") quoted_form_value)))
+}
+
+/// ~@form => (unquote-splicing form)
+pub fn try_read_unquote_spliced(input: &str) -> IResult<&str, Value> {
+    named!(unquote_splicing<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("~@")));
+
+    let (form, _) = unquote_splicing(input)?;
+
+    let (rest_input, unquoted_form_value) = try_read(form)?;
+
+    // (unquote-splicing value)
+    Ok((rest_input, list_val!(sym!("unquote-splicing") unquoted_form_value)))
+}
+
+/// ~form => (unquote form)
+pub fn try_read_unquoted(input: &str) -> IResult<&str, Value> {
+    named!(unquote<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("~")));
+
+    let (form, _) = unquote(input)?;
+
+    let (rest_input, unquoted_form_value) = try_read(form)?;
+
+    // (unquote value)
+    Ok((rest_input, list_val!(sym!("unquote") unquoted_form_value)))
+}
+
+/// @form => (deref form)
+pub fn try_read_deref(input: &str) -> IResult<&str, Value> {
+    named!(deref<&str, &str>, preceded!(consume_clojure_whitespaces_parser, tag!("@")));
+
+    let (form, _) = deref(input)?;
+
+    let (rest_input, derefed_form_value) = try_read(form)?;
+
+    // (deref value)
+    Ok((rest_input, list_val!(sym!("deref") derefed_form_value)))
+}
+
 pub fn try_read(input: &str) -> IResult<&str, Value> {
     preceded(
         consume_clojure_whitespaces_parser,
         alt((
             try_read_meta,
             try_read_quoted,
+            try_read_syntax_quoted,
+            // Before try_read_unquoted, else ~@x reads as (unquote @x)
+            try_read_unquote_spliced,
+            try_read_unquoted,
+            try_read_deref,
             try_read_nil,
             try_read_map,
+            try_read_set,
             try_read_string,
             try_read_f64,
             try_read_i32,
@@ -658,42 +853,107 @@ pub fn try_read(input: &str) -> IResult<&str, Value> {
 //
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
-// This is the high level read function that Clojure RS wraps
-pub fn read<R: BufRead>(reader: &mut R) -> Option<Value> {
-    // This is a buffer that will accumulate if a read requires more
-    // text to make sense, such as trying to read (+ 1
-    let mut input_buffer = String::new();
+/// Reads forms one after another from input, keeping track of where in it we are, so that
+/// each form read knows the file, line and column it was read from
+pub struct Reader<R: BufRead> {
+    lines: std::io::Lines<R>,
+    /// Text taken from input, but not yet read as a form
+    buffer: String,
+    file: Option<String>,
+    /// The line and column buffer starts at
+    line: usize,
+    column: usize,
+}
+impl<R: BufRead> Reader<R> {
+    pub fn new(input: R) -> Reader<R> {
+        Reader {
+            lines: input.lines(),
+            buffer: String::new(),
+            file: None,
+            line: 1,
+            column: 1,
+        }
+    }
+    /// A reader of the file at path, whose forms will know they were read from there
+    pub fn from_file(input: R, path: &str) -> Reader<R> {
+        Reader {
+            file: Some(String::from(path)),
+            ..Reader::new(input)
+        }
+    }
 
-    // Ask for a line from the reader, try to read, and if unable (because we need more text),
-    // loop over and ask for more lines, accumulating them in input_buffer until we can read
-    let mut lines = reader.by_ref().lines();
-    loop {
-        let maybe_line = lines.next();
-
-        match maybe_line {
-            Some(Err(e)) => return Some(Value::Condition(format!("Reader error: {}", e))),
-            // `lines` does not include \n,  but \n is part of the whitespace given to the reader
-            // (and is important for reading comments) so we will push a newline as well
-            Some(Ok(line)) => {
-                input_buffer.push_str(&line);
-                input_buffer.push_str("\n");
+    /// Reads the next form, or returns None when there are none left.  Forms may span lines,
+    /// or share them;  whatever follows a form on its line is kept for the next read
+    pub fn read(&mut self) -> Option<Value> {
+        loop {
+            if !self.buffer.is_empty() {
+                READ_SOURCE.with(|read_source| {
+                    *read_source.borrow_mut() = Some(ReadSource {
+                        text: self.buffer.clone(),
+                        file: self.file.clone(),
+                        line: self.line,
+                        column: self.column,
+                    })
+                });
+                let read = try_read(&self.buffer)
+                    .map(|(rest, value)| (rest.len(), value))
+                    // Our error is still borrowing the buffer, so keep just its description
+                    .map_err(|err| match err {
+                        Incomplete(_) => None,
+                        err => Some(format!("{:?}", err)),
+                    });
+                let position = source_position(self.buffer.trim_start());
+                READ_SOURCE.with(|read_source| *read_source.borrow_mut() = None);
+
+                match read {
+                    Ok((rest_len, value)) => {
+                        let rest = self.buffer.split_off(self.buffer.len() - rest_len);
+                        let (line, column) = position_after(self.line, self.column, &self.buffer);
+                        self.line = line;
+                        self.column = column;
+                        self.buffer = rest;
+                        return Some(value);
+                    }
+                    // Continue accumulating more input
+                    Err(None) => {}
+                    Err(Some(err)) => {
+                        // Skip what we couldn't read, rather than failing on it forever
+                        let (line, column) = position_after(self.line, self.column, &self.buffer);
+                        self.line = line;
+                        self.column = column;
+                        self.buffer.clear();
+                        return Some(Value::Condition(Rc::new(
+                            crate::condition::Condition::new(
+                                crate::condition::ConditionKind::RuntimeException,
+                                format!("Reader Error: could not read next form; {}", err),
+                            )
+                            .with_position(position),
+                        )));
+                    }
+                }
             }
-            None => return None,
-        }
 
-        let line_read = try_read(&input_buffer);
-        match line_read {
-            Ok((_, value)) => return Some(value),
-            // Continue accumulating more input
-            Err(Incomplete(_)) => continue,
-            Err(err) => return Some(Value::Condition(format!(
-                "Reader Error: could not read next form; {:?}",
-                err,
-            ))),
+            // Ask for a line from the input, and loop over to try reading again
+            match self.lines.next() {
+                Some(Err(e)) => return Some(error_message::custom(format!("Reader error: {}", e))),
+                // `lines` does not include \n,  but \n is part of the whitespace given to the reader
+                // (and is important for reading comments) so we will push a newline as well
+                Some(Ok(line)) => {
+                    self.buffer.push_str(&line);
+                    self.buffer.push('\n');
+                }
+                None => return None,
+            }
         }
     }
 }
 
+// This is the high level read function that Clojure RS wraps
+/// Reads one form from reader;  to read several, one after another, use a Reader
+pub fn read<R: BufRead>(reader: &mut R) -> Option<Value> {
+    Reader::new(reader).read()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -966,6 +1226,29 @@ mod tests {
             );
         }
 
+        #[test]
+        fn try_read_set_test() {
+            let set = [1_i32, 2, 3]
+                .iter()
+                .map(|i| i.to_rc_value())
+                .collect::<crate::persistent_hash_set::PersistentHashSet>();
+            assert_eq!(Value::PersistentHashSet(set), try_read("#{3 1 2} ").ok().unwrap().1);
+            assert_eq!(
+                Value::PersistentHashSet(Default::default()),
+                try_read("#{} ").ok().unwrap().1
+            );
+        }
+
+        #[test]
+        fn try_read_set_with_duplicate_test() {
+            match try_read("#{1 :a 1} ").ok().unwrap() {
+                (" ", Value::Condition(condition)) => {
+                    assert_eq!("Duplicate key: 1", condition.message)
+                }
+                read => panic!("expected a condition, got {:?}", read),
+            }
+        }
+
         #[test]
         fn try_read_empty_list_test() {
             assert_eq!(
@@ -977,7 +1260,7 @@ mod tests {
         #[test]
         fn try_read_empty_vector_test() {
             assert_eq!(
-                PersistentVector(persistent_vector::PersistentVector { vals: [].to_vec() }),
+                PersistentVector(persistent_vector::PersistentVector::empty()),
                 try_read("[] ").ok().unwrap().1
             );
         }
@@ -1056,6 +1339,31 @@ mod tests {
             }
         }
         #[test]
+        fn try_read_syntax_quote_test() {
+            assert_eq!(
+                list_val!(sym!("syntax-quote") list_val!(sym!("a") list_val!(sym!("unquote") sym!("b")) list_val!(sym!("unquote-splicing") sym!("c")))),
+                try_read("`(a ~b ~@c) ").ok().unwrap().1
+            );
+        }
+        #[test]
+        fn try_read_deref_test() {
+            assert_eq!(
+                list_val!(sym!("deref") sym!("a")),
+                try_read("@a ").ok().unwrap().1
+            );
+            assert_eq!(
+                list_val!(sym!("unquote-splicing") sym!("a")),
+                try_read("~@a ").ok().unwrap().1
+            );
+        }
+        #[test]
+        fn try_read_auto_gensym_symbol_test() {
+            assert_eq!(
+                Value::Symbol(Symbol::intern("x#")),
+                try_read("x# ").ok().unwrap().1
+            );
+        }
+        #[test]
         fn try_read_forward_slash_test() {
             assert_eq!(
                 Value::Symbol(Symbol::intern(&"/")),
@@ -1137,6 +1445,14 @@ mod tests {
                 try_read(r#"#"h\"e\"l\" l \"o" something"#).ok().unwrap().1
             );
         }
+
+        #[test]
+        fn try_read_regex_pattern_escapes_test() {
+            assert_eq!(
+                Value::Pattern(regex::Regex::new(r"\d+\s\\").unwrap()),
+                try_read(r#"#"\d+\s\\" "#).ok().unwrap().1
+            );
+        }
     }
 
     mod consume_clojure_whitespaces_tests {
@@ -1222,4 +1538,58 @@ mod tests {
             assert_eq!(false, is_clojure_whitespace('a'));
         }
     }
+
+    mod reader_tests {
+        use crate::persistent_list::ToPersistentListIter;
+        use crate::reader::Reader;
+        use crate::source_position::SourcePosition;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn position(value: &Value) -> Option<(usize, usize)> {
+            SourcePosition::of(value).map(|position| (position.line, position.column))
+        }
+
+        #[test]
+        fn reads_several_forms_per_line() {
+            let mut reader = Reader::new("1 (+ 1 2) [3]\n  4".as_bytes());
+            assert_eq!(Some(Value::I32(1)), reader.read());
+            assert!(matches!(reader.read(), Some(Value::PersistentList(_))));
+            assert!(matches!(reader.read(), Some(Value::PersistentVector(_))));
+            assert_eq!(Some(Value::I32(4)), reader.read());
+            assert_eq!(None, reader.read());
+        }
+
+        #[test]
+        fn records_line_and_column() {
+            let mut reader = Reader::new("(a b)\n\n  [c\n   d] {:e f}\n sym".as_bytes());
+            assert_eq!(Some((1, 1)), position(&reader.read().unwrap()));
+            assert_eq!(Some((3, 3)), position(&reader.read().unwrap()));
+            assert_eq!(Some((4, 7)), position(&reader.read().unwrap()));
+            assert_eq!(Some((5, 2)), position(&reader.read().unwrap()));
+        }
+
+        #[test]
+        fn records_nested_positions() {
+            let mut reader = Reader::new("\n  (f\n    (g x))".as_bytes());
+            match reader.read() {
+                Some(Value::PersistentList(plist)) => {
+                    let forms = Rc::new(plist).iter().collect::<Vec<_>>();
+                    assert_eq!(Some((2, 4)), position(&forms[0]));
+                    assert_eq!(Some((3, 5)), position(&forms[1]));
+                }
+                read => panic!("expected a list, got {:?}", read),
+            }
+        }
+
+        #[test]
+        fn records_file() {
+            let mut reader = Reader::from_file("(a)".as_bytes(), "script.clj");
+            let read = reader.read().unwrap();
+            assert_eq!(
+                Some(String::from("script.clj:1:1")),
+                SourcePosition::of(&read).map(|position| position.to_string())
+            );
+        }
+    }
 }
@@ -2,17 +2,50 @@ use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
 
+use crate::clojure_pprint;
+use crate::condition::Condition;
 use crate::environment::Environment;
+use crate::error_message;
+use crate::printer;
 use crate::reader;
+use crate::seq;
+use crate::stream::{self, Writer};
 use crate::value::{Evaluable, Value};
 use std::rc::Rc;
 
+/// Walks a lazy result through before it's printed, so a condition hit realizing it is
+/// reported as such rather than printed as one of its elements
+fn realize(value: Value) -> Value {
+    match &value {
+        Value::LazySeq(_) | Value::Cons(_) => {
+            let value = Rc::new(value);
+            match seq::iter(&value).find(|item| matches!(**item, Value::Condition(_))) {
+                Some(condition) => (*condition).clone(),
+                None => Rc::try_unwrap(value).unwrap_or_else(|value| (*value).clone()),
+            }
+        }
+        _ => value,
+    }
+}
+
 pub struct Repl {
     environment: Rc<Environment>,
+    /// Whether results are printed as clojure.pprint/pprint would, rather than as pr would
+    pretty_print: bool,
 }
 impl Repl {
     pub fn new(environment: Rc<Environment>) -> Repl {
-        Repl { environment }
+        Repl {
+            environment,
+            pretty_print: false,
+        }
+    }
+    /// Us, pretty printing results or not
+    pub fn with_pretty_print(self, pretty_print: bool) -> Repl {
+        Repl {
+            pretty_print,
+            ..self
+        }
     }
 
     // Just wraps reader's read
@@ -28,7 +61,11 @@ impl Repl {
     // to frame eval as "environment.eval(value)", and then likewise define a
     // 'repl.eval(value)', rather than 'value.eval(environment)'
     pub fn eval(&self, value: &Value) -> Value {
-        value.eval(Rc::clone(&self.environment))
+        match value.eval(Rc::clone(&self.environment)) {
+            // A recur with no loop or fn to return to
+            Value::Recur(_) => error_message::non_tail_recur(),
+            evaled => evaled,
+        }
     }
     //
     // Will possibly just add this to our environment, or turn this into a parallel of clojure.lang.RT
@@ -37,21 +74,24 @@ impl Repl {
     pub fn try_eval_file(&self, filepath: &str) -> Result<Option<Value>, std::io::Error> {
         let core = File::open(filepath)?;
         let reader = BufReader::new(core);
-        Ok(self.eval_readable(reader))
+        Ok(self.eval_reader(reader::Reader::from_file(reader, filepath)))
     }
     pub fn eval_file(&self, filepath: &str) -> Option<Value> {
         match self.try_eval_file(filepath) {
             Ok(Some(v)) => Some(v),
             Ok(None) => None,
-            Err(e) => Some(Value::Condition(e.to_string())),
+            Err(e) => Some(error_message::custom(e.to_string())),
         }
     }
 
     /// reads & evaluates code sequentially, returning the final value (*not* an
     /// end-of-input `Value::Condition`)
-    pub fn eval_readable<R: BufRead>(&self, mut r: R) -> Option<Value> {
+    pub fn eval_readable<R: BufRead>(&self, r: R) -> Option<Value> {
+        self.eval_reader(reader::Reader::new(r))
+    }
+    fn eval_reader<R: BufRead>(&self, mut reader: reader::Reader<R>) -> Option<Value> {
         let mut prev_evaled = None;
-        let mut maybe_just_read = Repl::read(&mut r);
+        let mut maybe_just_read = reader.read();
         loop {
             match maybe_just_read.as_ref() {
                 None => return prev_evaled,
@@ -60,35 +100,42 @@ impl Repl {
                     //       we can properly identify them
                     // @FIXME
                     if let Value::Condition(cond) = just_read {
-                        if cond == "Tried to read empty stream; unexpected EOF" {
+                        if cond.message == "Tried to read empty stream; unexpected EOF" {
                             return None;
                         }
 
-                        eprintln!("error eval-ing {}: {}", just_read, cond);
+                        self.report_error(just_read, cond);
                         return maybe_just_read;
                     }
-                    prev_evaled = Some(self.eval(just_read));
+                    let evaled = self.eval(just_read);
+                    // Like a script in Clojure, we stop at the first form to throw
+                    if let Value::Condition(cond) = &evaled {
+                        self.report_error(just_read, cond);
+                        return Some(evaled);
+                    }
+                    prev_evaled = Some(evaled);
                 }
             }
-            maybe_just_read = Repl::read(&mut r);
+            maybe_just_read = reader.read();
         }
     }
 
     pub fn run<I, O>(
         &self,
-        mut input: I,
+        input: I,
         mut output: O,
     ) where
         I: std::io::BufRead,
         O: std::io::Write,
     {
+        let mut reader = reader::Reader::new(input);
         loop {
             let _ = write!(&mut output, "{}=> ", self.environment.get_current_namespace_name());
             let _ = output.flush();
 
             let next = {
                 // Read
-                let next = Repl::read(&mut input);
+                let next = reader.read();
 
                 if_chain::if_chain! {
                     if let Some(Value::Keyword(kw)) = &next;
@@ -102,26 +149,66 @@ impl Repl {
 
             if let Some(next) = next {
                 // Eval
-                let evaled_next = self.eval(&next);
+                let evaled_next = realize(self.eval(&next));
                 // Print
-                println!("{}", evaled_next);
+                let _ = writeln!(&mut output, "{}", self.pr_str(&evaled_next));
+                if let Value::Condition(cond) = &evaled_next {
+                    report_trace(&self.err(), cond);
+                }
             }
             // Loop
         }
     }
+
+    /// value as the repl prints a result;  readably, as pr would (or pprint, if we're pretty
+    /// printing), unless it's a condition
+    pub fn pr_str(&self, value: &Value) -> String {
+        let value = Rc::new(value.clone());
+        let printed = match &*value {
+            Value::Condition(_) => return value.to_string(),
+            _ if self.pretty_print => clojure_pprint::pprint::pprint_str(&self.environment, &value),
+            _ => printer::print_str(&self.environment, &value, true),
+        };
+        printed.unwrap_or_else(|condition| condition.to_string())
+    }
+
+    /// Where we report errors;  *err*, or stderr if that's been bound to something else
+    fn err(&self) -> Writer {
+        stream::err(&self.environment).unwrap_or_else(|_| Writer::stderr())
+    }
+    /// Prints, to *err*, that evaluating form failed with cond, and where
+    fn report_error(&self, form: &Value, cond: &Condition) {
+        let err = self.err();
+        let _ = match &cond.position {
+            Some(position) => err.write_str(&format!(
+                "error eval-ing {} at {}: {}\n",
+                form, position, cond.message
+            )),
+            None => err.write_str(&format!("error eval-ing {}: {}\n", form, cond.message)),
+        };
+        report_trace(&err, cond);
+    }
+}
+
+/// Prints, to err, the fns that were being run when cond was created, innermost first
+fn report_trace(err: &Writer, cond: &Condition) {
+    for frame in cond.trace.iter() {
+        let _ = err.write_str(&format!("    at {}\n", frame));
+    }
+    let _ = err.flush();
 }
 
 impl Default for Repl {
     fn default() -> Repl {
-        Repl {
-            environment: Environment::clojure_core_environment(),
-        }
+        Repl::new(Environment::clojure_core_environment())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::environment::Environment;
     use crate::repl::Repl;
+    use crate::stream::{Reader, Writer};
     use crate::value::Value;
     //@TODO separate into individual tests
     #[test]
@@ -162,11 +249,54 @@ mod tests {
 
     #[test]
     fn eval_readable_string_as_bytes_vector_literal() {
-        let evaled = Repl::default().eval_readable("[some quoted vec]".as_bytes());
+        let evaled = Repl::default().eval_readable("'[some quoted vec]".as_bytes());
         assert!(
             matches!(evaled, Some(Value::PersistentVector(_))),
             "read & eval of [some quoted vec] should have returned Some(Value::PersistentVector(_)), instead got {:?}",
             evaled,
         );
     }
+
+    #[test]
+    fn run_with_embedder_streams() {
+        let environment = Environment::clojure_core_environment();
+        let out = Writer::buffer();
+        let err = Writer::buffer();
+        environment.set_out(out.clone());
+        environment.set_err(err.clone());
+        environment.set_in(Reader::from_string("typed\n".to_string()));
+
+        let mut output = vec![];
+        Repl::new(environment).run(
+            "(println \"hi\") (read-line) \"a\\nb\" (throw (ex-info \"oops\" {})) :repl/quit".as_bytes(),
+            &mut output,
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("nil"));
+        assert!(output.contains("\"typed\""));
+        assert!(output.contains("\"a\\nb\""));
+        assert!(output.contains("oops"));
+        assert_eq!(Some("hi\n".to_string()), out.contents());
+    }
+
+    #[test]
+    fn run_pretty_printing() {
+        let environment = Environment::clojure_core_environment();
+        let mut output = vec![];
+        Repl::new(environment).with_pretty_print(true).run(
+            "(into [] (range 40)) :repl/quit".as_bytes(),
+            &mut output,
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("[0\n 1\n 2\n"));
+    }
+
+    #[test]
+    fn eval_file_reports_errors_to_err() {
+        let environment = Environment::clojure_core_environment();
+        let err = Writer::buffer();
+        environment.set_err(err.clone());
+        Repl::new(environment).eval_readable("(+ 1 :a)".as_bytes());
+        assert!(err.contents().unwrap().starts_with("error eval-ing (+ 1 :a)"));
+    }
 }
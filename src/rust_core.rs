@@ -13,10 +13,34 @@ pub(crate) mod meta;
 pub use self::meta::*;
 pub(crate) mod with_meta;
 pub use self::with_meta::*;
+pub(crate) mod gensym;
+pub use self::gensym::*;
+pub(crate) mod keyword;
+pub use self::keyword::*;
 // macros
 pub(crate) mod do_macro;
 pub use self::do_macro::*;
 
+// exceptions
+pub(crate) mod throw;
+pub use self::throw::*;
+pub(crate) mod ex_info;
+pub use self::ex_info::*;
+pub(crate) mod ex_data;
+pub use self::ex_data::*;
+pub(crate) mod ex_trace;
+pub use self::ex_trace::*;
+pub(crate) mod ex_message;
+pub use self::ex_message::*;
+pub(crate) mod ex_cause;
+pub use self::ex_cause::*;
+pub(crate) mod signal;
+pub use self::signal::*;
+pub(crate) mod error;
+pub use self::error::*;
+pub(crate) mod invoke_restart;
+pub use self::invoke_restart::*;
+
 // namespacing
 pub(crate) mod ns;
 pub use self::ns::*;
@@ -71,6 +95,14 @@ pub(crate) mod assoc;
 pub use self::assoc::*;
 pub(crate) mod get;
 pub use self::get::*;
+pub(crate) mod conj;
+pub use self::conj::*;
+pub(crate) mod pop;
+pub use self::pop::*;
+pub(crate) mod disj;
+pub use self::disj::*;
+pub(crate) mod contains;
+pub use self::contains::*;
 pub(crate) mod map;
 pub use self::map::*;
 
@@ -83,6 +115,118 @@ pub use self::second::*;
 pub(crate) mod count;
 pub use self::count::*;
 
+// seqs
+pub(crate) mod seq;
+pub use self::seq::*;
+pub(crate) mod cons;
+pub use self::cons::*;
+pub(crate) mod lazy_seq;
+pub use self::lazy_seq::*;
+pub(crate) mod range;
+pub use self::range::*;
+pub(crate) mod realized;
+pub use self::realized::*;
+pub(crate) mod compare;
+pub use self::compare::*;
+pub(crate) mod reduce;
+pub use self::reduce::*;
+pub(crate) mod reduced;
+pub use self::reduced::*;
+pub(crate) mod filter;
+pub use self::filter::*;
+pub(crate) mod remove;
+pub use self::remove::*;
+pub(crate) mod take;
+pub use self::take::*;
+pub(crate) mod drop;
+pub use self::drop::*;
+pub(crate) mod take_while;
+pub use self::take_while::*;
+pub(crate) mod drop_while;
+pub use self::drop_while::*;
+pub(crate) mod partition;
+pub use self::partition::*;
+pub(crate) mod partition_by;
+pub use self::partition_by::*;
+pub(crate) mod interleave;
+pub use self::interleave::*;
+pub(crate) mod distinct;
+pub use self::distinct::*;
+pub(crate) mod frequencies;
+pub use self::frequencies::*;
+pub(crate) mod group_by;
+pub use self::group_by::*;
+pub(crate) mod sort;
+pub use self::sort::*;
+pub(crate) mod sort_by;
+pub use self::sort_by::*;
+pub(crate) mod reverse;
+pub use self::reverse::*;
+pub(crate) mod some;
+pub use self::some::*;
+pub(crate) mod every;
+pub use self::every::*;
+pub(crate) mod mapcat;
+pub use self::mapcat::*;
+pub(crate) mod keep;
+pub use self::keep::*;
+pub(crate) mod into;
+pub use self::into::*;
+// transducers
+pub(crate) mod comp;
+pub use self::comp::*;
+pub(crate) mod completing;
+pub use self::completing::*;
+pub(crate) mod transduce;
+pub use self::transduce::*;
+pub(crate) mod sequence;
+pub use self::sequence::*;
+pub(crate) mod eduction;
+pub use self::eduction::*;
+pub(crate) mod partition_all;
+pub use self::partition_all::*;
+pub(crate) mod dedupe;
+pub use self::dedupe::*;
+// atoms
+pub(crate) mod atom;
+pub use self::atom::*;
+pub(crate) mod deref;
+pub use self::deref::*;
+pub(crate) mod swap;
+pub use self::swap::*;
+pub(crate) mod reset;
+pub use self::reset::*;
+pub(crate) mod compare_and_set;
+pub use self::compare_and_set::*;
+pub(crate) mod watch;
+pub use self::watch::*;
+// dynamic vars
+pub(crate) mod with_bindings;
+pub use self::with_bindings::*;
+pub(crate) mod var_set;
+pub use self::var_set::*;
+pub(crate) mod with_redefs;
+pub use self::with_redefs::*;
+// instants
+pub(crate) mod inst_ms;
+pub use self::inst_ms::*;
+// multimethods
+pub(crate) mod multi_fn;
+pub use self::multi_fn::*;
+// regexes
+pub(crate) mod re_pattern;
+pub use self::re_pattern::*;
+pub(crate) mod re_matcher;
+pub use self::re_matcher::*;
+pub(crate) mod re_find;
+pub use self::re_find::*;
+pub(crate) mod re_matches;
+pub use self::re_matches::*;
+pub(crate) mod re_seq;
+pub use self::re_seq::*;
+pub(crate) mod re_groups;
+pub use self::re_groups::*;
+
 // input and output
 pub(crate) mod system_newline;
 pub use self::system_newline::*;
@@ -94,6 +238,20 @@ pub(crate) mod string_print;
 pub use self::string_print::*;
 pub(crate) mod read_line;
 pub use self::read_line::*;
+pub(crate) mod string_writer;
+pub use self::string_writer::*;
+pub(crate) mod string_reader;
+pub use self::string_reader::*;
+pub(crate) mod pr_str;
+pub use self::pr_str::*;
+pub(crate) mod pr_on;
+pub use self::pr_on::*;
+pub(crate) mod spit;
+pub use self::spit::*;
+pub(crate) mod line_seq;
+pub use self::line_seq::*;
+pub(crate) mod file_seq;
+pub use self::file_seq::*;
 
 // other
 pub(crate) mod slurp;
@@ -114,7 +272,7 @@ impl crate::ifn::IFn for ReadStringFn {
     fn invoke(&self, args: Vec<std::rc::Rc<crate::value::Value>>) -> crate::value::Value {
         match &*args.first().unwrap().clone() {
             crate::value::Value::String(s) => crate::repl::Repl::read_string(&s).expect("successful read"),
-            _ => crate::value::Value::Condition("read-string requires a string as its first argument".to_string()),
+            _ => crate::error_message::custom("read-string requires a string as its first argument"),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
@@ -20,11 +21,12 @@ impl IFn for DivideFn {
             1 => {
                 let val = args.get(0).unwrap().to_value();
                 match val {
-                    Value::I32(0) => Value::Condition("Divide by zero".to_string()),
-                    Value::F64(f_) if f_ == 0.0 => Value::Condition("Divide by zero".to_string()),
+                    Value::I32(0) | Value::I64(0) => error_message::divide_by_zero(),
+                    Value::F64(f_) if f_ == 0.0 => error_message::divide_by_zero(),
                     Value::I32(a_) => Value::F64(1.0 / a_ as f64),
+                    Value::I64(a_) => Value::F64(1.0 / a_ as f64),
                     Value::F64(f_) => Value::F64(1.0 / f_),
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         val.type_tag()
@@ -36,28 +38,41 @@ impl IFn for DivideFn {
                 let first_arg = args_iterator.next().unwrap();
                 args_iterator.fold(first_arg.to_value(), |a, b| match a {
                     Value::I32(a_) => match *b {
-                        Value::I32(0) => Value::Condition("Divide by zero".to_string()),
-                        Value::F64(b_) if b_ == 0.0 => Value::Condition("Divide by zero".to_string()),
+                        Value::I32(0) | Value::I64(0) => error_message::divide_by_zero(),
+                        Value::F64(b_) if b_ == 0.0 => error_message::divide_by_zero(),
                         Value::I32(b_) => Value::I32(a_ / b_),
+                        Value::I64(b_) => Value::I64(i64::from(a_) / b_),
                         Value::F64(b_) => Value::F64(a_ as f64 / b_),
-                        _ => Value::Condition(format!(
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
+                            // TODO: what error message should be returned regarding using typetags?
+                            "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                            b.type_tag()
+                        )),
+                    },
+                    Value::I64(a_) => match *b {
+                        Value::I32(0) | Value::I64(0) | Value::F64(0.0) => error_message::divide_by_zero(),
+                        Value::I32(b_) => Value::I64(a_ / i64::from(b_)),
+                        Value::I64(b_) => Value::I64(a_ / b_),
+                        Value::F64(b_) => Value::F64(a_ as f64 / b_),
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             b.type_tag()
                         )),
                     },
                     Value::F64(a_) => match *b {
-                        Value::I32(0) => Value::Condition("Divide by zero".to_string()),
-                        Value::F64(b_) if b_ == 0.0 => Value::Condition("Divide by zero".to_string()),
+                        Value::I32(0) | Value::I64(0) => error_message::divide_by_zero(),
+                        Value::F64(b_) if b_ == 0.0 => error_message::divide_by_zero(),
                         Value::I32(b_) => Value::F64(a_ / b_ as f64),
+                        Value::I64(b_) => Value::F64(a_ / b_ as f64),
                         Value::F64(b_) => Value::F64(a_ / b_),
-                        _ => Value::Condition(format!(
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             b.type_tag()
                         )),
                     },
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch: Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         a.type_tag()
@@ -71,6 +86,8 @@ impl IFn for DivideFn {
 #[cfg(test)]
 mod tests {
     mod divide_tests {
+        use crate::condition::ConditionKind;
+        use crate::error_message;
         use crate::ifn::IFn;
         use crate::rust_core::_divide_::DivideFn;
         use crate::value::Value;
@@ -81,9 +98,10 @@ mod tests {
             let divide = DivideFn {};
             let args = vec![];
             assert_eq!(
-                Value::Condition(String::from(
+                error_message::condition(
+                    ConditionKind::ArityException,
                     "Wrong number of arguments given to function (Given: 0)"
-                )),
+                ),
                 divide.invoke(args)
             );
         }
@@ -1,3 +1,5 @@
+use crate::error_message;
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
@@ -16,8 +18,19 @@ impl IFn for MultiplyFn {
         args.into_iter().fold(1_i32.to_value(), |a, b| match a {
             Value::I32(a_) => match *b {
                 Value::I32(b_) => Value::I32(a_ * b_),
+                Value::I64(b_) => Value::I64(i64::from(a_) * b_),
                 Value::F64(b_) => Value::F64(a_ as f64 * b_),
-                _ => Value::Condition(format!(
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
+                    // TODO: what error message should be returned regarding using typetags?
+                    "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                    b.type_tag()
+                )),
+            },
+            Value::I64(a_) => match *b {
+                Value::I32(b_) => Value::I64(a_ * i64::from(b_)),
+                Value::I64(b_) => Value::I64(a_ * b_),
+                Value::F64(b_) => Value::F64(a_ as f64 * b_),
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b.type_tag()
@@ -25,14 +38,15 @@ impl IFn for MultiplyFn {
             },
             Value::F64(a_) => match *b {
                 Value::I32(b_) => Value::F64(a_ * b_ as f64),
+                Value::I64(b_) => Value::F64(a_ * b_ as f64),
                 Value::F64(b_) => Value::F64(a_ * b_),
-                _ => Value::Condition(format!(
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b.type_tag()
                 )),
             },
-            _ => Value::Condition(format!(
+            _ => error_message::condition(ConditionKind::ClassCastException, format!(
                 // TODO: what error message should be returned regarding using typetags?
                 "Type mismatch: Expecting: (i32 | i64 | f32 | f64), Found: {}",
                 a.type_tag()
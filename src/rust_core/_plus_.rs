@@ -1,3 +1,5 @@
+use crate::error_message;
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
@@ -16,8 +18,19 @@ impl IFn for AddFn {
         args.into_iter().fold(0_i32.to_value(), |a, b| match a {
             Value::I32(a_) => match *b {
                 Value::I32(b_) => Value::I32(a_ + b_),
+                Value::I64(b_) => Value::I64(i64::from(a_) + b_),
                 Value::F64(b_) => Value::F64(a_ as f64 + b_),
-                _ => Value::Condition(format!(
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
+                    // TODO: what error message should be returned regarding using typetags?
+                    "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                    b.type_tag()
+                )),
+            },
+            Value::I64(a_) => match *b {
+                Value::I32(b_) => Value::I64(a_ + i64::from(b_)),
+                Value::I64(b_) => Value::I64(a_ + b_),
+                Value::F64(b_) => Value::F64(a_ as f64 + b_),
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b.type_tag()
@@ -25,14 +38,15 @@ impl IFn for AddFn {
             },
             Value::F64(a_) => match *b {
                 Value::I32(b_) => Value::F64(a_ + b_ as f64),
+                Value::I64(b_) => Value::F64(a_ + b_ as f64),
                 Value::F64(b_) => Value::F64(a_ + b_),
-                _ => Value::Condition(format!(
+                _ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b.type_tag()
                 )),
             },
-            _ => Value::Condition(format!(
+            _ => error_message::condition(ConditionKind::ClassCastException, format!(
                 // TODO: what error message should be returned regarding using typetags?
                 "Type mismatch: Expecting: (i32 | i64 | f32 | f64), Found: {}",
                 a.type_tag()
@@ -69,5 +83,14 @@ mod tests {
             let args = vec![Rc::new(Value::I32(5)), Rc::new(Value::I32(6))];
             assert_eq!(Value::I32(11), addition.invoke(args));
         }
+
+        #[test]
+        fn plus_with_an_i64_gives_an_i64() {
+            let addition = AddFn {};
+            let args = vec![Rc::new(Value::I64(12345678901)), Rc::new(Value::I32(1))];
+            assert_eq!(Value::I64(12345678902), addition.invoke(args));
+            let args = vec![Rc::new(Value::I64(12345678901)), Rc::new(Value::F64(0.5))];
+            assert_eq!(Value::F64(12345678901.5), addition.invoke(args));
+        }
     }
 }
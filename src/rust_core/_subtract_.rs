@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
@@ -21,8 +22,9 @@ impl IFn for SubtractFn {
                 let val = args.get(0).unwrap().to_value();
                 match val {
                     Value::I32(a_) => Value::I32(-a_),
+                    Value::I64(a_) => Value::I64(-a_),
                     Value::F64(f_) => Value::F64(-f_),
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         val.type_tag()
@@ -35,8 +37,19 @@ impl IFn for SubtractFn {
                 args_iterator.fold(first_arg.to_value(), |a, b| match a {
                     Value::I32(a_) => match *b {
                         Value::I32(b_) => Value::I32(a_ - b_),
+                        Value::I64(b_) => Value::I64(i64::from(a_) - b_),
                         Value::F64(b_) => Value::F64(a_ as f64 - b_),
-                        _ => Value::Condition(format!(
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
+                            // TODO: what error message should be returned regarding using typetags?
+                            "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                            b.type_tag()
+                        )),
+                    },
+                    Value::I64(a_) => match *b {
+                        Value::I32(b_) => Value::I64(a_ - i64::from(b_)),
+                        Value::I64(b_) => Value::I64(a_ - b_),
+                        Value::F64(b_) => Value::F64(a_ as f64 - b_),
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             b.type_tag()
@@ -44,14 +57,15 @@ impl IFn for SubtractFn {
                     },
                     Value::F64(a_) => match *b {
                         Value::I32(b_) => Value::F64(a_ - b_ as f64),
+                        Value::I64(b_) => Value::F64(a_ - b_ as f64),
                         Value::F64(b_) => Value::F64(a_ - b_),
-                        _ => Value::Condition(format!(
+                        _ => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             b.type_tag()
                         )),
                     },
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch: Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         a.type_tag()
@@ -65,6 +79,8 @@ impl IFn for SubtractFn {
 #[cfg(test)]
 mod tests {
     mod subtract_tests {
+        use crate::condition::ConditionKind;
+        use crate::error_message;
         use crate::ifn::IFn;
         use crate::rust_core::_subtract_::SubtractFn;
         use crate::value::Value;
@@ -75,9 +91,10 @@ mod tests {
             let subtract = SubtractFn {};
             let args = vec![];
             assert_eq!(
-                Value::Condition(String::from(
+                error_message::condition(
+                    ConditionKind::ArityException,
                     "Wrong number of arguments given to function (Given: 0)"
-                )),
+                ),
                 subtract.invoke(args)
             );
         }
@@ -1,5 +1,10 @@
+use crate::error_message;
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::persistent_list_map::IPersistentMap;
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::type_tag::TypeTag;
 use crate::util::IsEven;
 use crate::value::{ToValue, Value};
 use itertools::Itertools;
@@ -8,7 +13,8 @@ use std::rc::Rc;
 /// (assoc map key val & kvs)
 ///
 // General assoc fn; however,  currently just implemented
-// for our one map type, PersistentListMap
+// for our map types, PersistentListMap and PersistentHashMap,
+// and PersistentVector, whose keys are its indexes
 #[derive(Debug, Clone)]
 pub struct AssocFn {}
 impl ToValue for AssocFn {
@@ -23,23 +29,61 @@ impl IFn for AssocFn {
         // (assoc {} :a 1 :b 2) ;; 5 args
         // (assoc {} :a 1 :b 2 :c 3) ;; 7 args ...
         if args.len() < 3 || args.len().is_even() {
-            return Value::Condition(format!(
+            return error_message::condition(ConditionKind::ArityException, format!(
                 "Wrong number of arguments given to function (Given: {}, Expected: 3 | 5 | 7 | ..)",
                 args.len()
             ));
         }
 
-        if let Value::PersistentListMap(pmap) = &*(args.get(0).unwrap().clone()) {
-            let mut retval = pmap.clone();
+        if let Some(pmap) = args.get(0).unwrap().try_as_protocol::<protocols::IPersistentMap>() {
+            let mut retval = pmap;
             for (key_value, val_value) in args.into_iter().skip(1).tuples() {
                 let key = key_value.to_rc_value();
                 let val = val_value.to_rc_value();
-                println!("key: {:?}, val: {:?}", key, val);
-                retval = pmap.assoc(key, val);
+                retval = retval.assoc(key, val);
             }
-            return Value::PersistentListMap(retval);
+            return retval.unwrap().to_value();
+        }
+        if let Value::PersistentVector(pvector) = &*args[0] {
+            let mut retval = pvector.clone();
+            for (index, val) in args.iter().skip(1).tuples() {
+                retval = match **index {
+                    Value::I32(index) if index >= 0 => match retval.assoc(index as usize, Rc::clone(val)) {
+                        Some(pvector) => pvector,
+                        None => return error_message::index_out_of_bounds(index as usize, retval.len()),
+                    },
+                    _ => return error_message::type_mismatch(TypeTag::Integer, index),
+                };
+            }
+            return Value::PersistentVector(retval);
         }
 
         Value::Nil
     }
 }
+
+#[cfg(test)]
+mod tests {
+    mod assoc_tests {
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::rust_core::AssocFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn assoc_vector() {
+            let assoc = AssocFn {};
+            assert_eq!(
+                read("[1 :b 3 :d]"),
+                assoc.invoke(read_args(&["[1 2 3]", "1", ":b", "3", ":d"]))
+            );
+            match assoc.invoke(read_args(&["[1 2 3]", "4", ":e"])) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IndexOutOfBoundsException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::atom::Atom;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (atom x & options)
+///
+/// An atom holding x.  The only option is :validator, a fn called on each state the atom
+/// would take, which it only takes if that gives back something truthy
+#[derive(Debug, Clone)]
+pub struct AtomFn {}
+impl ToValue for AtomFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for AtomFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (state, options) = match args.split_first() {
+            Some((state, options)) if options.len() % 2 == 0 => (state, options),
+            _ => return error_message::wrong_variadic_arg_count(&[1], 3, args.len()),
+        };
+        let mut validator = None;
+        for option in options.chunks(2) {
+            match (&*option[0], &*option[1]) {
+                (Value::Keyword(key), _) if *key != Keyword::intern("validator") => {}
+                (_, Value::Nil) => validator = None,
+                (Value::Keyword(_), _) => match option[1].try_as_protocol::<protocols::IFn>() {
+                    Some(f) => validator = Some(f),
+                    None => return error_message::type_mismatch(TypeTag::IFn, &option[1]),
+                },
+                _ => {
+                    return error_message::condition(
+                        ConditionKind::IllegalArgumentException,
+                        format!("Atom options must be keywords, not {}", option[0]),
+                    )
+                }
+            }
+        }
+        match Atom::new(Rc::clone(state), validator) {
+            Ok(atom) => Value::Atom(Rc::new(atom)),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod atom_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AtomFn, DerefFn, EqualsFn, StrFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn positive() -> Rc<Value> {
+            Rc::new(eval_str("(fn [x] (gt x 0))"))
+        }
+
+        #[test]
+        fn atom_holds_its_value() {
+            let atom = AtomFn {};
+            let deref = DerefFn {};
+            let one = atom.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(Value::I32(1), deref.invoke(vec![one]));
+            let pair = atom.invoke(read_args(&["[1 2]"])).to_rc_value();
+            assert_eq!(read("[1 2]"), deref.invoke(vec![pair]));
+            let a = atom.invoke(read_args(&["\"a\""])).to_rc_value();
+            assert_eq!(
+                Value::String("#atom[\"a\"]".to_string()),
+                StrFn {}.invoke(vec![a])
+            );
+        }
+
+        #[test]
+        fn atoms_are_only_equal_to_themselves() {
+            let atom = AtomFn {};
+            let equals = EqualsFn {};
+            let a = atom.invoke(read_args(&["1"])).to_rc_value();
+            let b = atom.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(Value::Boolean(false), equals.invoke(vec![Rc::clone(&a), b]));
+            assert_eq!(Value::Boolean(true), equals.invoke(vec![Rc::clone(&a), a]));
+        }
+
+        #[test]
+        fn atom_with_invalid_state() {
+            let atom = AtomFn {};
+            let validator = Rc::new(read(":validator"));
+            match atom.invoke(vec![Rc::new(read("-1")), Rc::clone(&validator), positive()]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+            let valid = atom.invoke(vec![Rc::new(read("1")), validator, positive()]);
+            assert_eq!(Value::I32(1), DerefFn {}.invoke(vec![valid.to_rc_value()]));
+        }
+    }
+}
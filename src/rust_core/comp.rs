@@ -0,0 +1,129 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (comp & fs)
+///
+/// The fn calling the last of fs on its arguments, then each fn before it on what the one
+/// after gave back;  with no fs, identity.  Composing transducers this way transforms in
+/// the order they're given
+#[derive(Debug, Clone)]
+pub struct CompFn {}
+impl ToValue for CompFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CompFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match Composition::of(&args) {
+            Ok(composition) => (*composition).clone(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// The composition of fns, as comp makes
+#[derive(Debug, Clone)]
+pub struct Composition {
+    /// Called last first
+    fns: Vec<protocols::IFn>,
+}
+impl Composition {
+    /// The composition of fns;  fns itself if there's just the one
+    pub(crate) fn of(fns: &[Rc<Value>]) -> Result<Rc<Value>, Rc<Value>> {
+        if let [f] = fns {
+            if f.instanceof::<protocols::IFn>() {
+                return Ok(Rc::clone(f));
+            }
+        }
+        let mut composed = vec![];
+        for f in fns.iter() {
+            match f.try_as_protocol::<protocols::IFn>() {
+                Some(f) => composed.push(f),
+                None => return Err(Rc::new(error_message::type_mismatch(TypeTag::IFn, f))),
+            }
+        }
+        Ok(Composition { fns: composed }.to_rc_value())
+    }
+}
+impl ToValue for Composition {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for Composition {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let mut fns = self.fns.iter().rev();
+        let mut result = match fns.next() {
+            Some(f) => Rc::new(f.invoke(args)),
+            // identity
+            None => match args.as_slice() {
+                [x] => Rc::clone(x),
+                _ => return error_message::wrong_arg_count(1, args.len()),
+            },
+        };
+        for f in fns {
+            if result.non_tail().is_some() {
+                break;
+            }
+            result = Rc::new(f.invoke(vec![result]));
+        }
+        (*result).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod comp_tests {
+        use crate::ifn::IFn;
+        use crate::protocol::ProtocolCastable;
+        use crate::protocols;
+        use crate::rust_core::{AddFn, CompFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn comp(fns: Vec<Rc<Value>>) -> protocols::IFn {
+            CompFn {}
+                .invoke(fns)
+                .to_rc_value()
+                .as_protocol::<protocols::IFn>()
+        }
+
+        fn inc() -> Rc<Value> {
+            Rc::new(eval_str("(fn [x] (+ x 1))"))
+        }
+
+        #[test]
+        fn comp_fns() {
+            let triple = Rc::new(eval_str("(fn [x] (* x 3))"));
+            assert_eq!(
+                Value::I32(4),
+                comp(vec![inc(), triple]).invoke(read_args(&["1"]))
+            );
+            assert_eq!(
+                Value::I32(6),
+                comp(vec![inc(), AddFn {}.to_rc_value()]).invoke(read_args(&["2", "3"]))
+            );
+            assert_eq!(Value::I32(1), comp(vec![]).invoke(read_args(&["1"])));
+            assert_eq!(
+                Value::I32(2),
+                comp(read_args(&[":a"])).invoke(read_args(&["{:a 2}"]))
+            );
+        }
+
+        #[test]
+        fn comp_non_fns() {
+            let comp = CompFn {};
+            match comp.invoke(vec![inc(), Rc::new(Value::I32(1))]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
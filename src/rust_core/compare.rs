@@ -0,0 +1,176 @@
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// (compare x y)
+///
+/// A negative number, zero or a positive number as x is less than, equal to or greater
+/// than y.  nil comes before everything;  numbers, strings, keywords, symbols and booleans
+/// compare with their own kind, and vectors by length and then value by value
+#[derive(Debug, Clone)]
+pub struct CompareFn {}
+impl ToValue for CompareFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CompareFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (x, y) = match args.as_slice() {
+            [x, y] => (x, y),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        match compare(x, y) {
+            Some(ordering) => Value::I32(ordering as i32),
+            None => not_comparable(x, y),
+        }
+    }
+}
+
+/// How x orders against y, or None if the two can't be compared
+pub fn compare(x: &Value, y: &Value) -> Option<Ordering> {
+    match (x, y) {
+        (Value::Nil, Value::Nil) => Some(Ordering::Equal),
+        (Value::Nil, _) => Some(Ordering::Less),
+        (_, Value::Nil) => Some(Ordering::Greater),
+        (Value::I32(x), Value::I32(y)) => Some(x.cmp(y)),
+        (Value::I32(x), Value::F64(y)) => f64::from(*x).partial_cmp(y),
+        (Value::F64(x), Value::I32(y)) => x.partial_cmp(&f64::from(*y)),
+        (Value::F64(x), Value::F64(y)) => x.partial_cmp(y),
+        (Value::I64(x), Value::I64(y)) => Some(x.cmp(y)),
+        (Value::I32(x), Value::I64(y)) => Some(i64::from(*x).cmp(y)),
+        (Value::I64(x), Value::I32(y)) => Some(x.cmp(&i64::from(*y))),
+        (Value::I64(x), Value::F64(y)) => (*x as f64).partial_cmp(y),
+        (Value::F64(x), Value::I64(y)) => x.partial_cmp(&(*y as f64)),
+        (Value::String(x), Value::String(y)) => Some(x.cmp(y)),
+        (Value::Boolean(x), Value::Boolean(y)) => Some(x.cmp(y)),
+        (Value::Keyword(x), Value::Keyword(y)) => {
+            Some((x.namespace(), x.name()).cmp(&(y.namespace(), y.name())))
+        }
+        (Value::Symbol(x), Value::Symbol(y)) => {
+            Some((x.namespace(), x.name()).cmp(&(y.namespace(), y.name())))
+        }
+        (Value::PersistentVector(x), Value::PersistentVector(y)) => {
+            match x.len().cmp(&y.len()) {
+                Ordering::Equal => {}
+                ordering => return Some(ordering),
+            }
+            for (x, y) in x.to_vec().iter().zip(y.to_vec().iter()) {
+                match compare(x, y)? {
+                    Ordering::Equal => {}
+                    ordering => return Some(ordering),
+                }
+            }
+            Some(Ordering::Equal)
+        }
+        _ => None,
+    }
+}
+
+/// How x orders against y by comparator, a fn either giving back a number, negative, zero
+/// or positive, or a truthy value if x comes before y (as lt does).  Err if the comparator
+/// throws, or gives back anything else
+pub(crate) fn compare_by(
+    comparator: &dyn IFn,
+    x: &Rc<Value>,
+    y: &Rc<Value>,
+) -> Result<Ordering, Rc<Value>> {
+    let order = Rc::new(comparator.invoke(vec![Rc::clone(x), Rc::clone(y)]));
+    if let Some(interruption) = order.non_tail() {
+        return Err(interruption);
+    }
+    match &*order {
+        Value::I32(n) => Ok(n.cmp(&0)),
+        Value::I64(n) => Ok(n.cmp(&0)),
+        Value::F64(n) => Ok(n.partial_cmp(&0.0).unwrap_or(Ordering::Equal)),
+        Value::Boolean(true) => Ok(Ordering::Less),
+        Value::Boolean(false) | Value::Nil => {
+            let reversed = Rc::new(comparator.invoke(vec![Rc::clone(y), Rc::clone(x)]));
+            if let Some(interruption) = reversed.non_tail() {
+                return Err(interruption);
+            }
+            if reversed.is_truthy() {
+                Ok(Ordering::Greater)
+            } else {
+                Ok(Ordering::Equal)
+            }
+        }
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::I32, &order))),
+    }
+}
+
+/// How sort_values compares two values
+pub(crate) type Comparison<'a, T> = dyn FnMut(&T, &T) -> Result<Ordering, Rc<Value>> + 'a;
+
+/// Stably sorts values with compare, giving back the first Err it does.  Unlike the sorts
+/// of std, this won't panic on a compare that doesn't give a total order, which a
+/// comparator written in Clojure mightn't
+pub(crate) fn sort_values<T>(
+    values: Vec<T>,
+    compare: &mut Comparison<'_, T>,
+) -> Result<Vec<T>, Rc<Value>> {
+    if values.len() <= 1 {
+        return Ok(values);
+    }
+    let mut left = values;
+    let right = left.split_off(left.len() / 2);
+    let left = sort_values(left, compare)?;
+    let right = sort_values(right, compare)?;
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+        if compare(y, x)? == Ordering::Less {
+            sorted.push(right.next().unwrap());
+        } else {
+            sorted.push(left.next().unwrap());
+        }
+    }
+    sorted.extend(left);
+    sorted.extend(right);
+    Ok(sorted)
+}
+
+/// The condition for trying to compare x with y, when they can't be
+pub fn not_comparable(x: &Value, y: &Value) -> Value {
+    error_message::condition(
+        ConditionKind::ClassCastException,
+        format!("{} cannot be compared with {}", x.type_tag(), y.type_tag()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    mod compare_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::CompareFn;
+        use crate::test_util::read_args;
+        use crate::value::Value;
+
+        #[test]
+        fn compare_like_kinds() {
+            let compare = CompareFn {};
+            let compared = |x, y| compare.invoke(read_args(&[x, y]));
+            assert_eq!(Value::I32(-1), compared("1", "2"));
+            assert_eq!(Value::I32(1), compared("2.5", "2"));
+            assert_eq!(Value::I32(0), compared("\"a\"", "\"a\""));
+            assert_eq!(Value::I32(-1), compared(":a", ":b"));
+            assert_eq!(Value::I32(-1), compared("nil", "0"));
+            assert_eq!(Value::I32(1), compared("[1 2]", "[1 1]"));
+            assert_eq!(Value::I32(-1), compared("[9]", "[1 1]"));
+        }
+
+        #[test]
+        fn compare_unlike_kinds() {
+            let compare = CompareFn {};
+            match compare.invoke(read_args(&["1", ":a"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
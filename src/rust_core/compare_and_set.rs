@@ -0,0 +1,64 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (compare-and-set! atom old new)
+///
+/// Sets atom to new if it's currently old, giving back whether it was.  In Clojure, old
+/// must be the very value the atom holds;  we've no identity for most values a program can
+/// get at, so here it need only be equal to it
+#[derive(Debug, Clone)]
+pub struct CompareAndSetFn {}
+impl ToValue for CompareAndSetFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CompareAndSetFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (atom, old, new) = match args.as_slice() {
+            [atom, old, new] => (atom, old, new),
+            _ => return error_message::wrong_arg_count(3, args.len()),
+        };
+        let atom = match &**atom {
+            Value::Atom(atom) => atom,
+            _ => return error_message::type_mismatch(TypeTag::Atom, atom),
+        };
+        let current = atom.deref();
+        if current != *old {
+            return Value::Boolean(false);
+        }
+        match atom.compare_and_set(&current, Rc::clone(new)) {
+            Ok(set) => Value::Boolean(set),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod compare_and_set_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AtomFn, CompareAndSetFn, DerefFn};
+        use crate::test_util::read_args;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn compare_and_set_only_from_old() {
+            let a = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            let compare_and_set = |old, new| {
+                let mut args = read_args(&[old, new]);
+                args.insert(0, Rc::clone(&a));
+                CompareAndSetFn {}.invoke(args)
+            };
+            let deref = || DerefFn {}.invoke(vec![Rc::clone(&a)]);
+            assert_eq!(Value::Boolean(false), compare_and_set("2", "3"));
+            assert_eq!(Value::I32(1), deref());
+            assert_eq!(Value::Boolean(true), compare_and_set("1", "3"));
+            assert_eq!(Value::I32(3), deref());
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (completing f)
+/// (completing f cf)
+///
+/// f as a reducing fn, completed by cf, or by giving back the result as is
+#[derive(Debug, Clone)]
+pub struct CompletingFn {}
+impl ToValue for CompletingFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for CompletingFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() || args.len() > 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let mut fns = vec![];
+        for f in args.iter() {
+            match f.try_as_protocol::<protocols::IFn>() {
+                Some(f) => fns.push(f),
+                None => return error_message::type_mismatch(TypeTag::IFn, f),
+            }
+        }
+        let mut fns = fns.into_iter();
+        Completing {
+            f: fns.next().unwrap(),
+            cf: fns.next(),
+        }
+        .to_value()
+    }
+}
+
+/// A reducing fn made by completing
+#[derive(Debug, Clone)]
+pub struct Completing {
+    f: protocols::IFn,
+    cf: Option<protocols::IFn>,
+}
+impl ToValue for Completing {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for Completing {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match (args.as_slice(), &self.cf) {
+            ([result], Some(cf)) => cf.invoke(vec![Rc::clone(result)]),
+            ([result], None) => (**result).clone(),
+            _ => self.f.invoke(args),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod completing_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddFn, CompletingFn, StrFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn completing(fns: Vec<Rc<Value>>) -> Rc<dyn IFn> {
+            let completing = CompletingFn {};
+            match completing.invoke(fns) {
+                Value::IFn(rf) => rf,
+                value => panic!("expected a fn, got {}", value),
+            }
+        }
+
+        #[test]
+        fn completing_arities() {
+            let rf = completing(vec![AddFn {}.to_rc_value(), StrFn {}.to_rc_value()]);
+            assert_eq!(Value::I32(0), rf.invoke(vec![]));
+            assert_eq!(Value::I32(3), rf.invoke(read_args(&["1", "2"])));
+            assert_eq!(read("\"1\""), rf.invoke(read_args(&["1"])));
+            let rf = completing(vec![AddFn {}.to_rc_value()]);
+            assert_eq!(Value::I32(1), rf.invoke(read_args(&["1"])));
+        }
+    }
+}
@@ -1,12 +1,13 @@
+use crate::cons::Cons;
 use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::seq;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
-use crate::persistent_list::{PersistentList, ToPersistentListIter};
-use crate::persistent_vector::ToPersistentVectorIter;
-
-/// (concat x y & zs)
+/// (concat & colls)
 ///
+/// The lazy seq of the values of each of colls, one after another
 #[derive(Debug, Clone)]
 pub struct ConcatFn {}
 impl ToValue for ConcatFn {
@@ -16,20 +17,57 @@ impl ToValue for ConcatFn {
 }
 impl IFn for ConcatFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        let concatted_vec = args.iter().fold(Vec::new(), |mut sum, coll| {
-            let coll_vec = match &**coll {
-                Value::PersistentList(plist) => {
-                    Rc::new(plist.clone()).iter().collect::<Vec<Rc<Value>>>()
-                }
-                Value::PersistentVector(pvector) => {
-                    Rc::new(pvector.clone()).iter().collect::<Vec<Rc<Value>>>()
-                }
-                _ => vec![],
-            };
+        concat_seq(Rc::new(args), 0).to_value()
+    }
+}
+
+/// The lazy seq of the values of colls, starting from colls[coll_index]
+fn concat_seq(colls: Rc<Vec<Rc<Value>>>, coll_index: usize) -> LazySeq {
+    LazySeq::new(move || match colls.get(coll_index) {
+        Some(coll) => concat_from(seq::seq(coll), colls, coll_index),
+        None => Rc::new(Value::Nil),
+    })
+}
+/// The seq of the values of seq, and then of colls after colls[coll_index]
+fn concat_from(seq: Rc<Value>, colls: Rc<Vec<Rc<Value>>>, coll_index: usize) -> Rc<Value> {
+    match &*seq {
+        Value::Nil => concat_seq(colls, coll_index + 1).to_rc_value(),
+        Value::Condition(_) => seq,
+        _ => {
+            let more = seq::more(&seq);
+            let rest = LazySeq::new(move || concat_from(seq::seq(&more), colls, coll_index));
+            Cons::new(seq::first(&seq), rest.to_rc_value()).to_rc_value()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod concat_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{ConcatFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn concat_colls() {
+            let concat = ConcatFn {};
+            assert_eq!(
+                read("(1 2 3 4 5)"),
+                concat.invoke(read_args(&["(1)", "nil", "[]", "[2 3]", "#{4}", "(5)"]))
+            );
+            assert_eq!(read("()"), concat.invoke(vec![]));
+        }
 
-            sum.extend(coll_vec);
-            sum
-        });
-        Value::PersistentList(concatted_vec.into_iter().collect::<PersistentList>())
+        #[test]
+        fn concat_is_lazy() {
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let concat = ConcatFn {}.invoke(vec![Rc::new(read("[1 2]")), naturals]);
+            assert_eq!(
+                read("(1 2 0 1)"),
+                TakeFn {}.invoke(vec![4.to_rc_value(), concat.to_rc_value()])
+            );
+        }
     }
 }
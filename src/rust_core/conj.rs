@@ -0,0 +1,117 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::iterable::Iterable;
+use crate::persistent_list::{self, PersistentList};
+use crate::persistent_list_map::IPersistentMap;
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (conj coll x & xs)
+///
+/// Adds each x to coll where it is cheapest to;  the end of a vector, the front of a list
+/// or anywhere in a set
+#[derive(Debug, Clone)]
+pub struct ConjFn {}
+impl ToValue for ConjFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ConjFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let mut args = args.into_iter();
+        let mut coll = match args.next() {
+            Some(coll) => coll,
+            None => return Value::PersistentVector(PersistentVector::empty()),
+        };
+        for x in args {
+            coll = conj(&coll, x);
+            if let Value::Condition(_) = &*coll {
+                break;
+            }
+        }
+        coll.to_value()
+    }
+}
+
+/// coll with x added, or a condition if coll isn't a collection
+pub(crate) fn conj(coll: &Rc<Value>, x: Rc<Value>) -> Rc<Value> {
+    match &**coll {
+        Value::Nil => persistent_list::cons_rc(x, Rc::new(PersistentList::Empty)).to_rc_value(),
+        Value::PersistentList(plist) => {
+            persistent_list::cons_rc(x, Rc::new(plist.clone())).to_rc_value()
+        }
+        Value::PersistentVector(pvector) => pvector.conj(x).to_rc_value(),
+        Value::PersistentHashSet(pset) => pset.conj(x).to_rc_value(),
+        _ => match coll.try_as_protocol::<protocols::IPersistentMap>() {
+            Some(pmap) => conj_entries(pmap, &x),
+            None => Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll)),
+        },
+    }
+}
+
+/// pmap with the entries of x, either a [key val] vector or another map
+fn conj_entries(pmap: protocols::IPersistentMap, x: &Rc<Value>) -> Rc<Value> {
+    let entries = match &**x {
+        Value::PersistentVector(pvector) if pvector.len() == 2 => vec![Rc::clone(x)],
+        Value::PersistentVector(_) => {
+            return Rc::new(error_message::custom(
+                "Vector arg to map conj must be a pair",
+            ))
+        }
+        _ if x.try_as_protocol::<protocols::IPersistentMap>().is_some() => x
+            .as_protocol::<Iterable>()
+            .iter()
+            .collect::<Vec<Rc<Value>>>(),
+        _ => return Rc::new(error_message::type_mismatch(TypeTag::PersistentListMap, x)),
+    };
+    let mut pmap = pmap;
+    for entry in entries {
+        if let Value::PersistentVector(pvector) = &*entry {
+            if let (Some(key), Some(val)) = (pvector.nth(0), pvector.nth(1)) {
+                pmap = pmap.assoc(key, val);
+            }
+        }
+    }
+    pmap.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    mod conj_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::ConjFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn conj_onto_each_coll() {
+            let conj = ConjFn {};
+            assert_eq!(read("[1 2 3]"), conj.invoke(read_args(&["[1]", "2", "3"])));
+            assert_eq!(read("(3 2 1)"), conj.invoke(read_args(&["(1)", "2", "3"])));
+            assert_eq!(read("(1)"), conj.invoke(read_args(&["nil", "1"])));
+            assert_eq!(read("[]"), conj.invoke(vec![]));
+            assert_eq!(
+                read("#{1 2 3}"),
+                conj.invoke(read_args(&["#{1 2}", "3", "1"]))
+            );
+            assert_eq!(
+                read("{:a 1 :b 2 :c 3}"),
+                conj.invoke(read_args(&["{:a 1}", "[:b 2]", "{:c 3}"]))
+            );
+        }
+
+        #[test]
+        fn conj_onto_a_non_coll() {
+            let conj = ConjFn {};
+            match conj.invoke(read_args(&["1", "2"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::cons::Cons;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::{self, PersistentList};
+use crate::seq;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (cons x seq)
+///
+/// The seq of x followed by the values of seq, which is left unrealized if it is lazy
+#[derive(Debug, Clone)]
+pub struct ConsFn {}
+impl ToValue for ConsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ConsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let (x, coll) = (Rc::clone(&args[0]), &args[1]);
+        match &**coll {
+            Value::Nil => persistent_list::cons_rc(x, Rc::new(PersistentList::Empty)).to_value(),
+            Value::PersistentList(plist) => {
+                persistent_list::cons_rc(x, Rc::new(plist.clone())).to_value()
+            }
+            Value::LazySeq(_) | Value::Cons(_) => Cons::new(x, Rc::clone(coll)).to_value(),
+            _ => match &*seq::seq(coll) {
+                Value::Condition(condition) => Value::Condition(Rc::clone(condition)),
+                _ => Cons::new(x, seq::seq(coll)).to_value(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod cons_tests {
+        use crate::ifn::IFn;
+        use crate::lazy_seq::LazySeq;
+        use crate::rust_core::ConsFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn cons_onto_each_seqable() {
+            let cons = ConsFn {};
+            assert_eq!(read("(1)"), cons.invoke(read_args(&["1", "nil"])));
+            assert_eq!(read("(1 2 3)"), cons.invoke(read_args(&["1", "(2 3)"])));
+            assert_eq!(read("(1 2 3)"), cons.invoke(read_args(&["1", "[2 3]"])));
+            assert_eq!(read("(1 \"a\")"), cons.invoke(read_args(&["1", "\"a\""])));
+        }
+
+        #[test]
+        fn cons_onto_lazy_seq() {
+            let cons = ConsFn {};
+            let lazy = LazySeq::from_iter(vec![Rc::new(read("3"))].into_iter()).to_rc_value();
+            let rest = cons.invoke(vec![2.to_rc_value(), lazy]).to_rc_value();
+            assert_eq!(read("(1 2 3)"), cons.invoke(vec![1.to_rc_value(), rest]));
+        }
+    }
+}
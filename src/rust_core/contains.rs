@@ -0,0 +1,68 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list_map::IPersistentMap;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (contains? coll key)
+///
+/// Whether key is a key of coll;  for a set, one of its values, and for a vector, one of
+/// its indexes
+#[derive(Debug, Clone)]
+pub struct ContainsFn {}
+impl ToValue for ContainsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ContainsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let (coll, key) = (&args[0], &args[1]);
+        let contains = match &**coll {
+            Value::Nil => false,
+            Value::PersistentHashSet(pset) => pset.contains(key),
+            Value::PersistentVector(pvector) => match **key {
+                Value::I32(index) => index >= 0 && (index as usize) < pvector.len(),
+                _ => false,
+            },
+            _ => match coll.try_as_protocol::<protocols::IPersistentMap>() {
+                Some(pmap) => pmap.contains_key(key),
+                None => {
+                    return error_message::custom(format!(
+                        "contains? not supported on type: {}",
+                        coll.type_tag()
+                    ))
+                }
+            },
+        };
+        Value::Boolean(contains)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod contains_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::ContainsFn;
+        use crate::test_util::read_args;
+        use crate::value::Value;
+
+        #[test]
+        fn contains_in_each_coll() {
+            let contains = ContainsFn {};
+            let contains_in = |coll, key| contains.invoke(read_args(&[coll, key]));
+            assert_eq!(Value::Boolean(true), contains_in("#{1 2}", "2"));
+            assert_eq!(Value::Boolean(false), contains_in("#{1 2}", "3"));
+            assert_eq!(Value::Boolean(true), contains_in("{:a nil}", ":a"));
+            assert_eq!(Value::Boolean(false), contains_in("{:a 1}", ":b"));
+            assert_eq!(Value::Boolean(true), contains_in("[:a :b]", "1"));
+            assert_eq!(Value::Boolean(false), contains_in("[:a :b]", "2"));
+            assert_eq!(Value::Boolean(false), contains_in("nil", "1"));
+        }
+    }
+}
@@ -5,6 +5,7 @@ use std::rc::Rc;
 use crate::error_message;
 use crate::iterable::Iterable;
 use crate::protocol::ProtocolCastable;
+use crate::seq;
 use crate::type_tag::TypeTag;
 
 /// (count coll)
@@ -22,6 +23,15 @@ impl IFn for CountFn {
             return error_message::wrong_arg_count(1, args.len());
         }
 
+        // A seq is walked a value at a time, so that one throwing as it's worked out throws
+        if seq::is_seq(&args[0]) {
+            let count =
+                seq::try_iter(&args[0]).try_fold(0, |count, value| value.map(|_| count + 1));
+            return match count {
+                Ok(count) => Value::I32(count),
+                Err(condition) => (*condition).clone(),
+            };
+        }
         let coll_size = match args.get(0).unwrap().try_as_protocol::<Iterable>() {
             Some(iterable) => iterable.iter().count(),
             None => match args.get(0).unwrap().to_value() {
@@ -37,9 +47,12 @@ impl IFn for CountFn {
 #[cfg(test)]
 mod tests {
     mod count_tests {
+        use crate::condition::ConditionKind;
+        use crate::error_message;
         use crate::ifn::IFn;
         use crate::persistent_vector::PersistentVector;
         use crate::rust_core::CountFn;
+        use crate::test_util::{is_boom, seq_throwing_at_2};
         use crate::value::Value;
         use std::rc::Rc;
 
@@ -69,12 +82,18 @@ mod tests {
             let count = CountFn {};
             let args = vec![Rc::new(Value::Boolean(true))];
             assert_eq!(
-                Value::Condition(
+                error_message::condition(
+                    ConditionKind::ClassCastException,
                     "Type mismatch; Expected instance of clojure.lang.ISeq,  Recieved type rust.std.bool"
-                        .to_string()
                 ),
                 count.invoke(args)
             );
         }
+
+        #[test]
+        fn count_throws_what_working_out_a_seq_does() {
+            let count = CountFn {};
+            assert!(is_boom(&count.invoke(vec![seq_throwing_at_2()])));
+        }
     }
 }
@@ -0,0 +1,93 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{self, Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (dedupe)
+/// (dedupe coll)
+///
+/// The lazy seq of the values of coll, leaving off any equal to the one before
+#[derive(Debug, Clone)]
+pub struct DedupeFn {}
+impl ToValue for DedupeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DedupeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let xform = Transducer::new(DedupeXForm {}).to_rc_value();
+        match args.as_slice() {
+            [] => (*xform).clone(),
+            [coll] if seq::is_seqable(coll) => {
+                match transducer::iter(&xform.as_protocol::<protocols::IFn>(), coll) {
+                    Ok(values) => LazySeq::from_iter(values).to_value(),
+                    Err(condition) => (*condition).clone(),
+                }
+            }
+            [coll] => error_message::type_mismatch(TypeTag::ISeq, coll),
+            _ => error_message::wrong_varg_count(&[0, 1], args.len()),
+        }
+    }
+}
+
+/// (dedupe), the transducer stepping in only inputs unequal to the one before;  its state
+/// is the one before
+#[derive(Debug, Clone)]
+pub struct DedupeXForm {}
+impl XForm for DedupeXForm {
+    type State = Option<Rc<Value>>;
+    fn step(
+        &self,
+        state: &RefCell<Option<Rc<Value>>>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let previous = state.replace(Some(Rc::clone(&input)));
+        if previous.as_ref() == Some(&input) {
+            return result;
+        }
+        Rc::new(rf.invoke(vec![result, input]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod dedupe_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{DedupeFn, IntoFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn dedupe_colls() {
+            let dedupe = DedupeFn {};
+            assert_eq!(
+                read("(1 2 1 3)"),
+                dedupe.invoke(read_args(&["[1 1 2 2 1 3 3]"]))
+            );
+            assert_eq!(read("()"), dedupe.invoke(read_args(&["nil"])));
+        }
+
+        #[test]
+        fn dedupe_transducer() {
+            assert_eq!(
+                read("[1 2 1]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    DedupeFn {}.invoke(vec![]).to_rc_value(),
+                    Rc::new(read("[1 1 2 1 1]"))
+                ])
+            );
+        }
+    }
+}
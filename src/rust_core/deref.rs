@@ -0,0 +1,60 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (deref ref)
+///
+/// What ref holds, for an atom, var or reduced value.  @ref reads as this
+#[derive(Debug, Clone)]
+pub struct DerefFn {}
+impl ToValue for DerefFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DerefFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Atom(atom) => (*atom.deref()).clone(),
+            Value::Var(var) => (*var.deref()).clone(),
+            Value::Reduced(value) => (**value).clone(),
+            _ => error_message::type_mismatch(TypeTag::Atom, &args[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod deref_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AtomFn, DerefFn, ReducedFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn deref_refs() {
+            let deref = DerefFn {};
+            let atom = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(Value::I32(1), deref.invoke(vec![atom]));
+            let reduced = ReducedFn {}.invoke(read_args(&["2"])).to_rc_value();
+            assert_eq!(Value::I32(2), deref.invoke(vec![reduced]));
+            let var = Rc::new(eval_str("(do (def x 3) (var x))"));
+            assert_eq!(Value::I32(3), deref.invoke(vec![var]));
+        }
+
+        #[test]
+        fn deref_non_ref() {
+            let deref = DerefFn {};
+            match deref.invoke(read_args(&["1"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (disj set & ks)
+///
+/// set without each of ks
+#[derive(Debug, Clone)]
+pub struct DisjFn {}
+impl ToValue for DisjFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DisjFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (set, ks) = match args.split_first() {
+            Some(split) => split,
+            None => return error_message::wrong_varg_count(&[1, 2], 0),
+        };
+        match &**set {
+            Value::Nil => Value::Nil,
+            Value::PersistentHashSet(pset) => {
+                Value::PersistentHashSet(ks.iter().fold(pset.clone(), |pset, k| pset.disj(k)))
+            }
+            _ => error_message::type_mismatch(TypeTag::PersistentHashSet, set),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod disj_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::DisjFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn disj_from_set() {
+            let disj = DisjFn {};
+            assert_eq!(
+                read("#{2}"),
+                disj.invoke(read_args(&["#{1 2 3}", "1", "3", "4"]))
+            );
+            assert_eq!(read("#{1}"), disj.invoke(read_args(&["#{1}"])));
+            assert_eq!(Value::Nil, disj.invoke(read_args(&["nil", "1"])));
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::persistent_hash_set::PersistentHashSet;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (distinct coll)
+///
+/// The lazy seq of the values of coll, leaving off any seen before
+#[derive(Debug, Clone)]
+pub struct DistinctFn {}
+impl ToValue for DistinctFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DistinctFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return Transducer::new(DistinctXForm {}).to_value();
+        }
+        if args.len() != 1 {
+            return error_message::wrong_varg_count(&[0, 1], args.len());
+        }
+        let coll = &args[0];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        let mut seen = PersistentHashSet::default();
+        let values = seq::iter(coll).filter(move |value| {
+            if seen.contains(value) {
+                return false;
+            }
+            seen = seen.conj(Rc::clone(value));
+            true
+        });
+        LazySeq::from_iter(values).to_value()
+    }
+}
+
+/// (distinct), the transducer stepping in only inputs it hasn't seen before;  its state is
+/// those it has
+#[derive(Debug, Clone)]
+pub struct DistinctXForm {}
+impl XForm for DistinctXForm {
+    type State = PersistentHashSet;
+    fn step(
+        &self,
+        state: &RefCell<PersistentHashSet>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        if state.borrow().contains(&input) {
+            return result;
+        }
+        let seen = state.borrow().conj(Rc::clone(&input));
+        state.replace(seen);
+        Rc::new(rf.invoke(vec![result, input]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod distinct_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{DistinctFn, IntoFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn distinct_colls() {
+            let distinct = DistinctFn {};
+            assert_eq!(
+                read("(1 2 3 [1])"),
+                distinct.invoke(read_args(&["[1 2 1 3 2 [1] [1]]"]))
+            );
+            assert_eq!(read("()"), distinct.invoke(read_args(&["nil"])));
+            let naturals = distinct.invoke(vec![RangeFn {}.invoke(vec![]).to_rc_value()]);
+            assert_eq!(
+                read("(0 1 2)"),
+                TakeFn {}.invoke(vec![3.to_rc_value(), naturals.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn distinct_transducer() {
+            assert_eq!(
+                read("[1 2 3]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    DistinctFn {}.invoke(vec![]).to_rc_value(),
+                    Rc::new(read("[1 2 1 3 2]"))
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (drop n coll)
+///
+/// The lazy seq of the values of coll after the first n
+#[derive(Debug, Clone)]
+pub struct DropFn {}
+impl ToValue for DropFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DropFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let n = match &*args[0] {
+            Value::I32(n) => (*n).max(0),
+            _ => return error_message::type_mismatch(TypeTag::Integer, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(DropXForm { n: n as usize }).to_value();
+        }
+        let coll = Rc::clone(&args[1]);
+        if !seq::is_seqable(&coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, &coll);
+        }
+        LazySeq::new(move || {
+            let mut rest = coll;
+            for _ in 0..n {
+                rest = seq::more(&rest);
+                if let Value::Condition(_) = &*rest {
+                    return rest;
+                }
+            }
+            seq::seq(&rest)
+        })
+        .to_value()
+    }
+}
+
+/// (drop n), the transducer stepping in the inputs after the first n;  its state is how
+/// many it's dropped
+#[derive(Debug, Clone)]
+pub struct DropXForm {
+    n: usize,
+}
+impl XForm for DropXForm {
+    type State = usize;
+    fn step(
+        &self,
+        state: &RefCell<usize>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let dropped = *state.borrow();
+        if dropped < self.n {
+            *state.borrow_mut() = dropped + 1;
+            return result;
+        }
+        Rc::new(rf.invoke(vec![result, input]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod drop_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{DropFn, IntoFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn drop_colls() {
+            let drop = DropFn {};
+            assert_eq!(read("(3)"), drop.invoke(read_args(&["2", "[1 2 3]"])));
+            assert_eq!(read("()"), drop.invoke(read_args(&["5", "(1 2 3)"])));
+            assert_eq!(read("(1 2 3)"), drop.invoke(read_args(&["-1", "[1 2 3]"])));
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let dropped = drop.invoke(vec![5.to_rc_value(), naturals]);
+            assert_eq!(
+                read("(5 6)"),
+                TakeFn {}.invoke(vec![2.to_rc_value(), dropped.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn drop_transducer() {
+            assert_eq!(
+                read("[3]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    DropFn {}.invoke(read_args(&["2"])).to_rc_value(),
+                    Rc::new(read("[1 2 3]"))
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::cons::Cons;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (drop-while pred coll)
+///
+/// The lazy seq of the values of coll from the first that pred is falsey for
+#[derive(Debug, Clone)]
+pub struct DropWhileFn {}
+impl ToValue for DropWhileFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for DropWhileFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let pred = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(pred) => pred,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(DropWhileXForm { pred }).to_value();
+        }
+        let coll = Rc::clone(&args[1]);
+        if !seq::is_seqable(&coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, &coll);
+        }
+        LazySeq::new(move || {
+            let mut values = seq::iter(&coll);
+            loop {
+                let value = match values.next() {
+                    Some(value) => value,
+                    None => return Rc::new(Value::Nil),
+                };
+                if value.non_tail().is_some() {
+                    return value;
+                }
+                let test = Rc::new(pred.invoke(vec![Rc::clone(&value)]));
+                if let Some(interruption) = test.non_tail() {
+                    return interruption;
+                }
+                if !test.is_truthy() {
+                    let rest = LazySeq::from_iter(values).to_rc_value();
+                    return Cons::new(value, rest).to_rc_value();
+                }
+            }
+        })
+        .to_value()
+    }
+}
+
+/// (drop-while pred), the transducer stepping in inputs from the first that pred is
+/// falsey for;  its state is whether that's been found
+#[derive(Debug, Clone)]
+pub struct DropWhileXForm {
+    pred: protocols::IFn,
+}
+impl XForm for DropWhileXForm {
+    type State = bool;
+    fn step(
+        &self,
+        state: &RefCell<bool>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        if !*state.borrow() {
+            let test = Rc::new(self.pred.invoke(vec![Rc::clone(&input)]));
+            if let Some(interruption) = test.non_tail() {
+                return interruption;
+            }
+            if test.is_truthy() {
+                return result;
+            }
+            *state.borrow_mut() = true;
+        }
+        Rc::new(rf.invoke(vec![result, input]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod drop_while_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{DropWhileFn, IntoFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn less_than(n: i32) -> Rc<Value> {
+            Rc::new(eval_str(&format!("(fn [x] (lt x {}))", n)))
+        }
+
+        #[test]
+        fn drop_while_colls() {
+            let drop_while = DropWhileFn {};
+            assert_eq!(
+                read("(3 1)"),
+                drop_while.invoke(vec![less_than(3), Rc::new(read("[1 2 3 1]"))])
+            );
+            assert_eq!(
+                read("()"),
+                drop_while.invoke(read_args(&[":a", "[{:a 1}]"]))
+            );
+            let dropped =
+                drop_while.invoke(vec![less_than(4), RangeFn {}.invoke(vec![]).to_rc_value()]);
+            assert_eq!(
+                read("(4 5)"),
+                TakeFn {}.invoke(vec![2.to_rc_value(), dropped.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn drop_while_transducer() {
+            assert_eq!(
+                read("[3 1]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    DropWhileFn {}.invoke(vec![less_than(3)]).to_rc_value(),
+                    Rc::new(read("[1 2 3 1]"))
+                ])
+            );
+        }
+    }
+}
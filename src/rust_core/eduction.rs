@@ -0,0 +1,71 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::rust_core::comp::Composition;
+use crate::rust_core::sequence::transduced_seq;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (eduction xform* coll)
+///
+/// The values of coll as transformed by each xform in turn.  Unlike Clojure's, this is a
+/// lazy seq, so it's only transformed once, however many times it's walked
+#[derive(Debug, Clone)]
+pub struct EductionFn {}
+impl ToValue for EductionFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for EductionFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (coll, xforms) = match args.split_last() {
+            Some(split) => split,
+            None => return error_message::wrong_variadic_arg_count(&[], 1, 0),
+        };
+        let xform = match Composition::of(xforms) {
+            Ok(xform) => xform,
+            Err(condition) => return (*condition).clone(),
+        };
+        (*transduced_seq(&xform, coll)).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod eduction_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddFn, EductionFn, MapFn, ReduceFn, TakeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn map_inc() -> Rc<Value> {
+            MapFn {}
+                .invoke(vec![Rc::new(eval_str("(fn [x] (+ x 1))"))])
+                .to_rc_value()
+        }
+
+        #[test]
+        fn eduction_of_xforms() {
+            let eduction = EductionFn {};
+            assert_eq!(
+                read("(2 3)"),
+                eduction.invoke(vec![
+                    map_inc(),
+                    TakeFn {}.invoke(read_args(&["2"])).to_rc_value(),
+                    Rc::new(read("[1 2 3]"))
+                ])
+            );
+            assert_eq!(read("(1 2)"), eduction.invoke(read_args(&["[1 2]"])));
+        }
+
+        #[test]
+        fn eduction_is_reducible() {
+            let incremented = EductionFn {}.invoke(vec![map_inc(), Rc::new(read("[1 2]"))]);
+            assert_eq!(
+                Value::I32(5),
+                ReduceFn {}.invoke(vec![AddFn {}.to_rc_value(), incremented.to_rc_value()])
+            );
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use crate::error_message;
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
+use crate::seq;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
@@ -17,7 +20,7 @@ impl IFn for EqualsFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
         if args.is_empty() {
             //@TODO use proper error function
-            return Value::Condition(format!(
+            return error_message::condition(ConditionKind::ArityException, format!(
                 "Wrong number of arguments given to function (Given: {}, Expected: > 0)",
                 args.len()
             ));
@@ -26,7 +29,16 @@ impl IFn for EqualsFn {
         for pair in args.windows(2) {
             let a = &pair[0];
             let b = &pair[1];
-            if a != b {
+            // Walked here rather than by ==, so that a seq throwing as it's worked out throws
+            let equal = if seq::is_sequential(a) && seq::is_sequential(b) {
+                match seq::equals(a, b) {
+                    Ok(equal) => equal,
+                    Err(condition) => return (*condition).clone(),
+                }
+            } else {
+                a == b
+            };
+            if !equal {
                 return Value::Boolean(false);
             }
         }
@@ -38,8 +50,10 @@ impl IFn for EqualsFn {
 mod tests {
     use crate::ifn::IFn;
     use crate::keyword::Keyword;
-    use crate::rust_core::EqualsFn;
+    use crate::rust_core::{EqualsFn, MapFn};
+    use crate::test_util::{eval_str, is_boom, read, read_args, seq_throwing_at_2};
     use crate::value::{ToValue, Value};
+    use std::rc::Rc;
 
     // Just checks that different Values do not count as equal, and that
     // at least one Value of the same kind does, and that one Value of the same
@@ -70,4 +84,31 @@ mod tests {
         assert!(!equals.invoke(vec![keyword2, keyword3]).is_truthy());
         assert!(!equals.invoke(vec![keyword, _i32]).is_truthy());
     }
+
+    #[test]
+    fn seqs_and_vectors_of_the_same_values_are_equal() {
+        let equals = EqualsFn {};
+        let inc = Rc::new(eval_str("(fn [x] (+ x 1))"));
+        let seq = Rc::new(MapFn {}.invoke(vec![inc, Rc::new(read("[0 1]"))]));
+        assert_eq!(
+            Value::Boolean(true),
+            equals.invoke(vec![Rc::clone(&seq), Rc::new(read("[1 2]"))])
+        );
+        assert_eq!(
+            Value::Boolean(false),
+            equals.invoke(vec![seq, Rc::new(read("[1]"))])
+        );
+        assert_eq!(
+            Value::Boolean(true),
+            equals.invoke(read_args(&["(1 2)", "[1 2]"]))
+        );
+    }
+
+    #[test]
+    fn equals_throws_what_working_out_a_seq_does() {
+        let equals = EqualsFn {};
+        assert!(is_boom(
+            &equals.invoke(vec![seq_throwing_at_2(), Rc::new(read("[1]"))])
+        ));
+    }
 }
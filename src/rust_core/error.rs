@@ -0,0 +1,30 @@
+use crate::condition_system;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (error e)
+/// signals condition e like (signal e), but if no handler invokes a restart, throws e
+#[derive(Debug, Clone)]
+pub struct ErrorFn {}
+impl ToValue for ErrorFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ErrorFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => match condition_system::signal(condition) {
+                Some(interruption) => interruption.to_value(),
+                None => Value::Condition(Rc::clone(condition)),
+            },
+            _ => error_message::type_mismatch(TypeTag::Condition, &args[0]),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (every? pred coll)
+///
+/// Whether pred is truthy for every value of coll;  true for an empty coll
+#[derive(Debug, Clone)]
+pub struct EveryFn {}
+impl ToValue for EveryFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for EveryFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let pred = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(pred) => pred,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        for value in seq::iter(coll) {
+            if value.non_tail().is_some() {
+                return (*value).clone();
+            }
+            let test = Rc::new(pred.invoke(vec![value]));
+            if let Some(interruption) = test.non_tail() {
+                return (*interruption).clone();
+            }
+            if !test.is_truthy() {
+                return Value::Boolean(false);
+            }
+        }
+        Value::Boolean(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod every_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{EveryFn, RangeFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn every_colls() {
+            let every = EveryFn {};
+            assert_eq!(
+                Value::Boolean(true),
+                every.invoke(read_args(&[":a", "[{:a 1} {:a 2}]"]))
+            );
+            assert_eq!(
+                Value::Boolean(false),
+                every.invoke(read_args(&[":a", "[{:a 1} {:b 2}]"]))
+            );
+            assert_eq!(Value::Boolean(true), every.invoke(read_args(&[":a", "[]"])));
+        }
+
+        #[test]
+        fn every_stops_at_the_first_falsey() {
+            assert_eq!(
+                Value::Boolean(false),
+                EveryFn {}.invoke(vec![
+                    Rc::new(eval_str("(fn [x] (lt x 10))")),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+    }
+}
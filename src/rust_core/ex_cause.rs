@@ -0,0 +1,28 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (ex-cause e)
+/// the condition that caused condition e, or nil
+#[derive(Debug, Clone)]
+pub struct ExCauseFn {}
+impl ToValue for ExCauseFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ExCauseFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => match &condition.cause {
+                Some(cause) => Value::Throwable(Rc::clone(cause)),
+                None => Value::Nil,
+            },
+            _ => Value::Nil,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (ex-data e)
+/// the map given to the ex-info that made e, or nil
+#[derive(Debug, Clone)]
+pub struct ExDataFn {}
+impl ToValue for ExDataFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ExDataFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => condition.data.to_value(),
+            _ => Value::Nil,
+        }
+    }
+}
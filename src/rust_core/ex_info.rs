@@ -0,0 +1,86 @@
+use crate::condition::{Condition, ConditionKind};
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (ex-info msg map) (ex-info msg map cause)
+/// a condition, ready to be thrown, carrying msg, the data map, and the condition that
+/// caused it, if any
+#[derive(Debug, Clone)]
+pub struct ExInfoFn {}
+impl ToValue for ExInfoFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ExInfoFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 && args.len() != 3 {
+            return error_message::wrong_varg_count(&[2, 3], args.len());
+        }
+        let message = match &*args[0] {
+            Value::String(message) => message.clone(),
+            _ => return error_message::type_mismatch(TypeTag::String, &args[0]),
+        };
+        match &*args[1] {
+            Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {}
+            _ => return error_message::type_mismatch(TypeTag::PersistentListMap, &args[1]),
+        }
+        let cause = match args.get(2).map(|cause| &**cause) {
+            None | Some(Value::Nil) => None,
+            Some(Value::Throwable(cause)) => Some(Rc::clone(cause)),
+            Some(_) => return error_message::type_mismatch(TypeTag::Condition, &args[2]),
+        };
+        Value::Throwable(Rc::new(
+            Condition::new(ConditionKind::ExceptionInfo, message)
+                .with_data(Rc::clone(&args[1]))
+                .with_cause(cause),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod ex_info_tests {
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::persistent_list_map::IPersistentMap;
+        use crate::rust_core::ExInfoFn;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn makes_throwable() {
+            let data = persistent_list_map!(map_entry!("k", "v")).to_rc_value();
+            let ex_info = ExInfoFn {}.invoke(vec![
+                Rc::new(Value::String(String::from("boom"))),
+                Rc::clone(&data),
+            ]);
+            match ex_info {
+                Value::Throwable(condition) => {
+                    assert_eq!(ConditionKind::ExceptionInfo, condition.kind);
+                    assert_eq!("boom", condition.message);
+                    assert_eq!(data, condition.data);
+                    assert_eq!(None, condition.cause);
+                }
+                value => panic!("expected a throwable, got {}", value),
+            }
+        }
+
+        #[test]
+        fn requires_map() {
+            let ex_info = ExInfoFn {}.invoke(vec![
+                Rc::new(Value::String(String::from("boom"))),
+                Rc::new(Value::Nil),
+            ]);
+            match ex_info {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::ClassCastException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
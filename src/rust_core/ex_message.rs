@@ -0,0 +1,25 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (ex-message e)
+/// the message of condition e, or nil if e isn't one
+#[derive(Debug, Clone)]
+pub struct ExMessageFn {}
+impl ToValue for ExMessageFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ExMessageFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => Value::String(condition.message.clone()),
+            _ => Value::Nil,
+        }
+    }
+}
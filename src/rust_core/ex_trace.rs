@@ -0,0 +1,93 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::persistent_vector::PersistentVector;
+use crate::symbol::Symbol;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (ex-trace e)
+/// the fns that were being run when e was made, innermost first, as maps like
+/// {:fn f :file "script.clj" :line 6 :column 1};  the position is where each was called
+/// from, and is left out for fns not called from source
+#[derive(Debug, Clone)]
+pub struct ExTraceFn {}
+impl ToValue for ExTraceFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ExTraceFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        let condition = match &*args[0] {
+            Value::Throwable(condition) => condition,
+            _ => return Value::Nil,
+        };
+        condition
+            .trace
+            .iter()
+            .map(|frame| {
+                let mut frame_map = PersistentListMap::Empty.assoc(
+                    Keyword::intern("fn").to_rc_value(),
+                    Symbol::intern(&frame.name).to_rc_value(),
+                );
+                if let Some(position) = &frame.position {
+                    frame_map = position.add_to_meta(frame_map);
+                }
+                frame_map.to_rc_value()
+            })
+            .collect::<PersistentVector>()
+            .to_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod ex_trace_tests {
+        use crate::environment::Environment;
+        use crate::reader::Reader;
+        use crate::value::{Evaluable, Value};
+
+        fn eval_all(source: &str) -> Value {
+            let environment = Environment::clojure_core_environment();
+            let mut reader = Reader::from_file(source.as_bytes(), "script.clj");
+            let mut result = Value::Nil;
+            while let Some(form) = reader.read() {
+                result = form.eval(environment.clone());
+            }
+            result
+        }
+
+        #[test]
+        fn trace_of_nested_calls() {
+            assert_eq!(
+                eval_all(
+                    "[[\"inner\" \"script.clj\" 2 17]
+                      [\"outer\" \"script.clj\" 3 16]
+                      [\"trace\" \"script.clj\" 5 1]]"
+                ),
+                eval_all(
+                    "(defn inner [x] (throw (ex-info \"boom\" {})))\n\
+                     (defn outer [x] (inner x))\n\
+                     (defn trace [] (outer 1))\n\
+                     (let [trace (try\n\
+                       (trace)\n\
+                       (catch :default e (ex-trace e)))\n\
+                           frame (fn [n]\n\
+                                   (let [f (nth trace n)]\n\
+                                     [(str (get f :fn)) (get f :file) (get f :line) (get f :column)]))]\n\
+                       [(frame 0) (frame 1) (frame 2)])"
+                )
+            );
+        }
+
+        #[test]
+        fn trace_of_non_throwable() {
+            assert_eq!(Value::Nil, eval_all("(ex-trace 1)"));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::clojure_java_io::file;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::stream;
+use crate::value::{ToValue, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+/// (file-seq dir)
+///
+/// The file dir, and if it's a directory, everything under it;  a lazy seq, each directory
+/// followed by what's in it (in order of name), and each directory only read once the seq
+/// gets to it
+#[derive(Debug, Clone)]
+pub struct FileSeqFn {}
+impl ToValue for FileSeqFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for FileSeqFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        let dir = match file::path(&args[0]) {
+            Ok(dir) => dir.to_path_buf(),
+            Err(condition) => return (*condition).clone(),
+        };
+        // The files still to come, the next last
+        let mut files = vec![dir];
+        LazySeq::from_iter(std::iter::from_fn(move || {
+            let file = files.pop()?;
+            if file.is_dir() {
+                match children(&file) {
+                    Ok(children) => files.extend(children.into_iter().rev()),
+                    Err(condition) => return Some(condition),
+                }
+            }
+            Some(Rc::new(Value::String(file.to_string_lossy().into_owned())))
+        }))
+        .to_value()
+    }
+}
+
+/// What's in the directory dir, in order of name
+fn children(dir: &Path) -> Result<Vec<PathBuf>, Rc<Value>> {
+    let mut children = fs::read_dir(dir)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<PathBuf>, _>>()
+        })
+        .map_err(|error| stream::file_error(dir, error))?;
+    children.sort();
+    Ok(children)
+}
+
+#[cfg(test)]
+mod tests {
+    mod file_seq_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{FileSeqFn, IntoFn};
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn files_under_dir() {
+            let dir = std::env::temp_dir().join(format!("file-seq-test-{}", std::process::id()));
+            std::fs::create_dir_all(dir.join("b")).unwrap();
+            std::fs::write(dir.join("b").join("c"), "").unwrap();
+            std::fs::write(dir.join("a"), "").unwrap();
+            let file = |path: &std::path::Path| Value::String(path.to_string_lossy().into_owned());
+            let files = FileSeqFn {}.invoke(vec![Rc::new(file(&dir))]);
+            let files = IntoFn {}.invoke(vec![Rc::new(read("[]")), files.to_rc_value()]);
+            std::fs::remove_dir_all(&dir).unwrap();
+            assert_eq!(
+                Value::PersistentVector(
+                    vec![
+                        file(&dir),
+                        file(&dir.join("a")),
+                        file(&dir.join("b")),
+                        file(&dir.join("b").join("c")),
+                    ]
+                    .into_iter()
+                    .map(Rc::new)
+                    .collect()
+                ),
+                files
+            );
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (filter pred coll)
+///
+/// The lazy seq of the values of coll that pred is truthy for
+#[derive(Debug, Clone)]
+pub struct FilterFn {}
+impl ToValue for FilterFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for FilterFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.len() {
+            1 => return filter_transducer(&args[0], true),
+            2 => {}
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        }
+        match filter_seq(&args[0], &args[1], true) {
+            Ok(lazy_seq) => lazy_seq.to_value(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// The lazy seq of the values of coll that pred is truthy for, or, if keep is false, falsey
+/// for;  Err if pred isn't a fn or coll can't be seq'd
+pub(crate) fn filter_seq(
+    pred: &Rc<Value>,
+    coll: &Rc<Value>,
+    keep: bool,
+) -> Result<LazySeq, Rc<Value>> {
+    let pred = match pred.try_as_protocol::<protocols::IFn>() {
+        Some(pred) => pred,
+        None => return Err(Rc::new(error_message::type_mismatch(TypeTag::IFn, pred))),
+    };
+    if !seq::is_seqable(coll) {
+        return Err(Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll)));
+    }
+    let values = seq::iter(coll).filter_map(move |value| {
+        if value.non_tail().is_some() {
+            return Some(value);
+        }
+        let test = Rc::new(pred.invoke(vec![Rc::clone(&value)]));
+        match test.non_tail() {
+            Some(interruption) => Some(interruption),
+            None if test.is_truthy() == keep => Some(value),
+            None => None,
+        }
+    });
+    Ok(LazySeq::from_iter(values))
+}
+
+/// The transducer stepping in only the inputs pred is truthy for, or, if keep is false,
+/// falsey for
+pub(crate) fn filter_transducer(pred: &Rc<Value>, keep: bool) -> Value {
+    match pred.try_as_protocol::<protocols::IFn>() {
+        Some(pred) => Transducer::new(FilterXForm { pred, keep }).to_value(),
+        None => error_message::type_mismatch(TypeTag::IFn, pred),
+    }
+}
+#[derive(Debug, Clone)]
+pub struct FilterXForm {
+    pred: protocols::IFn,
+    keep: bool,
+}
+impl XForm for FilterXForm {
+    type State = ();
+    fn step(
+        &self,
+        _state: &RefCell<()>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let test = Rc::new(self.pred.invoke(vec![Rc::clone(&input)]));
+        if let Some(interruption) = test.non_tail() {
+            return interruption;
+        }
+        if test.is_truthy() == self.keep {
+            Rc::new(rf.invoke(vec![result, input]))
+        } else {
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod filter_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{FilterFn, FirstFn, IntoFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn multiple_of(n: i32) -> Rc<Value> {
+            Rc::new(eval_str(&format!("(fn [x] (= 0 (rem x {})))", n)))
+        }
+
+        #[test]
+        fn filter_colls() {
+            let filter = FilterFn {};
+            assert_eq!(
+                read("(2 4)"),
+                filter.invoke(vec![multiple_of(2), Rc::new(read("[1 2 3 4 5]"))])
+            );
+            assert_eq!(
+                read("()"),
+                filter.invoke(vec![multiple_of(2), Rc::new(Value::Nil)])
+            );
+            assert_eq!(
+                read("({:a 1})"),
+                filter.invoke(read_args(&[":a", "[{:a 1} {:b 2}]"]))
+            );
+            assert_eq!(
+                read("(1 1)"),
+                filter.invoke(read_args(&["#{1}", "[1 2 1 3]"]))
+            );
+        }
+
+        #[test]
+        fn filter_is_lazy() {
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let filtered = FilterFn {}.invoke(vec![multiple_of(3), naturals]);
+            assert_eq!(
+                read("(0 3 6)"),
+                TakeFn {}.invoke(vec![3.to_rc_value(), filtered.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn filter_transducer() {
+            let xform = FilterFn {}.invoke(vec![Rc::new(eval_str("(fn [x] (= 1 (rem x 2)))"))]);
+            assert_eq!(
+                read("[1 3]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    xform.to_rc_value(),
+                    RangeFn {}.invoke(read_args(&["4"])).to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn filter_propagates_conditions() {
+            let filtered = FilterFn {}.invoke(vec![
+                Rc::new(eval_str("(fn [x] (+ x :a))")),
+                Rc::new(read("[1]")),
+            ]);
+            let first = FirstFn {};
+            match first.invoke(vec![filtered.to_rc_value()]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
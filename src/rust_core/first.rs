@@ -1,14 +1,11 @@
 use crate::error_message;
 use crate::ifn::IFn;
-use crate::iterable::Iterable;
-use crate::protocol::ProtocolCastable;
-use crate::type_tag::TypeTag;
+use crate::seq;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 /// (first x)
 /// returns first element or nil
-/// TODO: support for strings
 #[derive(Debug, Clone)]
 pub struct FirstFn {}
 impl ToValue for FirstFn {
@@ -21,13 +18,7 @@ impl IFn for FirstFn {
         if args.len() != 1 {
             return error_message::wrong_arg_count(1, args.len());
         }
-        match args.get(0).unwrap().try_as_protocol::<Iterable>() {
-            Some(iterable) => match iterable.iter().next() {
-                Some(val) => val.to_value(),
-                _ => Value::Nil,
-            },
-            _ => error_message::type_mismatch(TypeTag::ISeq, args.get(0).unwrap()),
-        }
+        seq::first(args.get(0).unwrap()).to_value()
     }
 }
 
@@ -64,7 +55,7 @@ mod tests {
         #[should_panic]
         fn first_on_non_iterable_value() {
             let first = FirstFn {};
-            let args = vec![Rc::new(Value::Nil)];
+            let args = vec![Rc::new(Value::Boolean(true))];
             assert_eq!(Value::Nil, first.invoke(args));
         }
     }
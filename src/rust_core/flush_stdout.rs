@@ -1,16 +1,23 @@
+use crate::environment::Environment;
+use crate::error_message;
 use crate::ifn::IFn;
+use crate::stream;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
-use std::io;
-
-use crate::error_message;
-use std::io::Write;
-
-/// Read a line from stdin TODO: should be aware of *in*
-/// (defn read-line [])
+/// Flushes *out*, which is stdout unless it's been rebound
+/// (defn flush-stdout [])
 #[derive(Debug, Clone)]
-pub struct FlushStdoutFn {}
+pub struct FlushStdoutFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl FlushStdoutFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> FlushStdoutFn {
+        FlushStdoutFn {
+            enclosing_environment,
+        }
+    }
+}
 impl ToValue for FlushStdoutFn {
     fn to_value(&self) -> Value {
         Value::IFn(Rc::new(self.clone()))
@@ -18,10 +25,12 @@ impl ToValue for FlushStdoutFn {
 }
 impl IFn for FlushStdoutFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        if args.len() != 0 {
+        if !args.is_empty() {
             return error_message::wrong_arg_count(0, args.len());
         }
-        io::stdout().flush().unwrap();
-        Value::Nil
+        match stream::out(&self.enclosing_environment).and_then(|out| out.flush()) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
     }
 }
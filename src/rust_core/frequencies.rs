@@ -0,0 +1,67 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::seq;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (frequencies coll)
+///
+/// The map of each distinct value of coll to the number of times it appears
+#[derive(Debug, Clone)]
+pub struct FrequenciesFn {}
+impl ToValue for FrequenciesFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for FrequenciesFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        let values = match seq::values(&args[0]) {
+            Ok(values) => values,
+            Err(condition) => return (*condition).clone(),
+        };
+        let mut counts = PersistentListMap::Empty
+            .to_rc_value()
+            .as_protocol::<protocols::IPersistentMap>();
+        for value in values {
+            let count = match &*counts.get(&value) {
+                Value::I32(count) => count + 1,
+                _ => 1,
+            };
+            counts = counts.assoc(value, Value::I32(count).to_rc_value());
+        }
+        (*counts.unwrap()).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod frequencies_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{CountFn, FrequenciesFn, RangeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+
+        #[test]
+        fn frequencies_colls() {
+            let frequencies = FrequenciesFn {};
+            assert_eq!(
+                read("{:a 2 :b 1 \"c\" 3}"),
+                frequencies.invoke(read_args(&["[:a \"c\" :b \"c\" :a \"c\"]"]))
+            );
+            assert_eq!(read("{}"), frequencies.invoke(read_args(&["nil"])));
+            let twenty =
+                frequencies.invoke(vec![RangeFn {}.invoke(read_args(&["20"])).to_rc_value()]);
+            assert_eq!(
+                Value::I32(20),
+                CountFn {}.invoke(vec![twenty.to_rc_value()])
+            );
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::symbol::Symbol;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (gensym) (gensym prefix-string)
+/// returns a new symbol, unique among those made by gensym
+#[derive(Debug, Clone)]
+pub struct GensymFn {}
+impl ToValue for GensymFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for GensymFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.len() {
+            0 => Value::Symbol(Symbol::gensym("G__")),
+            1 => match &*args[0] {
+                Value::String(prefix) => Value::Symbol(Symbol::gensym(prefix)),
+                _ => error_message::type_mismatch(TypeTag::String, &args[0]),
+            },
+            _ => error_message::wrong_varg_count(&[0, 1], args.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod gensym_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::GensymFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        #[test]
+        fn gensym_is_unique() {
+            let gensym = GensymFn {};
+            assert_ne!(gensym.invoke(vec![]), gensym.invoke(vec![]));
+        }
+
+        #[test]
+        fn gensym_with_prefix() {
+            let gensym = GensymFn {};
+            match gensym.invoke(vec![Rc::new(Value::String(String::from("foo")))]) {
+                Value::Symbol(sym) => assert!(sym.name.starts_with("foo")),
+                _ => panic!("gensym should return a symbol"),
+            }
+        }
+    }
+}
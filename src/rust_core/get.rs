@@ -1,11 +1,15 @@
 use crate::error_message;
 use crate::ifn::IFn;
 use crate::persistent_list_map::IPersistentMap;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 // General get fn; however,  currently just implemented
-// for our one map type, PersistentListMap
+// for our map types, PersistentListMap and PersistentHashMap,
+// PersistentVector, whose keys are its indexes, and
+// PersistentHashSet, whose keys are its values
 #[derive(Debug, Clone)]
 pub struct GetFn {}
 impl ToValue for GetFn {
@@ -19,7 +23,7 @@ impl IFn for GetFn {
             return error_message::wrong_varg_count(&[2, 3], args.len());
         }
 
-        if let Value::PersistentListMap(pmap) = &*(args.get(0).unwrap().clone()) {
+        if let Some(pmap) = args.get(0).unwrap().try_as_protocol::<protocols::IPersistentMap>() {
             let key = args.get(1).unwrap();
             return if let Some(not_found) = args.get(2) {
                 pmap.get_with_default(key, not_found)
@@ -27,6 +31,24 @@ impl IFn for GetFn {
                 pmap.get(key)
             }.to_value();
         }
+        if let Value::PersistentVector(pvector) = &*args[0] {
+            let found = match *args[1] {
+                Value::I32(index) if index >= 0 => pvector.nth(index as usize),
+                _ => None,
+            };
+            return match (found, args.get(2)) {
+                (Some(val), _) => val.to_value(),
+                (None, Some(not_found)) => not_found.to_value(),
+                (None, None) => Value::Nil,
+            };
+        }
+        if let Value::PersistentHashSet(pset) = &*args[0] {
+            return match (pset.contains(&args[1]), args.get(2)) {
+                (true, _) => (*pset.get(&args[1])).clone(),
+                (false, Some(not_found)) => not_found.to_value(),
+                (false, None) => Value::Nil,
+            };
+        }
         // @TODO add error in here with erkk's new error tools
 
         Value::Nil
@@ -49,8 +71,8 @@ mod tests {
         let val = GetFn {}.invoke(args);
         // then
         match val {
-            Value::Condition(msg) => assert_eq!(
-                msg,
+            Value::Condition(condition) => assert_eq!(
+                condition.message,
                 "Wrong number of arguments given to function (Given: 0, Expected: [2, 3])".to_owned()
             ),
             _ => panic!("expected to error"),
@@ -70,8 +92,8 @@ mod tests {
         let val = GetFn {}.invoke(args);
         // then
         match val {
-            Value::Condition(msg) => assert_eq!(
-                msg,
+            Value::Condition(condition) => assert_eq!(
+                condition.message,
                 "Wrong number of arguments given to function (Given: 1, Expected: [2, 3])".to_owned()
             ),
             _ => panic!("expected to error"),
@@ -94,8 +116,8 @@ mod tests {
         let val = GetFn {}.invoke(args);
         // then
         match val {
-            Value::Condition(msg) => assert_eq!(
-                msg,
+            Value::Condition(condition) => assert_eq!(
+                condition.message,
                 "Wrong number of arguments given to function (Given: 4, Expected: [2, 3])".to_owned()
             ),
             _ => panic!("expected to error"),
@@ -210,4 +232,66 @@ mod tests {
             assert_eq!(&val, if_needle_not_found.as_ref());
         }
     }
+    mod vector {
+        use super::*;
+        use crate::persistent_vector::PersistentVector;
+
+        #[test]
+        fn returns_val_at_index_or_not_found() {
+            // given
+            let haystack = Value::PersistentVector(
+                vec![Value::I32(10).to_rc_value(), Value::I32(11).to_rc_value()]
+                    .into_iter()
+                    .collect::<PersistentVector>(),
+            )
+            .to_rc_value();
+            let if_needle_not_found = Value::Keyword(Keyword::intern("not-found")).to_rc_value();
+            // when, then
+            assert_eq!(
+                Value::I32(11),
+                GetFn {}.invoke(vec![Rc::clone(&haystack), Value::I32(1).to_rc_value()])
+            );
+            assert_eq!(
+                Value::Nil,
+                GetFn {}.invoke(vec![Rc::clone(&haystack), Value::I32(2).to_rc_value()])
+            );
+            assert_eq!(
+                &GetFn {}.invoke(vec![haystack, Value::I32(-1).to_rc_value(), if_needle_not_found.clone()]),
+                if_needle_not_found.as_ref()
+            );
+        }
+    }
+    mod set {
+        use super::*;
+        use crate::persistent_hash_set::PersistentHashSet;
+
+        #[test]
+        fn returns_val_in_set_or_not_found() {
+            // given
+            let haystack = Value::PersistentHashSet(
+                vec![Value::Nil.to_rc_value(), Value::I32(10).to_rc_value()]
+                    .into_iter()
+                    .collect::<PersistentHashSet>(),
+            )
+            .to_rc_value();
+            let if_needle_not_found = Value::Keyword(Keyword::intern("not-found")).to_rc_value();
+            // when, then
+            assert_eq!(
+                Value::I32(10),
+                GetFn {}.invoke(vec![Rc::clone(&haystack), Value::I32(10).to_rc_value()])
+            );
+            assert_eq!(
+                Value::Nil,
+                GetFn {}.invoke(vec![Rc::clone(&haystack), Value::I32(11).to_rc_value()])
+            );
+            assert_eq!(
+                &GetFn {}.invoke(vec![Rc::clone(&haystack), Value::I32(11).to_rc_value(), if_needle_not_found.clone()]),
+                if_needle_not_found.as_ref()
+            );
+            assert_eq!(
+                Value::Nil,
+                GetFn {}.invoke(vec![haystack, Value::Nil.to_rc_value(), if_needle_not_found])
+            );
+        }
+    }
 }
@@ -0,0 +1,80 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (group-by f coll)
+///
+/// The map of what f gives back for each value of coll to a vector of the values it gave
+/// that back for, in the order they appear in coll
+#[derive(Debug, Clone)]
+pub struct GroupByFn {}
+impl ToValue for GroupByFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for GroupByFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let f = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        let values = match seq::values(&args[1]) {
+            Ok(values) => values,
+            Err(condition) => return (*condition).clone(),
+        };
+        let mut groups = PersistentListMap::Empty
+            .to_rc_value()
+            .as_protocol::<protocols::IPersistentMap>();
+        for value in values {
+            let key = Rc::new(f.invoke(vec![Rc::clone(&value)]));
+            if let Some(interruption) = key.non_tail() {
+                return (*interruption).clone();
+            }
+            let group = match &*groups.get(&key) {
+                Value::PersistentVector(group) => group.conj(value),
+                _ => PersistentVector::empty().conj(value),
+            };
+            groups = groups.assoc(key, group.to_rc_value());
+        }
+        (*groups.unwrap()).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod group_by_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{GroupByFn, RangeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn group_by_colls() {
+            let group_by = GroupByFn {};
+            assert_eq!(
+                read("{true [0 2 4] false [1 3]}"),
+                group_by.invoke(vec![
+                    Rc::new(eval_str("(fn [x] (= 0 (rem x 2)))")),
+                    RangeFn {}.invoke(read_args(&["5"])).to_rc_value()
+                ])
+            );
+            assert_eq!(
+                read("{1 [{:a 1} {:a 1 :b 2}] nil [{:b 3}]}"),
+                group_by.invoke(read_args(&[":a", "[{:a 1} {:b 3} {:a 1 :b 2}]"]))
+            );
+            assert_eq!(read("{}"), group_by.invoke(read_args(&[":a", "[]"])));
+        }
+    }
+}
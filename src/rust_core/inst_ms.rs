@@ -0,0 +1,57 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (inst-ms inst)
+///
+/// Milliseconds since 1970-01-01T00:00:00Z of an #inst, as a float since they overflow
+/// our integers
+#[derive(Debug, Clone)]
+pub struct InstMsFn {}
+impl ToValue for InstMsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for InstMsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Inst(inst) => Value::F64(inst.millis() as f64),
+            _ => error_message::type_mismatch(TypeTag::Inst, &args[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod inst_ms_tests {
+        use crate::clojure_edn::read_string::ReadStringFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::InstMsFn;
+        use crate::test_util::read_args;
+        use crate::value::{ToValue, Value};
+
+        #[test]
+        fn inst_ms_of_inst() {
+            let inst = ReadStringFn {}.invoke(vec!["#inst \"2020-01-01\"".to_rc_value()]);
+            assert_eq!(
+                Value::F64(1_577_836_800_000.0),
+                InstMsFn {}.invoke(vec![inst.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn inst_ms_of_non_inst() {
+            let inst_ms = InstMsFn {};
+            match inst_ms.invoke(read_args(&["1"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::cons::Cons;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (interleave & colls)
+///
+/// The lazy seq of the first value of each of colls, then the second of each, and so on,
+/// until any one of them runs out
+#[derive(Debug, Clone)]
+pub struct InterleaveFn {}
+impl ToValue for InterleaveFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for InterleaveFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if let Some(coll) = args.iter().find(|coll| !seq::is_seqable(coll)) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        interleave_seq(args).to_value()
+    }
+}
+
+fn interleave_seq(colls: Vec<Rc<Value>>) -> LazySeq {
+    LazySeq::new(move || {
+        if colls.is_empty() {
+            return Rc::new(Value::Nil);
+        }
+        let mut seqs = vec![];
+        for coll in colls.iter() {
+            let seq = seq::seq(coll);
+            match &*seq {
+                Value::Nil | Value::Condition(_) => return seq,
+                _ => seqs.push(seq),
+            }
+        }
+        let rests = seqs.iter().map(seq::more).collect::<Vec<Rc<Value>>>();
+        seqs.iter()
+            .rev()
+            .fold(interleave_seq(rests).to_rc_value(), |rest, seq| {
+                Cons::new(seq::first(seq), rest).to_rc_value()
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod interleave_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{InterleaveFn, RangeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn interleave_colls() {
+            let interleave = InterleaveFn {};
+            assert_eq!(
+                read("(1 :a 2 :b)"),
+                interleave.invoke(read_args(&["[1 2 3]", "[:a :b]"]))
+            );
+            assert_eq!(
+                read("(0 :a 1 :b)"),
+                interleave.invoke(vec![
+                    RangeFn {}.invoke(vec![]).to_rc_value(),
+                    Rc::new(read("[:a :b]"))
+                ])
+            );
+            assert_eq!(read("()"), interleave.invoke(vec![]));
+            assert_eq!(read("()"), interleave.invoke(read_args(&["[1]", "nil"])));
+        }
+    }
+}
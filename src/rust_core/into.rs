@@ -0,0 +1,116 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::rust_core::conj::{conj, ConjFn};
+use crate::rust_core::transduce::transduce;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (into)
+/// (into to)
+/// (into to from)
+/// (into to xform from)
+///
+/// to with each value of from conj'd onto it, in order;  with an xform, each value as
+/// transformed by it
+#[derive(Debug, Clone)]
+pub struct IntoFn {}
+impl ToValue for IntoFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for IntoFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (to, from) = match args.as_slice() {
+            [] => return Value::PersistentVector(PersistentVector::empty()),
+            [to] => return (**to).clone(),
+            [to, from] => (to, from),
+            [to, xform, from] => {
+                let conj = ConjFn {}.to_rc_value().as_protocol::<protocols::IFn>();
+                return (*transduce(xform, &conj, Rc::clone(to), from)).clone();
+            }
+            _ => return error_message::wrong_varg_count(&[0, 1, 2, 3], args.len()),
+        };
+        if !seq::is_seqable(from) {
+            return error_message::type_mismatch(TypeTag::ISeq, from);
+        }
+        let mut to = Rc::clone(to);
+        for value in seq::iter(from) {
+            if value.non_tail().is_some() {
+                return (*value).clone();
+            }
+            to = conj(&to, value);
+            if let Value::Condition(_) = &*to {
+                break;
+            }
+        }
+        (*to).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod into_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{CompFn, FilterFn, IntoFn, MapFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn into_each_coll() {
+            let into = IntoFn {};
+            assert_eq!(read("[1 2 3]"), into.invoke(read_args(&["[1]", "(2 3)"])));
+            assert_eq!(read("(3 2 1)"), into.invoke(read_args(&["(1)", "[2 3]"])));
+            assert_eq!(read("#{1 2}"), into.invoke(read_args(&["#{}", "[1 2 1]"])));
+            assert_eq!(
+                read("{:a 1 :b 2}"),
+                into.invoke(read_args(&["{}", "[[:a 1] [:b 2]]"]))
+            );
+            assert_eq!(
+                read("{:a 1}"),
+                into.invoke(read_args(&["{:a 0}", "{:a 1}"]))
+            );
+            assert_eq!(
+                read("[0 1 2]"),
+                into.invoke(vec![
+                    Rc::new(read("[]")),
+                    RangeFn {}.invoke(read_args(&["3"])).to_rc_value()
+                ])
+            );
+            assert_eq!(read("[]"), into.invoke(vec![]));
+        }
+
+        #[test]
+        fn into_with_xform() {
+            let into = IntoFn {};
+            let inc = MapFn {}.invoke(vec![Rc::new(eval_str("(fn [x] (+ x 1))"))]);
+            assert_eq!(
+                read("[1 2 3]"),
+                into.invoke(vec![
+                    Rc::new(read("[1]")),
+                    inc.to_rc_value(),
+                    Rc::new(read("[1 2]"))
+                ])
+            );
+            let evens = FilterFn {}.invoke(vec![Rc::new(eval_str("(fn [x] (= 0 (rem x 2)))"))]);
+            let xform = CompFn {}.invoke(vec![
+                TakeFn {}.invoke(read_args(&["3"])).to_rc_value(),
+                evens.to_rc_value(),
+            ]);
+            assert_eq!(
+                read("#{0 2}"),
+                into.invoke(vec![
+                    Rc::new(read("#{}")),
+                    xform.to_rc_value(),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::condition_system;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (invoke-restart name & args)
+/// unwinds to the innermost restart-case offering the restart named name (a symbol or
+/// keyword), and runs that restart on args
+#[derive(Debug, Clone)]
+pub struct InvokeRestartFn {}
+impl ToValue for InvokeRestartFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for InvokeRestartFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.is_empty() {
+            return error_message::wrong_variadic_arg_count(&[], 1, args.len());
+        }
+        match &*args[0] {
+            Value::Symbol(sym) => condition_system::invoke_restart(sym.name(), args[1..].to_vec()),
+            Value::Keyword(kw) => condition_system::invoke_restart(kw.name(), args[1..].to_vec()),
+            _ => error_message::type_mismatch(TypeTag::Symbol, &args[0]),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (keep f coll)
+///
+/// The lazy seq of what f gives back for each value of coll, leaving off the nils
+#[derive(Debug, Clone)]
+pub struct KeepFn {}
+impl ToValue for KeepFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for KeepFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let f = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(KeepXForm { f }).to_value();
+        }
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        let values = seq::iter(coll).filter_map(move |value| {
+            if value.non_tail().is_some() {
+                return Some(value);
+            }
+            match f.invoke(vec![value]) {
+                Value::Nil => None,
+                kept => Some(Rc::new(kept)),
+            }
+        });
+        LazySeq::from_iter(values).to_value()
+    }
+}
+
+/// (keep f), the transducer stepping in what f gives back for each input, unless it's nil
+#[derive(Debug, Clone)]
+pub struct KeepXForm {
+    f: protocols::IFn,
+}
+impl XForm for KeepXForm {
+    type State = ();
+    fn step(
+        &self,
+        _state: &RefCell<()>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let kept = Rc::new(self.f.invoke(vec![input]));
+        if let Some(interruption) = kept.non_tail() {
+            return interruption;
+        }
+        match &*kept {
+            Value::Nil => result,
+            _ => Rc::new(rf.invoke(vec![result, kept])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod keep_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, KeepFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn keep_colls() {
+            let keep = KeepFn {};
+            assert_eq!(
+                read("(1 false)"),
+                keep.invoke(read_args(&[":a", "[{:a 1} {:b 2} {:a false}]"]))
+            );
+            let odds = keep.invoke(vec![
+                Rc::new(eval_str("(fn [x] (if (= 0 (rem x 2)) nil x))")),
+                RangeFn {}.invoke(vec![]).to_rc_value(),
+            ]);
+            assert_eq!(
+                read("(1 3)"),
+                TakeFn {}.invoke(vec![2.to_rc_value(), odds.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn keep_transducer() {
+            assert_eq!(
+                read("[1 false]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    KeepFn {}.invoke(read_args(&[":a"])).to_rc_value(),
+                    Rc::new(read("[{:a 1} {} {:a false}]"))
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::keyword::Keyword;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (keyword name)
+/// (keyword ns name)
+///
+/// The keyword with name (and ns), which are strings;  given a keyword, it's given back, and
+/// given a symbol, the keyword with its ns and name
+#[derive(Debug, Clone)]
+pub struct KeywordFn {}
+impl ToValue for KeywordFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for KeywordFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.as_slice() {
+            [name] => match &**name {
+                Value::Keyword(_) => (**name).clone(),
+                Value::Symbol(sym) => {
+                    Keyword::intern_with_ns(sym.namespace().unwrap_or(""), sym.name()).to_value()
+                }
+                Value::String(name) => Keyword::intern(name).to_value(),
+                _ => error_message::type_mismatch(TypeTag::String, name),
+            },
+            [ns, name] => match (&**ns, &**name) {
+                (Value::String(ns), Value::String(name)) => {
+                    Keyword::intern_with_ns(ns, name).to_value()
+                }
+                (Value::Nil, Value::String(name)) => Keyword::intern_with_ns("", name).to_value(),
+                (Value::String(_), _) | (Value::Nil, _) => {
+                    error_message::type_mismatch(TypeTag::String, name)
+                }
+                _ => error_message::type_mismatch(TypeTag::String, ns),
+            },
+            _ => error_message::wrong_varg_count(&[1, 2], args.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod keyword_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::KeywordFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn keyword_of_strings_symbols_and_keywords() {
+            let keyword = KeywordFn {};
+            assert_eq!(read(":a"), keyword.invoke(read_args(&["\"a\""])));
+            assert_eq!(read(":b/c"), keyword.invoke(read_args(&["\"b/c\""])));
+            assert_eq!(read(":d/e"), keyword.invoke(read_args(&["\"d\"", "\"e\""])));
+            assert_eq!(read(":f"), keyword.invoke(read_args(&["f"])));
+            assert_eq!(read(":g"), keyword.invoke(read_args(&[":g"])));
+        }
+
+        #[test]
+        fn keyword_of_non_string() {
+            let keyword = KeywordFn {};
+            match keyword.invoke(read_args(&["1"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (lazy-seq* f)
+///
+/// The lazy seq of what f, a fn of no arguments, gives back;  only called once it is first
+/// asked for.  What the lazy-seq macro expands into
+#[derive(Debug, Clone)]
+pub struct LazySeqFn {}
+impl ToValue for LazySeqFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for LazySeqFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::IFn(ifn) => LazySeq::from_ifn(Rc::clone(ifn)).to_value(),
+            value => error_message::type_mismatch(TypeTag::IFn, value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod lazy_seq_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn realized_only_when_asked() {
+            assert_eq!(
+                eval_str("[false 1 true '(1 2)]"),
+                eval_str("(let [s (lazy-seq '(1 2))] [(realized? s) (first s) (realized? s) s])")
+            );
+        }
+
+        #[test]
+        fn empty_lazy_seqs() {
+            assert_eq!(eval_str("'()"), eval_str("(lazy-seq nil)"));
+            assert_eq!(Value::Nil, eval_str("(seq (lazy-seq (lazy-seq [])))"));
+        }
+    }
+}
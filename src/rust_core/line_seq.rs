@@ -0,0 +1,88 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (line-seq rdr)
+///
+/// The lines of the reader rdr, as a lazy seq;  each is only read once it's asked for, so
+/// a file can be gone through without all of it being in memory at once
+#[derive(Debug, Clone)]
+pub struct LineSeqFn {}
+impl ToValue for LineSeqFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for LineSeqFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        let reader = match &*args[0] {
+            Value::Reader(reader) => reader.clone(),
+            _ => return error_message::type_mismatch(TypeTag::Reader, &args[0]),
+        };
+        LazySeq::from_iter(std::iter::from_fn(move || match reader.read_line() {
+            Ok(line) => line.map(|line| Rc::new(Value::String(line))),
+            Err(condition) => Some(condition),
+        }))
+        .to_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod line_seq_tests {
+        use crate::clojure_java_io::close::CloseFn;
+        use crate::ifn::IFn;
+        use crate::rust_core::{CountFn, FirstFn, LineSeqFn, SeqFn, StringReaderFn};
+        use crate::test_util::read;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn string_reader(s: &str) -> Rc<Value> {
+            StringReaderFn {}
+                .invoke(vec![s.to_rc_value()])
+                .to_rc_value()
+        }
+
+        #[test]
+        fn lines_of_reader() {
+            let line_seq = LineSeqFn {};
+            assert_eq!(
+                read(r#"("a" "" "b")"#),
+                line_seq.invoke(vec![string_reader("a\n\nb\n")])
+            );
+            let empty = line_seq.invoke(vec![string_reader("")]);
+            assert_eq!(Value::Nil, SeqFn {}.invoke(vec![empty.to_rc_value()]));
+        }
+
+        #[test]
+        fn lines_only_read_when_asked_for() {
+            let reader = string_reader("a\nb\nc");
+            let lines = LineSeqFn {}.invoke(vec![Rc::clone(&reader)]);
+            assert_eq!(read(r#""a""#), FirstFn {}.invoke(vec![lines.to_rc_value()]));
+            match &*reader {
+                Value::Reader(reader) => {
+                    assert_eq!(Some("b".to_string()), reader.read_line().unwrap())
+                }
+                value => panic!("expected a reader, got {}", value),
+            }
+        }
+
+        #[test]
+        fn counting_lines_of_closed_reader_throws() {
+            let reader = string_reader("a\nb");
+            CloseFn {}.invoke(vec![Rc::clone(&reader)]);
+            let lines = LineSeqFn {}.invoke(vec![reader]);
+            let count = CountFn {};
+            match count.invoke(vec![lines.to_rc_value()]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::error_message;
+use crate::condition::ConditionKind;
 use crate::environment::Environment;
 use crate::ifn::IFn;
 use crate::repl::Repl;
@@ -24,7 +26,7 @@ impl ToValue for LoadFileFn {
 impl IFn for LoadFileFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
         if args.len() != 1 {
-            Value::Condition(format!(
+            error_message::condition(ConditionKind::ArityException, format!(
                 "Wrong number of arguments given to function (Given: {}, Expected: 1)",
                 args.len()
             ))
@@ -34,7 +36,7 @@ impl IFn for LoadFileFn {
             //@TODO remove this placeholder value, return last value evaluated in try_eval_file
             Value::Nil
         } else {
-            Value::Condition(format!(
+            error_message::condition(ConditionKind::ClassCastException, format!(
                 "Type mismatch; Expected instance of {}, Recieved type {}",
                 TypeTag::String,
                 args.len()
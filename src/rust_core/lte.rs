@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::error_message;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
@@ -20,8 +21,19 @@ impl IFn for LteFn {
         match args.get(0).unwrap().to_value() {
             Value::I32(a) => match args.get(1).unwrap().to_value() {
                 Value::I32(b) => Value::Boolean(a <= b),
+                Value::I64(b) => Value::Boolean(i64::from(a) <= b),
                 Value::F64(b) => Value::Boolean(a <= b as i32),
-                b_ => Value::Condition(format!(
+                b_ => error_message::condition(ConditionKind::ClassCastException, format!(
+                    // TODO: what error message should be returned regarding using typetags?
+                    "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                    b_.type_tag()
+                )),
+            },
+            Value::I64(a) => match args.get(1).unwrap().to_value() {
+                Value::I32(b) => Value::Boolean(a <= i64::from(b)),
+                Value::I64(b) => Value::Boolean(a <= b),
+                Value::F64(b) => Value::Boolean((a as f64) <= b),
+                b_ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b_.type_tag()
@@ -29,14 +41,15 @@ impl IFn for LteFn {
             },
             Value::F64(a) => match args.get(0).unwrap().to_value() {
                 Value::I32(b) => Value::Boolean(a <= b as f64),
+                Value::I64(b) => Value::Boolean(a <= b as f64),
                 Value::F64(b) => Value::Boolean(a <= b),
-                b_ => Value::Condition(format!(
+                b_ => error_message::condition(ConditionKind::ClassCastException, format!(
                     // TODO: what error message should be returned regarding using typetags?
                     "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                     b_.type_tag()
                 )),
             },
-            a_ => Value::Condition(format!(
+            a_ => error_message::condition(ConditionKind::ClassCastException, format!(
                 // TODO: what error message should be returned regarding using typetags?
                 "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                 a_.type_tag()
@@ -1,10 +1,15 @@
+use crate::condition::ConditionKind;
+use crate::cons::Cons;
 use crate::error_message;
 use crate::ifn::IFn;
-use crate::iterable::Iterable;
-use crate::persistent_list::PersistentList;
+use crate::lazy_seq::LazySeq;
 use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
 use crate::type_tag::TypeTag;
 use crate::value::{ToValue, Value};
+use std::cell::RefCell;
 use std::rc::Rc;
 
 // This is a tide me over rust wrapper, as map is implemented in lower level primitives
@@ -19,34 +24,136 @@ impl ToValue for MapFn {
 }
 impl IFn for MapFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() == 1 {
+            return match args[0].try_as_protocol::<protocols::IFn>() {
+                Some(f) => Transducer::new(MapXForm { f }).to_value(),
+                None => error_message::type_mismatch(TypeTag::IFn, &args[0]),
+            };
+        }
         if args.len() != 2 {
-            return error_message::wrong_arg_count(2, args.len());
+            return error_message::wrong_varg_count(&[1, 2], args.len());
         }
         let ifn_val = &**args.get(0).unwrap();
-        let iterable_rc_val = args.get(1).unwrap();
-        let iterable = iterable_rc_val.try_as_protocol::<Iterable>();
-        match (ifn_val, iterable) {
-            // @TODO first arg can be any callable, not necessarily a "function"
-            (Value::IFn(ifn), Some(iterable)) => {
-                iterable
-                    .iter()
-                    .map(|rc_val| Rc::new(ifn.invoke(vec![rc_val])))
-                    .collect::<PersistentList>()
-                    .to_value()
+        let coll = args.get(1).unwrap();
+        match ifn_val {
+            Value::IFn(ifn) if seq::is_seqable(coll) => {
+                map_seq(Rc::clone(ifn), Rc::clone(coll)).to_value()
             }
-            (_, None) => {
-                Value::Condition(format!(
+            Value::IFn(_) => error_message::condition(
+                ConditionKind::ClassCastException,
+                format!(
                     "Type mismatch; Expected iterable type, Recieved type {}",
-                    iterable_rc_val.as_ref().type_tag(),
-                ))
-            }
-            _ => {
-                Value::Condition(format!(
+                    coll.as_ref().type_tag(),
+                ),
+            ),
+            _ => error_message::condition(
+                ConditionKind::ClassCastException,
+                format!(
                     "Type mismatch; Expected instance of {}, Recieved type {}",
                     TypeTag::IFn,
                     ifn_val.type_tag(),
-                ))
+                ),
+            ),
+        }
+    }
+}
+
+/// The lazy seq of ifn called on each value of coll, each call only made once its result is
+/// asked for
+fn map_seq(ifn: Rc<dyn IFn>, coll: Rc<Value>) -> LazySeq {
+    LazySeq::new(move || {
+        let coll = seq::seq(&coll);
+        match &*coll {
+            Value::Nil | Value::Condition(_) => coll,
+            _ => {
+                let mapped = Rc::new(ifn.invoke(vec![seq::first(&coll)]));
+                if let Value::Condition(_) = &*mapped {
+                    return mapped;
+                }
+                Cons::new(mapped, map_seq(ifn, seq::more(&coll)).to_rc_value()).to_rc_value()
             }
         }
+    })
+}
+
+/// (map f), the transducer stepping in what f gives back for each input
+#[derive(Debug, Clone)]
+pub struct MapXForm {
+    f: protocols::IFn,
+}
+impl XForm for MapXForm {
+    type State = ();
+    fn step(
+        &self,
+        _state: &RefCell<()>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let mapped = Rc::new(self.f.invoke(vec![input]));
+        if let Some(interruption) = mapped.non_tail() {
+            return interruption;
+        }
+        Rc::new(rf.invoke(vec![result, mapped]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod map_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddFn, FirstFn, IntoFn, MapFn, RangeFn, TakeFn, TransduceFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn inc() -> Rc<Value> {
+            Rc::new(eval_str("(fn [x] (+ x 1))"))
+        }
+
+        #[test]
+        fn map_over_each_seqable() {
+            let map = MapFn {};
+            assert_eq!(
+                read("(2 3)"),
+                map.invoke(vec![inc(), Rc::new(read("[1 2]"))])
+            );
+            assert_eq!(read("()"), map.invoke(vec![inc(), Rc::new(Value::Nil)]));
+            assert_eq!(
+                read("(:a)"),
+                map.invoke(vec![FirstFn {}.to_rc_value(), Rc::new(read("{:a 1}"))])
+            );
+        }
+
+        #[test]
+        fn map_over_infinite_seq() {
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let doubled = MapFn {}.invoke(vec![Rc::new(eval_str("(fn [x] (* x 2))")), naturals]);
+            assert_eq!(
+                read("(0 2 4 6 8 10 12 14 16 18)"),
+                TakeFn {}.invoke(vec![10.to_rc_value(), doubled.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn map_transducer() {
+            let xform = MapFn {}.invoke(vec![inc()]).to_rc_value();
+            assert_eq!(
+                read("[2 3 4]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    Rc::clone(&xform),
+                    Rc::new(read("[1 2 3]"))
+                ])
+            );
+            assert_eq!(
+                Value::I32(9),
+                TransduceFn {}.invoke(vec![
+                    xform,
+                    AddFn {}.to_rc_value(),
+                    Rc::new(read("[1 2 3]"))
+                ])
+            );
+        }
     }
 }
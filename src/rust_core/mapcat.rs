@@ -0,0 +1,135 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (mapcat f coll)
+///
+/// The lazy seq of the values of each coll f gives back for the values of coll, one after
+/// another;  (apply concat (map f coll))
+#[derive(Debug, Clone)]
+pub struct MapcatFn {}
+impl ToValue for MapcatFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for MapcatFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let f = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(MapcatXForm { f }).to_value();
+        }
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        let values = seq::iter(coll).flat_map(move |value| {
+            if value.non_tail().is_some() {
+                return seq::iter(&value);
+            }
+            seq::iter(&Rc::new(f.invoke(vec![value])))
+        });
+        LazySeq::from_iter(values).to_value()
+    }
+}
+
+/// (mapcat f), the transducer stepping in each value of the coll f gives back for each
+/// input
+#[derive(Debug, Clone)]
+pub struct MapcatXForm {
+    f: protocols::IFn,
+}
+impl XForm for MapcatXForm {
+    type State = ();
+    fn step(
+        &self,
+        _state: &RefCell<()>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let coll = Rc::new(self.f.invoke(vec![input]));
+        let mut result = result;
+        for value in seq::iter(&coll) {
+            if let Some(interruption) = value.non_tail() {
+                return interruption;
+            }
+            result = Rc::new(rf.invoke(vec![result, value]));
+            // A reduced result is handed back as is, so whatever's reducing stops too
+            if result.non_tail().is_some() || matches!(&*result, Value::Reduced(_)) {
+                break;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod mapcat_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{FirstFn, IntoFn, MapcatFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn mapcat_colls() {
+            let mapcat = MapcatFn {};
+            assert_eq!(
+                read("(1 1 2 2)"),
+                mapcat.invoke(vec![
+                    Rc::new(eval_str("(fn [x] [x x])")),
+                    Rc::new(read("[1 2]"))
+                ])
+            );
+            let ranges = mapcat.invoke(vec![
+                RangeFn {}.to_rc_value(),
+                RangeFn {}.invoke(vec![]).to_rc_value(),
+            ]);
+            assert_eq!(
+                read("(0 0 1)"),
+                TakeFn {}.invoke(vec![3.to_rc_value(), ranges.to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn mapcat_transducer() {
+            assert_eq!(
+                read("[0 0 1 0 1 2]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    MapcatFn {}
+                        .invoke(vec![RangeFn {}.to_rc_value()])
+                        .to_rc_value(),
+                    Rc::new(read("[1 2 3]"))
+                ])
+            );
+        }
+
+        #[test]
+        fn mapcat_of_non_colls() {
+            let mapcat =
+                MapcatFn {}.invoke(vec![Rc::new(eval_str("(fn [x] x)")), Rc::new(read("[1]"))]);
+            let first = FirstFn {};
+            match first.invoke(vec![mapcat.to_rc_value()]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -38,7 +38,7 @@ impl IFn for MetaFn {
             // In order to avoid having the cryptic error messages of Clojure, we're experimenting here
             // already with some other error messages. As we finds ones we like, they will likewise be
             // abstracted out to their own functions -- for now, they're just one offs
-            _ => error_message::custom(&format!(
+            _ => error_message::custom(format!(
                 "In (meta ..), .. must be an instance of IMeta, and {} is of type {}, which is not",
                 args.get(0).unwrap(),
                 args.get(0).unwrap().type_tag())
@@ -1,15 +1,11 @@
 use crate::error_message;
 use crate::ifn::IFn;
-use crate::iterable::Iterable;
-use crate::persistent_list::PersistentList;
-use crate::protocol::ProtocolCastable;
-use crate::type_tag::TypeTag;
+use crate::seq;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 /// (more x)
 /// for rest, returns possibly empty sequence after the first
-/// TODO: support for strings
 #[derive(Debug, Clone)]
 pub struct MoreFn {}
 impl ToValue for MoreFn {
@@ -22,15 +18,7 @@ impl IFn for MoreFn {
         if args.len() != 1 {
             return error_message::wrong_arg_count(1, args.len());
         }
-        match args.get(0).unwrap().try_as_protocol::<Iterable>() {
-            Some(iterable) => match iterable.iter().collect::<Vec<Rc<Value>>>().split_first() {
-                Some((_, more)) => {
-                    Value::PersistentList(more.to_vec().into_iter().collect::<PersistentList>())
-                }
-                _ => Value::PersistentList(vec![].into_iter().collect::<PersistentList>()),
-            },
-            _ => error_message::type_mismatch(TypeTag::ISeq, args.get(0).unwrap()),
-        }
+        seq::more(args.get(0).unwrap()).to_value()
     }
 }
 
@@ -0,0 +1,197 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::multi_fn::MultiFn;
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (multi-fn* name dispatch-fn)
+///
+/// A multimethod without methods, dispatching on dispatch-fn;  see defmulti
+#[derive(Debug, Clone)]
+pub struct MultiFnFn {}
+impl ToValue for MultiFnFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for MultiFnFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (name, dispatch_fn) = match args.as_slice() {
+            [name, dispatch_fn] => (name, dispatch_fn),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        let name = match &**name {
+            Value::Symbol(name) => name,
+            _ => return error_message::type_mismatch(TypeTag::Symbol, name),
+        };
+        match dispatch_fn.try_as_protocol::<protocols::IFn>() {
+            Some(dispatch_fn) => Value::MultiFn(Rc::new(MultiFn::new(name.clone(), dispatch_fn))),
+            None => error_message::type_mismatch(TypeTag::IFn, dispatch_fn),
+        }
+    }
+}
+
+/// (add-method* multifn dispatch-val f)
+///
+/// Adds f as multifn's method for dispatch-val, giving back multifn;  see defmethod
+#[derive(Debug, Clone)]
+pub struct AddMethodFn {}
+impl ToValue for AddMethodFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for AddMethodFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (multi_fn, dispatch_value, method) = match args.as_slice() {
+            [multi_fn, dispatch_value, method] => (multi_fn, dispatch_value, method),
+            _ => return error_message::wrong_arg_count(3, args.len()),
+        };
+        let method = match method.try_as_protocol::<protocols::IFn>() {
+            Some(method) => method,
+            None => return error_message::type_mismatch(TypeTag::IFn, method),
+        };
+        match &**multi_fn {
+            Value::MultiFn(added_to) => {
+                added_to.add_method(Rc::clone(dispatch_value), method);
+                (**multi_fn).clone()
+            }
+            _ => error_message::type_mismatch(TypeTag::MultiFn, multi_fn),
+        }
+    }
+}
+
+/// (remove-method multifn dispatch-val)
+///
+/// Gives back multifn
+#[derive(Debug, Clone)]
+pub struct RemoveMethodFn {}
+impl ToValue for RemoveMethodFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RemoveMethodFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (multi_fn, dispatch_value) = match args.as_slice() {
+            [multi_fn, dispatch_value] => (multi_fn, dispatch_value),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        match &**multi_fn {
+            Value::MultiFn(removed_from) => {
+                removed_from.remove_method(dispatch_value);
+                (**multi_fn).clone()
+            }
+            _ => error_message::type_mismatch(TypeTag::MultiFn, multi_fn),
+        }
+    }
+}
+
+/// (get-method multifn dispatch-val)
+///
+/// The method multifn would call for dispatch-val, falling back on its :default;  nil if
+/// there's neither
+#[derive(Debug, Clone)]
+pub struct GetMethodFn {}
+impl ToValue for GetMethodFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for GetMethodFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (multi_fn, dispatch_value) = match args.as_slice() {
+            [multi_fn, dispatch_value] => (multi_fn, dispatch_value),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        match &**multi_fn {
+            Value::MultiFn(multi_fn) => match multi_fn.get_method(dispatch_value) {
+                Some(method) => (*method.unwrap()).clone(),
+                None => Value::Nil,
+            },
+            _ => error_message::type_mismatch(TypeTag::MultiFn, multi_fn),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod multi_fn_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddMethodFn, GetMethodFn, MultiFnFn, RemoveMethodFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        /// A multimethod dispatching on the :kind of its argument, with a method giving back
+        /// each of methods' dispatch values
+        fn shape(methods: &[&str]) -> Rc<Value> {
+            let shape = MultiFnFn {}
+                .invoke(read_args(&["shape", ":kind"]))
+                .to_rc_value();
+            for method in methods {
+                let f = Rc::new(eval_str(&format!("(fn [s] {})", method)));
+                AddMethodFn {}.invoke(vec![Rc::clone(&shape), Rc::new(read(method)), f]);
+            }
+            shape
+        }
+
+        fn call(multi_fn: &Value, arg: &str) -> Value {
+            match multi_fn {
+                Value::MultiFn(multi_fn) => multi_fn.invoke(read_args(&[arg])),
+                value => panic!("expected a multimethod, got {}", value),
+            }
+        }
+
+        #[test]
+        fn dispatch_on_dispatch_fn() {
+            let shape = shape(&[":circle", ":square", ":default"]);
+            assert_eq!(read(":circle"), call(&shape, "{:kind :circle}"));
+            assert_eq!(read(":square"), call(&shape, "{:kind :square}"));
+            assert_eq!(read(":default"), call(&shape, "{}"));
+        }
+
+        #[test]
+        fn remove_and_get_methods() {
+            let shape = shape(&[":square"]);
+            let square = Rc::new(read(":square"));
+            let get_method = GetMethodFn {};
+            match get_method.invoke(vec![Rc::clone(&shape), Rc::clone(&square)]) {
+                Value::IFn(method) => assert_eq!(
+                    read(":square"),
+                    method.invoke(read_args(&["{:kind :square}"]))
+                ),
+                value => panic!("expected a method, got {}", value),
+            }
+            assert_eq!(
+                *shape,
+                RemoveMethodFn {}.invoke(vec![Rc::clone(&shape), Rc::clone(&square)])
+            );
+            assert_eq!(Value::Nil, get_method.invoke(vec![shape, square]));
+        }
+
+        #[test]
+        fn no_method_for_dispatch_value() {
+            match call(&shape(&[]), "{:kind :circle}") {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn defmulti_and_defmethod() {
+            assert_eq!(
+                read("[:circle :shape]"),
+                eval_str(
+                    "(do (defmulti shape (fn [s] (get s :kind)))
+                         (defmethod shape :circle [s] :circle)
+                         (defmethod shape :default [s] :shape)
+                         [(shape {:kind :circle}) (shape {})])"
+                )
+            );
+        }
+    }
+}
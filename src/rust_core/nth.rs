@@ -4,12 +4,12 @@ use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 use crate::error_message;
-use crate::persistent_list::PersistentList::{Cons, Empty};
-use crate::persistent_list::ToPersistentListIter;
-use crate::persistent_vector::PersistentVector;
+use crate::seq;
 
-/// (nth coll index)
+/// (nth coll index) (nth coll index not-found)
 ///
+/// The value at index in coll, walking it if it's a seq (realizing only as much of a lazy one
+/// as it has to);  if coll runs short, not-found, or without it an IndexOutOfBoundsException
 #[derive(Debug, Clone)]
 pub struct NthFn {}
 impl ToValue for NthFn {
@@ -19,41 +19,132 @@ impl ToValue for NthFn {
 }
 impl IFn for NthFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        // @TODO generalize arity exceptions, and other exceptions
-        if args.len() != 2 {
+        if args.len() != 2 && args.len() != 3 {
             return error_message::wrong_varg_count(&[2, 3], args.len());
         }
-        // @TODO change iteration to work with Value references, or even change invoke to work on Rc<..>
-        //       as we do everything else; surely we don't want to clone just to read from a collection
-        if let Value::I32(ind) = **args.get(1).unwrap() {
-            if ind < 0 {
-                return error_message::index_cannot_be_negative(ind as usize);
-            }
-            let ind = ind as usize;
-
-            match &**args.get(0).unwrap() {
-                Value::PersistentList(Cons(head, tail, count)) => {
-                    let count = *count as usize;
-                    if ind >= count {
-                        error_message::index_out_of_bounds(ind, count)
-                    } else if ind == 0 {
-                        head.to_value()
-                    } else {
-                        tail.iter().nth(ind - 1).unwrap().to_value()
+        let coll = &args[0];
+        let not_found = args.get(2);
+        let ind = match &*args[1] {
+            Value::I32(ind) => *ind,
+            _ => return error_message::type_mismatch(TypeTag::Integer, &args[1]),
+        };
+        if ind < 0 {
+            return match not_found {
+                Some(not_found) => (**not_found).clone(),
+                None => error_message::index_cannot_be_negative(ind as usize),
+            };
+        }
+        let ind = ind as usize;
+        let count = match &**coll {
+            Value::PersistentVector(pvector) => match pvector.nth(ind) {
+                Some(val) => return val.to_value(),
+                None => pvector.len(),
+            },
+            _ if seq::is_seqable(coll) => {
+                let mut count = 0;
+                for value in seq::iter(coll) {
+                    if let Value::Condition(_) = &*value {
+                        return (*value).clone();
                     }
-                }
-                Value::PersistentList(Empty) => error_message::index_out_of_bounds(ind, 0),
-                Value::PersistentVector(PersistentVector { vals }) => {
-                    if ind >= vals.len() {
-                        error_message::index_out_of_bounds(ind, vals.len())
-                    } else {
-                        vals.get(ind).unwrap().to_value()
+                    if count == ind {
+                        return (*value).clone();
                     }
+                    count += 1;
                 }
-                _ => error_message::type_mismatch(TypeTag::ISeq, &**args.get(0).unwrap()),
+                count
             }
-        } else {
-            error_message::type_mismatch(TypeTag::Integer, &**args.get(1).unwrap())
+            _ => return error_message::type_mismatch(TypeTag::ISeq, coll),
+        };
+        match not_found {
+            Some(not_found) => (**not_found).clone(),
+            None => error_message::index_out_of_bounds(ind, count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod nth_tests {
+        use crate::cons::Cons;
+        use crate::ifn::IFn;
+        use crate::keyword::Keyword;
+        use crate::lazy_seq::LazySeq;
+        use crate::persistent_vector::PersistentVector;
+        use crate::rust_core::NthFn;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn ints(ints: Vec<i32>) -> impl Iterator<Item = Rc<Value>> {
+            ints.into_iter().map(|i| Rc::new(Value::I32(i)))
+        }
+
+        #[test]
+        fn nth_of_vector_and_list() {
+            let nth = NthFn {};
+            let vector = ints(vec![1, 2, 3])
+                .collect::<PersistentVector>()
+                .to_rc_value();
+            assert_eq!(
+                Value::I32(2),
+                nth.invoke(vec![vector, Rc::new(Value::I32(1))])
+            );
+            let list = Value::PersistentList(ints(vec![1, 2, 3]).collect()).to_rc_value();
+            assert_eq!(
+                Value::I32(3),
+                nth.invoke(vec![list, Rc::new(Value::I32(2))])
+            );
+        }
+
+        #[test]
+        fn nth_of_lazy_seq() {
+            let nth = NthFn {};
+            let lazy = LazySeq::from_iter(ints(vec![2, 3, 4])).to_rc_value();
+            assert_eq!(
+                Value::I32(3),
+                nth.invoke(vec![lazy, Rc::new(Value::I32(1))])
+            );
+            let cons = Cons::new(
+                Rc::new(Value::I32(1)),
+                LazySeq::from_iter(ints(vec![2, 3])).to_rc_value(),
+            )
+            .to_rc_value();
+            assert_eq!(
+                Value::I32(3),
+                nth.invoke(vec![cons, Rc::new(Value::I32(2))])
+            );
+        }
+
+        #[test]
+        fn nth_of_infinite_seq() {
+            let nth = NthFn {};
+            let naturals = LazySeq::from_iter((0..).map(|i| Rc::new(Value::I32(i)))).to_rc_value();
+            assert_eq!(
+                Value::I32(5),
+                nth.invoke(vec![naturals, Rc::new(Value::I32(5))])
+            );
+        }
+
+        #[test]
+        fn nth_past_the_end() {
+            let nth = NthFn {};
+            let lazy = || LazySeq::from_iter(ints(vec![1, 2])).to_rc_value();
+            assert!(matches!(
+                nth.invoke(vec![lazy(), Rc::new(Value::I32(2))]),
+                Value::Condition(_)
+            ));
+            let not_found = Keyword::intern("none").to_rc_value();
+            assert_eq!(
+                *not_found,
+                nth.invoke(vec![lazy(), Rc::new(Value::I32(2)), Rc::clone(&not_found)])
+            );
+            assert_eq!(
+                *not_found,
+                nth.invoke(vec![
+                    Rc::new(Value::Nil),
+                    Rc::new(Value::I32(0)),
+                    not_found.clone()
+                ])
+            );
         }
     }
 }
@@ -0,0 +1,140 @@
+use crate::cons::Cons;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::persistent_list::PersistentList;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (partition n coll)
+/// (partition n step coll)
+/// (partition n step pad coll)
+///
+/// The lazy seq of lists of n values of coll, each starting step (or n) values after the
+/// last.  A last partition of fewer than n values is left off, unless pad is given, in
+/// which case it's filled out with the values of pad, as far as they go
+#[derive(Debug, Clone)]
+pub struct PartitionFn {}
+impl ToValue for PartitionFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PartitionFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (n, step, partial, coll) = match args.as_slice() {
+            [n, coll] => (n, n, Partial::Drop, coll),
+            [n, step, coll] => (n, step, Partial::Drop, coll),
+            [n, step, pad, coll] => (n, step, Partial::Pad(Rc::clone(pad)), coll),
+            _ => return error_message::wrong_varg_count(&[2, 3, 4], args.len()),
+        };
+        let (n, step) = match partition_size(n, step) {
+            Ok(size) => size,
+            Err(condition) => return (*condition).clone(),
+        };
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        partition_seq(n, step, partial, Rc::clone(coll)).to_value()
+    }
+}
+
+/// The size and step of a partition, as the positive integers they must be
+pub(crate) fn partition_size(n: &Rc<Value>, step: &Rc<Value>) -> Result<(usize, usize), Rc<Value>> {
+    match (&**n, &**step) {
+        (Value::I32(n), Value::I32(step)) if *n > 0 && *step > 0 => {
+            Ok((*n as usize, *step as usize))
+        }
+        (Value::I32(_), Value::I32(_)) => Err(Rc::new(error_message::custom(
+            "partition size and step must be positive",
+        ))),
+        (Value::I32(_), _) => Err(Rc::new(error_message::type_mismatch(
+            TypeTag::Integer,
+            step,
+        ))),
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::Integer, n))),
+    }
+}
+
+/// What's done with partitions of fewer than n values, at the end of a coll
+#[derive(Clone)]
+pub(crate) enum Partial {
+    /// They're left off
+    Drop,
+    /// The first is filled out with the values of a pad, and the rest left off
+    Pad(Rc<Value>),
+    /// They're kept, as partition-all does
+    Keep,
+}
+
+/// The lazy seq of lists of n values of coll, each step values after the last
+pub(crate) fn partition_seq(n: usize, step: usize, partial: Partial, coll: Rc<Value>) -> LazySeq {
+    LazySeq::new(move || {
+        let coll = seq::seq(&coll);
+        if let Value::Nil | Value::Condition(_) = &*coll {
+            return coll;
+        }
+        let mut partition = seq::iter(&coll).take(n).collect::<Vec<Rc<Value>>>();
+        if let Some(condition) = partition.iter().find(|value| value.non_tail().is_some()) {
+            return Rc::clone(condition);
+        }
+        if partition.len() < n {
+            match &partial {
+                Partial::Drop => return Rc::new(Value::Nil),
+                Partial::Pad(pad) => {
+                    partition.extend(seq::iter(pad).take(n - partition.len()));
+                    let partition = partition.into_iter().collect::<PersistentList>();
+                    return Cons::new(partition.to_rc_value(), Rc::new(Value::Nil)).to_rc_value();
+                }
+                Partial::Keep => {}
+            }
+        }
+        let mut rest = coll;
+        for _ in 0..step {
+            rest = seq::more(&rest);
+        }
+        let partition = partition.into_iter().collect::<PersistentList>();
+        let rest = partition_seq(n, step, partial, rest);
+        Cons::new(partition.to_rc_value(), rest.to_rc_value()).to_rc_value()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    mod partition_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{PartitionFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+
+        #[test]
+        fn partition_arities() {
+            let partition = PartitionFn {};
+            assert_eq!(
+                read("((1 2) (3 4))"),
+                partition.invoke(read_args(&["2", "[1 2 3 4 5]"]))
+            );
+            assert_eq!(
+                read("((1 2) (2 3) (3 4))"),
+                partition.invoke(read_args(&["2", "1", "[1 2 3 4]"]))
+            );
+            assert_eq!(
+                read("((1 2 3) (4 :a))"),
+                partition.invoke(read_args(&["3", "3", "[:a]", "[1 2 3 4]"]))
+            );
+            assert_eq!(read("()"), partition.invoke(read_args(&["2", "nil"])));
+        }
+
+        #[test]
+        fn partition_is_lazy() {
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let pairs = PartitionFn {}.invoke(vec![2.to_rc_value(), naturals]);
+            assert_eq!(
+                read("((0 1) (2 3))"),
+                TakeFn {}.invoke(vec![2.to_rc_value(), pairs.to_rc_value()])
+            );
+        }
+    }
+}
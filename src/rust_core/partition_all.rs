@@ -0,0 +1,143 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_vector::PersistentVector;
+use crate::protocols;
+use crate::rust_core::partition::{partition_seq, partition_size, Partial};
+use crate::rust_core::reduce::unreduced;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (partition-all n)
+/// (partition-all n coll)
+/// (partition-all n step coll)
+///
+/// As partition, but keeping the partitions of fewer than n values at the end of coll
+#[derive(Debug, Clone)]
+pub struct PartitionAllFn {}
+impl ToValue for PartitionAllFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PartitionAllFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (n, step, coll) = match args.as_slice() {
+            [n] => (n, n, None),
+            [n, coll] => (n, n, Some(coll)),
+            [n, step, coll] => (n, step, Some(coll)),
+            _ => return error_message::wrong_varg_count(&[1, 2, 3], args.len()),
+        };
+        let (n, step) = match partition_size(n, step) {
+            Ok(size) => size,
+            Err(condition) => return (*condition).clone(),
+        };
+        match coll {
+            Some(coll) if seq::is_seqable(coll) => {
+                partition_seq(n, step, Partial::Keep, Rc::clone(coll)).to_value()
+            }
+            Some(coll) => error_message::type_mismatch(TypeTag::ISeq, coll),
+            None => Transducer::new(PartitionAllXForm { n }).to_value(),
+        }
+    }
+}
+
+/// (partition-all n), the transducer stepping in vectors of every n inputs;  its state is
+/// the inputs of the partition so far
+#[derive(Debug, Clone)]
+pub struct PartitionAllXForm {
+    n: usize,
+}
+impl XForm for PartitionAllXForm {
+    type State = Vec<Rc<Value>>;
+    fn step(
+        &self,
+        state: &RefCell<Vec<Rc<Value>>>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        state.borrow_mut().push(input);
+        if state.borrow().len() < self.n {
+            return result;
+        }
+        let partition = state
+            .replace(vec![])
+            .into_iter()
+            .collect::<PersistentVector>();
+        Rc::new(rf.invoke(vec![result, partition.to_rc_value()]))
+    }
+    fn complete(
+        &self,
+        state: &RefCell<Vec<Rc<Value>>>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+    ) -> Rc<Value> {
+        let mut result = result;
+        if !state.borrow().is_empty() {
+            let partition = state
+                .replace(vec![])
+                .into_iter()
+                .collect::<PersistentVector>();
+            result = unreduced(Rc::new(rf.invoke(vec![result, partition.to_rc_value()])));
+            if let Some(interruption) = result.non_tail() {
+                return interruption;
+            }
+        }
+        Rc::new(rf.invoke(vec![result]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod partition_all_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{CompFn, IntoFn, PartitionAllFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn partition_all_colls() {
+            let partition_all = PartitionAllFn {};
+            assert_eq!(
+                read("((1 2) (3 4) (5))"),
+                partition_all.invoke(read_args(&["2", "[1 2 3 4 5]"]))
+            );
+            assert_eq!(
+                read("((1 2 3) (2 3 4) (3 4) (4))"),
+                partition_all.invoke(read_args(&["3", "1", "[1 2 3 4]"]))
+            );
+            assert_eq!(read("()"), partition_all.invoke(read_args(&["2", "nil"])));
+        }
+
+        #[test]
+        fn partition_all_transducer() {
+            let into = IntoFn {};
+            let pairs = PartitionAllFn {}.invoke(read_args(&["2"])).to_rc_value();
+            assert_eq!(
+                read("[[1 2] [3 4] [5]]"),
+                into.invoke(vec![
+                    Rc::new(read("[]")),
+                    Rc::clone(&pairs),
+                    Rc::new(read("[1 2 3 4 5]"))
+                ])
+            );
+            let xform = CompFn {}.invoke(vec![
+                TakeFn {}.invoke(read_args(&["3"])).to_rc_value(),
+                pairs,
+            ]);
+            assert_eq!(
+                read("[[0 1] [2]]"),
+                into.invoke(vec![
+                    Rc::new(read("[]")),
+                    xform.to_rc_value(),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use crate::cons::Cons;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::persistent_list::PersistentList;
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::rust_core::reduce::unreduced;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (partition-by f coll)
+///
+/// The lazy seq of lists of the values of coll, split each time f gives back something new
+#[derive(Debug, Clone)]
+pub struct PartitionByFn {}
+impl ToValue for PartitionByFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PartitionByFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let f = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(PartitionByXForm { f }).to_value();
+        }
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        partition_by_seq(f, Rc::clone(coll)).to_value()
+    }
+}
+
+fn partition_by_seq(f: protocols::IFn, coll: Rc<Value>) -> LazySeq {
+    LazySeq::new(move || {
+        let mut rest = seq::seq(&coll);
+        if let Value::Nil | Value::Condition(_) = &*rest {
+            return rest;
+        }
+        let mut partition = vec![];
+        let mut partition_key = None;
+        loop {
+            let value = seq::first(&rest);
+            let key = Rc::new(f.invoke(vec![Rc::clone(&value)]));
+            if let Some(interruption) = key.non_tail() {
+                return interruption;
+            }
+            match &partition_key {
+                Some(partition_key) if *partition_key != key => break,
+                Some(_) => {}
+                None => partition_key = Some(key),
+            }
+            partition.push(value);
+            rest = seq::seq(&seq::more(&rest));
+            match &*rest {
+                Value::Nil => break,
+                Value::Condition(_) => return rest,
+                _ => {}
+            }
+        }
+        let partition = partition.into_iter().collect::<PersistentList>();
+        let rest = partition_by_seq(f, rest);
+        Cons::new(partition.to_rc_value(), rest.to_rc_value()).to_rc_value()
+    })
+}
+
+/// (partition-by f), the transducer stepping in vectors of the inputs, split each time f
+/// gives back something new;  its state is the inputs of the partition so far, and what f
+/// gave back for them
+#[derive(Debug, Clone)]
+pub struct PartitionByXForm {
+    f: protocols::IFn,
+}
+impl XForm for PartitionByXForm {
+    type State = (Vec<Rc<Value>>, Option<Rc<Value>>);
+    fn step(
+        &self,
+        state: &RefCell<Self::State>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let key = Rc::new(self.f.invoke(vec![Rc::clone(&input)]));
+        if let Some(interruption) = key.non_tail() {
+            return interruption;
+        }
+        let mut state = state.borrow_mut();
+        let (partition, partition_key) = &mut *state;
+        let mut result = result;
+        if partition_key
+            .as_ref()
+            .is_some_and(|partition_key| *partition_key != key)
+        {
+            let full = std::mem::take(partition)
+                .into_iter()
+                .collect::<PersistentVector>();
+            result = Rc::new(rf.invoke(vec![result, full.to_rc_value()]));
+        }
+        *partition_key = Some(key);
+        if result.non_tail().is_none() && !matches!(&*result, Value::Reduced(_)) {
+            partition.push(input);
+        }
+        result
+    }
+    fn complete(
+        &self,
+        state: &RefCell<Self::State>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+    ) -> Rc<Value> {
+        let (partition, _) = state.replace((vec![], None));
+        let mut result = result;
+        if !partition.is_empty() {
+            let partition = partition.into_iter().collect::<PersistentVector>();
+            result = unreduced(Rc::new(rf.invoke(vec![result, partition.to_rc_value()])));
+            if let Some(interruption) = result.non_tail() {
+                return interruption;
+            }
+        }
+        Rc::new(rf.invoke(vec![result]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod partition_by_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, PartitionByFn, RangeFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn is_one() -> Rc<Value> {
+            Rc::new(eval_str("(fn [x] (= x 1))"))
+        }
+
+        #[test]
+        fn partition_by_colls() {
+            let partition_by = PartitionByFn {};
+            assert_eq!(
+                read("((1 1) (2) (1 1 1))"),
+                partition_by.invoke(vec![is_one(), Rc::new(read("[1 1 2 1 1 1]"))])
+            );
+            assert_eq!(
+                read("()"),
+                partition_by.invoke(vec![is_one(), Rc::new(read("[]"))])
+            );
+            assert_eq!(
+                read("((0 1 2) (3 4 5))"),
+                partition_by.invoke(vec![
+                    Rc::new(eval_str("(fn [x] (lt x 3))")),
+                    RangeFn {}.invoke(read_args(&["6"])).to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn partition_by_transducer() {
+            assert_eq!(
+                read("[[1 1] [2] [1]]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    PartitionByFn {}.invoke(vec![is_one()]).to_rc_value(),
+                    Rc::new(read("[1 1 2 1]"))
+                ])
+            );
+        }
+    }
+}
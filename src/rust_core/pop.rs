@@ -0,0 +1,63 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::PersistentList::{Cons, Empty};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (pop coll)
+///
+/// coll without the value conj would have added last;  the end of a vector, the front of a list
+#[derive(Debug, Clone)]
+pub struct PopFn {}
+impl ToValue for PopFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PopFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Nil => Value::Nil,
+            Value::PersistentVector(pvector) => match pvector.pop() {
+                Some(pvector) => Value::PersistentVector(pvector),
+                None => error_message::custom("Can't pop empty vector"),
+            },
+            Value::PersistentList(Cons(_, tail, _, _)) => tail.to_value(),
+            Value::PersistentList(Empty) => error_message::custom("Can't pop empty list"),
+            coll => error_message::type_mismatch(TypeTag::ISeq, coll),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod pop_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::PopFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn pop_each_coll() {
+            let pop = PopFn {};
+            assert_eq!(read("[1 2]"), pop.invoke(read_args(&["[1 2 3]"])));
+            assert_eq!(read("(2 3)"), pop.invoke(read_args(&["(1 2 3)"])));
+            assert_eq!(Value::Nil, pop.invoke(read_args(&["nil"])));
+        }
+
+        #[test]
+        fn pop_empty() {
+            let pop = PopFn {};
+            match pop.invoke(read_args(&["[]"])) {
+                Value::Condition(condition) => {
+                    assert_eq!("Can't pop empty vector", condition.message)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
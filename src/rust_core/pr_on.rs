@@ -0,0 +1,47 @@
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::printer;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (pr-on x writer)
+///
+/// Writes x to writer as pr would, readably unless *print-readably* is bound false;  what
+/// print-method does for anything without a method of its own
+#[derive(Debug, Clone)]
+pub struct PrOnFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrOnFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrOnFn {
+        PrOnFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PrOnFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PrOnFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (x, writer) = match args.as_slice() {
+            [x, writer] => (x, writer),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        let writer = match &**writer {
+            Value::Writer(writer) => writer,
+            _ => return error_message::type_mismatch(TypeTag::Writer, writer),
+        };
+        let readably = printer::print_readably(&self.enclosing_environment);
+        match printer::print_str(&self.enclosing_environment, x, readably)
+            .and_then(|printed| writer.write_str(&printed))
+        {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
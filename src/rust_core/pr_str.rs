@@ -0,0 +1,227 @@
+use crate::environment::Environment;
+use crate::ifn::IFn;
+use crate::printer;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (pr-str & xs)
+///
+/// xs as pr prints them, separated by spaces;  readably, unless *print-readably* is bound
+/// false
+#[derive(Debug, Clone)]
+pub struct PrStrFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrStrFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrStrFn {
+        PrStrFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PrStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PrStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let readably = printer::print_readably(&self.enclosing_environment);
+        print_all(&self.enclosing_environment, &args, readably, "")
+    }
+}
+
+/// (prn-str & xs)
+///
+/// As pr-str, followed by a newline
+#[derive(Debug, Clone)]
+pub struct PrnStrFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrnStrFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrnStrFn {
+        PrnStrFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PrnStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PrnStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let readably = printer::print_readably(&self.enclosing_environment);
+        print_all(&self.enclosing_environment, &args, readably, "\n")
+    }
+}
+
+/// (print-str & xs)
+///
+/// xs as print prints them, separated by spaces;  strings as they are, rather than readably
+#[derive(Debug, Clone)]
+pub struct PrintStrFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrintStrFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrintStrFn {
+        PrintStrFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PrintStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PrintStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        print_all(&self.enclosing_environment, &args, false, "")
+    }
+}
+
+/// (println-str & xs)
+///
+/// As print-str, followed by a newline
+#[derive(Debug, Clone)]
+pub struct PrintlnStrFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrintlnStrFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrintlnStrFn {
+        PrintlnStrFn {
+            enclosing_environment,
+        }
+    }
+}
+impl ToValue for PrintlnStrFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for PrintlnStrFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        print_all(&self.enclosing_environment, &args, false, "\n")
+    }
+}
+
+/// xs printed one after the other, separated by spaces and followed by end
+fn print_all(environment: &Environment, xs: &[Rc<Value>], readably: bool, end: &str) -> Value {
+    let mut printed = vec![];
+    for x in xs {
+        match printer::print_str(environment, x, readably) {
+            Ok(x) => printed.push(x),
+            Err(condition) => return (*condition).clone(),
+        }
+    }
+    Value::String(printed.join(" ") + end)
+}
+
+#[cfg(test)]
+mod tests {
+    mod pr_str_tests {
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{PrStrFn, PrintStrFn, PrintlnStrFn, PrnStrFn, RangeFn, TakeFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn string(string: &str) -> Value {
+            Value::String(string.to_string())
+        }
+
+        #[test]
+        fn pr_str_prints_readably() {
+            let pr_str = PrStrFn::new(Environment::clojure_core_environment());
+            assert_eq!(string("\"a\""), pr_str.invoke(read_args(&["\"a\""])));
+            assert_eq!(
+                string("\"say \\\"hi\\\"\\n\\\\\""),
+                pr_str.invoke(read_args(&["\"say \\\"hi\\\"\\n\\\\\""]))
+            );
+            assert_eq!(
+                string("[1 2.0 \"b\" :c d nil] {:a (\"x\")} #{}"),
+                pr_str.invoke(read_args(&[
+                    "[1 2.0 \"b\" :c d nil]",
+                    "{:a (\"x\")}",
+                    "#{}"
+                ]))
+            );
+            assert_eq!(string(""), pr_str.invoke(vec![]));
+            let prn_str = PrnStrFn::new(Environment::clojure_core_environment());
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            let two = TakeFn {}
+                .invoke(vec![2.to_rc_value(), naturals])
+                .to_rc_value();
+            assert_eq!(string("(0 1)\n"), prn_str.invoke(vec![two]));
+        }
+
+        #[test]
+        fn print_str_prints_strings_as_they_are() {
+            let environment = Environment::clojure_core_environment();
+            let print_str = PrintStrFn::new(Rc::clone(&environment));
+            assert_eq!(
+                string("a [b \"c\"] 1.5"),
+                print_str.invoke(read_args(&["\"a\"", "[\"b\" \"\\\"c\\\"\"]", "1.5"]))
+            );
+            let println_str = PrintlnStrFn::new(environment);
+            assert_eq!(string("a\n"), println_str.invoke(read_args(&["\"a\""])));
+        }
+
+        #[test]
+        fn prn_and_println_through_out() {
+            assert_eq!(
+                string("\"a\" 1\na 1\n"),
+                eval_str("(with-out-str (prn \"a\" 1) (println \"a\" 1))")
+            );
+        }
+
+        #[test]
+        fn pr_str_unreadably() {
+            assert_eq!(
+                string("[a]"),
+                eval_str("(binding [*print-readably* false] (pr-str [\"a\"]))")
+            );
+        }
+
+        #[test]
+        fn pr_str_with_print_length_and_level() {
+            assert_eq!(
+                string("(0 1 ...) {:a [1 #]}"),
+                eval_str(
+                    "(binding [*print-length* 2 *print-level* 2]
+                       (pr-str (range) {:a [1 [2]]}))"
+                )
+            );
+        }
+
+        #[test]
+        fn pr_str_reads_back() {
+            assert_eq!(
+                Value::Boolean(true),
+                eval_str(
+                    "(let [data {:s \"q\\\"\\n\" :v [1.0 \"t\\tab\" #{:k}] :l '(x y)}]
+                       (= data (read-string (pr-str data))))"
+                )
+            );
+        }
+
+        #[test]
+        fn pr_str_with_print_method() {
+            assert_eq!(
+                string("[#point[1 \"a\"] {:x 1}]"),
+                eval_str(
+                    "(do (defmethod print-method :point [p writer]
+                           (print-string writer \"#point[\")
+                           (print-method (get p :x) writer)
+                           (print-string writer \" \")
+                           (print-method (get p :y) writer)
+                           (print-string writer \"]\"))
+                         (pr-str [(with-meta {:x 1 :y \"a\"} {:type :point}) {:x 1}]))"
+                )
+            );
+        }
+    }
+}
@@ -1,13 +1,25 @@
+use crate::environment::Environment;
+use crate::error_message;
 use crate::ifn::IFn;
+use crate::stream;
+use crate::type_tag::TypeTag;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
-use crate::error_message;
-
-/// Primitive printing function; TODO: should be aware of *out*
+/// Primitive printing function;  writes to *out*, or to writer if given one
 /// (defn print-string [string] .. prints single string without linebreak.. )
+/// (defn print-string [writer string] .. )
 #[derive(Debug, Clone)]
-pub struct PrintStringFn {}
+pub struct PrintStringFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl PrintStringFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> PrintStringFn {
+        PrintStringFn {
+            enclosing_environment,
+        }
+    }
+}
 impl ToValue for PrintStringFn {
     fn to_value(&self) -> Value {
         Value::IFn(Rc::new(self.clone()))
@@ -15,10 +27,68 @@ impl ToValue for PrintStringFn {
 }
 impl IFn for PrintStringFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        if args.len() != 1 {
-            return error_message::wrong_arg_count(1, args.len());
+        let (writer, string) = match args.as_slice() {
+            [string] => (stream::out(&self.enclosing_environment), string),
+            [writer, string] => match &**writer {
+                Value::Writer(writer) => (Ok(writer.clone()), string),
+                _ => return error_message::type_mismatch(TypeTag::Writer, writer),
+            },
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        match writer.and_then(|writer| writer.write_str(&string.to_string())) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod print_string_tests {
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{PrintStringFn, StrFn, StringWriterFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn print_string_to_out() {
+            assert_eq!(
+                Value::String("a [1 2]".to_string()),
+                eval_str(
+                    "(let [out (string-writer)]
+                       (do (binding [*out* out]
+                             (print-string \"a \")
+                             (print-string [1 2]))
+                           (str out)))"
+                )
+            );
+        }
+
+        #[test]
+        fn print_string_to_writer() {
+            let print_string = PrintStringFn::new(Environment::clojure_core_environment());
+            let writer = StringWriterFn {}.invoke(vec![]).to_rc_value();
+            for string in read_args(&["\"a\"", "[1 2]"]) {
+                assert_eq!(
+                    Value::Nil,
+                    print_string.invoke(vec![Rc::clone(&writer), string])
+                );
+            }
+            assert_eq!(
+                Value::String("a[1 2]".to_string()),
+                StrFn {}.invoke(vec![writer])
+            );
+        }
+
+        #[test]
+        fn print_string_to_non_writer() {
+            let print_string = PrintStringFn::new(Environment::clojure_core_environment());
+            match print_string.invoke(read_args(&["1", "\"a\""])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
         }
-        print!("{}", args.get(0).unwrap().to_string());
-        Value::Nil
     }
 }
@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::error_message;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
@@ -22,7 +23,7 @@ impl IFn for RandFn {
                 match arg {
                     Value::I32(i_) => Value::F64(thread_rng().gen_range(0.0, i_ as f64)),
                     Value::F64(f_) => Value::F64(thread_rng().gen_range(0.0, f_)),
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         arg.type_tag()
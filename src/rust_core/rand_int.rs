@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::error_message;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
@@ -21,7 +22,7 @@ impl IFn for RandIntFn {
                 match arg {
                     Value::I32(i_) => Value::I32(thread_rng().gen_range(0, i_)),
                     Value::F64(f_) => Value::I32(thread_rng().gen_range(0, f_ as i32)),
-                    _ => Value::Condition(format!(
+                    _ => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         arg.type_tag()
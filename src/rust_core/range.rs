@@ -0,0 +1,78 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (range)
+/// (range end)
+/// (range start end)
+/// (range start end step)
+///
+/// The lazy seq of the integers from start (or 0), up to but not including end, step
+/// (or 1) apart;  without an end, it goes on for as long as it's walked
+#[derive(Debug, Clone)]
+pub struct RangeFn {}
+impl ToValue for RangeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RangeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let mut ints = vec![];
+        for arg in args.iter() {
+            match **arg {
+                Value::I32(int) => ints.push(int),
+                _ => return error_message::type_mismatch(TypeTag::Integer, arg),
+            }
+        }
+        let (start, end, step) = match ints.as_slice() {
+            [] => (0, None, 1),
+            [end] => (0, Some(*end), 1),
+            [start, end] => (*start, Some(*end), 1),
+            [start, end, step] => (*start, Some(*end), *step),
+            _ => return error_message::wrong_varg_count(&[0, 1, 2, 3], args.len()),
+        };
+        let ints = std::iter::successors(Some(start), move |int| int.checked_add(step))
+            .take_while(move |int| match end {
+                Some(end) if step >= 0 => *int < end,
+                Some(end) => *int > end,
+                None => true,
+            })
+            .map(|int| Value::I32(int).to_rc_value());
+        LazySeq::from_iter(ints).to_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod range_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+
+        #[test]
+        fn range_arities() {
+            let range = RangeFn {};
+            assert_eq!(read("(0 1 2)"), range.invoke(read_args(&["3"])));
+            assert_eq!(read("(2 3)"), range.invoke(read_args(&["2", "4"])));
+            assert_eq!(
+                read("(10 7 4)"),
+                range.invoke(read_args(&["10", "2", "-3"]))
+            );
+            assert_eq!(read("()"), range.invoke(read_args(&["4", "2"])));
+        }
+
+        #[test]
+        fn range_without_end() {
+            let naturals = RangeFn {}.invoke(vec![]).to_rc_value();
+            assert_eq!(
+                read("(0 1 2)"),
+                TakeFn {}.invoke(vec![3.to_rc_value(), naturals])
+            );
+        }
+    }
+}
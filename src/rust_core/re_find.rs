@@ -0,0 +1,86 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::matcher::Matcher;
+use crate::rust_core::re_matcher::matcher;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-find m) (re-find re s)
+///
+/// The next match of matcher m, or the first of re in s;  a string if re has no groups, else
+/// a vector of the match and its groups, and nil if there's no match
+#[derive(Debug, Clone)]
+pub struct ReFindFn {}
+impl ToValue for ReFindFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReFindFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.len() {
+            1 => match &*args[0] {
+                Value::Matcher(matcher) => find(matcher).unwrap_or(Value::Nil),
+                _ => error_message::type_mismatch(TypeTag::Matcher, &args[0]),
+            },
+            2 => match matcher(&args[0], &args[1]) {
+                Ok(matcher) => find(&matcher).unwrap_or(Value::Nil),
+                Err(condition) => (*condition).clone(),
+            },
+            _ => error_message::wrong_varg_count(&[1, 2], args.len()),
+        }
+    }
+}
+
+/// The groups of the next match of matcher, if there is one
+pub(crate) fn find(matcher: &Matcher) -> Option<Value> {
+    if matcher.find() {
+        matcher.groups().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_find_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{ReFindFn, ReMatcherFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn find_first_match() {
+            let re_find = ReFindFn {};
+            assert_eq!(
+                Value::String("123".to_string()),
+                re_find.invoke(read_args(&[r#"#"\d+""#, r#""abc123def456""#]))
+            );
+            assert_eq!(
+                Value::Nil,
+                re_find.invoke(read_args(&[r#"#"\d+""#, r#""abc""#]))
+            );
+            assert_eq!(
+                read(r#"["key=1" "key" "1"]"#),
+                re_find.invoke(read_args(&[r#"#"(\w+)=(\d+)?""#, r#""key=1""#]))
+            );
+            assert_eq!(
+                read(r#"["key=" "key" nil]"#),
+                re_find.invoke(read_args(&[r#"#"(\w+)=(\d+)?""#, r#""key=""#]))
+            );
+        }
+
+        #[test]
+        fn find_one_match_after_another() {
+            let re_find = ReFindFn {};
+            let matcher = ReMatcherFn {}
+                .invoke(read_args(&[r#"#"\d+""#, r#""a1b22""#]))
+                .to_rc_value();
+            assert_eq!(read(r#""1""#), re_find.invoke(vec![Rc::clone(&matcher)]));
+            assert_eq!(read(r#""22""#), re_find.invoke(vec![Rc::clone(&matcher)]));
+            assert_eq!(Value::Nil, re_find.invoke(vec![matcher]));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::rust_core::re_matcher;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-groups m)
+///
+/// The groups of the last match matcher m found, as re-find gave them
+#[derive(Debug, Clone)]
+pub struct ReGroupsFn {}
+impl ToValue for ReGroupsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReGroupsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Matcher(matcher) => matcher.groups().unwrap_or_else(|err| (*err).clone()),
+            _ => error_message::type_mismatch(TypeTag::Matcher, &args[0]),
+        }
+    }
+}
+
+/// (re-named-groups m) (re-named-groups re s)
+///
+/// The named groups of the last match matcher m found, or of the first match of re in s, as a
+/// map of keywords of their names to what they matched;  nil if re doesn't match s
+#[derive(Debug, Clone)]
+pub struct ReNamedGroupsFn {}
+impl ToValue for ReNamedGroupsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReNamedGroupsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let named_groups = match args.len() {
+            1 => match &*args[0] {
+                Value::Matcher(matcher) => matcher.named_groups(),
+                _ => return error_message::type_mismatch(TypeTag::Matcher, &args[0]),
+            },
+            2 => match re_matcher::matcher(&args[0], &args[1]) {
+                Ok(matcher) if matcher.find() => matcher.named_groups(),
+                Ok(_) => return Value::Nil,
+                Err(condition) => return (*condition).clone(),
+            },
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        named_groups.unwrap_or_else(|err| (*err).clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_groups_tests {
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::rust_core::{GetFn, ReFindFn, ReGroupsFn, ReMatcherFn, ReNamedGroupsFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn groups_of_last_match() {
+            let re_groups = ReGroupsFn {};
+            let matcher = ReMatcherFn {}
+                .invoke(read_args(&[r#"#"(\w)=(\d)""#, r#""a=1 b=2""#]))
+                .to_rc_value();
+            ReFindFn {}.invoke(vec![Rc::clone(&matcher)]);
+            ReFindFn {}.invoke(vec![Rc::clone(&matcher)]);
+            assert_eq!(read(r#"["b=2" "b" "2"]"#), re_groups.invoke(vec![matcher]));
+            let unmatched = ReMatcherFn {}.invoke(read_args(&[r#"#"\d""#, r#""a""#]));
+            match re_groups.invoke(vec![unmatched.to_rc_value()]) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IllegalStateException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn named_groups_as_map() {
+            let re_named_groups = ReNamedGroupsFn {};
+            let named_groups = re_named_groups
+                .invoke(read_args(&[
+                    r#"#"(?P<year>\d{4})-(?P<month>\d{2})(-(?P<day>\d{2}))?""#,
+                    r#""2020-01""#,
+                ]))
+                .to_rc_value();
+            let get = |key| GetFn {}.invoke(vec![Rc::clone(&named_groups), Rc::new(read(key))]);
+            assert_eq!(read(r#""2020""#), get(":year"));
+            assert_eq!(read(r#""01""#), get(":month"));
+            assert_eq!(Value::Nil, get(":day"));
+            assert_eq!(
+                Value::Nil,
+                re_named_groups.invoke(read_args(&[r#"#"(?P<year>\d{4})""#, r#""abc""#]))
+            );
+        }
+    }
+}
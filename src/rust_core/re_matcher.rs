@@ -0,0 +1,38 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::matcher::Matcher;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-matcher re s)
+///
+/// A matcher running re over s, for re-find to find one match after another in, and
+/// re-groups to give the groups of the last
+#[derive(Debug, Clone)]
+pub struct ReMatcherFn {}
+impl ToValue for ReMatcherFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReMatcherFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        match matcher(&args[0], &args[1]) {
+            Ok(matcher) => Value::Matcher(Rc::new(matcher)),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// A matcher running re over s;  Err if either isn't what it should be
+pub(crate) fn matcher(re: &Value, s: &Value) -> Result<Matcher, Rc<Value>> {
+    match (re, s) {
+        (Value::Pattern(re), Value::String(s)) => Ok(Matcher::new(re.clone(), s.clone())),
+        (Value::Pattern(_), _) => Err(Rc::new(error_message::type_mismatch(TypeTag::String, s))),
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::Pattern, re))),
+    }
+}
@@ -0,0 +1,82 @@
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::matcher;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-matches re s)
+///
+/// As re-find, but only if re matches the whole of s;  nil if it doesn't
+#[derive(Debug, Clone)]
+pub struct ReMatchesFn {}
+impl ToValue for ReMatchesFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReMatchesFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let (re, s) = match (&*args[0], &*args[1]) {
+            (Value::Pattern(re), Value::String(s)) => (re, s),
+            (Value::Pattern(_), _) => {
+                return error_message::type_mismatch(TypeTag::String, &args[1])
+            }
+            _ => return error_message::type_mismatch(TypeTag::Pattern, &args[0]),
+        };
+        // Anchored at both ends, the leftmost match is the whole of s if any is;  wrapping re
+        // leaves its groups numbered as they were
+        let whole = match regex::Regex::new(&format!(r"\A(?:{})\z", re.as_str())) {
+            Ok(whole) => whole,
+            Err(err) => {
+                return error_message::condition(
+                    ConditionKind::IllegalArgumentException,
+                    err.to_string(),
+                )
+            }
+        };
+        match whole.captures(s) {
+            Some(captures) => matcher::groups(
+                captures
+                    .iter()
+                    .map(|group| group.map(|group| group.as_str())),
+            ),
+            None => Value::Nil,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_matches_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::ReMatchesFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn match_whole_string() {
+            let re_matches = ReMatchesFn {};
+            assert_eq!(
+                Value::String("123".to_string()),
+                re_matches.invoke(read_args(&[r#"#"\d+""#, r#""123""#]))
+            );
+            assert_eq!(
+                Value::Nil,
+                re_matches.invoke(read_args(&[r#"#"\d+""#, r#""123a""#]))
+            );
+            assert_eq!(
+                Value::String("ab".to_string()),
+                re_matches.invoke(read_args(&[r#"#"a|ab""#, r#""ab""#]))
+            );
+            assert_eq!(
+                read(r#"["2020-01" "2020" "01"]"#),
+                re_matches.invoke(read_args(&[r#"#"(\d{4})-(\d{2})""#, r#""2020-01""#]))
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-pattern s)
+///
+/// The regex s is the source of, as #"s" would read it;  a regex is given back as it is
+#[derive(Debug, Clone)]
+pub struct RePatternFn {}
+impl ToValue for RePatternFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RePatternFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Pattern(_) => (*args[0]).clone(),
+            Value::String(s) => match regex::Regex::new(s) {
+                Ok(re) => Value::Pattern(re),
+                Err(err) => error_message::condition(
+                    ConditionKind::IllegalArgumentException,
+                    err.to_string(),
+                ),
+            },
+            _ => error_message::type_mismatch(TypeTag::String, &args[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_pattern_tests {
+        use crate::condition::ConditionKind;
+        use crate::ifn::IFn;
+        use crate::rust_core::RePatternFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn pattern_of_string() {
+            let re_pattern = RePatternFn {};
+            assert_eq!(
+                Value::Pattern(regex::Regex::new(r"\d+").unwrap()),
+                re_pattern.invoke(read_args(&[r#""\\d+""#]))
+            );
+            assert_eq!(
+                read(r#"#"\d+""#),
+                re_pattern.invoke(read_args(&[r#"#"\d+""#]))
+            );
+        }
+
+        #[test]
+        fn refuse_invalid_regex() {
+            let re_pattern = RePatternFn {};
+            match re_pattern.invoke(read_args(&[r#""(""#])) {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::IllegalArgumentException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::ToPersistentList;
+use crate::rust_core::re_find::find;
+use crate::rust_core::re_matcher::matcher;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (re-seq re s)
+///
+/// Every match of re in s, one after another as re-find finds them, or nil if there are none
+#[derive(Debug, Clone)]
+pub struct ReSeqFn {}
+impl ToValue for ReSeqFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReSeqFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let matcher = match matcher(&args[0], &args[1]) {
+            Ok(matcher) => matcher,
+            Err(condition) => return (*condition).clone(),
+        };
+        let found = std::iter::from_fn(|| find(&matcher))
+            .map(Rc::new)
+            .collect::<Vec<Rc<Value>>>();
+        if found.is_empty() {
+            return Value::Nil;
+        }
+        found.into_list_value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod re_seq_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::ReSeqFn;
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn seq_of_matches() {
+            let re_seq = ReSeqFn {};
+            assert_eq!(
+                read(r#"("1" "22" "333")"#),
+                re_seq.invoke(read_args(&[r#"#"\d+""#, r#""a1b22c333""#]))
+            );
+            assert_eq!(
+                read(r#"(["a=1" "a" "1"] ["b=2" "b" "2"])"#),
+                re_seq.invoke(read_args(&[r#"#"(\w)=(\d)""#, r#""a=1, b=2""#]))
+            );
+            assert_eq!(
+                read(r#"("" "b" "" "")"#),
+                re_seq.invoke(read_args(&[r#"#"b*""#, r#""abc""#]))
+            );
+        }
+
+        #[test]
+        fn nil_without_matches() {
+            let re_seq = ReSeqFn {};
+            assert_eq!(
+                Value::Nil,
+                re_seq.invoke(read_args(&[r#"#"\d""#, r#""abc""#]))
+            );
+        }
+    }
+}
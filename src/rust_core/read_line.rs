@@ -1,16 +1,23 @@
+use crate::environment::Environment;
+use crate::error_message;
 use crate::ifn::IFn;
+use crate::stream;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
-use std::io;
-
-use crate::error_message;
-use nom::lib::std::convert::TryFrom;
-
-/// Read a line from stdin TODO: should be aware of *in*
+/// Read a line from *in*, or nil if there's nothing left to read
 /// (defn read-line [])
 #[derive(Debug, Clone)]
-pub struct ReadLineFn {}
+pub struct ReadLineFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl ReadLineFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> ReadLineFn {
+        ReadLineFn {
+            enclosing_environment,
+        }
+    }
+}
 impl ToValue for ReadLineFn {
     fn to_value(&self) -> Value {
         Value::IFn(Rc::new(self.clone()))
@@ -18,16 +25,31 @@ impl ToValue for ReadLineFn {
 }
 impl IFn for ReadLineFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        if args.len() != 0 {
+        if !args.is_empty() {
             return error_message::wrong_arg_count(0, args.len());
         }
-        let mut input = String::new();
-        match io::stdin().read_line(&mut input) {
-            Ok(_) => {
-                input.pop();
-                Value::String(input)
-            }
-            Err(error) => error_message::generic_err(Box::try_from(error).unwrap()),
+        match stream::input(&self.enclosing_environment).and_then(|input| input.read_line()) {
+            Ok(Some(line)) => Value::String(line),
+            Ok(None) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod read_line_tests {
+        use crate::test_util::{eval_str, read};
+
+        #[test]
+        fn read_line_from_in() {
+            assert_eq!(
+                read("[\"a\" \"b\" nil]"),
+                eval_str(
+                    "(binding [*in* (string-reader \"a\\nb\")]
+                       [(read-line) (read-line) (read-line)])"
+                )
+            );
         }
     }
 }
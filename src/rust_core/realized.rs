@@ -0,0 +1,27 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (realized? x)
+///
+/// Whether the lazy seq x has had its values asked for yet
+#[derive(Debug, Clone)]
+pub struct RealizedFn {}
+impl ToValue for RealizedFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RealizedFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::LazySeq(lazy_seq) => Value::Boolean(lazy_seq.is_realized()),
+            value => error_message::type_mismatch(TypeTag::LazySeq, value),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (reduce f coll)
+/// (reduce f init coll)
+///
+/// Calls f on init (or the first value of coll) and the next value of coll, then on that
+/// result and the value after, and so on, giving back the last result;  stopping early if
+/// f gives back a (reduced ..) value.  Without an init, an empty coll is (f) and a coll of
+/// one value is that value
+#[derive(Debug, Clone)]
+pub struct ReduceFn {}
+impl ToValue for ReduceFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReduceFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (f, init, coll) = match args.as_slice() {
+            [f, coll] => (f, None, coll),
+            [f, init, coll] => (f, Some(Rc::clone(init)), coll),
+            _ => return error_message::wrong_varg_count(&[2, 3], args.len()),
+        };
+        let f = match f.try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, f),
+        };
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        let mut values = seq::iter(coll);
+        let init = match init {
+            Some(init) => init,
+            None => match values.next() {
+                Some(first) => first,
+                None => return f.invoke(vec![]),
+            },
+        };
+        unreduced(reduce(&f, init, values)).to_value()
+    }
+}
+
+/// Reduces values with f, starting from init;  stops at the first (reduced ..) result, which
+/// is given back still wrapped, so that callers can tell they were cut short
+pub(crate) fn reduce<I: Iterator<Item = Rc<Value>>>(
+    f: &dyn IFn,
+    init: Rc<Value>,
+    values: I,
+) -> Rc<Value> {
+    let mut acc = init;
+    if let Some(interruption) = acc.non_tail() {
+        return interruption;
+    }
+    for value in values {
+        if let Some(interruption) = value.non_tail() {
+            return interruption;
+        }
+        acc = Rc::new(f.invoke(vec![acc, value]));
+        if let Some(interruption) = acc.non_tail() {
+            return interruption;
+        }
+        if let Value::Reduced(_) = &*acc {
+            break;
+        }
+    }
+    acc
+}
+
+/// value itself, or what it wraps if it's a (reduced ..) value
+pub(crate) fn unreduced(value: Rc<Value>) -> Rc<Value> {
+    match &*value {
+        Value::Reduced(inner) => Rc::clone(inner),
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod reduce_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddFn, ConjFn, RangeFn, ReduceFn};
+        use crate::test_util::{eval_str, read};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn sum(args: &[&str]) -> Value {
+            let mut args = args
+                .iter()
+                .map(|arg| Rc::new(read(arg)))
+                .collect::<Vec<_>>();
+            args.insert(0, AddFn {}.to_rc_value());
+            ReduceFn {}.invoke(args)
+        }
+
+        #[test]
+        fn reduce_with_and_without_init() {
+            assert_eq!(Value::I32(10), sum(&["[1 2 3 4]"]));
+            assert_eq!(Value::I32(15), sum(&["5", "(1 2 3 4)"]));
+            assert_eq!(Value::I32(0), sum(&["[]"]));
+            assert_eq!(Value::I32(7), sum(&["[7]"]));
+            assert_eq!(Value::I32(7), sum(&["7", "nil"]));
+            assert_eq!(
+                read("[3 2 1]"),
+                ReduceFn {}.invoke(vec![
+                    ConjFn {}.to_rc_value(),
+                    Rc::new(read("[3]")),
+                    Rc::new(read("[2 1]"))
+                ])
+            );
+        }
+
+        #[test]
+        fn reduce_stops_at_reduced() {
+            assert_eq!(
+                Value::I32(6),
+                ReduceFn {}.invoke(vec![
+                    Rc::new(eval_str(
+                        "(fn [acc x] (if (gt x 3) (reduced acc) (+ acc x)))"
+                    )),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn reduce_propagates_conditions() {
+            match sum(&["[1 :a 2]"]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (reduced x)
+///
+/// x, wrapped so that a reduce given it as a result stops there and returns x
+#[derive(Debug, Clone)]
+pub struct ReducedFn {}
+impl ToValue for ReducedFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReducedFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        Value::Reduced(Rc::clone(&args[0]))
+    }
+}
+
+/// (reduced? x)
+#[derive(Debug, Clone)]
+pub struct IsReducedFn {}
+impl ToValue for IsReducedFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for IsReducedFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        Value::Boolean(matches!(&*args[0], Value::Reduced(_)))
+    }
+}
+
+/// (unreduced x)
+///
+/// What x wraps if it's reduced, and otherwise x
+#[derive(Debug, Clone)]
+pub struct UnreducedFn {}
+impl ToValue for UnreducedFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for UnreducedFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Reduced(value) => (**value).clone(),
+            value => value.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod reduced_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IsReducedFn, ReducedFn, UnreducedFn};
+        use crate::test_util::read_args;
+        use crate::value::{ToValue, Value};
+
+        #[test]
+        fn reduced_wraps_and_unwraps() {
+            let reduced = ReducedFn {}.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(
+                Value::Boolean(true),
+                IsReducedFn {}.invoke(vec![reduced.clone()])
+            );
+            assert_eq!(
+                Value::Boolean(false),
+                IsReducedFn {}.invoke(read_args(&["1"]))
+            );
+            assert_eq!(Value::I32(1), UnreducedFn {}.invoke(vec![reduced]));
+            assert_eq!(Value::I32(2), UnreducedFn {}.invoke(read_args(&["2"])));
+        }
+    }
+}
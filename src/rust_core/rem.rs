@@ -1,3 +1,4 @@
+use crate::condition::ConditionKind;
 use crate::ifn::IFn;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
@@ -19,28 +20,41 @@ impl IFn for RemFn {
             2 => {
                 match args.get(0).unwrap().to_value() {
                     Value::I32(a_) => match args.get(1).unwrap().to_value() {
-                        Value::I32(0) => Value::Condition("Divide by zero".to_string()),
-                        Value::F64(b_) if b_ == 0.0 => Value::Condition("Divide by zero".to_string()),
+                        Value::I32(0) | Value::I64(0) => error_message::divide_by_zero(),
+                        Value::F64(b_) if b_ == 0.0 => error_message::divide_by_zero(),
                         Value::I32(b_) => Value::I32(a_ % b_),
+                        Value::I64(b_) => Value::I64(i64::from(a_) % b_),
                         Value::F64(b_) => Value::F64(a_ as f64 % b_),
-                        _b => Value::Condition(format!(
+                        _b => error_message::condition(ConditionKind::ClassCastException, format!(
+                            // TODO: what error message should be returned regarding using typetags?
+                            "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
+                            _b.type_tag()
+                        )),
+                    },
+                    Value::I64(a_) => match args.get(1).unwrap().to_value() {
+                        Value::I32(0) | Value::I64(0) | Value::F64(0.0) => error_message::divide_by_zero(),
+                        Value::I32(b_) => Value::I64(a_ % i64::from(b_)),
+                        Value::I64(b_) => Value::I64(a_ % b_),
+                        Value::F64(b_) => Value::F64(a_ as f64 % b_),
+                        _b => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             _b.type_tag()
                         )),
                     },
                     Value::F64(a_) => match args.get(1).unwrap().to_value() {
-                        Value::I32(0) => Value::Condition("Divide by zero".to_string()),
-                        Value::F64(b_) if b_ == 0.0 => Value::Condition("Divide by zero".to_string()),
+                        Value::I32(0) | Value::I64(0) => error_message::divide_by_zero(),
+                        Value::F64(b_) if b_ == 0.0 => error_message::divide_by_zero(),
                         Value::I32(b_) => Value::F64(a_ % b_ as f64),
+                        Value::I64(b_) => Value::F64(a_ % b_ as f64),
                         Value::F64(b_) => Value::F64(a_ % b_),
-                        _b => Value::Condition(format!(
+                        _b => error_message::condition(ConditionKind::ClassCastException, format!(
                             // TODO: what error message should be returned regarding using typetags?
                             "Type mismatch; Expecting: (i32 | i64 | f32 | f64), Found: {}",
                             _b.type_tag()
                         )),
                     },
-                    _a => Value::Condition(format!(
+                    _a => error_message::condition(ConditionKind::ClassCastException, format!(
                         // TODO: what error message should be returned regarding using typetags?
                         "Type mismatch: Expecting: (i32 | i64 | f32 | f64), Found: {}",
                         _a.type_tag()
@@ -55,6 +69,8 @@ impl IFn for RemFn {
 #[cfg(test)]
 mod tests {
     mod rem_tests {
+        use crate::condition::ConditionKind;
+        use crate::error_message;
         use crate::ifn::IFn;
         use crate::rust_core::rem::RemFn;
         use crate::value::Value;
@@ -65,7 +81,7 @@ mod tests {
             let rem = RemFn {};
             let args = vec![];
             assert_eq!(
-                Value::Condition(String::from(
+                error_message::condition(ConditionKind::ArityException, String::from(
                     "Wrong number of arguments given to function (Given: 0, Expected: 2)"
                 )),
                 rem.invoke(args)
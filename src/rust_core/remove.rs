@@ -0,0 +1,68 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::rust_core::filter::{filter_seq, filter_transducer};
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (remove pred coll)
+///
+/// The lazy seq of the values of coll that pred is falsey for
+#[derive(Debug, Clone)]
+pub struct RemoveFn {}
+impl ToValue for RemoveFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RemoveFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.len() {
+            1 => return filter_transducer(&args[0], false),
+            2 => {}
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        }
+        match filter_seq(&args[0], &args[1], false) {
+            Ok(lazy_seq) => lazy_seq.to_value(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod remove_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, RemoveFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn remove_colls() {
+            let remove = RemoveFn {};
+            assert_eq!(
+                read("(1 3 5)"),
+                remove.invoke(vec![
+                    Rc::new(eval_str("(fn [x] (= 0 (rem x 2)))")),
+                    Rc::new(read("[1 2 3 4 5]"))
+                ])
+            );
+            assert_eq!(
+                read("(2 3)"),
+                remove.invoke(read_args(&["#{1}", "[1 2 1 3]"]))
+            );
+        }
+
+        #[test]
+        fn remove_transducer() {
+            assert_eq!(
+                read("[2 3]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    RemoveFn {}.invoke(read_args(&["#{1}"])).to_rc_value(),
+                    Rc::new(read("[1 2 1 3]"))
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_vector::PersistentVector;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (reset! atom x)
+///
+/// Sets atom to x, whatever it was, giving back x
+#[derive(Debug, Clone)]
+pub struct ResetFn {}
+impl ToValue for ResetFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ResetFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match reset(&args) {
+            Ok((_, new)) => (*new).clone(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// (reset-vals! atom x)
+///
+/// As reset!, but gives back [old x]
+#[derive(Debug, Clone)]
+pub struct ResetValsFn {}
+impl ToValue for ResetValsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ResetValsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match reset(&args) {
+            Ok((old, new)) => Value::PersistentVector(PersistentVector::from(vec![old, new])),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+fn reset(args: &[Rc<Value>]) -> Result<(Rc<Value>, Rc<Value>), Rc<Value>> {
+    match args {
+        [atom, new] => match &**atom {
+            Value::Atom(atom) => Ok((atom.reset(Rc::clone(new))?, Rc::clone(new))),
+            _ => Err(Rc::new(error_message::type_mismatch(TypeTag::Atom, atom))),
+        },
+        _ => Err(Rc::new(error_message::wrong_arg_count(2, args.len()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod reset_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AtomFn, DerefFn, ResetFn, ResetValsFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn reset_sets_value() {
+            let a = AtomFn {}.invoke(read_args(&[":a"])).to_rc_value();
+            assert_eq!(
+                read(":b"),
+                ResetFn {}.invoke(vec![Rc::clone(&a), Rc::new(read(":b"))])
+            );
+            assert_eq!(read(":b"), DerefFn {}.invoke(vec![a]));
+            let a = AtomFn {}.invoke(read_args(&[":a"])).to_rc_value();
+            assert_eq!(
+                read("[:a :b]"),
+                ResetValsFn {}.invoke(vec![a, Rc::new(read(":b"))])
+            );
+        }
+
+        #[test]
+        fn reset_non_atom() {
+            let reset = ResetFn {};
+            match reset.invoke(read_args(&["1", "2"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::PersistentList;
+use crate::seq;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (reverse coll)
+///
+/// The values of coll, last first
+#[derive(Debug, Clone)]
+pub struct ReverseFn {}
+impl ToValue for ReverseFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ReverseFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match seq::values(&args[0]) {
+            Ok(values) => values
+                .into_iter()
+                .rev()
+                .collect::<PersistentList>()
+                .to_value(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod reverse_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{RangeFn, ReverseFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+
+        #[test]
+        fn reverse_colls() {
+            let reverse = ReverseFn {};
+            assert_eq!(read("(3 2 1)"), reverse.invoke(read_args(&["[1 2 3]"])));
+            assert_eq!(
+                read("(2 1 0)"),
+                reverse.invoke(vec![RangeFn {}.invoke(read_args(&["3"])).to_rc_value()])
+            );
+            assert_eq!(read("()"), reverse.invoke(read_args(&["nil"])));
+        }
+    }
+}
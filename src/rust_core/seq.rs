@@ -0,0 +1,24 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::seq;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (seq coll)
+///
+/// nil if coll is empty, otherwise a seq of its values
+#[derive(Debug, Clone)]
+pub struct SeqFn {}
+impl ToValue for SeqFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SeqFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        seq::seq(&args[0]).to_value()
+    }
+}
@@ -0,0 +1,109 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::persistent_list::PersistentList;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (sequence coll)
+/// (sequence xform coll)
+///
+/// The seq of the values of coll, or the empty list if there are none;  with an xform,
+/// the lazy seq of the values of coll as transformed by it
+#[derive(Debug, Clone)]
+pub struct SequenceFn {}
+impl ToValue for SequenceFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SequenceFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.as_slice() {
+            [coll] => match &*seq::seq(coll) {
+                Value::Nil => Value::PersistentList(PersistentList::Empty),
+                seq => seq.clone(),
+            },
+            [xform, coll] => (*transduced_seq(xform, coll)).clone(),
+            _ => error_message::wrong_varg_count(&[1, 2], args.len()),
+        }
+    }
+}
+
+/// The lazy seq of the values of coll as transformed by xform
+pub(crate) fn transduced_seq(xform: &Rc<Value>, coll: &Rc<Value>) -> Rc<Value> {
+    let xform = match xform.try_as_protocol::<protocols::IFn>() {
+        Some(xform) => xform,
+        None => return Rc::new(error_message::type_mismatch(TypeTag::IFn, xform)),
+    };
+    if !seq::is_seqable(coll) {
+        return Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll));
+    }
+    match transducer::iter(&xform, coll) {
+        Ok(values) => LazySeq::from_iter(values).to_rc_value(),
+        Err(condition) => condition,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod sequence_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{
+            CompFn, FilterFn, MapFn, PartitionAllFn, RangeFn, SequenceFn, TakeFn,
+        };
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn sequence_of_colls() {
+            let sequence = SequenceFn {};
+            assert_eq!(read("(1 2)"), sequence.invoke(read_args(&["[1 2]"])));
+            assert_eq!(read("()"), sequence.invoke(read_args(&["nil"])));
+        }
+
+        #[test]
+        fn sequence_with_xform() {
+            let sequence = SequenceFn {};
+            let xform = CompFn {}.invoke(vec![
+                MapFn {}
+                    .invoke(vec![Rc::new(eval_str("(fn [x] (+ x 1))"))])
+                    .to_rc_value(),
+                FilterFn {}
+                    .invoke(vec![Rc::new(eval_str("(fn [x] (= 0 (rem x 2)))"))])
+                    .to_rc_value(),
+            ]);
+            assert_eq!(
+                read("(2 4)"),
+                sequence.invoke(vec![xform.to_rc_value(), Rc::new(read("[1 2 3]"))])
+            );
+        }
+
+        #[test]
+        fn sequence_with_stateful_xform() {
+            let sequence = SequenceFn {};
+            let pairs = PartitionAllFn {}.invoke(read_args(&["2"])).to_rc_value();
+            let naturals = sequence.invoke(vec![
+                Rc::clone(&pairs),
+                RangeFn {}.invoke(vec![]).to_rc_value(),
+            ]);
+            assert_eq!(
+                read("([0 1] [2 3])"),
+                TakeFn {}.invoke(vec![2.to_rc_value(), naturals.to_rc_value()])
+            );
+            assert_eq!(
+                read("([0 1] [2])"),
+                sequence.invoke(vec![
+                    pairs,
+                    RangeFn {}.invoke(read_args(&["3"])).to_rc_value()
+                ])
+            );
+        }
+    }
+}
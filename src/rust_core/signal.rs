@@ -0,0 +1,31 @@
+use crate::condition_system;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (signal e)
+/// runs the handler-bind handlers matching condition e, innermost first, until one invokes a
+/// restart;  if they all decline, returns nil
+#[derive(Debug, Clone)]
+pub struct SignalFn {}
+impl ToValue for SignalFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SignalFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => match condition_system::signal(condition) {
+                Some(interruption) => interruption.to_value(),
+                None => Value::Nil,
+            },
+            _ => error_message::type_mismatch(TypeTag::Condition, &args[0]),
+        }
+    }
+}
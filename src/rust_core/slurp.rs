@@ -1,10 +1,13 @@
+use crate::clojure_data_json::options::KeywordOptions;
+use crate::clojure_java_io::encoding::Encoding;
 use crate::ifn::IFn;
+use crate::seq;
+use crate::stream;
 use crate::value::{ToValue, Value};
-use nom::lib::std::convert::TryFrom;
 use std::rc::Rc;
 
-use std::fs::File;
-use std::io::Read;
+use std::fs;
+use std::path::Path;
 
 use reqwest;
 use url::Url;
@@ -15,6 +18,9 @@ use crate::error_message;
 ///
 /// * Read a file provided (filename string) into a string (slurp "text.txt")
 /// * GET an URL into a string (slurp "http://www.example.com")
+/// * Read what's left of a reader into a string, closing it after
+///
+/// The only option is :encoding, the name of the encoding what's read is in, UTF-8 by default
 /// TODO: clojure.java.io works with following types: Reader, BufferedReader,
 /// TODO: InputStream, File, URI, URL, Socket, byte arrays, character arrays,
 /// TODO and String
@@ -30,37 +36,36 @@ impl ToValue for SlurpFn {
 
 impl IFn for SlurpFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        if !args.is_empty() {
-            let first_arg = &args.into_iter().next().unwrap().to_string();
-
-            let possible_url = Url::parse(first_arg);
-
-            match possible_url {
-                Ok(url) => {
-                    let rslt = reqwest::blocking::get(url.as_str());
-                    match rslt {
-                        Ok(res) => Value::String(res.text().unwrap()),
-                        Err(e) => error_message::generic_err(Box::try_from(e).unwrap()),
-                    }
-                }
-                Err(_) => {
-                    // try to find a file, if url parsing fails
-                    let filename = first_arg;
-                    let file_descriptor = File::open(filename);
-
-                    let mut f = match file_descriptor {
-                        Ok(file) => file,
-                        Err(e) => {
-                            return error_message::generic_err(Box::try_from(e).unwrap());
-                        }
-                    };
-                    let mut s = String::new();
-                    f.read_to_string(&mut s).unwrap();
-                    Value::String(s)
-                }
-            }
-        } else {
-            Value::Nil
+        if args.is_empty() {
+            return Value::Nil;
+        }
+        // core.clj's slurp hands us its opts as they are, a seq of them or nil
+        let options = args.get(1).map_or(vec![], |options| {
+            seq::iter(options).collect::<Vec<Rc<Value>>>()
+        });
+        match slurp(&args[0], &options) {
+            Ok(slurped) => Value::String(slurped),
+            Err(condition) => (*condition).clone(),
         }
     }
 }
+
+fn slurp(f: &Value, options: &[Rc<Value>]) -> Result<String, Rc<Value>> {
+    let encoding = Encoding::from_options(&KeywordOptions::new(options)?)?;
+    if let Value::Reader(reader) = f {
+        let slurped = reader.read_to_string();
+        reader.close();
+        return slurped;
+    }
+    let first_arg = f.to_string();
+    match Url::parse(&first_arg) {
+        Ok(url) => reqwest::blocking::get(url.as_str())
+            .and_then(|res| res.bytes())
+            .map(|bytes| encoding.decode(&bytes))
+            .map_err(|e| Rc::new(error_message::generic_err(Box::new(e)))),
+        // try to find a file, if url parsing fails
+        Err(_) => fs::read(&first_arg)
+            .map(|bytes| encoding.decode(&bytes))
+            .map_err(|error| stream::file_error(Path::new(&first_arg), error)),
+    }
+}
@@ -0,0 +1,81 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (some pred coll)
+///
+/// The first truthy value pred gives back for a value of coll, or nil if there's none
+#[derive(Debug, Clone)]
+pub struct SomeFn {}
+impl ToValue for SomeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SomeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 2 {
+            return error_message::wrong_arg_count(2, args.len());
+        }
+        let pred = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(pred) => pred,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        for value in seq::iter(coll) {
+            if value.non_tail().is_some() {
+                return (*value).clone();
+            }
+            let test = pred.invoke(vec![value]);
+            if test.is_truthy() {
+                return test;
+            }
+        }
+        Value::Nil
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod some_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{RangeFn, SomeFn};
+        use crate::test_util::{eval_str, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn some_colls() {
+            let some = SomeFn {};
+            assert_eq!(
+                Value::I32(2),
+                some.invoke(read_args(&[":a", "[{:b 1} {:a 2} {:a 3}]"]))
+            );
+            assert_eq!(
+                Value::I32(3),
+                some.invoke(read_args(&["#{3 4}", "[1 3 4]"]))
+            );
+            assert_eq!(Value::Nil, some.invoke(read_args(&["#{5}", "[1 3 4]"])));
+            assert_eq!(Value::Nil, some.invoke(read_args(&[":a", "nil"])));
+        }
+
+        #[test]
+        fn some_stops_at_the_first_truthy() {
+            assert_eq!(
+                Value::Boolean(true),
+                SomeFn {}.invoke(vec![
+                    Rc::new(eval_str("(fn [x] (gt x 10))")),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+    }
+}
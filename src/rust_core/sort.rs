@@ -0,0 +1,91 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::PersistentList;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::rust_core::compare::{compare, compare_by, not_comparable, sort_values};
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (sort coll)
+/// (sort comparator coll)
+///
+/// The values of coll in order, as compare (or comparator) orders them;  values ordered the
+/// same are left as they were
+#[derive(Debug, Clone)]
+pub struct SortFn {}
+impl ToValue for SortFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SortFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (comparator, coll) = match args.as_slice() {
+            [coll] => (None, coll),
+            [comparator, coll] => match comparator.try_as_protocol::<protocols::IFn>() {
+                Some(comparator) => (Some(comparator), coll),
+                None => return error_message::type_mismatch(TypeTag::IFn, comparator),
+            },
+            _ => return error_message::wrong_varg_count(&[1, 2], args.len()),
+        };
+        let values = match seq::values(coll) {
+            Ok(values) => values,
+            Err(condition) => return (*condition).clone(),
+        };
+        let sorted = match &comparator {
+            Some(comparator) => sort_values(values, &mut |x, y| compare_by(comparator, x, y)),
+            None => sort_values(values, &mut |x, y| {
+                compare(x, y).ok_or_else(|| Rc::new(not_comparable(x, y)))
+            }),
+        };
+        match sorted {
+            Ok(sorted) => sorted.into_iter().collect::<PersistentList>().to_value(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod sort_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{GtFn, SortFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn sort_by_compare() {
+            let sort = SortFn {};
+            assert_eq!(read("(1 2 3 4)"), sort.invoke(read_args(&["[3 1 4 2]"])));
+            assert_eq!(
+                read("(\"a\" \"b\")"),
+                sort.invoke(read_args(&["#{\"b\" \"a\"}"]))
+            );
+            assert_eq!(read("()"), sort.invoke(read_args(&["nil"])));
+            match sort.invoke(read_args(&["[1 :a]"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn sort_by_comparator() {
+            let sort = SortFn {};
+            assert_eq!(
+                read("(4 3 2 1)"),
+                sort.invoke(vec![GtFn {}.to_rc_value(), Rc::new(read("[3 1 4 2]"))])
+            );
+            assert_eq!(
+                read("(4 3 2 1)"),
+                sort.invoke(vec![
+                    Rc::new(eval_str("(fn [x y] (compare y x))")),
+                    Rc::new(read("[3 1 4 2]"))
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_list::PersistentList;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::rust_core::compare::{compare, compare_by, not_comparable, sort_values};
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (sort-by keyfn coll)
+/// (sort-by keyfn comparator coll)
+///
+/// The values of coll in order of what keyfn gives back for each, as compare (or
+/// comparator) orders those;  keyfn is called once per value
+#[derive(Debug, Clone)]
+pub struct SortByFn {}
+impl ToValue for SortByFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SortByFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (keyfn, comparator, coll) = match args.as_slice() {
+            [keyfn, coll] => (keyfn, None, coll),
+            [keyfn, comparator, coll] => match comparator.try_as_protocol::<protocols::IFn>() {
+                Some(comparator) => (keyfn, Some(comparator), coll),
+                None => return error_message::type_mismatch(TypeTag::IFn, comparator),
+            },
+            _ => return error_message::wrong_varg_count(&[2, 3], args.len()),
+        };
+        let keyfn = match keyfn.try_as_protocol::<protocols::IFn>() {
+            Some(keyfn) => keyfn,
+            None => return error_message::type_mismatch(TypeTag::IFn, keyfn),
+        };
+        let values = match seq::values(coll) {
+            Ok(values) => values,
+            Err(condition) => return (*condition).clone(),
+        };
+        let mut keyed = vec![];
+        for value in values.into_iter() {
+            let key = Rc::new(keyfn.invoke(vec![Rc::clone(&value)]));
+            if key.non_tail().is_some() {
+                return (*key).clone();
+            }
+            keyed.push((key, value));
+        }
+        let sorted = match &comparator {
+            Some(comparator) => {
+                sort_values(keyed, &mut |(x, _), (y, _)| compare_by(comparator, x, y))
+            }
+            None => sort_values(keyed, &mut |(x, _), (y, _)| {
+                compare(x, y).ok_or_else(|| Rc::new(not_comparable(x, y)))
+            }),
+        };
+        match sorted {
+            Ok(sorted) => sorted
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect::<PersistentList>()
+                .to_value(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod sort_by_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{GtFn, SecondFn, SortByFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::ToValue;
+        use std::rc::Rc;
+
+        #[test]
+        fn sort_by_keys() {
+            let sort_by = SortByFn {};
+            assert_eq!(
+                read("({:a 1} {:a 2} {:a 3})"),
+                sort_by.invoke(read_args(&[":a", "[{:a 3} {:a 1} {:a 2}]"]))
+            );
+            assert_eq!(
+                read("([:b 2] [:c 2] [:a 1])"),
+                sort_by.invoke(vec![
+                    SecondFn {}.to_rc_value(),
+                    GtFn {}.to_rc_value(),
+                    Rc::new(read("[[:a 1] [:b 2] [:c 2]]"))
+                ])
+            );
+        }
+    }
+}
@@ -7,11 +7,11 @@ Clojure Special Forms
 TODO: &
 TODO: monitor-exit
 TODO: case*
-TODO: try
+* try
 TODO: reify*
-TODO: finally
-TODO: loop*
-TODO: do
+* finally
+* loop
+* do
 TODO: letfn*
 TODO: if
 TODO: clojure.core/import*
@@ -19,13 +19,13 @@ TODO: new
 TODO: deftype*
 TODO: let*
 TODO: fn*
-TODO: recur
+* recur
 TODO: set!
 TODO: .
 * var
 TODO: quote
-TODO: catch
-TODO: throw
+* catch
+* throw
 TODO: monitor-enter
 TODO: def
 
@@ -0,0 +1,72 @@
+use crate::clojure_java_io::writer;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (spit f content & opts)
+///
+/// Writes content, as str has it, to the file f (or writer), closing it after;  opts are as
+/// for clojure.java.io/writer, so (spit f content :append true) adds to what's there
+#[derive(Debug, Clone)]
+pub struct SpitFn {}
+impl ToValue for SpitFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SpitFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() < 2 {
+            return error_message::wrong_variadic_arg_count(&[], 2, args.len());
+        }
+        let spat = writer::writer(&args[0], &args[2..]).and_then(|writer| {
+            let written = writer.write_str(&args[1].to_string());
+            // Closed even if the write failed, though that's the error we give back
+            let closed = writer.close();
+            written.and(closed)
+        });
+        match spat {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod spit_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{SlurpFn, SpitFn};
+        use crate::test_util::read_args;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn spit_and_slurp_back() {
+            let file = std::env::temp_dir().join(format!("spit-test-{}.txt", std::process::id()));
+            let path = file.to_string_lossy().to_rc_value();
+            let with_path = |args: &[&str]| {
+                let mut args = read_args(args);
+                args.insert(0, Rc::clone(&path));
+                args
+            };
+            let spit = SpitFn {};
+            let slurp = SlurpFn {};
+            assert_eq!(Value::Nil, spit.invoke(with_path(&["\"a\\nb\""])));
+            spit.invoke(with_path(&["[1 2]", ":append", "true"]));
+            assert_eq!(
+                Value::String("a\nb[1 2]".to_string()),
+                slurp.invoke(with_path(&[]))
+            );
+            spit.invoke(with_path(&["\"ñ\"", ":encoding", "\"ISO-8859-1\""]));
+            assert_eq!(vec![0xf1], std::fs::read(&file).unwrap());
+            // core.clj's slurp hands SlurpFn its opts in a seq
+            assert_eq!(
+                Value::String("ñ".to_string()),
+                slurp.invoke(with_path(&["(:encoding \"ISO-8859-1\")"]))
+            );
+            std::fs::remove_file(&file).unwrap();
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use crate::ifn::IFn;
+use crate::seq;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
 /// (str x y & zs)
 ///
+/// The strings of each of xs run together;  nil gives nothing, as it does in Clojure
 #[derive(Debug, Clone)]
 pub struct StrFn {}
 impl ToValue for StrFn {
@@ -13,11 +15,50 @@ impl ToValue for StrFn {
 }
 impl IFn for StrFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        Value::String(
-            args.into_iter()
-                .map(|arg| arg.to_string())
-                .collect::<Vec<String>>()
-                .join(""),
-        )
+        let mut strs = Vec::with_capacity(args.len());
+        for arg in args {
+            // A seq is worked out first, so that one throwing as it is throws
+            if seq::is_seq(&arg) {
+                if let Err(condition) = seq::values(&arg) {
+                    return (*condition).clone();
+                }
+            }
+            strs.push(match &*arg {
+                Value::Nil => String::new(),
+                arg => arg.to_string(),
+            });
+        }
+        Value::String(strs.join(""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod str_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::StrFn;
+        use crate::test_util::read_args;
+        use crate::test_util::{is_boom, seq_throwing_at_2};
+        use crate::value::Value;
+
+        #[test]
+        fn str_of_nil_is_empty() {
+            let str_fn = StrFn {};
+            assert_eq!(
+                Value::String("".to_string()),
+                str_fn.invoke(read_args(&["nil"]))
+            );
+            assert_eq!(
+                Value::String("a1".to_string()),
+                str_fn.invoke(read_args(&["\"a\"", "nil", "1"]))
+            );
+            assert_eq!(Value::String("".to_string()), str_fn.invoke(vec![]));
+        }
+
+        #[test]
+        fn str_throws_what_working_out_a_seq_does() {
+            let str_fn = StrFn {};
+            assert!(is_boom(&str_fn.invoke(vec![seq_throwing_at_2()])));
+        }
     }
 }
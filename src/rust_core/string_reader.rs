@@ -0,0 +1,63 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::Reader;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (string-reader s)
+///
+/// A reader of the string s.  Bind *in* to one to read from s, as with-in-str does
+#[derive(Debug, Clone)]
+pub struct StringReaderFn {}
+impl ToValue for StringReaderFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for StringReaderFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::String(string) => Value::Reader(Reader::from_string(string.clone())),
+            _ => error_message::type_mismatch(TypeTag::String, &args[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod string_reader_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::StringReaderFn;
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::Value;
+
+        #[test]
+        fn string_reader_reads_the_string() {
+            let string_reader = StringReaderFn {};
+            match string_reader.invoke(read_args(&["\"first\\nsecond\""])) {
+                Value::Reader(reader) => {
+                    assert_eq!(Some("first".to_string()), reader.read_line().unwrap());
+                    assert_eq!(Some("second".to_string()), reader.read_line().unwrap());
+                    assert_eq!(None, reader.read_line().unwrap());
+                }
+                value => panic!("expected a reader, got {}", value),
+            }
+            match string_reader.invoke(read_args(&["1"])) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn with_in_str_reads_from_string() {
+            assert_eq!(
+                read("[\"first\" \"second\"]"),
+                eval_str("(with-in-str \"first\\nsecond\\n\" [(read-line) (read-line)])")
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::stream::Writer;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (string-writer)
+///
+/// A writer collecting what's written to it;  (str writer) gives that back.  Bind *out* to
+/// one to capture what's printed, as with-out-str does
+#[derive(Debug, Clone)]
+pub struct StringWriterFn {}
+impl ToValue for StringWriterFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for StringWriterFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if !args.is_empty() {
+            return error_message::wrong_arg_count(0, args.len());
+        }
+        Value::Writer(Writer::buffer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod string_writer_tests {
+        use crate::environment::Environment;
+        use crate::ifn::IFn;
+        use crate::rust_core::{PrintStringFn, StrFn, StringWriterFn};
+        use crate::test_util::eval_str;
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn string_writer_collects_what_is_written() {
+            let writer = StringWriterFn {}.invoke(vec![]).to_rc_value();
+            let print_string = PrintStringFn::new(Environment::clojure_core_environment());
+            assert_eq!(
+                Value::String("".to_string()),
+                StrFn {}.invoke(vec![Rc::clone(&writer)])
+            );
+            print_string.invoke(vec![
+                Rc::clone(&writer),
+                Rc::new(Value::String("a".to_string())),
+            ]);
+            print_string.invoke(vec![
+                Rc::clone(&writer),
+                Rc::new(Value::String("b".to_string())),
+            ]);
+            assert_eq!(
+                Value::String("ab".to_string()),
+                StrFn {}.invoke(vec![writer])
+            );
+        }
+
+        #[test]
+        fn with_out_str_captures_printing() {
+            assert_eq!(
+                Value::String("a 1\nb\n".to_string()),
+                eval_str("(with-out-str (print \"a\" 1) (newline) (println \"b\"))")
+            );
+        }
+
+        #[test]
+        fn with_out_str_nests() {
+            assert_eq!(
+                Value::String("outer inner".to_string()),
+                eval_str(
+                    "(with-out-str
+                       (print \"outer\")
+                       (with-out-str (print \"lost\"))
+                       (print \" inner\"))"
+                )
+            );
+        }
+    }
+}
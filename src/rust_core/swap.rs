@@ -0,0 +1,124 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (swap! atom f & args)
+///
+/// Sets atom to (f @atom args..), giving back what it was set to
+#[derive(Debug, Clone)]
+pub struct SwapFn {}
+impl ToValue for SwapFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SwapFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match swap(&args) {
+            Ok((_, new)) => (*new).clone(),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+/// (swap-vals! atom f & args)
+///
+/// As swap!, but gives back [old new];  what atom was, and what it was set to
+#[derive(Debug, Clone)]
+pub struct SwapValsFn {}
+impl ToValue for SwapValsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for SwapValsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match swap(&args) {
+            Ok((old, new)) => Value::PersistentVector(PersistentVector::from(vec![old, new])),
+            Err(condition) => (*condition).clone(),
+        }
+    }
+}
+
+fn swap(args: &[Rc<Value>]) -> Result<(Rc<Value>, Rc<Value>), Rc<Value>> {
+    let (atom, f, args) = match args {
+        [atom, f, args @ ..] => (atom, f, args),
+        _ => {
+            return Err(Rc::new(error_message::wrong_variadic_arg_count(
+                &[],
+                2,
+                args.len(),
+            )))
+        }
+    };
+    let atom = match &**atom {
+        Value::Atom(atom) => atom,
+        _ => return Err(Rc::new(error_message::type_mismatch(TypeTag::Atom, atom))),
+    };
+    match f.try_as_protocol::<protocols::IFn>() {
+        Some(f) => atom.swap(&f, args),
+        None => Err(Rc::new(error_message::type_mismatch(TypeTag::IFn, f))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod swap_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddFn, AssocFn, AtomFn, DerefFn, SubtractFn, SwapFn, SwapValsFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn inc() -> Rc<Value> {
+            Rc::new(eval_str("(fn [x] (+ x 1))"))
+        }
+
+        #[test]
+        fn swap_sets_and_gives_back_new_value() {
+            let swap = SwapFn {};
+            let a = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(Value::I32(2), swap.invoke(vec![Rc::clone(&a), inc()]));
+            let mut args = vec![Rc::clone(&a), AddFn {}.to_rc_value()];
+            args.extend(read_args(&["4", "6"]));
+            assert_eq!(Value::I32(12), swap.invoke(args));
+            assert_eq!(Value::I32(12), DerefFn {}.invoke(vec![a]));
+
+            let a = AtomFn {}.invoke(read_args(&["{}"])).to_rc_value();
+            let mut args = vec![Rc::clone(&a), AssocFn {}.to_rc_value()];
+            args.extend(read_args(&[":n", "1"]));
+            assert_eq!(read("{:n 1}"), swap.invoke(args));
+        }
+
+        #[test]
+        fn swap_vals_gives_back_old_and_new() {
+            let a = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            assert_eq!(read("[1 2]"), SwapValsFn {}.invoke(vec![a, inc()]));
+        }
+
+        #[test]
+        fn swap_to_invalid_state() {
+            let a = AtomFn {}.invoke(vec![
+                Rc::new(read("1")),
+                Rc::new(read(":validator")),
+                Rc::new(eval_str("(fn [x] (gt x 0))")),
+            ]);
+            let a = a.to_rc_value();
+            let swap = SwapFn {};
+            match swap.invoke(vec![
+                Rc::clone(&a),
+                SubtractFn {}.to_rc_value(),
+                Rc::new(read("5")),
+            ]) {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+            assert_eq!(Value::I32(1), DerefFn {}.invoke(vec![a]));
+        }
+    }
+}
@@ -1,14 +1,23 @@
+use crate::environment::Environment;
+use crate::error_message;
 use crate::ifn::IFn;
+use crate::stream;
 use crate::value::{ToValue, Value};
 use std::rc::Rc;
 
-use crate::error_message;
-
-/// Prints system newline, `\n` in rust on all platforms to stdout
-/// TODO: should be aware of *out*
-/// (defn print-string [string] .. prints single string without linebreak.. )
+/// Prints system newline, `\n` in rust on all platforms, to *out*
+/// (defn system-newline [] .. )
 #[derive(Debug, Clone)]
-pub struct SystemNewlineFn {}
+pub struct SystemNewlineFn {
+    enclosing_environment: Rc<Environment>,
+}
+impl SystemNewlineFn {
+    pub fn new(enclosing_environment: Rc<Environment>) -> SystemNewlineFn {
+        SystemNewlineFn {
+            enclosing_environment,
+        }
+    }
+}
 impl ToValue for SystemNewlineFn {
     fn to_value(&self) -> Value {
         Value::IFn(Rc::new(self.clone()))
@@ -16,10 +25,12 @@ impl ToValue for SystemNewlineFn {
 }
 impl IFn for SystemNewlineFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
-        if args.len() != 0 {
+        if !args.is_empty() {
             return error_message::wrong_arg_count(0, args.len());
         }
-        print!("\n");
-        Value::Nil
+        match stream::out(&self.enclosing_environment).and_then(|out| out.write_str("\n")) {
+            Ok(()) => Value::Nil,
+            Err(condition) => (*condition).clone(),
+        }
     }
 }
@@ -0,0 +1,112 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{self, Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (take n coll)
+///
+/// The lazy seq of the first n values of coll, or all of them if there are fewer
+#[derive(Debug, Clone)]
+pub struct TakeFn {}
+impl ToValue for TakeFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for TakeFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let n = match &*args[0] {
+            Value::I32(n) => (*n).max(0) as usize,
+            _ => return error_message::type_mismatch(TypeTag::Integer, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(TakeXForm { n }).to_value();
+        }
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        LazySeq::from_iter(seq::iter(coll).take(n)).to_value()
+    }
+}
+
+/// (take n), the transducer stepping in the first n inputs, and then stopping;  its state
+/// is how many it's taken
+#[derive(Debug, Clone)]
+pub struct TakeXForm {
+    n: usize,
+}
+impl XForm for TakeXForm {
+    type State = usize;
+    fn step(
+        &self,
+        state: &RefCell<usize>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let taken = *state.borrow() + 1;
+        *state.borrow_mut() = taken;
+        let result = if taken <= self.n {
+            Rc::new(rf.invoke(vec![result, input]))
+        } else {
+            result
+        };
+        if taken >= self.n && result.non_tail().is_none() {
+            transducer::ensure_reduced(result)
+        } else {
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod take_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, RangeFn, TakeFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn naturals() -> Rc<Value> {
+            RangeFn {}.invoke(vec![]).to_rc_value()
+        }
+
+        #[test]
+        fn take_colls() {
+            let take = TakeFn {};
+            assert_eq!(read("(1 2)"), take.invoke(read_args(&["2", "[1 2 3]"])));
+            assert_eq!(read("(1 2 3)"), take.invoke(read_args(&["5", "(1 2 3)"])));
+            assert_eq!(read("()"), take.invoke(read_args(&["-1", "[1 2 3]"])));
+            assert_eq!(read("()"), take.invoke(read_args(&["3", "nil"])));
+            assert_eq!(
+                read("(0 1 2)"),
+                take.invoke(vec![3.to_rc_value(), naturals()])
+            );
+        }
+
+        #[test]
+        fn take_transducer() {
+            let into = IntoFn {};
+            let take = |n: i32| TakeFn {}.invoke(vec![n.to_rc_value()]).to_rc_value();
+            assert_eq!(
+                read("[0 1 2]"),
+                into.invoke(vec![Rc::new(read("[]")), take(3), naturals()])
+            );
+            assert_eq!(
+                read("[]"),
+                into.invoke(vec![Rc::new(read("[]")), take(0), naturals()])
+            );
+        }
+    }
+}
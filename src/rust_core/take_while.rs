@@ -0,0 +1,123 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::lazy_seq::LazySeq;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::transducer::{Transducer, XForm};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// (take-while pred coll)
+///
+/// The lazy seq of the values of coll up to the first that pred is falsey for
+#[derive(Debug, Clone)]
+pub struct TakeWhileFn {}
+impl ToValue for TakeWhileFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for TakeWhileFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 && args.len() != 2 {
+            return error_message::wrong_varg_count(&[1, 2], args.len());
+        }
+        let pred = match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(pred) => pred,
+            None => return error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        };
+        if args.len() == 1 {
+            return Transducer::new(TakeWhileXForm { pred }).to_value();
+        }
+        let coll = &args[1];
+        if !seq::is_seqable(coll) {
+            return error_message::type_mismatch(TypeTag::ISeq, coll);
+        }
+        let values = seq::iter(coll).map_while(move |value| {
+            if value.non_tail().is_some() {
+                return Some(value);
+            }
+            let test = Rc::new(pred.invoke(vec![Rc::clone(&value)]));
+            match test.non_tail() {
+                Some(interruption) => Some(interruption),
+                None if test.is_truthy() => Some(value),
+                None => None,
+            }
+        });
+        LazySeq::from_iter(values).to_value()
+    }
+}
+
+/// (take-while pred), the transducer stepping in inputs up to the first that pred is falsey
+/// for, and then stopping
+#[derive(Debug, Clone)]
+pub struct TakeWhileXForm {
+    pred: protocols::IFn,
+}
+impl XForm for TakeWhileXForm {
+    type State = ();
+    fn step(
+        &self,
+        _state: &RefCell<()>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value> {
+        let test = Rc::new(self.pred.invoke(vec![Rc::clone(&input)]));
+        if let Some(interruption) = test.non_tail() {
+            return interruption;
+        }
+        if test.is_truthy() {
+            Rc::new(rf.invoke(vec![result, input]))
+        } else {
+            Rc::new(Value::Reduced(result))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod take_while_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{IntoFn, RangeFn, TakeWhileFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn less_than(n: i32) -> Rc<Value> {
+            Rc::new(eval_str(&format!("(fn [x] (lt x {}))", n)))
+        }
+
+        #[test]
+        fn take_while_colls() {
+            let take_while = TakeWhileFn {};
+            assert_eq!(
+                read("(1 2)"),
+                take_while.invoke(vec![less_than(3), Rc::new(read("[1 2 3 1]"))])
+            );
+            assert_eq!(
+                read("()"),
+                take_while.invoke(read_args(&[":a", "[{:b 1}]"]))
+            );
+            assert_eq!(
+                read("(0 1 2 3)"),
+                take_while.invoke(vec![less_than(4), RangeFn {}.invoke(vec![]).to_rc_value()])
+            );
+        }
+
+        #[test]
+        fn take_while_transducer() {
+            assert_eq!(
+                read("[0 1 2]"),
+                IntoFn {}.invoke(vec![
+                    Rc::new(read("[]")),
+                    TakeWhileFn {}.invoke(vec![less_than(3)]).to_rc_value(),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (throw e)
+/// throws e, a condition made by ex-info or caught by a catch, on up to the nearest try
+/// that catches it
+#[derive(Debug, Clone)]
+pub struct ThrowFn {}
+impl ToValue for ThrowFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for ThrowFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match &*args[0] {
+            Value::Throwable(condition) => Value::Condition(Rc::clone(condition)),
+            _ => error_message::type_mismatch(TypeTag::Condition, &args[0]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod throw_tests {
+        use crate::condition::{Condition, ConditionKind};
+        use crate::ifn::IFn;
+        use crate::rust_core::ThrowFn;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        #[test]
+        fn throws_throwable() {
+            let condition = Rc::new(Condition::new(ConditionKind::ExceptionInfo, "boom"));
+            assert_eq!(
+                Value::Condition(Rc::clone(&condition)),
+                ThrowFn {}.invoke(vec![Rc::new(Value::Throwable(condition))])
+            );
+        }
+
+        #[test]
+        fn only_throws_throwables() {
+            let thrown = ThrowFn {}.invoke(vec![Rc::new(Value::I32(1))]);
+            match thrown {
+                Value::Condition(condition) => {
+                    assert_eq!(ConditionKind::ClassCastException, condition.kind)
+                }
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::{Protocol, ProtocolCastable};
+use crate::protocols;
+use crate::rust_core::reduce::{reduce, unreduced};
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (transduce xform f coll)
+/// (transduce xform f init coll)
+///
+/// Reduces coll with f, as transformed by the transducer xform, starting from init (or what
+/// f gives back called with nothing), and then completes the result
+#[derive(Debug, Clone)]
+pub struct TransduceFn {}
+impl ToValue for TransduceFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for TransduceFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (xform, f, init, coll) = match args.as_slice() {
+            [xform, f, coll] => (xform, f, None, coll),
+            [xform, f, init, coll] => (xform, f, Some(Rc::clone(init)), coll),
+            _ => return error_message::wrong_varg_count(&[3, 4], args.len()),
+        };
+        let f = match f.try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, f),
+        };
+        let init = match init {
+            Some(init) => init,
+            None => Rc::new(f.invoke(vec![])),
+        };
+        (*transduce(xform, &f, init, coll)).clone()
+    }
+}
+
+/// Reduces coll with f as transformed by xform, starting from init, and completes the result
+pub(crate) fn transduce(
+    xform: &Rc<Value>,
+    f: &protocols::IFn,
+    init: Rc<Value>,
+    coll: &Rc<Value>,
+) -> Rc<Value> {
+    let xform = match xform.try_as_protocol::<protocols::IFn>() {
+        Some(xform) => xform,
+        None => return Rc::new(error_message::type_mismatch(TypeTag::IFn, xform)),
+    };
+    if !seq::is_seqable(coll) {
+        return Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll));
+    }
+    let rf = Rc::new(xform.invoke(vec![f.unwrap()]));
+    if let Some(interruption) = rf.non_tail() {
+        return interruption;
+    }
+    let rf = match rf.try_as_protocol::<protocols::IFn>() {
+        Some(rf) => rf,
+        None => return Rc::new(error_message::type_mismatch(TypeTag::IFn, &rf)),
+    };
+    let result = unreduced(reduce(&rf, init, seq::iter(coll)));
+    if let Some(interruption) = result.non_tail() {
+        return interruption;
+    }
+    Rc::new(rf.invoke(vec![result]))
+}
+
+#[cfg(test)]
+mod tests {
+    mod transduce_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{
+            AddFn, CompFn, CompletingFn, ConjFn, FilterFn, MapFn, PartitionAllFn, RangeFn, StrFn,
+            TakeFn, TransduceFn,
+        };
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        fn map_inc() -> Rc<Value> {
+            MapFn {}
+                .invoke(vec![Rc::new(eval_str("(fn [x] (+ x 1))"))])
+                .to_rc_value()
+        }
+
+        #[test]
+        fn transduce_with_and_without_init() {
+            let transduce = TransduceFn {};
+            let evens = FilterFn {}.invoke(vec![Rc::new(eval_str("(fn [x] (= 0 (rem x 2)))"))]);
+            let xform = CompFn {}.invoke(vec![evens.to_rc_value(), map_inc()]);
+            assert_eq!(
+                Value::I32(9),
+                transduce.invoke(vec![
+                    xform.to_rc_value(),
+                    AddFn {}.to_rc_value(),
+                    RangeFn {}.invoke(read_args(&["6"])).to_rc_value()
+                ])
+            );
+            assert_eq!(
+                Value::I32(15),
+                transduce.invoke(vec![
+                    map_inc(),
+                    AddFn {}.to_rc_value(),
+                    Rc::new(read("10")),
+                    Rc::new(read("[1 2]"))
+                ])
+            );
+        }
+
+        #[test]
+        fn transduce_flushes_stateful_xforms() {
+            let xform = CompFn {}.invoke(vec![
+                TakeFn {}.invoke(read_args(&["3"])).to_rc_value(),
+                PartitionAllFn {}.invoke(read_args(&["2"])).to_rc_value(),
+            ]);
+            assert_eq!(
+                read("[[0 1] [2]]"),
+                TransduceFn {}.invoke(vec![
+                    xform.to_rc_value(),
+                    ConjFn {}.to_rc_value(),
+                    Rc::new(read("[]")),
+                    RangeFn {}.invoke(vec![]).to_rc_value()
+                ])
+            );
+        }
+
+        #[test]
+        fn transduce_completes() {
+            let rf = CompletingFn {}.invoke(vec![AddFn {}.to_rc_value(), StrFn {}.to_rc_value()]);
+            assert_eq!(
+                read("\"3\""),
+                TransduceFn {}.invoke(vec![
+                    map_inc(),
+                    rf.to_rc_value(),
+                    Rc::new(read("0")),
+                    Rc::new(read("[0 1]"))
+                ])
+            );
+        }
+    }
+}
@@ -1,9 +1,15 @@
 use std::rc::Rc;
 
 use crate::{error_message, ifn::IFn, value::{ToValue, Value}};
+use crate::keyword::Keyword;
+use crate::persistent_list_map::IPersistentMap;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::traits::IMeta;
 
 /// ```clojure
 /// (type 'foo) ;; => "clojure.lang.Symbol"
+/// (type (with-meta {} {:type :point})) ;; => :point
 /// ```
 #[derive(Debug, Clone)]
 pub struct TypeFn {}
@@ -15,9 +21,20 @@ impl ToValue for TypeFn {
 impl IFn for TypeFn {
     fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
         if args.len() == 1 {
-            Value::String(format!("{}", args[0].type_tag()))
+            (*type_of(&args[0])).clone()
         } else {
             error_message::wrong_arg_count(1, args.len())
         }
     }
 }
+
+/// The :type of x's meta, if it has one, as in Clojure;  otherwise the name of its type
+pub fn type_of(x: &Rc<Value>) -> Rc<Value> {
+    if let Some(imeta) = x.try_as_protocol::<protocols::IMeta>() {
+        let type_ = imeta.meta().get(&Keyword::intern("type").to_rc_value());
+        if !matches!(*type_, Value::Nil) {
+            return type_;
+        }
+    }
+    Rc::new(Value::String(format!("{}", x.type_tag())))
+}
@@ -0,0 +1,60 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (var-set var val)
+///
+/// Sets this thread's binding of var to val, giving back val;  var must be bound on this
+/// thread, by binding.  (set! x val) is (var-set (var x) val)
+#[derive(Debug, Clone)]
+pub struct VarSetFn {}
+impl ToValue for VarSetFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for VarSetFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (var, val) = match args.as_slice() {
+            [var, val] => (var, val),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        match &**var {
+            Value::Var(var) => match var.set(Rc::clone(val)) {
+                Ok(()) => (**val).clone(),
+                Err(condition) => (*condition).clone(),
+            },
+            _ => error_message::type_mismatch(TypeTag::Var, var),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod var_set_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn set_bound_var() {
+            assert_eq!(
+                eval_str("[3 3 1]"),
+                eval_str(
+                    "(do (def ^:dynamic *x* 1)
+                         (let [bound (binding [*x* 2] [(set! *x* 3) *x*])]
+                           (conj bound *x*)))"
+                )
+            );
+        }
+
+        #[test]
+        fn set_unbound_var() {
+            match eval_str("(do (def ^:dynamic *x* 1) (set! *x* 2))") {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (add-watch atom key f)
+///
+/// Calls (f key atom old new) each time atom changes, until the watch is removed by key.
+/// Adding another watch under key replaces this one.  Gives back atom
+#[derive(Debug, Clone)]
+pub struct AddWatchFn {}
+impl ToValue for AddWatchFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for AddWatchFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (atom, key, f) = match args.as_slice() {
+            [atom, key, f] => (atom, key, f),
+            _ => return error_message::wrong_arg_count(3, args.len()),
+        };
+        let f = match f.try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, f),
+        };
+        match &**atom {
+            Value::Atom(watched) => {
+                watched.add_watch(Rc::clone(key), f);
+                (**atom).clone()
+            }
+            _ => error_message::type_mismatch(TypeTag::Atom, atom),
+        }
+    }
+}
+
+/// (remove-watch atom key)
+///
+/// Gives back atom
+#[derive(Debug, Clone)]
+pub struct RemoveWatchFn {}
+impl ToValue for RemoveWatchFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for RemoveWatchFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (atom, key) = match args.as_slice() {
+            [atom, key] => (atom, key),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        match &**atom {
+            Value::Atom(watched) => {
+                watched.remove_watch(key);
+                (**atom).clone()
+            }
+            _ => error_message::type_mismatch(TypeTag::Atom, atom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod watch_tests {
+        use crate::ifn::IFn;
+        use crate::rust_core::{AddWatchFn, AtomFn, DerefFn, RemoveWatchFn, ResetFn, SwapFn};
+        use crate::test_util::{eval_str, read, read_args};
+        use crate::value::{ToValue, Value};
+        use std::rc::Rc;
+
+        /// (fn [k r old new] ..), giving what it's called with to record, a fn of seen, an
+        /// atom
+        fn watcher(record: &str, seen: &Rc<Value>) -> Rc<Value> {
+            let watcher = eval_str(&format!("(fn [seen] (fn [k r old new] {}))", record));
+            match watcher {
+                Value::IFn(watcher) => watcher.invoke(vec![Rc::clone(seen)]).to_rc_value(),
+                value => panic!("expected a fn, got {}", value),
+            }
+        }
+
+        #[test]
+        fn watches_see_each_change() {
+            let a = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            let seen = AtomFn {}.invoke(read_args(&["[]"])).to_rc_value();
+            let key = Rc::new(read(":w"));
+            let watcher = watcher("(swap! seen conj [k old new])", &seen);
+            AddWatchFn {}.invoke(vec![Rc::clone(&a), Rc::clone(&key), watcher]);
+            SwapFn {}.invoke(vec![Rc::clone(&a), Rc::new(eval_str("(fn [x] (+ x 1))"))]);
+            ResetFn {}.invoke(vec![Rc::clone(&a), Rc::new(read("12"))]);
+            RemoveWatchFn {}.invoke(vec![Rc::clone(&a), key]);
+            ResetFn {}.invoke(vec![a, Rc::new(read("0"))]);
+            assert_eq!(read("[[:w 1 2] [:w 2 12]]"), DerefFn {}.invoke(vec![seen]));
+        }
+
+        #[test]
+        fn watch_is_given_the_atom() {
+            let a = AtomFn {}.invoke(read_args(&["1"])).to_rc_value();
+            let seen = AtomFn {}.invoke(read_args(&["nil"])).to_rc_value();
+            let watcher = watcher("(reset! seen r)", &seen);
+            AddWatchFn {}.invoke(vec![Rc::clone(&a), Rc::new(read(":w")), watcher]);
+            ResetFn {}.invoke(vec![Rc::clone(&a), Rc::new(read("2"))]);
+            assert_eq!(*a, DerefFn {}.invoke(vec![seen]));
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use crate::environment::Environment;
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::persistent_vector::PersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq;
+use crate::type_tag::TypeTag;
+use crate::value::{Evaluable, ToValue, Value};
+use crate::var::Var;
+use std::rc::Rc;
+
+/// (with-bindings* binding-map f & args)
+///
+/// Calls (f args..) with each var of binding-map bound on this thread to its value, as binding
+/// runs its body;  the vars must be dynamic
+#[derive(Debug, Clone)]
+pub struct WithBindingsFn {}
+impl ToValue for WithBindingsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WithBindingsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (bindings, f, args) = match args.as_slice() {
+            [bindings, f, args @ ..] => (bindings, f, args),
+            _ => return error_message::wrong_variadic_arg_count(&[], 2, args.len()),
+        };
+        let bindings = match var_bindings(bindings) {
+            Ok(bindings) => bindings,
+            Err(condition) => return (*condition).clone(),
+        };
+        let f = match f.try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, f),
+        };
+        if let Err(condition) = Var::push_thread_bindings(bindings) {
+            return (*condition).clone();
+        }
+        let result = f.invoke(args.to_vec());
+        Var::pop_thread_bindings();
+        result
+    }
+}
+
+/// Evaluates the arguments of a (binding* binding-map expr*) in environment, as the binding
+/// macro expands to;  the exprs are run with binding-map's vars bound, as with-bindings* would
+/// run them in a fn, save that a recur in tail position has nowhere to go, rather than
+/// recurring to that fn
+pub fn eval_binding(environment: &Rc<Environment>, args: Vec<Rc<Value>>) -> Rc<Value> {
+    let (binding_map, body) = match args.split_first() {
+        Some(split) => split,
+        None => return Rc::new(error_message::wrong_variadic_arg_count(&[], 1, 0)),
+    };
+    let binding_map = binding_map.eval_to_rc(Rc::clone(environment));
+    if let Some(interruption) = binding_map.non_tail() {
+        return interruption;
+    }
+    let bindings = match var_bindings(&binding_map) {
+        Ok(bindings) => bindings,
+        Err(condition) => return condition,
+    };
+    if let Err(condition) = Var::push_thread_bindings(bindings) {
+        return condition;
+    }
+    let result = Value::implicit_do(body).eval_to_rc(Rc::clone(environment));
+    Var::pop_thread_bindings();
+    if result.is_recur() {
+        return Rc::new(error_message::recur_across("binding"));
+    }
+    result
+}
+
+/// The vars of binding_map, a map of vars to values, each with its value
+pub(crate) fn var_bindings(binding_map: &Rc<Value>) -> Result<Vec<(Var, Rc<Value>)>, Rc<Value>> {
+    match &**binding_map {
+        Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {}
+        _ => {
+            return Err(Rc::new(error_message::type_mismatch(
+                TypeTag::PersistentListMap,
+                binding_map,
+            )))
+        }
+    }
+    seq::iter(binding_map)
+        .map(|entry| match &*entry {
+            Value::PersistentVector(entry) => binding(entry),
+            _ => Err(entry),
+        })
+        .collect()
+}
+fn binding(entry: &PersistentVector) -> Result<(Var, Rc<Value>), Rc<Value>> {
+    let (var, val) = match (entry.nth(0), entry.nth(1)) {
+        (Some(var), Some(val)) => (var, val),
+        _ => {
+            return Err(Rc::new(error_message::custom(
+                "Malformed binding map entry",
+            )))
+        }
+    };
+    match &*var {
+        Value::Var(var) => Ok((var.clone(), val)),
+        _ => Err(Rc::new(error_message::type_mismatch(TypeTag::Var, &var))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod with_bindings_tests {
+        use crate::error_message;
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn binding_dynamic_vars() {
+            assert_eq!(
+                eval_str("[2 :b 1]"),
+                eval_str(
+                    "(do (def ^:dynamic *x* 1)
+                         (def ^:dynamic *y* :a)
+                         (defn get-x [] *x*)
+                         (let [bound (binding [*x* 2 *y* :b] [(get-x) *y*])]
+                           (conj bound *x*)))"
+                )
+            );
+        }
+
+        #[test]
+        fn binding_nests() {
+            assert_eq!(
+                eval_str("[3 2 1]"),
+                eval_str(
+                    "(do (def ^:dynamic *x* 1)
+                         (binding [*x* 2]
+                           [(binding [*x* 3] *x*) *x* (get (with-bindings* {} (fn [] [1]) ) 0)]))"
+                )
+            );
+        }
+
+        #[test]
+        fn binding_non_dynamic_var() {
+            match eval_str("(do (def x 1) (binding [x 2] x))") {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn binding_is_popped_after_a_condition() {
+            assert_eq!(
+                Value::I32(1),
+                eval_str(
+                    "(do (def ^:dynamic *x* 1)
+                         (try (binding [*x* 2] (throw (ex-info \"oops\" {})))
+                              (catch :default e nil))
+                         *x*)"
+                )
+            );
+        }
+
+        #[test]
+        fn recur_across_binding() {
+            assert_eq!(
+                error_message::recur_across("binding"),
+                eval_str(
+                    "(do (def ^:dynamic *x* 1)
+                         (loop [i 0] (binding [*x* i] (if (= i 3) i (recur (inc i))))))"
+                )
+            );
+            assert_eq!(
+                Value::I32(5),
+                eval_str(
+                    "(do (def ^:dynamic *x* 2)
+                         (binding [*x* 3] (loop [i 0] (if (= i 2) (+ i *x*) (recur (inc i))))))"
+                )
+            );
+        }
+    }
+}
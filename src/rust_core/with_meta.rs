@@ -38,6 +38,9 @@ impl IFn for WithMetaFn {
                 Value::PersistentListMap(plistmap) => {
                     obj.with_meta(plistmap).unwrap().to_value()
                 }
+                Value::PersistentHashMap(phashmap) => {
+                    obj.with_meta(phashmap.to_list_map()).unwrap().to_value()
+                }
                 _ => error_message::type_mismatch(
                     TypeTag::PersistentListMap,
                     args.get(0).unwrap(),
@@ -45,7 +48,7 @@ impl IFn for WithMetaFn {
             }
             // Again, this will likely be swapped for a proper error function, we are currently
             // experimenting with new error messages
-            _ => error_message::custom(&format!(
+            _ => error_message::custom(format!(
                 "In with-meta: first argument is supposed to be of instance IObj, but its type {} is not",
                 args.get(0).unwrap().type_tag()
             ))
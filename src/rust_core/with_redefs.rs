@@ -0,0 +1,84 @@
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::rust_core::with_bindings::var_bindings;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::rc::Rc;
+
+/// (with-redefs-fn binding-map f)
+///
+/// Calls (f) with the root of each var of binding-map set to its value, setting them back
+/// after, as the with-redefs macro does.  Unlike binding, this is seen on every thread, and
+/// the vars needn't be dynamic
+#[derive(Debug, Clone)]
+pub struct WithRedefsFn {}
+impl ToValue for WithRedefsFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for WithRedefsFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let (bindings, f) = match args.as_slice() {
+            [bindings, f] => (bindings, f),
+            _ => return error_message::wrong_arg_count(2, args.len()),
+        };
+        let bindings = match var_bindings(bindings) {
+            Ok(bindings) => bindings,
+            Err(condition) => return (*condition).clone(),
+        };
+        let f = match f.try_as_protocol::<protocols::IFn>() {
+            Some(f) => f,
+            None => return error_message::type_mismatch(TypeTag::IFn, f),
+        };
+        let roots = bindings
+            .into_iter()
+            .map(|(var, val)| {
+                let root = var.root.replace(val);
+                (var, root)
+            })
+            .collect::<Vec<_>>();
+        let result = f.invoke(vec![]);
+        // Set back in reverse, so a var redefined twice gets its first root back
+        for (var, root) in roots.into_iter().rev() {
+            var.bind_root(root);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod with_redefs_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn redefs_are_undone() {
+            assert_eq!(
+                eval_str("[:stub 2]"),
+                eval_str(
+                    "(do (defn f [] 2)
+                         (defn g [] (f))
+                         (let [redefined (with-redefs [f (fn [] :stub)] (g))]
+                           [redefined (g)]))"
+                )
+            );
+        }
+
+        #[test]
+        fn redefs_are_undone_after_a_condition() {
+            assert_eq!(
+                Value::I32(1),
+                eval_str(
+                    "(do (def x 1)
+                         (try (with-redefs [x 2] (throw (ex-info \"oops\" {})))
+                              (catch :default e nil))
+                         x)"
+                )
+            );
+        }
+    }
+}
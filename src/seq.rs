@@ -0,0 +1,206 @@
+//! The seq abstraction, as in Clojure;  walking any collection (or nil, or a string) one
+//! value at a time, as a first value and the seq of everything after it.  What can be seq'd
+//! is what's Seqable, and nil
+//!
+//! The seqs themselves are nonempty PersistentLists, Conses and LazySeqs;  seq gives back one
+//! of these, or nil, and first and more work on anything seq does
+use crate::error_message;
+use crate::persistent_list::PersistentList;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::traits::Seqable;
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+/// nil if coll is empty, otherwise a nonempty seq of its values;  a condition if coll
+/// can't be seq'd, or working out its values raised one
+pub fn seq(coll: &Rc<Value>) -> Rc<Value> {
+    match &**coll {
+        Value::Nil | Value::Condition(_) => Rc::clone(coll),
+        _ => match coll.try_as_protocol::<protocols::Seqable>() {
+            Some(seqable) => seqable.seq(),
+            None => Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll)),
+        },
+    }
+}
+
+/// Whether coll is nil, or something that can be seq'd
+pub fn is_seqable(coll: &Rc<Value>) -> bool {
+    matches!(&**coll, Value::Nil) || coll.instanceof::<protocols::Seqable>()
+}
+
+/// Whether value is itself a seq, rather than a collection that can be seq'd
+pub fn is_seq(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::PersistentList(_) | Value::Cons(_) | Value::LazySeq(_)
+    )
+}
+
+/// The first value of coll, or nil if it's empty
+pub fn first(coll: &Rc<Value>) -> Rc<Value> {
+    let seq = seq(coll);
+    match &*seq {
+        Value::PersistentList(PersistentList::Cons(head, ..)) => Rc::clone(head),
+        Value::Cons(cons) => cons.first(),
+        Value::Condition(_) => seq,
+        _ => Rc::new(Value::Nil),
+    }
+}
+
+/// The seq of everything in coll after its first value;  the empty list if there's nothing
+pub fn more(coll: &Rc<Value>) -> Rc<Value> {
+    let seq = seq(coll);
+    match &*seq {
+        Value::PersistentList(PersistentList::Cons(_, tail, ..)) => {
+            Value::PersistentList((**tail).clone()).to_rc_value()
+        }
+        Value::Cons(cons) => cons.more(),
+        Value::Condition(_) => seq,
+        _ => PersistentList::Empty.to_rc_value(),
+    }
+}
+
+/// Walks the values of coll, realizing only as many as are taken.  Should working them out
+/// raise a condition, that condition is the last value given
+pub fn iter(coll: &Rc<Value>) -> SeqIter {
+    SeqIter {
+        rest: Some(Rc::clone(coll)),
+    }
+}
+pub struct SeqIter {
+    /// What we've yet to walk, or None once we're done
+    rest: Option<Rc<Value>>,
+}
+impl Iterator for SeqIter {
+    type Item = Rc<Value>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let seq = seq(&self.rest.take()?);
+        match &*seq {
+            Value::PersistentList(PersistentList::Cons(head, tail, ..)) => {
+                self.rest = Some(Value::PersistentList((**tail).clone()).to_rc_value());
+                Some(Rc::clone(head))
+            }
+            Value::Cons(cons) => {
+                self.rest = Some(cons.more());
+                Some(cons.first())
+            }
+            Value::Condition(_) => Some(seq),
+            _ => None,
+        }
+    }
+}
+
+/// Walks the values of coll as iter does, but gives any condition raised working them out
+/// as an Err
+pub fn try_iter(coll: &Rc<Value>) -> impl Iterator<Item = Result<Rc<Value>, Rc<Value>>> {
+    iter(coll).map(|value| match value.non_tail() {
+        Some(interruption) => Err(interruption),
+        None => Ok(value),
+    })
+}
+
+/// Every value of coll;  Err if coll can't be seq'd, or walking it throws
+pub fn values(coll: &Rc<Value>) -> Result<Vec<Rc<Value>>, Rc<Value>> {
+    if !is_seqable(coll) {
+        return Err(Rc::new(error_message::type_mismatch(TypeTag::ISeq, coll)));
+    }
+    try_iter(coll).collect()
+}
+
+/// Whether value is sequential;  a seq or a vector, either equal to any other with the same
+/// values in the same order
+pub fn is_sequential(value: &Value) -> bool {
+    is_seq(value) || matches!(value, Value::PersistentVector(_))
+}
+
+/// Whether two sequential colls have the same values, in the same order;  Err if working
+/// out the values of either throws
+pub fn equals(coll: &Value, other: &Value) -> Result<bool, Rc<Value>> {
+    if !is_sequential(coll) || !is_sequential(other) {
+        return Ok(false);
+    }
+    let mut values = try_iter(&coll.to_rc_value());
+    let mut others = try_iter(&other.to_rc_value());
+    loop {
+        match (values.next().transpose()?, others.next().transpose()?) {
+            (None, None) => return Ok(true),
+            (Some(value), Some(other)) if value == other => {}
+            _ => return Ok(false),
+        }
+    }
+}
+
+/// Hashes values into state in order;  any seqs with the same values hash the same
+pub fn hash_ordered<I: Iterator<Item = Rc<Value>>, H: Hasher>(values: I, state: &mut H) {
+    let mut count = 0_usize;
+    let values_hash = values.fold(1_u64, |hash, value| {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        count += 1;
+        hash.wrapping_mul(31).wrapping_add(hasher.finish())
+    });
+    values_hash.hash(state);
+    count.hash(state);
+}
+
+/// Writes the seq coll as (a b c)
+pub fn fmt_seq(coll: &Rc<Value>, f: &mut fmt::Formatter) -> fmt::Result {
+    let str = iter(coll)
+        .map(|value| value.to_string_explicit())
+        .collect::<Vec<String>>()
+        .join(" ");
+    write!(f, "({})", str)
+}
+
+// A string is seq'd as its characters, each as a string of its own
+impl Seqable for String {
+    fn seq(&self) -> Rc<Value> {
+        let chars = self
+            .chars()
+            .map(|c| Value::String(c.to_string()).to_rc_value())
+            .collect::<Vec<Rc<Value>>>();
+        crate::lazy_seq::LazySeq::from_iter(chars.into_iter()).seq()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod seq_tests {
+        use crate::seq;
+        use crate::test_util::read;
+        use crate::value::Value;
+        use std::rc::Rc;
+
+        fn seq_of(source: &str) -> Rc<Value> {
+            seq::seq(&Rc::new(read(source)))
+        }
+
+        #[test]
+        fn seq_of_each_seqable() {
+            assert_eq!(Value::Nil, *seq_of("nil"));
+            assert_eq!(Value::Nil, *seq_of("[]"));
+            assert_eq!(Value::Nil, *seq_of("\"\""));
+            assert_eq!(read("(1 2)"), *seq_of("[1 2]"));
+            assert_eq!(read("(1 2)"), *seq_of("(1 2)"));
+            assert_eq!(read("([:a 1])"), *seq_of("{:a 1}"));
+            assert_eq!(read("(\"a\" \"b\")"), *seq_of("\"ab\""));
+            match &*seq_of("1") {
+                Value::Condition(_) => {}
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn first_and_more_of_nil() {
+            let nil = Rc::new(Value::Nil);
+            assert_eq!(Value::Nil, *seq::first(&nil));
+            assert_eq!(read("()"), *seq::more(&nil));
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Where a form was read from;  kept as :file, :line and :column metadata on the lists,
+//! vectors, maps and symbols the reader reads, and given to the Conditions raised while
+//! evaluating them
+use crate::keyword::Keyword;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
+use crate::traits::IMeta;
+use crate::value::{ToValue, Value};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourcePosition {
+    /// None when read from something other than a file, like the REPL or a string
+    pub file: Option<String>,
+    /// Starting from 1, like the lines and columns of most editors
+    pub line: usize,
+    pub column: usize,
+}
+impl SourcePosition {
+    /// The position recorded in the metadata of a form, if any
+    pub fn from_meta(meta: &PersistentListMap) -> Option<SourcePosition> {
+        let line = match &*meta.get(&Keyword::intern("line").to_rc_value()) {
+            Value::I32(line) => *line as usize,
+            _ => return None,
+        };
+        let column = match &*meta.get(&Keyword::intern("column").to_rc_value()) {
+            Value::I32(column) => *column as usize,
+            _ => return None,
+        };
+        let file = match &*meta.get(&Keyword::intern("file").to_rc_value()) {
+            Value::String(file) => Some(file.clone()),
+            _ => None,
+        };
+        Some(SourcePosition { file, line, column })
+    }
+    /// The position of a form, if it was read by the reader
+    pub fn of(form: &Value) -> Option<SourcePosition> {
+        match form {
+            Value::PersistentList(plist) => SourcePosition::from_meta(&plist.meta()),
+            Value::PersistentVector(pvector) => SourcePosition::from_meta(&pvector.meta()),
+            Value::PersistentListMap(plistmap) => SourcePosition::from_meta(&plistmap.meta()),
+            Value::PersistentHashMap(phashmap) => SourcePosition::from_meta(&phashmap.meta()),
+            Value::PersistentHashSet(pset) => SourcePosition::from_meta(&pset.meta()),
+            Value::Symbol(sym) => SourcePosition::from_meta(&sym.meta()),
+            _ => None,
+        }
+    }
+    /// meta, with our :file, :line and :column added
+    pub fn add_to_meta(&self, meta: PersistentListMap) -> PersistentListMap {
+        let meta = meta
+            .assoc(
+                Keyword::intern("line").to_rc_value(),
+                Rc::new(Value::I32(self.line as i32)),
+            )
+            .assoc(
+                Keyword::intern("column").to_rc_value(),
+                Rc::new(Value::I32(self.column as i32)),
+            );
+        match &self.file {
+            Some(file) => meta.assoc(
+                Keyword::intern("file").to_rc_value(),
+                Rc::new(Value::String(file.clone())),
+            ),
+            None => meta,
+        }
+    }
+}
+impl fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Like Clojure, which reports a position in something that isn't a file as being
+        // in NO_SOURCE_FILE
+        let file = self.file.as_deref().unwrap_or("NO_SOURCE_FILE");
+        write!(f, "{}:{}:{}", file, self.line, self.column)
+    }
+}
@@ -0,0 +1,335 @@
+//! Writers and readers;  what *out*, *err* and *in* hold, and so what all of our printing
+//! and reading goes through.  By default they're the process's stdout, stderr and stdin, but
+//! a Rust embedder can give an Environment its own (see Environment::set_out), and Clojure
+//! code can rebind them, as with-out-str does
+use crate::condition::ConditionKind;
+use crate::environment::Environment;
+use crate::error_message;
+use crate::symbol::Symbol;
+use crate::type_tag::TypeTag;
+use crate::value::Value;
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+/// Somewhere to write text;  clones write to the same place, and closing one closes them all
+#[derive(Clone)]
+pub struct Writer {
+    sink: Rc<RefCell<Sink>>,
+}
+enum Sink {
+    Write(Box<dyn Write>),
+    /// Collects what's written, as a StringWriter does
+    Buffer(String),
+    Closed,
+}
+impl Writer {
+    pub fn new(write: impl Write + 'static) -> Writer {
+        Writer {
+            sink: Rc::new(RefCell::new(Sink::Write(Box::new(write)))),
+        }
+    }
+    /// A writer collecting what's written to it, which is then its contents
+    pub fn buffer() -> Writer {
+        Writer {
+            sink: Rc::new(RefCell::new(Sink::Buffer(String::new()))),
+        }
+    }
+    pub fn stdout() -> Writer {
+        Writer::new(io::stdout())
+    }
+    pub fn stderr() -> Writer {
+        Writer::new(io::stderr())
+    }
+    pub fn write_str(&self, string: &str) -> Result<(), Rc<Value>> {
+        match &mut *self.sink.borrow_mut() {
+            Sink::Write(write) => write.write_all(string.as_bytes()).map_err(io_error),
+            Sink::Buffer(buffer) => {
+                buffer.push_str(string);
+                Ok(())
+            }
+            Sink::Closed => Err(stream_closed()),
+        }
+    }
+    pub fn flush(&self) -> Result<(), Rc<Value>> {
+        match &mut *self.sink.borrow_mut() {
+            Sink::Write(write) => write.flush().map_err(io_error),
+            Sink::Buffer(_) => Ok(()),
+            Sink::Closed => Err(stream_closed()),
+        }
+    }
+    /// Flushes what's been written, and lets go of where it was going, so that it's written
+    /// to no more;  closing again does nothing
+    pub fn close(&self) -> Result<(), Rc<Value>> {
+        let mut sink = self.sink.borrow_mut();
+        let flushed = match &mut *sink {
+            Sink::Write(write) => write.flush().map_err(io_error),
+            // A buffer keeps what it's collected, as a closed StringWriter does
+            Sink::Buffer(_) | Sink::Closed => return Ok(()),
+        };
+        *sink = Sink::Closed;
+        flushed
+    }
+    /// What's been written so far, if we're a buffer
+    pub fn contents(&self) -> Option<String> {
+        match &*self.sink.borrow() {
+            Sink::Write(_) | Sink::Closed => None,
+            Sink::Buffer(buffer) => Some(buffer.clone()),
+        }
+    }
+}
+impl fmt::Debug for Writer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Writer")
+    }
+}
+// A buffer prints as what it's collected, so that (str writer) gives it back, as in Clojure
+impl fmt::Display for Writer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.contents() {
+            Some(contents) => write!(f, "{}", contents),
+            None => write!(f, "#writer[]"),
+        }
+    }
+}
+// Writers are only equal to themselves, and their clones
+impl PartialEq for Writer {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.sink, &other.sink)
+    }
+}
+impl Hash for Writer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.sink).hash(state)
+    }
+}
+
+/// Somewhere to read text from, a line at a time;  clones read from the same place, and closing
+/// one closes them all
+#[derive(Clone)]
+pub struct Reader {
+    source: Rc<RefCell<Source>>,
+    /// Text given back by unread, to be read again before anything more of source
+    unread: Rc<RefCell<String>>,
+}
+enum Source {
+    // Read through io::stdin() each time, rather than buffered here, so that we take no more
+    // of it than we use, and leave the rest to anything else reading it
+    Stdin,
+    BufRead(Box<dyn BufRead>),
+    Closed,
+}
+impl Reader {
+    pub fn new(read: impl BufRead + 'static) -> Reader {
+        Reader {
+            source: Rc::new(RefCell::new(Source::BufRead(Box::new(read)))),
+            unread: Rc::new(RefCell::new(String::new())),
+        }
+    }
+    /// A reader of string
+    pub fn from_string(string: String) -> Reader {
+        Reader::new(io::Cursor::new(string.into_bytes()))
+    }
+    pub fn stdin() -> Reader {
+        Reader {
+            source: Rc::new(RefCell::new(Source::Stdin)),
+            unread: Rc::new(RefCell::new(String::new())),
+        }
+    }
+    /// The next line, without its line ending, or None if there's nothing left to read
+    pub fn read_line(&self) -> Result<Option<String>, Rc<Value>> {
+        let mut line = {
+            let mut unread = self.unread.borrow_mut();
+            match unread.find('\n') {
+                Some(newline) => unread.drain(..=newline).collect(),
+                None => std::mem::take(&mut *unread),
+            }
+        };
+        // A whole line was unread, so there's no need to read more
+        let read = if line.ends_with('\n') {
+            Ok(line.len())
+        } else {
+            match &mut *self.source.borrow_mut() {
+                Source::Stdin => io::stdin().read_line(&mut line),
+                Source::BufRead(read) => read.read_line(&mut line),
+                Source::Closed => return Err(stream_closed()),
+            }
+        };
+        match read.map_err(io_error)? {
+            0 if line.is_empty() => Ok(None),
+            _ => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Ok(Some(line))
+            }
+        }
+    }
+    /// Everything left to read, line endings and all
+    pub fn read_to_string(&self) -> Result<String, Rc<Value>> {
+        let mut read = std::mem::take(&mut *self.unread.borrow_mut());
+        match &mut *self.source.borrow_mut() {
+            Source::Stdin => io::stdin().read_to_string(&mut read),
+            Source::BufRead(source) => source.read_to_string(&mut read),
+            Source::Closed => return Err(stream_closed()),
+        }
+        .map_err(io_error)?;
+        Ok(read)
+    }
+    /// Gives text back, to be read before anything else;  as when more was read than was used
+    pub fn unread(&self, text: &str) {
+        self.unread.borrow_mut().insert_str(0, text);
+    }
+    /// Lets go of what we read from, so that nothing more is read from it;  closing again
+    /// does nothing
+    pub fn close(&self) {
+        self.source.replace(Source::Closed);
+        self.unread.borrow_mut().clear();
+    }
+}
+impl fmt::Debug for Reader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Reader")
+    }
+}
+impl fmt::Display for Reader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#reader[]")
+    }
+}
+impl PartialEq for Reader {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.source, &other.source)
+    }
+}
+impl Hash for Reader {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.source).hash(state)
+    }
+}
+
+pub(crate) fn io_error(error: io::Error) -> Rc<Value> {
+    let kind = match error.kind() {
+        io::ErrorKind::NotFound => ConditionKind::FileNotFoundException,
+        _ => ConditionKind::IOException,
+    };
+    Rc::new(error_message::condition(kind, error.to_string()))
+}
+/// As io_error, for an error with the file at path, which its message names as Java's does
+pub(crate) fn file_error(path: &Path, error: io::Error) -> Rc<Value> {
+    io_error(io::Error::new(
+        error.kind(),
+        format!("{} ({})", path.display(), described(&error)),
+    ))
+}
+/// What error says went wrong, leaving out the OS's own code for it, as Java does
+pub(crate) fn described(error: &io::Error) -> String {
+    let mut message = error.to_string();
+    if let Some(code) = message.rfind(" (os error ") {
+        message.truncate(code);
+    }
+    message
+}
+fn stream_closed() -> Rc<Value> {
+    Rc::new(error_message::condition(
+        ConditionKind::IOException,
+        "Stream closed",
+    ))
+}
+
+/// What *out* is currently bound to, in environment
+pub fn out(environment: &Environment) -> Result<Writer, Rc<Value>> {
+    writer(environment, "*out*")
+}
+/// What *err* is currently bound to, in environment
+pub fn err(environment: &Environment) -> Result<Writer, Rc<Value>> {
+    writer(environment, "*err*")
+}
+/// What *in* is currently bound to, in environment
+pub fn input(environment: &Environment) -> Result<Reader, Rc<Value>> {
+    let input = environment.get(&Symbol::intern_with_ns("clojure.core", "*in*"));
+    match &*input {
+        Value::Reader(reader) => Ok(reader.clone()),
+        Value::Condition(_) => Err(input),
+        _ => Err(Rc::new(error_message::type_mismatch(
+            TypeTag::Reader,
+            &input,
+        ))),
+    }
+}
+fn writer(environment: &Environment, name: &str) -> Result<Writer, Rc<Value>> {
+    let writer = environment.get(&Symbol::intern_with_ns("clojure.core", name));
+    match &*writer {
+        Value::Writer(writer) => Ok(writer.clone()),
+        Value::Condition(_) => Err(writer),
+        _ => Err(Rc::new(error_message::type_mismatch(
+            TypeTag::Writer,
+            &writer,
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod writer_tests {
+        use crate::stream::Writer;
+
+        #[test]
+        fn buffer_collects_what_is_written() {
+            let writer = Writer::buffer();
+            writer.write_str("a").unwrap();
+            writer.clone().write_str("b").unwrap();
+            assert_eq!(Some("ab".to_string()), writer.contents());
+            assert_eq!("ab", writer.to_string());
+        }
+
+        #[test]
+        fn closed_writer_writes_no_more() {
+            let writer = Writer::new(Vec::new());
+            writer.write_str("a").unwrap();
+            writer.close().unwrap();
+            assert!(writer.write_str("b").is_err());
+            assert!(writer.close().is_ok());
+        }
+    }
+    mod reader_tests {
+        use crate::stream::Reader;
+
+        #[test]
+        fn read_lines() {
+            let reader = Reader::from_string("a\r\nb\n\nc".to_string());
+            assert_eq!(Some("a".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("b".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("c".to_string()), reader.read_line().unwrap());
+            assert_eq!(None, reader.read_line().unwrap());
+        }
+
+        #[test]
+        fn unread_is_read_again() {
+            let reader = Reader::from_string("c\nd\n".to_string());
+            reader.unread("a\nb");
+            assert_eq!(Some("a".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("bc".to_string()), reader.read_line().unwrap());
+            reader.unread("e\n");
+            assert_eq!(Some("e".to_string()), reader.read_line().unwrap());
+            assert_eq!(Some("d".to_string()), reader.read_line().unwrap());
+            assert_eq!(None, reader.read_line().unwrap());
+        }
+
+        #[test]
+        fn read_rest_then_close() {
+            let reader = Reader::from_string("a\r\nb\n".to_string());
+            reader.unread("c");
+            assert_eq!("ca\r\nb\n", reader.read_to_string().unwrap());
+            reader.close();
+            assert!(reader.read_line().is_err());
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::persistent_list_map::PersistentListMap;
 use crate::traits;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
 
 #[derive(Eq, Clone, Debug)]
 pub struct Symbol {
@@ -58,6 +59,12 @@ impl Symbol {
             meta: PersistentListMap::Empty,
         }
     }
+    /// A new symbol, unique among those made this way;  prefix followed by a number
+    pub fn gensym(prefix: &str) -> Symbol {
+        static GENSYM_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let id = GENSYM_COUNTER.fetch_add(1, AtomicOrdering::Relaxed);
+        Symbol::intern_(&format!("{}{}", prefix, id))
+    }
     pub fn unqualified(&self) -> Symbol {
         // So we can keep the same meta 
         let mut retval = self.clone();
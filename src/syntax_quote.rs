@@ -0,0 +1,350 @@
+//! Syntax-quote, the special form the reader turns `form into
+//!
+//! Like quote, except that within form
+//!   - symbols are namespace qualified;  `map => clojure.core/map, `foo => user/foo
+//!   - symbols ending in # are replaced with generated symbols, the same one for each
+//!     occurrence of a name throughout the syntax-quote;  `(let [x# 1] x#)
+//!   - ~x is replaced by the value of x, and ~@xs by the elements of the value of xs
+//!
+//! Unlike Clojure proper,  this happens when the syntax-quote is evaluated rather than when
+//! it's read, as the reader does not know our namespaces.  The symbols of those in a fn body
+//! (and so a macro's) are qualified when the fn is defined, though, so they mean what they
+//! would have where they were written
+use crate::environment::Environment;
+use crate::error_message;
+use crate::iterable::Iterable;
+use crate::maps::MapEntry;
+use crate::persistent_list::ToPersistentList;
+use crate::persistent_hash_map;
+use crate::persistent_list_map::ToPersistentListMapIter;
+use crate::persistent_vector::ToPersistentVector;
+use crate::protocol::ProtocolCastable;
+use crate::symbol::Symbol;
+use crate::traits::{IMeta, IObj};
+use crate::type_tag::TypeTag;
+use crate::value::{Evaluable, ToValue, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Special forms, which are never namespace qualified
+const SPECIAL_FORMS: &[&str] = &[
+    "def", "loop*", "recur", "if", "case*", "let*", "letfn*", "do", "fn*", "quote", "var",
+    "import*", ".", "set!", "deftype*", "reify*", "try", "throw", "monitor-enter",
+    "monitor-exit", "catch", "finally", "new", "&",
+];
+
+/// Expands form, the argument of a (syntax-quote form), in environment; returns a
+/// Condition if something unquoted does, or if a ~@ is given something that isn't a seq
+pub fn expand(environment: &Rc<Environment>, form: &Rc<Value>) -> Rc<Value> {
+    let mut syntax_quote = SyntaxQuote {
+        environment,
+        gensyms: HashMap::new(),
+    };
+    match syntax_quote.expand(form, 0) {
+        Ok(Expansion::One(value)) => value,
+        Ok(Expansion::Spliced(_)) => Rc::new(error_message::custom("unquote-splicing (~@) used outside of a list or vector")),
+        Err(condition) => condition,
+    }
+}
+
+/// What one form in a syntax-quote expands to;  a ~@ expands to any number of forms, to be
+/// spliced into the surrounding collection
+enum Expansion {
+    One(Rc<Value>),
+    Spliced(Vec<Rc<Value>>),
+}
+
+struct SyntaxQuote<'a> {
+    environment: &'a Rc<Environment>,
+    /// The symbols foo#, bar#, .. have been replaced with so far
+    gensyms: HashMap<String, Symbol>,
+}
+impl<'a> SyntaxQuote<'a> {
+    /// depth is how many syntax-quotes we are nested inside of beyond the outermost;  an
+    /// unquote is only evaluated at depth 0, deeper ones belong to a nested syntax-quote
+    fn expand(&mut self, form: &Rc<Value>, depth: usize) -> Result<Expansion, Rc<Value>> {
+        let expanded = match &**form {
+            Value::Symbol(sym) => Value::Symbol(self.qualify(sym)).to_rc_value(),
+            Value::PersistentList(_) => {
+                let items = form.as_protocol::<Iterable>().iter().collect::<Vec<Rc<Value>>>();
+                match (reader_form(&items), items.get(1)) {
+                    (Some("unquote"), Some(unquoted)) if depth == 0 => {
+                        return Ok(Expansion::One(self.eval(unquoted)?));
+                    }
+                    (Some("unquote-splicing"), Some(unquoted)) if depth == 0 => {
+                        let spliced = self.eval(unquoted)?;
+                        return match &*spliced {
+                            Value::Nil => Ok(Expansion::Spliced(vec![])),
+                            _ => match spliced.try_as_protocol::<Iterable>() {
+                                Some(iterable) => Ok(Expansion::Spliced(iterable.iter().collect())),
+                                None => Err(Rc::new(error_message::type_mismatch(
+                                    TypeTag::ISeq,
+                                    &spliced,
+                                ))),
+                            },
+                        };
+                    }
+                    (Some("unquote"), Some(_)) | (Some("unquote-splicing"), Some(_)) => {
+                        self.expand_seq(&items, depth - 1)?.into_list().to_rc_value()
+                    }
+                    (Some("syntax-quote"), Some(_)) => {
+                        self.expand_seq(&items, depth + 1)?.into_list().to_rc_value()
+                    }
+                    _ => self.expand_seq(&items, depth)?.into_list().to_rc_value(),
+                }
+            }
+            Value::PersistentVector(pvector) => self
+                .expand_seq(&pvector.to_vec(), depth)?
+                .into_vector()
+                .to_rc_value(),
+            Value::PersistentListMap(pmap) => self.expand_map(pmap.iter(), depth)?,
+            Value::PersistentHashMap(phashmap) => self.expand_map(phashmap.iter(), depth)?,
+            Value::PersistentHashSet(pset) => Value::PersistentHashSet(
+                self.expand_seq(&pset.iter().collect::<Vec<Rc<Value>>>(), depth)?
+                    .into_iter()
+                    .collect(),
+            )
+            .to_rc_value(),
+            _ => Rc::clone(form),
+        };
+        Ok(Expansion::One(expanded))
+    }
+
+    fn expand_seq(&mut self, forms: &[Rc<Value>], depth: usize) -> Result<Vec<Rc<Value>>, Rc<Value>> {
+        let mut expanded = vec![];
+        for form in forms.iter() {
+            match self.expand(form, depth)? {
+                Expansion::One(value) => expanded.push(value),
+                Expansion::Spliced(values) => expanded.extend(values),
+            }
+        }
+        Ok(expanded)
+    }
+
+    fn expand_map<I: Iterator<Item = MapEntry>>(&mut self, entries: I, depth: usize) -> Result<Rc<Value>, Rc<Value>> {
+        let mut expanded = vec![];
+        for MapEntry { key, val } in entries {
+            match (self.expand(&key, depth)?, self.expand(&val, depth)?) {
+                (Expansion::One(key), Expansion::One(val)) => expanded.push(MapEntry { key, val }),
+                _ => {
+                    return Err(Rc::new(error_message::custom("unquote-splicing (~@) used in a map; it can only be used in a list or vector")))
+                }
+            }
+        }
+        Ok(Rc::new(persistent_hash_map::build_map(expanded)))
+    }
+
+    fn eval(&self, form: &Rc<Value>) -> Result<Rc<Value>, Rc<Value>> {
+        let value = form.eval_to_rc(Rc::clone(self.environment));
+        match &*value {
+            Value::Condition(_) => Err(value),
+            _ => Ok(value),
+        }
+    }
+
+    /// What sym means within the syntax-quote
+    fn qualify(&mut self, sym: &Symbol) -> Symbol {
+        if let Some(prefix) = sym.name().strip_suffix('#') {
+            return self
+                .gensyms
+                .entry(sym.name.clone())
+                .or_insert_with(|| {
+                    Symbol::intern(&format!("{}__auto__", Symbol::gensym(&format!("{}__", prefix))))
+                })
+                .clone();
+        }
+        qualify(self.environment, sym)
+    }
+}
+
+/// What sym, other than an auto-gensym, means within a syntax-quote expanded in environment
+fn qualify(environment: &Rc<Environment>, sym: &Symbol) -> Symbol {
+    if sym.has_ns() || SPECIAL_FORMS.contains(&sym.name()) {
+        return sym.clone();
+    }
+    // Foo. and .foo, which are left for interop
+    if sym.name() != "." && (sym.name().starts_with('.') || sym.name().ends_with('.')) {
+        return sym.clone();
+    }
+    match environment.resolve_var(sym) {
+        Some(var) => Symbol::intern_with_ns(&var.ns.name, &sym.name),
+        None => Symbol::intern_with_ns(&environment.get_current_namespace_name(), &sym.name),
+    }
+}
+
+/// Qualifies the symbols of the syntax-quotes within form, a fn body say, as they would be
+/// qualified were they expanded now.  Clojure qualifies them as they're read;  we expand
+/// them later, so without this a macro's `(helper) would look for helper in whatever
+/// namespace the macro is called from rather than the one it was defined in.  Auto-gensyms,
+/// and the code in unquotes (bar any syntax-quotes of its own), are left as they are
+pub fn qualify_within(environment: &Rc<Environment>, form: &Rc<Value>) -> Rc<Value> {
+    if let Value::PersistentList(_) = &**form {
+        let items = form.as_protocol::<Iterable>().iter().collect::<Vec<Rc<Value>>>();
+        match items.first().map(|item| &**item) {
+            Some(Value::Symbol(sym)) if sym.name() == "quote" => return Rc::clone(form),
+            _ => {}
+        }
+        if let (Some("syntax-quote"), Some(quoted)) = (reader_form(&items), items.get(1)) {
+            return vec![Rc::clone(&items[0]), qualify_quoted(environment, quoted, 0)]
+                .into_list()
+                .to_rc_value();
+        }
+    }
+    map_forms(form, |form| qualify_within(environment, form))
+}
+
+/// Qualifies the symbols of form, found within a syntax-quote;  depth as for
+/// SyntaxQuote::expand
+fn qualify_quoted(environment: &Rc<Environment>, form: &Rc<Value>, depth: usize) -> Rc<Value> {
+    match &**form {
+        Value::Symbol(sym) if sym.name().ends_with('#') => Rc::clone(form),
+        Value::Symbol(sym) => Value::Symbol(qualify(environment, sym)).to_rc_value(),
+        Value::PersistentList(_) => {
+            let items = form.as_protocol::<Iterable>().iter().collect::<Vec<Rc<Value>>>();
+            let name = match reader_form(&items) {
+                Some(name) if items.len() > 1 => name,
+                _ => return map_forms(form, |form| qualify_quoted(environment, form, depth)),
+            };
+            let mut qualified = vec![Rc::clone(&items[0])];
+            qualified.extend(items[1..].iter().map(|form| match name {
+                "syntax-quote" => qualify_quoted(environment, form, depth + 1),
+                _ if depth == 0 => qualify_within(environment, form),
+                _ => qualify_quoted(environment, form, depth - 1),
+            }));
+            qualified.into_list().to_rc_value()
+        }
+        _ => map_forms(form, |form| qualify_quoted(environment, form, depth)),
+    }
+}
+
+/// form with f applied to each of its forms, if it's a collection of them;  form itself if
+/// that changes none of them (so a fn body keeps the metadata its lists were read with)
+fn map_forms(form: &Rc<Value>, f: impl Fn(&Rc<Value>) -> Rc<Value>) -> Rc<Value> {
+    let forms = match &**form {
+        Value::PersistentList(_) => form.as_protocol::<Iterable>().iter().collect::<Vec<Rc<Value>>>(),
+        Value::PersistentVector(pvector) => pvector.to_vec(),
+        Value::PersistentListMap(_) | Value::PersistentHashMap(_) => {
+            return map_entries(form, f)
+        }
+        Value::PersistentHashSet(pset) => pset.iter().collect(),
+        _ => return Rc::clone(form),
+    };
+    let mapped = forms.iter().map(&f).collect::<Vec<Rc<Value>>>();
+    if forms.iter().zip(mapped.iter()).all(|(form, mapped)| Rc::ptr_eq(form, mapped)) {
+        return Rc::clone(form);
+    }
+    match &**form {
+        Value::PersistentList(plist) => {
+            Value::PersistentList(mapped.into_list().with_meta(plist.meta())).to_rc_value()
+        }
+        Value::PersistentVector(_) => mapped.into_vector().to_rc_value(),
+        _ => Value::PersistentHashSet(mapped.into_iter().collect()).to_rc_value(),
+    }
+}
+
+fn map_entries(form: &Rc<Value>, f: impl Fn(&Rc<Value>) -> Rc<Value>) -> Rc<Value> {
+    let entries = match &**form {
+        Value::PersistentListMap(pmap) => pmap.iter().collect::<Vec<MapEntry>>(),
+        Value::PersistentHashMap(phashmap) => phashmap.iter().collect(),
+        _ => return Rc::clone(form),
+    };
+    let mapped = entries
+        .iter()
+        .map(|MapEntry { key, val }| MapEntry { key: f(key), val: f(val) })
+        .collect::<Vec<MapEntry>>();
+    if entries.iter().zip(mapped.iter()).all(|(entry, mapped)| {
+        Rc::ptr_eq(&entry.key, &mapped.key) && Rc::ptr_eq(&entry.val, &mapped.val)
+    }) {
+        return Rc::clone(form);
+    }
+    Rc::new(persistent_hash_map::build_map(mapped))
+}
+
+/// If items is a (unquote x), (unquote-splicing x) or (syntax-quote x),  which of those it is
+fn reader_form(items: &[Rc<Value>]) -> Option<&str> {
+    match items.first().map(|item| &**item) {
+        Some(Value::Symbol(sym))
+            if (sym.namespace().is_none() || sym.namespace() == Some("clojure.core"))
+                && ["unquote", "unquote-splicing", "syntax-quote"].contains(&sym.name()) =>
+        {
+            Some(sym.name())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod syntax_quote_tests {
+        use crate::environment::Environment;
+        use crate::error_message;
+        use crate::repl::Repl;
+        use crate::test_util::eval_str;
+        use crate::value::{Evaluable, Value};
+        use std::rc::Rc;
+
+        #[test]
+        fn qualifies_symbols() {
+            assert_eq!(
+                eval_str("(quote (clojure.core/map clojure.core/undefined-thing Thread/sleep if &))"),
+                eval_str("`(map undefined-thing Thread/sleep if &)")
+            );
+        }
+
+        #[test]
+        fn unquotes() {
+            assert_eq!(
+                eval_str("(quote (clojure.core/+ 1 2 3 [4 5] {:a 3}))"),
+                eval_str("(let [x 1 ys (list 2 3) zs [4 5]] `(+ ~x ~@ys [~@zs] {:a ~(+ x 2)}))")
+            );
+            assert_eq!(Value::I32(6), eval_str("(let [x 1 ys [2 3]] (eval `(+ ~x ~@ys)))"));
+        }
+
+        #[test]
+        fn auto_gensyms() {
+            assert_eq!(
+                Value::Boolean(true),
+                eval_str("(let [[a b] `(x# x#)] (= a b))")
+            );
+            assert_eq!(
+                Value::Boolean(false),
+                eval_str("(= `x# `x#)")
+            );
+        }
+
+        #[test]
+        fn in_macros() {
+            assert_eq!(
+                Value::I32(3),
+                eval_str("(do (defmacro unless [test & body] `(if ~test nil (do ~@body))) (unless false 1 2 3))")
+            );
+        }
+
+        #[test]
+        fn in_macros_called_from_another_namespace() {
+            let environment = Environment::clojure_core_environment();
+            let eval = |source: &str| {
+                Repl::read_string(source)
+                    .unwrap()
+                    .eval(Rc::clone(&environment))
+            };
+            eval("(ns foo)");
+            eval("(defn helper [] 42)");
+            eval("(defmacro m [] `(helper))");
+            eval("(defmacro n [x] `(let [y# ~x] (+ y# (helper))))");
+            eval("(defmacro k [] (list `helper))");
+            eval("(ns bar)");
+            assert_eq!(Value::I32(42), eval("(foo/m)"));
+            assert_eq!(Value::I32(43), eval("(foo/n 1)"));
+            assert_eq!(Value::I32(42), eval("(foo/k)"));
+        }
+
+        #[test]
+        fn splicing_outside_a_list() {
+            assert_eq!(
+                error_message::custom("unquote-splicing (~@) used outside of a list or vector"),
+                eval_str("`~@[1 2]")
+            );
+        }
+    }
+}
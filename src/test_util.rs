@@ -0,0 +1,37 @@
+//! Helpers shared by our tests
+use crate::environment::Environment;
+use crate::ifn::IFn;
+use crate::repl::Repl;
+use crate::rust_core::MapFn;
+use crate::value::{Evaluable, Value};
+use std::rc::Rc;
+
+/// source, read and evaluated in a fresh clojure.core environment;  for testing special forms
+/// and macros end to end, where there's no one fn to invoke
+pub fn eval_str(source: &str) -> Value {
+    Repl::read_string(source)
+        .unwrap()
+        .eval(Environment::clojure_core_environment())
+}
+
+/// What source reads as, unevaluated;  for what a fn should give back
+pub fn read(source: &str) -> Value {
+    Repl::read_string(source).unwrap()
+}
+
+/// What each of sources reads as, unevaluated, as the arguments to invoke a fn with
+pub fn read_args(sources: &[&str]) -> Vec<Rc<Value>> {
+    sources.iter().map(|source| Rc::new(read(source))).collect()
+}
+
+/// The lazy seq (map f [1 2 3]), where f throws "boom" once it's given 2;  for testing that
+/// what walks a seq throws what working it out does
+pub fn seq_throwing_at_2() -> Rc<Value> {
+    let f = eval_str(r#"(fn [x] (if (= x 2) (throw (ex-info "boom" {})) x))"#);
+    Rc::new(MapFn {}.invoke(vec![Rc::new(f), Rc::new(read("[1 2 3]"))]))
+}
+
+/// Whether value is the condition thrown walking seq_throwing_at_2
+pub fn is_boom(value: &Value) -> bool {
+    matches!(value, Value::Condition(condition) if condition.message == "boom")
+}
@@ -0,0 +1,8 @@
+use crate::value::Value;
+use std::rc::Rc;
+
+/// Something that can be walked as a seq;  see seq.rs
+pub trait Seqable {
+    /// nil if we're empty, otherwise a nonempty seq of our values
+    fn seq(&self) -> Rc<Value>;
+}
@@ -0,0 +1,212 @@
+//! Transducers, as in Clojure;  transformations of reducing fns, so that one transformation
+//! can be run over a reduce, a lazy seq or anything else that steps through values, without
+//! building collections in between
+//!
+//! A reducing fn is any IFn, called three ways;  with no arguments for an initial result,
+//! with a result for a completed result, and with a result and an input to step.  A
+//! transducer is a fn of a reducing fn, giving back another
+//!
+//! Here, each kind of transducer is an XForm;  what it does to each step, given the state
+//! it keeps for the reducing fn it's transforming
+use crate::error_message;
+use crate::ifn::IFn;
+use crate::protocol::ProtocolCastable;
+use crate::protocols;
+use crate::seq::{self, SeqIter};
+use crate::type_tag::TypeTag;
+use crate::value::{ToValue, Value};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// A kind of transducer
+pub trait XForm: Debug + Clone + 'static {
+    /// What's kept between the steps of one transformed reducing fn;  a fresh one for each
+    type State: Debug + Default + 'static;
+    /// Steps rf with result and input, or what this transformation makes of input
+    fn step(
+        &self,
+        state: &RefCell<Self::State>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+        input: Rc<Value>,
+    ) -> Rc<Value>;
+    /// Completes rf with result, once there's no input left;  where anything held back is
+    /// stepped out
+    fn complete(
+        &self,
+        _state: &RefCell<Self::State>,
+        rf: &protocols::IFn,
+        result: Rc<Value>,
+    ) -> Rc<Value> {
+        Rc::new(rf.invoke(vec![result]))
+    }
+}
+
+/// The transducer of an XForm, as a fn of a reducing fn
+#[derive(Debug, Clone)]
+pub struct Transducer<X: XForm> {
+    xform: X,
+}
+impl<X: XForm> Transducer<X> {
+    pub fn new(xform: X) -> Transducer<X> {
+        Transducer { xform }
+    }
+}
+impl<X: XForm> ToValue for Transducer<X> {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl<X: XForm> IFn for Transducer<X> {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        if args.len() != 1 {
+            return error_message::wrong_arg_count(1, args.len());
+        }
+        match args[0].try_as_protocol::<protocols::IFn>() {
+            Some(rf) => Reducer {
+                xform: self.xform.clone(),
+                state: Rc::new(RefCell::new(X::State::default())),
+                rf,
+            }
+            .to_value(),
+            None => error_message::type_mismatch(TypeTag::IFn, &args[0]),
+        }
+    }
+}
+
+/// A reducing fn transformed by an XForm
+#[derive(Debug)]
+pub struct Reducer<X: XForm> {
+    xform: X,
+    state: Rc<RefCell<X::State>>,
+    rf: protocols::IFn,
+}
+// Clones share their state, as they're the same reducing fn
+impl<X: XForm> Clone for Reducer<X> {
+    fn clone(&self) -> Self {
+        Reducer {
+            xform: self.xform.clone(),
+            state: Rc::clone(&self.state),
+            rf: self.rf.clone(),
+        }
+    }
+}
+impl<X: XForm> ToValue for Reducer<X> {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl<X: XForm> IFn for Reducer<X> {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        let mut args = args.into_iter();
+        let reduced = match (args.next(), args.next(), args.next()) {
+            (None, ..) => return self.rf.invoke(vec![]),
+            (Some(result), None, _) => self.xform.complete(&self.state, &self.rf, result),
+            (Some(result), Some(input), None) => {
+                self.xform.step(&self.state, &self.rf, result, input)
+            }
+            _ => return error_message::wrong_varg_count(&[0, 1, 2], 3 + args.len()),
+        };
+        (*reduced).clone()
+    }
+}
+
+/// result, wrapped as reduced if it isn't already
+pub fn ensure_reduced(result: Rc<Value>) -> Rc<Value> {
+    match &*result {
+        Value::Reduced(_) => result,
+        _ => Rc::new(Value::Reduced(result)),
+    }
+}
+
+/// Walks the values of coll as transformed by xform, a transducer;  each input is only taken
+/// from coll when what it steps out is asked for.  As with seq::iter, a condition is the
+/// last value given
+pub fn iter(xform: &protocols::IFn, coll: &Rc<Value>) -> Result<TransducedIter, Rc<Value>> {
+    let buffer = Rc::new(RefCell::new(VecDeque::new()));
+    let rf = Rc::new(xform.invoke(vec![BufferFn {
+        buffer: Rc::clone(&buffer),
+    }
+    .to_rc_value()]));
+    if let Some(interruption) = rf.non_tail() {
+        return Err(interruption);
+    }
+    match rf.try_as_protocol::<protocols::IFn>() {
+        Some(rf) => Ok(TransducedIter {
+            rf,
+            buffer,
+            inputs: Some(seq::iter(coll)),
+        }),
+        None => Err(Rc::new(error_message::type_mismatch(TypeTag::IFn, &rf))),
+    }
+}
+pub struct TransducedIter {
+    /// The transformed reducing fn, stepping out into our buffer
+    rf: protocols::IFn,
+    buffer: Rc<RefCell<VecDeque<Rc<Value>>>>,
+    /// What we've yet to step in, or None once we've completed
+    inputs: Option<SeqIter>,
+}
+impl TransducedIter {
+    /// Completes our rf, once there's no more input to step in, or it's been reduced
+    fn complete(&mut self) {
+        self.inputs = None;
+        let completed = Rc::new(self.rf.invoke(vec![Rc::new(Value::Nil)]));
+        if let Some(interruption) = completed.non_tail() {
+            self.buffer.borrow_mut().push_back(interruption);
+        }
+    }
+}
+impl Iterator for TransducedIter {
+    type Item = Rc<Value>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.buffer.borrow_mut().pop_front() {
+                return Some(value);
+            }
+            match self.inputs.as_mut()?.next() {
+                Some(input) if input.non_tail().is_some() => {
+                    self.inputs = None;
+                    return Some(input);
+                }
+                Some(input) => {
+                    let stepped = Rc::new(self.rf.invoke(vec![Rc::new(Value::Nil), input]));
+                    if let Some(interruption) = stepped.non_tail() {
+                        self.inputs = None;
+                        return Some(interruption);
+                    }
+                    if let Value::Reduced(_) = &*stepped {
+                        self.complete();
+                    }
+                }
+                None => self.complete(),
+            }
+        }
+    }
+}
+
+/// The reducing fn that TransducedIter transforms;  it adds each input to a buffer
+#[derive(Debug, Clone)]
+struct BufferFn {
+    buffer: Rc<RefCell<VecDeque<Rc<Value>>>>,
+}
+impl ToValue for BufferFn {
+    fn to_value(&self) -> Value {
+        Value::IFn(Rc::new(self.clone()))
+    }
+}
+impl IFn for BufferFn {
+    fn invoke(&self, args: Vec<Rc<Value>>) -> Value {
+        match args.as_slice() {
+            [] => Value::Nil,
+            [result] => (**result).clone(),
+            [result, input] => {
+                self.buffer.borrow_mut().push_back(Rc::clone(input));
+                (**result).clone()
+            }
+            _ => error_message::wrong_varg_count(&[0, 1, 2], args.len()),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! try, the special form that catches conditions thrown while evaluating its body
+//!
+//! ```clojure
+//! (try
+//!   expr1 expr2 ..
+//!   (catch ArityException e ..)             ; by kind;  also catches any more specific kind
+//!   (catch :default e ..)                   ; anything at all
+//!   (catch (fn [e] (:k (ex-data e))) e ..)  ; by predicate, given the caught Throwable
+//!   (finally ..))                           ; always run, for its side effects
+//! ```
+//!
+//! The first catch clause that matches is the one run, with e bound to the condition as a
+//! Value::Throwable
+use crate::condition::{Condition, ConditionKind};
+use crate::environment::Environment;
+use crate::error_message;
+use crate::iterable::Iterable;
+use crate::keyword::Keyword;
+use crate::protocol::ProtocolCastable;
+use crate::symbol::Symbol;
+use crate::value::{Evaluable, Value};
+use std::rc::Rc;
+
+/// Evaluates the arguments of a (try ..) in environment
+pub fn eval_try(environment: &Rc<Environment>, args: Vec<Rc<Value>>) -> Rc<Value> {
+    let clauses = match TryClauses::parse(args) {
+        Ok(clauses) => clauses,
+        Err(msg) => return Rc::new(error_message::custom(msg)),
+    };
+    let mut result = Value::implicit_do(&clauses.body).eval_to_rc(Rc::clone(environment));
+    if let Value::Condition(condition) = &*result {
+        for catch in clauses.catches.iter() {
+            match catch.target.matches(environment, condition) {
+                Ok(true) => {
+                    result = catch.eval(environment, condition);
+                    break;
+                }
+                Ok(false) => {}
+                Err(interruption) => {
+                    result = interruption;
+                    break;
+                }
+            }
+        }
+    }
+    // recur can't cross a try, as whatever it recurs to would skip our finally
+    if result.is_recur() {
+        result = Rc::new(error_message::non_tail_recur());
+    }
+    if let Some(finally) = &clauses.finally {
+        let finally_result = finally.eval_to_rc(Rc::clone(environment));
+        if let Some(interruption) = finally_result.non_tail() {
+            return interruption;
+        }
+    }
+    result
+}
+
+struct TryClauses {
+    body: Vec<Rc<Value>>,
+    catches: Vec<CatchClause>,
+    finally: Option<Rc<Value>>,
+}
+impl TryClauses {
+    fn parse(args: Vec<Rc<Value>>) -> Result<TryClauses, String> {
+        let mut clauses = TryClauses {
+            body: vec![],
+            catches: vec![],
+            finally: None,
+        };
+        for arg in args.into_iter() {
+            match clause_name(&arg) {
+                Some("catch") if clauses.finally.is_none() => {
+                    clauses.catches.push(CatchClause::parse(&arg)?)
+                }
+                Some("finally") if clauses.finally.is_none() => {
+                    let items = arg
+                        .as_protocol::<Iterable>()
+                        .iter()
+                        .collect::<Vec<Rc<Value>>>();
+                    clauses.finally = Some(Value::implicit_do(&items[1..]));
+                }
+                Some("catch") | Some("finally") => {
+                    return Err(String::from(
+                        "finally clause must be last in try expression",
+                    ))
+                }
+                _ if clauses.catches.is_empty() && clauses.finally.is_none() => {
+                    clauses.body.push(arg)
+                }
+                _ => {
+                    return Err(String::from(
+                        "Only catch or finally clause can follow catch in try expression",
+                    ))
+                }
+            }
+        }
+        Ok(clauses)
+    }
+}
+
+/// If form is a (catch ..) or (finally ..), which of those it is
+fn clause_name(form: &Rc<Value>) -> Option<&str> {
+    match &**form {
+        Value::PersistentList(_) => match &*form.as_protocol::<Iterable>().iter().next()? {
+            Value::Symbol(sym) if sym.name == "catch" => Some("catch"),
+            Value::Symbol(sym) if sym.name == "finally" => Some("finally"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// What a catch clause catches;  likewise, what a handler-bind handler handles
+pub(crate) enum CatchTarget {
+    /// A kind of condition, or anything more specific
+    Kind(ConditionKind),
+    /// :default, which catches everything
+    Default,
+    /// An expression evaluating to a predicate, given the Throwable
+    Predicate(Rc<Value>),
+}
+impl CatchTarget {
+    pub(crate) fn parse(form: &Rc<Value>) -> CatchTarget {
+        match &**form {
+            Value::Keyword(kw) if *kw == Keyword::intern("default") => CatchTarget::Default,
+            Value::Symbol(sym) if sym.namespace().is_none() => {
+                match ConditionKind::from_name(sym.name()) {
+                    Some(kind) => CatchTarget::Kind(kind),
+                    None => CatchTarget::Predicate(Rc::clone(form)),
+                }
+            }
+            _ => CatchTarget::Predicate(Rc::clone(form)),
+        }
+    }
+    /// Whether we match condition;  Err if evaluating our predicate throws
+    pub(crate) fn matches(
+        &self,
+        environment: &Rc<Environment>,
+        condition: &Rc<Condition>,
+    ) -> Result<bool, Rc<Value>> {
+        match self {
+            CatchTarget::Kind(kind) => Ok(condition.kind.isa(*kind)),
+            CatchTarget::Default => Ok(true),
+            CatchTarget::Predicate(predicate) => {
+                let predicate = predicate.eval_to_rc(Rc::clone(environment));
+                if let Some(interruption) = predicate.non_tail() {
+                    return Err(interruption);
+                }
+                let matched = match &*predicate {
+                    Value::IFn(ifn) => {
+                        Rc::new(ifn.invoke(vec![Rc::new(Value::Throwable(Rc::clone(condition)))]))
+                    }
+                    _ => Rc::new(error_message::custom(format!(
+                        "Unable to resolve classname: {}",
+                        predicate
+                    ))),
+                };
+                match matched.non_tail() {
+                    Some(interruption) => Err(interruption),
+                    None => Ok(matched.is_truthy()),
+                }
+            }
+        }
+    }
+}
+
+struct CatchClause {
+    target: CatchTarget,
+    binding: Symbol,
+    body: Rc<Value>,
+}
+impl CatchClause {
+    fn parse(form: &Rc<Value>) -> Result<CatchClause, String> {
+        let items = form
+            .as_protocol::<Iterable>()
+            .iter()
+            .collect::<Vec<Rc<Value>>>();
+        if items.len() < 3 {
+            return Err(String::from(
+                "catch expects a kind or predicate and a binding; (catch Exception e ..)",
+            ));
+        }
+        let target = CatchTarget::parse(&items[1]);
+        let binding = match &*items[2] {
+            Value::Symbol(sym) if sym.namespace().is_none() => sym.clone(),
+            binding => {
+                return Err(format!(
+                    "Unable to bind {} in catch; expected a symbol",
+                    binding
+                ))
+            }
+        };
+        Ok(CatchClause {
+            target,
+            binding,
+            body: Value::implicit_do(&items[3..]),
+        })
+    }
+    fn eval(&self, environment: &Rc<Environment>, condition: &Rc<Condition>) -> Rc<Value> {
+        let local_environment = Rc::new(Environment::new_local_environment(Rc::clone(environment)));
+        local_environment.insert(
+            self.binding.clone(),
+            Rc::new(Value::Throwable(Rc::clone(condition))),
+        );
+        self.body.eval_to_rc(local_environment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod try_catch_tests {
+        use crate::test_util::eval_str;
+        use crate::value::Value;
+
+        #[test]
+        fn body_without_condition() {
+            assert_eq!(Value::I32(3), eval_str("(try 1 2 3 (catch Exception e 4))"));
+            assert_eq!(Value::Nil, eval_str("(try)"));
+        }
+
+        #[test]
+        fn catch_by_kind() {
+            assert_eq!(
+                Value::Keyword(crate::keyword::Keyword::intern("arity")),
+                eval_str(
+                    "(try ((fn [x] x)) (catch ClassCastException e :type) (catch ArityException e :arity))"
+                )
+            );
+            assert_eq!(
+                Value::Keyword(crate::keyword::Keyword::intern("type")),
+                eval_str(
+                    "(try (+ 1 :a) (catch ArityException e :arity) (catch java.lang.ClassCastException e :type))"
+                )
+            );
+            // More general kinds catch more specific ones
+            assert_eq!(
+                Value::Boolean(true),
+                eval_str("(try ((fn [x] x)) (catch Exception e true))")
+            );
+        }
+
+        #[test]
+        fn uncaught_conditions_propagate() {
+            match eval_str("(try (+ 1 :a) (catch ArityException e :arity))") {
+                Value::Condition(condition) => assert_eq!(
+                    crate::condition::ConditionKind::ClassCastException,
+                    condition.kind
+                ),
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+
+        #[test]
+        fn catch_by_default_and_predicate() {
+            assert_eq!(
+                Value::String(String::from("boom")),
+                eval_str("(try (throw (ex-info \"boom\" {})) (catch :default e (ex-message e)))")
+            );
+            assert_eq!(
+                Value::I32(2),
+                eval_str(
+                    "(try (throw (ex-info \"boom\" {:code 2}))
+                       (catch (fn [e] (= 1 (get (ex-data e) :code))) e 1)
+                       (catch (fn [e] (= 2 (get (ex-data e) :code))) e 2))"
+                )
+            );
+        }
+
+        #[test]
+        fn finally_always_runs() {
+            assert_eq!(
+                eval_str("[1 2]"),
+                eval_str("[(try 1 (finally (def finally-ran 2))) finally-ran]")
+            );
+            assert_eq!(
+                Value::Keyword(crate::keyword::Keyword::intern("caught")),
+                eval_str(
+                    "(try (throw (ex-info \"boom\" {})) (catch Exception e :caught) (finally 3))"
+                )
+            );
+        }
+
+        #[test]
+        fn malformed_try() {
+            match eval_str("(try (catch Exception e 1) 2)") {
+                Value::Condition(condition) => assert_eq!(
+                    "Only catch or finally clause can follow catch in try expression",
+                    condition.message
+                ),
+                value => panic!("expected a condition, got {}", value),
+            }
+        }
+    }
+}
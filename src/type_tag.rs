@@ -3,6 +3,7 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub enum TypeTag {
     I32,
+    I64,
     F64,
     Boolean,
     Symbol,
@@ -13,8 +14,23 @@ pub enum TypeTag {
     PersistentList,
     PersistentVector,
     PersistentListMap,
+    PersistentHashMap,
+    PersistentHashSet,
+    LazySeq,
+    Cons,
+    Reduced,
+    Atom,
+    Writer,
+    Reader,
+    MultiFn,
+    Inst,
+    Uuid,
+    Matcher,
+    Process,
     // Experimental; may make no sense at runtime, as we will likely be unable to take the value of a macro
     Macro,
+    Recur,
+    Restart,
     String,
     Integer,
     ISeq,
@@ -28,6 +44,7 @@ impl fmt::Display for TypeTag {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str = match self {
             I32 => std::string::String::from("rust.std.i32"),
+            I64 => std::string::String::from("rust.std.i64"),
             Boolean => std::string::String::from("rust.std.bool"),
             F64 => std::string::String::from("rust.std.f64"),
             Symbol => std::string::String::from("clojure.lang.Symbol"),
@@ -38,7 +55,22 @@ impl fmt::Display for TypeTag {
             PersistentList => std::string::String::from("clojure.lang.PersistentList"),
             PersistentVector => std::string::String::from("clojure.lang.PersistentVector"),
             PersistentListMap => std::string::String::from("clojure.lang.PersistentListMap"),
+            PersistentHashMap => std::string::String::from("clojure.lang.PersistentHashMap"),
+            PersistentHashSet => std::string::String::from("clojure.lang.PersistentHashSet"),
+            LazySeq => std::string::String::from("clojure.lang.LazySeq"),
+            Cons => std::string::String::from("clojure.lang.Cons"),
+            Reduced => std::string::String::from("clojure.lang.Reduced"),
+            Atom => std::string::String::from("clojure.lang.Atom"),
+            Writer => std::string::String::from("rust.std.io.Write"),
+            Reader => std::string::String::from("rust.std.io.BufRead"),
+            MultiFn => std::string::String::from("clojure.lang.MultiFn"),
+            Inst => std::string::String::from("clojure.lang.Inst"),
+            Uuid => std::string::String::from("clojure.lang.UUID"),
+            Matcher => std::string::String::from("rust.regex.Matcher"),
+            Process => std::string::String::from("rust.std.process.Child"),
             Macro => std::string::String::from("clojure.lang.Macro"),
+            Recur => std::string::String::from("clojure.lang.Recur"),
+            Restart => std::string::String::from("clojure.lang.Restart"),
             TypeTag::String => std::string::String::from("rust.std.string.String"),
             TypeTag::Integer => std::string::String::from("clojure.lang.Integer"),
             ISeq => std::string::String::from("clojure.lang.ISeq"),
@@ -31,6 +31,18 @@ pub fn parse_args(arguments: Vec<String>) -> Action {
     }
 }
 
+/// Whether flag is among arguments (after the program's name), taking it out of them so that
+/// the rest parse as they would without it
+pub fn take_flag(arguments: &mut Vec<String>, flag: &str) -> bool {
+    match arguments.iter().skip(1).position(|argument| argument == flag) {
+        Some(position) => {
+            arguments.remove(position + 1);
+            true
+        }
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod parse_args_test {
@@ -104,6 +116,22 @@ mod tests {
             );
         }
 
+        #[test]
+        fn takes_flag() {
+            let mut arguments = vec![
+                "target/debug/rust_clojure".to_string(),
+                "--pprint".to_string(),
+                "-e".to_string(),
+                "{:a 1}".to_string(),
+            ];
+            assert!(user_action::take_flag(&mut arguments, "--pprint"));
+            assert!(!user_action::take_flag(&mut arguments, "--pprint"));
+            assert_eq!(
+                user_action::Action::Evaluate("{:a 1}".to_string()),
+                user_action::parse_args(arguments)
+            );
+        }
+
         #[test]
         fn parses_args_given_nil() {
             assert_eq!(
@@ -0,0 +1,68 @@
+//! UUIDs, as #uuid reads and prints them;  what java.util.UUID is to Clojure
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Uuid {
+    bits: u128,
+}
+impl Uuid {
+    pub fn from_u128(bits: u128) -> Uuid {
+        Uuid { bits }
+    }
+    /// Parses the canonical form of a UUID, 8-4-4-4-12 hex digits
+    pub fn parse(uuid: &str) -> Result<Uuid, String> {
+        let groups = uuid.split('-').map(str::len).collect::<Vec<usize>>();
+        if groups != [8, 4, 4, 4, 12] || !uuid.chars().all(|ch| ch == '-' || ch.is_ascii_hexdigit())
+        {
+            return Err(format!("Invalid UUID string: {}", uuid));
+        }
+        u128::from_str_radix(&uuid.replace('-', ""), 16)
+            .map(Uuid::from_u128)
+            .map_err(|_| format!("Invalid UUID string: {}", uuid))
+    }
+    /// The canonical form of this, as #uuid prints it
+    pub fn canonical(&self) -> String {
+        let hex = format!("{:032x}", self.bits);
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+}
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#uuid \"{}\"", self.canonical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod uuid_tests {
+        use crate::uuid::Uuid;
+
+        #[test]
+        fn parse_and_print() {
+            let uuid = Uuid::parse("F81D4FAE-7DEC-11D0-A765-00A0C91E6BF6").unwrap();
+            assert_eq!(Uuid::from_u128(0xf81d4fae7dec11d0a76500a0c91e6bf6), uuid);
+            assert_eq!(
+                "#uuid \"f81d4fae-7dec-11d0-a765-00a0c91e6bf6\"",
+                uuid.to_string()
+            );
+        }
+
+        #[test]
+        fn reject_invalid_uuids() {
+            for uuid in &[
+                "",
+                "f81d4fae7dec11d0a76500a0c91e6bf6",
+                "g81d4fae-7dec-11d0-a765-00a0c91e6bf6",
+            ] {
+                assert!(Uuid::parse(uuid).is_err(), "{}", uuid);
+            }
+        }
+    }
+}
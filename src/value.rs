@@ -1,21 +1,45 @@
+use crate::atom::Atom;
+use crate::call_stack;
+use crate::condition::ConditionKind;
 use crate::environment::Environment;
+use crate::destructure;
+use crate::condition;
+use crate::condition_system;
+use crate::error_message;
+use crate::syntax_quote;
+use crate::try_catch;
 use crate::ifn::IFn;
 use crate::keyword::Keyword;
 use crate::lambda;
 use crate::maps::MapEntry;
+use crate::matcher::Matcher;
+use crate::inst::Inst;
+use crate::multi_fn::MultiFn;
 use crate::persistent_list::PersistentList::Cons;
 use crate::persistent_list::{PersistentList, ToPersistentList, ToPersistentListIter};
+use crate::persistent_hash_map::{self, PersistentHashMap};
+use crate::persistent_hash_set::PersistentHashSet;
+use crate::lazy_seq::LazySeq;
+use crate::cons;
+use crate::seq;
 use crate::persistent_list_map::{PersistentListMap, ToPersistentListMapIter};
 use crate::persistent_vector::PersistentVector;
+use crate::process::Process;
+use crate::rust_core;
+use crate::source_position::SourcePosition;
+use crate::stream::{Reader, Writer};
 use crate::symbol::Symbol;
+use crate::uuid::Uuid;
 use crate::var::Var;
 use crate::type_tag::TypeTag;
+use crate::util::IsOdd;
 use core::fmt::Display;
 
 extern crate rand;
 use rand::Rng;
 
 use std::cmp::{Ord, Ordering};
+use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
@@ -30,6 +54,8 @@ use std::rc::Rc;
 #[derive(Debug, Clone)]
 pub enum Value {
     I32(i32),
+    /// An integer too wide for an I32
+    I64(i64),
     F64(f64),
     Boolean(bool),
     Symbol(Symbol),
@@ -47,8 +73,32 @@ pub enum Value {
     PersistentList(PersistentList),
     PersistentVector(PersistentVector),
     PersistentListMap(PersistentListMap),
+    PersistentHashMap(PersistentHashMap),
+    PersistentHashSet(PersistentHashSet),
+    LazySeq(LazySeq),
+    Cons(cons::Cons),
 
-    Condition(std::string::String),
+    // Something went wrong; a condition in flight, handed straight back up through the
+    // forms being evaluated around it until a try catches it.  See condition.rs
+    Condition(Rc<condition::Condition>),
+    // A condition held as an ordinary value, as made by ex-info or caught by catch
+    Throwable(Rc<condition::Condition>),
+    // A value wrapped by (reduced ..), telling whatever is reducing to stop and return it
+    Reduced(Rc<Value>),
+    // A reference to a value that changes, by swap! and reset!.  See atom.rs
+    Atom(Rc<Atom>),
+    // Streams, as held by *out*, *err* and *in*.  See stream.rs
+    Writer(Writer),
+    Reader(Reader),
+    // A fn choosing which of its methods to call by its arguments.  See multi_fn.rs
+    MultiFn(Rc<MultiFn>),
+    // As #inst and #uuid read.  See inst.rs and uuid.rs
+    Inst(Inst),
+    Uuid(Uuid),
+    // A regex being run over a string, one match at a time.  See matcher.rs
+    Matcher(Rc<Matcher>),
+    // Another program, running alongside us.  See process.rs
+    Process(Rc<Process>),
     // Macro body is still a function, that will be applied to our unevaled arguments
     Macro(Rc<dyn IFn>),
     //
@@ -60,6 +110,21 @@ pub enum Value {
     FnMacro,
     LetMacro,
     IfMacro,
+    DoMacro,
+    LoopMacro,
+    RecurMacro,
+    SyntaxQuoteMacro,
+    TryMacro,
+    HandlerBindMacro,
+    RestartCaseMacro,
+    BindingMacro,
+    // The evaluated arguments of a (recur ..), on their way back up to the
+    // loop or fn whose body it ends; that loop or fn rebinds its locals to
+    // them and runs its body again, rather than growing the stack
+    Recur(Vec<Rc<Value>>),
+    // An (invoke-restart ..), on its way back up to the restart-case that offered the
+    // restart;  see condition_system.rs
+    RestartInvocation(Rc<condition_system::RestartInvocation>),
 
     String(std::string::String),
     Nil,
@@ -74,6 +139,8 @@ impl PartialEq for Value {
         //
         match (self, other) {
             (I32(i), I32(i2)) => i == i2,
+            (I64(i), I64(i2)) => i == i2,
+            (I32(i), I64(i2)) | (I64(i2), I32(i)) => i64::from(*i) == *i2,
             (F64(d), F64(d2)) => d == d2,
             (Boolean(b), Boolean(b2)) => b == b2,
             (Symbol(sym), Symbol(sym2)) => sym == sym2,
@@ -87,11 +154,48 @@ impl PartialEq for Value {
             (PersistentList(plist), PersistentList(plist2)) => plist == plist2,
             (PersistentVector(pvector), PersistentVector(pvector2)) => *pvector == *pvector2,
             (PersistentListMap(plistmap), PersistentListMap(plistmap2)) => *plistmap == *plistmap2,
-            (Condition(msg), Condition(msg2)) => msg == msg2,
+            (PersistentHashMap(phashmap), PersistentHashMap(phashmap2)) => *phashmap == *phashmap2,
+            (PersistentHashMap(phashmap), PersistentListMap(plistmap))
+            | (PersistentListMap(plistmap), PersistentHashMap(phashmap)) => {
+                phashmap.equals_list_map(plistmap)
+            }
+            (PersistentHashSet(pset), PersistentHashSet(pset2)) => *pset == *pset2,
+            // Seqs and vectors are equal to any other seq or vector of the same values, lazy
+            // or not;  a seq that throws being worked out is equal to nothing
+            (LazySeq(_), _)
+            | (Value::Cons(_), _)
+            | (_, LazySeq(_))
+            | (_, Value::Cons(_))
+            | (PersistentList(_), PersistentVector(_))
+            | (PersistentVector(_), PersistentList(_)) => seq::equals(self, other).unwrap_or(false),
+            (Condition(condition), Condition(condition2)) => condition == condition2,
+            (Throwable(condition), Throwable(condition2)) => condition == condition2,
+            (Reduced(val), Reduced(val2)) => val == val2,
+            // Atoms are only equal to themselves, whatever they hold
+            (Atom(atom), Atom(atom2)) => Rc::ptr_eq(atom, atom2),
+            (Writer(writer), Writer(writer2)) => writer == writer2,
+            (Reader(reader), Reader(reader2)) => reader == reader2,
+            (MultiFn(multi_fn), MultiFn(multi_fn2)) => Rc::ptr_eq(multi_fn, multi_fn2),
+            (Inst(inst), Inst(inst2)) => inst == inst2,
+            (Uuid(uuid), Uuid(uuid2)) => uuid == uuid2,
+            (Matcher(matcher), Matcher(matcher2)) => Rc::ptr_eq(matcher, matcher2),
+            (Process(process), Process(process2)) => Rc::ptr_eq(process, process2),
             (QuoteMacro, QuoteMacro) => true,
             (DefmacroMacro, DefmacroMacro) => true,
             (DefMacro, DefMacro) => true,
             (LetMacro, LetMacro) => true,
+            (DoMacro, DoMacro) => true,
+            (LoopMacro, LoopMacro) => true,
+            (RecurMacro, RecurMacro) => true,
+            (SyntaxQuoteMacro, SyntaxQuoteMacro) => true,
+            (TryMacro, TryMacro) => true,
+            (HandlerBindMacro, HandlerBindMacro) => true,
+            (RestartCaseMacro, RestartCaseMacro) => true,
+            (BindingMacro, BindingMacro) => true,
+            (Recur(args), Recur(args2)) => args == args2,
+            (RestartInvocation(invocation), RestartInvocation(invocation2)) => {
+                invocation == invocation2
+            }
             (String(string), String(string2)) => string == string2,
             (Nil, Nil) => true,
             (Pattern(p1), Pattern(p2)) => p1.as_str() == p2.as_str(),
@@ -111,6 +215,14 @@ enum ValueHash {
     FnMacro,
     IfMacro,
     LetMacro,
+    DoMacro,
+    LoopMacro,
+    RecurMacro,
+    SyntaxQuoteMacro,
+    TryMacro,
+    HandlerBindMacro,
+    RestartCaseMacro,
+    BindingMacro,
     Nil,
 }
 impl Eq for Value {}
@@ -118,7 +230,13 @@ impl Hash for Value {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
             I32(i) => i.hash(state),
-            F64(d) => d.to_value().hash(state),
+            // As the I32 it's equal to, should it fit in one
+            I64(i) => match i32::try_from(*i) {
+                Ok(i) => i.hash(state),
+                Err(_) => i.hash(state),
+            },
+            // 0.0 and -0.0 are equal, so they hash alike
+            F64(d) => (if *d == 0.0 { 0.0 } else { *d }).to_bits().hash(state),
             Boolean(b) => b.hash(state),
             Symbol(sym) => sym.hash(state),
             Var(var) => var.hash(state),
@@ -132,7 +250,20 @@ impl Hash for Value {
             PersistentList(plist) => plist.hash(state),
             PersistentVector(pvector) => pvector.hash(state),
             PersistentListMap(plistmap) => plistmap.hash(state),
-            Condition(msg) => msg.hash(state),
+            PersistentHashMap(phashmap) => phashmap.hash(state),
+            PersistentHashSet(pset) => pset.hash(state),
+            LazySeq(_) | Value::Cons(_) => seq::hash_ordered(seq::iter(&self.to_rc_value()), state),
+            Condition(condition) => condition.message.hash(state),
+            Throwable(condition) => condition.message.hash(state),
+            Reduced(val) => val.hash(state),
+            Atom(atom) => Rc::as_ptr(atom).hash(state),
+            Writer(writer) => writer.hash(state),
+            Reader(reader) => reader.hash(state),
+            MultiFn(multi_fn) => Rc::as_ptr(multi_fn).hash(state),
+            Inst(inst) => inst.hash(state),
+            Uuid(uuid) => uuid.hash(state),
+            Matcher(matcher) => Rc::as_ptr(matcher).hash(state),
+            Process(process) => Rc::as_ptr(process).hash(state),
             // Random hash is temporary;
             // @TODO implement hashing for functions / macros
             Macro(_) => {
@@ -146,6 +277,16 @@ impl Hash for Value {
             FnMacro => ValueHash::FnMacro.hash(state),
             LetMacro => ValueHash::LetMacro.hash(state),
             IfMacro => ValueHash::IfMacro.hash(state),
+            DoMacro => ValueHash::DoMacro.hash(state),
+            LoopMacro => ValueHash::LoopMacro.hash(state),
+            RecurMacro => ValueHash::RecurMacro.hash(state),
+            SyntaxQuoteMacro => ValueHash::SyntaxQuoteMacro.hash(state),
+            TryMacro => ValueHash::TryMacro.hash(state),
+            HandlerBindMacro => ValueHash::HandlerBindMacro.hash(state),
+            RestartCaseMacro => ValueHash::RestartCaseMacro.hash(state),
+            BindingMacro => ValueHash::BindingMacro.hash(state),
+            Recur(args) => args.hash(state),
+            RestartInvocation(invocation) => invocation.hash(state),
 
             String(string) => string.hash(state),
             Pattern(p) => p.as_str().hash(state),
@@ -159,6 +300,7 @@ impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str = match self {
             I32(val) => val.to_string(),
+            I64(val) => val.to_string(),
             F64(val) => val.to_string(),
             Boolean(val) => val.to_string(),
             Symbol(sym) => sym.to_string(),
@@ -169,7 +311,21 @@ impl fmt::Display for Value {
             PersistentList(plist) => plist.to_string(),
             PersistentVector(pvector) => pvector.to_string(),
             PersistentListMap(plistmap) => plistmap.to_string(),
-            Condition(msg) => format!("#Condition[\"{}\"]", msg),
+            PersistentHashMap(phashmap) => phashmap.to_string(),
+            PersistentHashSet(pset) => pset.to_string(),
+            LazySeq(lazy_seq) => lazy_seq.to_string(),
+            Value::Cons(cons) => cons.to_string(),
+            Condition(condition) => condition.to_string(),
+            Throwable(condition) => condition.to_string(),
+            Reduced(val) => format!("#reduced[{}]", val.to_string_explicit()),
+            Atom(atom) => atom.to_string(),
+            Writer(writer) => writer.to_string(),
+            Reader(reader) => reader.to_string(),
+            MultiFn(multi_fn) => multi_fn.to_string(),
+            Inst(inst) => inst.to_string(),
+            Uuid(uuid) => uuid.to_string(),
+            Matcher(matcher) => matcher.to_string(),
+            Process(process) => process.to_string(),
             Macro(_) => std::string::String::from("#macro[]"),
             QuoteMacro => std::string::String::from("#macro[quote*]"),
             DefMacro => std::string::String::from("#macro[def*]"),
@@ -177,15 +333,43 @@ impl fmt::Display for Value {
             FnMacro => std::string::String::from("#macro[fn*]"),
             IfMacro => std::string::String::from("#macro[if*]"),
             LetMacro => std::string::String::from("#macro[let*]"),
-            Value::String(string) => string.clone(),
-            Pattern(pattern) => std::string::String::from(
-                "#\"".to_owned() + &pattern.as_str().escape_default().to_string().clone() + "\"",
+            DoMacro => std::string::String::from("#macro[do*]"),
+            LoopMacro => std::string::String::from("#macro[loop*]"),
+            RecurMacro => std::string::String::from("#macro[recur*]"),
+            SyntaxQuoteMacro => std::string::String::from("#macro[syntax-quote*]"),
+            TryMacro => std::string::String::from("#macro[try*]"),
+            HandlerBindMacro => std::string::String::from("#macro[handler-bind*]"),
+            RestartCaseMacro => std::string::String::from("#macro[restart-case*]"),
+            BindingMacro => std::string::String::from("#macro[binding*]"),
+            Recur(args) => format!(
+                "#recur[{}]",
+                args.iter()
+                    .map(|arg| arg.to_string_explicit())
+                    .collect::<Vec<std::string::String>>()
+                    .join(" ")
             ),
+            RestartInvocation(invocation) => invocation.to_string(),
+            Value::String(string) => string.clone(),
+            Pattern(pattern) => format!("#\"{}\"", pattern_source(pattern.as_str())),
             Nil => std::string::String::from("nil"),
         };
         write!(f, "{}", str)
     }
 }
+/// source as it's written in a regex literal;  its escapes are left as they are, as the reader
+/// leaves them, but a quote has to be escaped so as not to end it
+fn pattern_source(source: &str) -> std::string::String {
+    let mut written = std::string::String::new();
+    let mut escaped = false;
+    for ch in source.chars() {
+        if ch == '"' && !escaped {
+            written.push('\\');
+        }
+        escaped = ch == '\\' && !escaped;
+        written.push(ch);
+    }
+    written
+}
 impl Value {
     //
     // Likely temporary
@@ -204,6 +388,7 @@ impl Value {
     pub fn type_tag(&self) -> TypeTag {
         match self {
             Value::I32(_) => TypeTag::I32,
+            Value::I64(_) => TypeTag::I64,
             Value::F64(_) => TypeTag::F64,
             Value::Boolean(_) => TypeTag::Boolean,
             Value::Symbol(_) => TypeTag::Symbol,
@@ -214,7 +399,21 @@ impl Value {
             Value::PersistentList(_) => TypeTag::PersistentList,
             Value::PersistentVector(_) => TypeTag::PersistentVector,
             Value::PersistentListMap(_) => TypeTag::PersistentListMap,
+            Value::PersistentHashMap(_) => TypeTag::PersistentHashMap,
+            Value::PersistentHashSet(_) => TypeTag::PersistentHashSet,
+            Value::LazySeq(_) => TypeTag::LazySeq,
+            Value::Cons(_) => TypeTag::Cons,
             Value::Condition(_) => TypeTag::Condition,
+            Value::Throwable(_) => TypeTag::Condition,
+            Value::Reduced(_) => TypeTag::Reduced,
+            Value::Atom(_) => TypeTag::Atom,
+            Value::Writer(_) => TypeTag::Writer,
+            Value::Reader(_) => TypeTag::Reader,
+            Value::MultiFn(_) => TypeTag::MultiFn,
+            Value::Inst(_) => TypeTag::Inst,
+            Value::Uuid(_) => TypeTag::Uuid,
+            Value::Matcher(_) => TypeTag::Matcher,
+            Value::Process(_) => TypeTag::Process,
             // Note; normal Clojure cannot take the value of a macro, so I don't imagine this
             // having significance in the long run, but we will see
             Value::Macro(_) => TypeTag::Macro,
@@ -224,6 +423,16 @@ impl Value {
             Value::LetMacro => TypeTag::Macro,
             Value::FnMacro => TypeTag::Macro,
             Value::IfMacro => TypeTag::Macro,
+            Value::DoMacro => TypeTag::Macro,
+            Value::LoopMacro => TypeTag::Macro,
+            Value::RecurMacro => TypeTag::Macro,
+            Value::SyntaxQuoteMacro => TypeTag::Macro,
+            Value::TryMacro => TypeTag::Macro,
+            Value::HandlerBindMacro => TypeTag::Macro,
+            Value::RestartCaseMacro => TypeTag::Macro,
+            Value::BindingMacro => TypeTag::Macro,
+            Value::Recur(_) => TypeTag::Recur,
+            Value::RestartInvocation(_) => TypeTag::Restart,
             Value::String(_) => TypeTag::String,
             Value::Nil => TypeTag::Nil,
             Value::Pattern(_) => TypeTag::Pattern,
@@ -261,7 +470,10 @@ impl Value {
                 let mut args = PersistentList::iter(args);
                 let haystack = args.next().expect("haystack");
 
-                if !matches!(haystack.type_tag(), TypeTag::PersistentListMap) {
+                if !matches!(
+                    haystack.type_tag(),
+                    TypeTag::PersistentListMap | TypeTag::PersistentHashMap
+                ) {
                     // todo
                     return None;
                 }
@@ -283,16 +495,35 @@ impl Value {
             },
             Value::IFn(ifn) => {
                 // Eval arguments
-                let evaled_arg_refs = PersistentList::iter(args)
-                    .map(|rc_arg| rc_arg.eval_to_rc(Rc::clone(environment)))
-                    .collect::<Vec<Rc<Value>>>();
+                let evaled_arg_refs = match Value::eval_args(environment, args) {
+                    Ok(evaled_arg_refs) => evaled_arg_refs,
+                    Err(interruption) => return Some(interruption),
+                };
 
                 // Invoke fn on arguments
                 Some(Rc::new(ifn.invoke(evaled_arg_refs)))
             }
+            Value::MultiFn(multi_fn) => {
+                let evaled_arg_refs = match Value::eval_args(environment, args) {
+                    Ok(evaled_arg_refs) => evaled_arg_refs,
+                    Err(interruption) => return Some(interruption),
+                };
+                Some(Rc::new(multi_fn.invoke(evaled_arg_refs)))
+            }
+            // (#{a b} x) is x if it's in our set, and otherwise nil
+            Value::PersistentHashSet(pset) => {
+                let evaled_arg_refs = match Value::eval_args(environment, args) {
+                    Ok(evaled_arg_refs) => evaled_arg_refs,
+                    Err(interruption) => return Some(interruption),
+                };
+                match evaled_arg_refs.as_slice() {
+                    [val] => Some(pset.get(val)),
+                    _ => Some(Rc::new(error_message::wrong_arg_count(1, evaled_arg_refs.len()))),
+                }
+            }
             LexicalEvalFn => {
                 if args.len() != 1 {
-                    return Some(Rc::new(Value::Condition(format!(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expected: 1)",
                         args.len()
                     ))));
@@ -303,6 +534,9 @@ impl Value {
                     .collect::<Vec<Rc<Value>>>();
 
                 let evaled_arg = evaled_arg_values.get(0).unwrap();
+                if let Some(interruption) = evaled_arg.non_tail() {
+                    return Some(interruption);
+                }
 
                 Some(evaled_arg.eval_to_rc(Rc::clone(environment)))
             }
@@ -318,6 +552,9 @@ impl Value {
                 let arg_refs = PersistentList::iter(args).collect::<Vec<Rc<Value>>>();
 
                 let macroexpansion = Rc::new(ifn.invoke(arg_refs));
+                if let Value::Condition(_) = &*macroexpansion {
+                    return Some(macroexpansion);
+                }
 
                 Some(macroexpansion.eval_to_rc(Rc::clone(environment)))
             }
@@ -347,7 +584,7 @@ impl Value {
                     .collect::<Vec<Rc<Value>>>();
 
                 if arg_rc_values.len() > 3 || arg_rc_values.is_empty() {
-                    return Some(Rc::new(Value::Condition(format!(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expected: 1-3)",
                         arg_rc_values.len()
                     ))));
@@ -360,6 +597,9 @@ impl Value {
                     .or(Some(&Rc::new(Value::Nil)))
                     .unwrap()
                     .eval_to_rc(Rc::clone(&environment));
+                if let Some(interruption) = defval.non_tail() {
+                    return Some(interruption);
+                }
 
                 let doc_string = if arg_rc_values.len() == 3 {
                     match arg_rc_values.get(1).unwrap().to_value() {
@@ -383,9 +623,7 @@ impl Value {
                         // @TODO return var
                         Some(sym.to_rc_value())
                     }
-                    _ => Some(Rc::new(Value::Condition(std::string::String::from(
-                        "First argument to def must be a symbol",
-                    )))),
+                    _ => Some(Rc::new(error_message::custom("First argument to def must be a symbol"))),
                 }
             }
             DefmacroMacro => {
@@ -394,7 +632,7 @@ impl Value {
                     .collect::<Vec<Rc<Value>>>();
 
                 if arg_rc_values.len() < 2 || arg_rc_values.is_empty() {
-                    return Some(Rc::new(Value::Condition(format!(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expected: >=2)",
                         args.len()
                     ))));
@@ -407,8 +645,12 @@ impl Value {
                 } else {
                     arg_rc_values.get(2..).unwrap()
                 };
-                let mut macro_invokable_body_vec =
-                    vec![Symbol::intern("fn").to_rc_value(), Rc::clone(macro_args)];
+                // Named after the macro, so its expansions show up by name in traces
+                let mut macro_invokable_body_vec = vec![
+                    Symbol::intern("fn").to_rc_value(),
+                    Rc::clone(macro_name),
+                    Rc::clone(macro_args),
+                ];
                 // vec![do expr1 expr2 expr3]
                 macro_invokable_body_vec.extend_from_slice(macro_body_exprs);
                 let macro_invokable_body = macro_invokable_body_vec
@@ -416,7 +658,7 @@ impl Value {
                     .eval(Rc::clone(&environment));
                 let macro_value = match &macro_invokable_body {
 		    Value::IFn(ifn) => Rc::new(Value::Macro(Rc::clone(&ifn))),
-		    _ => Rc::new(Value::Condition(std::string::String::from("Compiler Error: your macro_value somehow compiled into something else entirely.  I don't even know how that happened,  this behavior is hardcoded, that's impressive")))
+		    _ => Rc::new(error_message::custom("Compiler Error: your macro_value somehow compiled into something else entirely.  I don't even know how that happened,  this behavior is hardcoded, that's impressive"))
 		};
                 Some(
                     vec![
@@ -430,67 +672,74 @@ impl Value {
             }
             //
             // (fn [x y z] (+ x y z))
+            // (fn name [x y z] (+ x y z))
+            // (fn name? ([x] x) ([x y] (+ x y)) ([x y & more] (apply + x y more)))
             //
             // @TODO Rename for* everywhere, define for in terms of for* in
             //       ClojureRS
             FnMacro => {
-                let arg_rc_values = PersistentList::iter(args)
+                let mut arg_rc_values = PersistentList::iter(args)
                     .map(|rc_arg| rc_arg)
                     .collect::<Vec<Rc<Value>>>();
 
                 if arg_rc_values.is_empty() {
-                    return Some(Rc::new(Value::Condition(format!(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expect: >=1)",
                         arg_rc_values.len()
                     ))));
                 }
-                // Let's not do fn names yet
-                // let fnname = arg_rc_values.get(0).unwrap();
-                let fn_args = arg_rc_values.get(0).unwrap();
+                let fn_name = match &*arg_rc_values[0] {
+                    Value::Symbol(sym) => Some(sym.clone()),
+                    _ => None,
+                };
+                if fn_name.is_some() {
+                    arg_rc_values.remove(0);
+                }
                 // Let's not do docstrings yet
                 // let docstring = ...
-                match &**fn_args {
-                    Value::PersistentVector(PersistentVector { vals }) => {
-                        let mut arg_syms_vec = vec![];
-                        let enclosing_environment =
-                            Rc::new(Environment::new_local_environment(Rc::clone(&environment)));
-                        for val in vals.iter() {
-                            if let Value::Symbol(sym) = &**val {
-                                arg_syms_vec.push(sym.clone());
-                            }
-                        }
 
-                        let fn_body =
-			// (fn [x y] ) -> nil 
-			    if arg_rc_values.len() <= 1 {
-				Rc::new(Value::Nil)
-				// (fn [x y] expr) -> expr 
-			    } else if arg_rc_values.len() == 2 {
-				Rc::clone(arg_rc_values.get(1).unwrap())
-				// (fn [x y] expr1 expr2 expr3) -> (do expr1 expr2 expr3) 
-			    } else {
-				// (&[expr1 expr2 expr3] 
-				let body_exprs = arg_rc_values.get(1..).unwrap();
-				// vec![do]
-				let mut do_body = vec![Symbol::intern("do").to_rc_value()];
-				// vec![do expr1 expr2 expr3]
-				do_body.extend_from_slice(body_exprs);
-				// (do expr1 expr2 expr3) 
-				do_body.into_list().to_rc_value()
-			    };
-
-                        Some(Rc::new(
-                            lambda::Fn {
-                                body: fn_body,
-                                enclosing_environment,
-                                arg_syms: arg_syms_vec,
+                // Each arity as its parameter vector and its body expressions
+                let arity_decls: Vec<Vec<Rc<Value>>> = match arg_rc_values.first().map(|v| &**v) {
+                    // (fn name? [x y] ..)
+                    Some(Value::PersistentVector(_)) => vec![arg_rc_values],
+                    // (fn name? ([x] ..) ([x y] ..))
+                    Some(Value::PersistentList(_)) => arg_rc_values
+                        .iter()
+                        .map(|arity_decl| match &**arity_decl {
+                            Value::PersistentList(plist) => {
+                                Rc::new(plist.clone()).iter().collect::<Vec<Rc<Value>>>()
                             }
-                            .to_value(),
-                        ))
+                            _ => vec![],
+                        })
+                        .collect(),
+                    _ => {
+                        return Some(Rc::new(error_message::custom("Parameter declaration missing; fn expects a vector of parameters, or lists of them")))
                     }
-                    _ => Some(Rc::new(Value::Condition(std::string::String::from(
-                        "First argument to def must be a symbol",
-                    )))),
+                };
+
+                let mut arities = vec![];
+                for arity_decl in arity_decls.iter() {
+                    let arity = match arity_decl.first().map(|v| &**v) {
+                        // Its syntax-quotes are qualified here, where it's defined
+                        Some(Value::PersistentVector(params)) => lambda::FnArity::new(
+                            &params.to_vec(),
+                            syntax_quote::qualify_within(environment, &Value::implicit_do(&arity_decl[1..])),
+                        ),
+                        _ => Err(std::string::String::from(
+                            "Parameter declaration missing; each arity of a fn should start with a vector of parameters",
+                        )),
+                    };
+                    match arity {
+                        Ok(arity) => arities.push(arity),
+                        Err(msg) => return Some(Rc::new(error_message::custom(msg))),
+                    }
+                }
+
+                let enclosing_environment =
+                    Rc::new(Environment::new_local_environment(Rc::clone(&environment)));
+                match lambda::Fn::new(fn_name, arities, enclosing_environment) {
+                    Ok(lambda) => Some(lambda.to_rc_value()),
+                    Err(msg) => Some(Rc::new(error_message::custom(msg))),
                 }
             }
             LetMacro => {
@@ -499,7 +748,7 @@ impl Value {
                     .collect::<Vec<Rc<Value>>>();
                 if arg_rc_values.is_empty() || arg_rc_values.len() > 2 {
                     // @TODO: we give 0 but it may be 3, 4, 5...
-                    return Some(Rc::new(Value::Condition(std::string::String::from(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, std::string::String::from(
                         "Wrong number of arguments given to let (Given: 0, Expecting: 1 or 2)",
                     ))));
                 }
@@ -510,8 +759,20 @@ impl Value {
                         //let mut local_environment_map : HashMap<Symbol,Rc<Value>> = HashMap::new();
                         let local_environment =
                             Rc::new(Environment::new_local_environment(Rc::clone(environment)));
+                        let vals = vector.to_vec();
+                        if vals.len().is_odd() {
+                            return Some(Rc::new(error_message::custom("let requires an even number of forms in its binding vector")));
+                        }
+                        if let Some(Err(msg)) = vals
+                            .iter()
+                            .step_by(2)
+                            .map(|binding| destructure::validate(binding))
+                            .find(|validated| validated.is_err())
+                        {
+                            return Some(Rc::new(error_message::custom(msg)));
+                        }
                         // let chunk_test2 =
-                        for pair in vector.vals.chunks(2) {
+                        for pair in vals.chunks(2) {
                             if let Some(rc_sym) = (&*pair).get(0)
                             //(*pair[0]).clone()
                             {
@@ -519,9 +780,13 @@ impl Value {
                                     .get(1)
                                     .unwrap()
                                     .eval_to_rc(Rc::clone(&local_environment));
-                                if let Value::Symbol(sym) = &(**rc_sym) {
-                                    local_environment.insert(sym.clone(), val);
-                                    //println!("Sym found: {:?}: {:?}",sym,val)
+                                if let Some(interruption) = val.non_tail() {
+                                    return Some(interruption);
+                                }
+                                if let Err(msg) =
+                                    destructure::bind(&local_environment, rc_sym, val)
+                                {
+                                    return Some(Rc::new(error_message::custom(msg)));
                                 }
                             } else {
                                 //println!("Nope; pair: {:?}",pair)
@@ -534,9 +799,7 @@ impl Value {
                             Some(Rc::new(Value::Nil))
                         }
                     }
-                    _ => Some(Rc::new(Value::Condition(std::string::String::from(
-                        "Bindings to let should be a vector",
-                    )))),
+                    _ => Some(Rc::new(error_message::custom("Bindings to let should be a vector"))),
                 }
             }
             //
@@ -545,12 +808,12 @@ impl Value {
             //
             QuoteMacro => {
                 match args.len().cmp(&1) {
-                    Ordering::Greater => Some(Rc::new(Value::Condition(format!(
+                    Ordering::Greater => Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expected: 1)",
                         args.len()
                     )))),
                     // @TODO define is_empty()
-                    Ordering::Less => Some(Rc::new(Value::Condition(std::string::String::from(
+                    Ordering::Less => Some(Rc::new(error_message::condition(ConditionKind::ArityException, std::string::String::from(
                         "Wrong number of arguments (Given: 0, Expected: 1)",
                     )))),
                     Ordering::Equal => Some(args.nth(0)),
@@ -558,13 +821,16 @@ impl Value {
             }
             IfMacro => {
                 if args.len() != 2 && args.len() != 3 {
-                    return Some(Rc::new(Value::Condition(format!(
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, format!(
                         "Wrong number of arguments (Given: {}, Expected: 2 or 3)",
                         args.len()
                     ))));
                 }
                 let arg_refs = PersistentList::iter(args).collect::<Vec<Rc<Value>>>();
-                let condition = arg_refs.get(0).unwrap().eval(Rc::clone(environment));
+                let condition = arg_refs.get(0).unwrap().eval_to_rc(Rc::clone(environment));
+                if let Some(interruption) = condition.non_tail() {
+                    return Some(interruption);
+                }
 
                 if condition.is_truthy() {
                     Some(arg_refs.get(1).unwrap().eval_to_rc(Rc::clone(environment)))
@@ -578,6 +844,139 @@ impl Value {
                 }
             }
             //
+            // (do expr1 expr2 expr3)
+            //
+            // A special form rather than a plain macro over do-fn*, so that its last
+            // expression stays in tail position (see loop / recur)
+            DoMacro => {
+                let mut result = Rc::new(Value::Nil);
+                for form in PersistentList::iter(args) {
+                    // Only the last expression may recur
+                    if let Some(interruption) = result.non_tail() {
+                        return Some(interruption);
+                    }
+                    result = form.eval_to_rc(Rc::clone(environment));
+                }
+                Some(result)
+            }
+            //
+            // (loop [x 0 y 10] expr1 expr2 (recur (inc x) y))
+            //
+            // Binds like let, but if its body ends in a (recur ..), we rebind our locals
+            // to recur's arguments and run the body again, without growing the stack
+            LoopMacro => {
+                let arg_rc_values = PersistentList::iter(args).collect::<Vec<Rc<Value>>>();
+                if arg_rc_values.is_empty() {
+                    return Some(Rc::new(error_message::condition(ConditionKind::ArityException, std::string::String::from(
+                        "Wrong number of arguments (Given: 0, Expected: >=1)",
+                    ))));
+                }
+                let bindings = match &**arg_rc_values.first().unwrap() {
+                    Value::PersistentVector(vector) => vector.to_vec(),
+                    _ => {
+                        return Some(Rc::new(error_message::custom("Bindings to loop should be a vector")))
+                    }
+                };
+                if bindings.len().is_odd() {
+                    return Some(Rc::new(error_message::custom("loop requires an even number of forms in its binding vector")));
+                }
+
+                let loop_bindings = bindings.iter().step_by(2).collect::<Vec<&Rc<Value>>>();
+                if let Some(Err(msg)) = loop_bindings
+                    .iter()
+                    .map(|binding| destructure::validate(binding))
+                    .find(|validated| validated.is_err())
+                {
+                    return Some(Rc::new(error_message::custom(msg)));
+                }
+
+                let mut local_environment =
+                    Rc::new(Environment::new_local_environment(Rc::clone(environment)));
+                for pair in bindings.chunks(2) {
+                    let val = pair[1].eval_to_rc(Rc::clone(&local_environment));
+                    if let Some(interruption) = val.non_tail() {
+                        return Some(interruption);
+                    }
+                    if let Err(msg) = destructure::bind(&local_environment, &pair[0], val) {
+                        return Some(Rc::new(error_message::custom(msg)));
+                    }
+                }
+
+                let body = Value::implicit_do(arg_rc_values.get(1..).unwrap());
+                loop {
+                    let result = body.eval_to_rc(Rc::clone(&local_environment));
+                    match &*result {
+                        Value::Recur(recur_args) => {
+                            if recur_args.len() != loop_bindings.len() {
+                                return Some(Rc::new(error_message::recur_arg_count(
+                                    loop_bindings.len(),
+                                    recur_args.len(),
+                                )));
+                            }
+                            // A fresh environment each time around, in case the last
+                            // iteration's was closed over
+                            local_environment =
+                                Rc::new(Environment::new_local_environment(Rc::clone(environment)));
+                            for (binding, val) in loop_bindings.iter().zip(recur_args.iter()) {
+                                if let Err(msg) =
+                                    destructure::bind(&local_environment, binding, Rc::clone(val))
+                                {
+                                    return Some(Rc::new(error_message::custom(msg)));
+                                }
+                            }
+                        }
+                        _ => return Some(result),
+                    }
+                }
+            }
+            //
+            // (recur expr1 expr2)
+            //
+            // Evaluates its arguments and hands them back up to the enclosing loop or fn
+            // as a Value::Recur.  Anything that would otherwise consume a Value::Recur
+            // (ie, a recur outside of tail position) rejects it
+            RecurMacro => match Value::eval_args(environment, args) {
+                Ok(evaled_arg_refs) => Some(Rc::new(Value::Recur(evaled_arg_refs))),
+                Err(interruption) => Some(interruption),
+            },
+            //
+            // (syntax-quote form), which the reader turns `form into
+            //
+            SyntaxQuoteMacro => {
+                if args.len() != 1 {
+                    return Some(Rc::new(error_message::wrong_arg_count(1, args.len() as usize)));
+                }
+                Some(syntax_quote::expand(environment, &args.nth(0)))
+            }
+            //
+            // (try expr* (catch kind-or-predicate e expr*)* (finally expr*)?)
+            //
+            TryMacro => Some(try_catch::eval_try(
+                environment,
+                PersistentList::iter(args).collect(),
+            )),
+            //
+            // (handler-bind [kind-or-predicate handler ..] expr*)
+            //
+            HandlerBindMacro => Some(condition_system::eval_handler_bind(
+                environment,
+                PersistentList::iter(args).collect(),
+            )),
+            //
+            // (restart-case expr (restart-name [params*] expr*)*)
+            //
+            RestartCaseMacro => Some(condition_system::eval_restart_case(
+                environment,
+                PersistentList::iter(args).collect(),
+            )),
+            //
+            // (binding* binding-map expr*), which binding expands to
+            //
+            BindingMacro => Some(rust_core::eval_binding(
+                environment,
+                PersistentList::iter(args).collect(),
+            )),
+            //
             // If we're not a valid IFn
             //
             _ => None,
@@ -586,6 +985,51 @@ impl Value {
     ////////////////////////////////////////////////////////////////////////////////////////////////////
     // Eval Helper
     ////////////////////////////////////////////////////////////////////////////////////////////////////
+    /// Wraps a body of expressions in a single expression;
+    ///   []                  => nil
+    ///   [expr]              => expr
+    ///   [expr1 expr2 expr3] => (do expr1 expr2 expr3)
+    pub(crate) fn implicit_do(body_exprs: &[Rc<Value>]) -> Rc<Value> {
+        match body_exprs.len() {
+            0 => Rc::new(Value::Nil),
+            1 => Rc::clone(&body_exprs[0]),
+            _ => {
+                let mut do_body = vec![Symbol::intern("do").to_rc_value()];
+                do_body.extend_from_slice(body_exprs);
+                do_body.into_list().to_rc_value()
+            }
+        }
+    }
+    /// Whether this is a (recur ..) on its way back up to its loop or fn
+    pub fn is_recur(&self) -> bool {
+        matches!(self, Value::Recur(_))
+    }
+    /// If this is the result of a form that isn't in tail position, yet must cut short
+    /// evaluation of whatever it's a part of, what to hand back up instead;  a thrown
+    /// Condition or an invoked restart is handed back as is, and a Recur becomes the error of
+    /// recurring from outside of tail position
+    pub fn non_tail(self: &Rc<Value>) -> Option<Rc<Value>> {
+        match &**self {
+            Value::Condition(_) | Value::RestartInvocation(_) => Some(Rc::clone(self)),
+            Value::Recur(_) => Some(Rc::new(error_message::non_tail_recur())),
+            _ => None,
+        }
+    }
+    /// Evaluates args from left to right, stopping at the first that is thrown or recurs
+    fn eval_args(
+        environment: &Rc<Environment>,
+        args: &Rc<PersistentList>,
+    ) -> Result<Vec<Rc<Value>>, Rc<Value>> {
+        let mut evaled_args = vec![];
+        for arg in PersistentList::iter(args) {
+            let evaled_arg = arg.eval_to_rc(Rc::clone(environment));
+            if let Some(interruption) = evaled_arg.non_tail() {
+                return Err(interruption);
+            }
+            evaled_args.push(evaled_arg);
+        }
+        Ok(evaled_args)
+    }
     pub fn is_truthy(&self) -> bool {
         if let Value::Boolean(false) = self {
             return false;
@@ -622,6 +1066,12 @@ impl ToValue for i32 {
     }
 }
 
+impl ToValue for i64 {
+    fn to_value(&self) -> Value {
+        Value::I64(*self)
+    }
+}
+
 impl ToValue for f64 {
     fn to_value(&self) -> Value {
         Value::F64(*self)
@@ -695,11 +1145,35 @@ impl ToValue for PersistentListMap {
     }
 }
 
+impl ToValue for PersistentHashMap {
+    fn to_value(&self) -> Value {
+        Value::PersistentHashMap(self.clone())
+    }
+}
+
+impl ToValue for PersistentHashSet {
+    fn to_value(&self) -> Value {
+        Value::PersistentHashSet(self.clone())
+    }
+}
+
+impl ToValue for LazySeq {
+    fn to_value(&self) -> Value {
+        Value::LazySeq(self.clone())
+    }
+}
+
+impl ToValue for cons::Cons {
+    fn to_value(&self) -> Value {
+        Value::Cons(self.clone())
+    }
+}
+
 impl<T: Display, V: ToValue> ToValue for Result<V, T> {
     fn to_value(&self) -> Value {
         match self {
             Ok(val) => val.to_value(),
-            Err(err) => Value::Condition(err.to_string()),
+            Err(err) => error_message::custom(err.to_string()),
         }
     }
 }
@@ -725,41 +1199,65 @@ impl Evaluable for Rc<Value> {
     fn eval_to_rc(&self, environment: Rc<Environment>) -> Rc<Value> {
         match &**self {
             // Evaluating a symbol means grabbing the value its been bound to in our environment
-            Value::Symbol(symbol) => environment.get(symbol),
+            Value::Symbol(symbol) => with_source_position(self, environment.get(symbol)),
             // Evaluating a vector [a b c] just means [(eval a) (eval b) (eval c)]
             Value::PersistentVector(pvector) => {
                 // Evaluate each Rc<Value> our PersistentVector wraps
                 // and return a new PersistentVector wrapping the new evaluated Values
-                let evaled_vals = pvector
-                    .vals
-                    .iter()
-                    .map(|rc_val| rc_val.eval_to_rc(Rc::clone(&environment)))
-                    .collect::<PersistentVector>();
-                Rc::new(Value::PersistentVector(evaled_vals))
+                let mut evaled_vals = vec![];
+                for rc_val in pvector.to_vec().iter() {
+                    let evaled_val = rc_val.eval_to_rc(Rc::clone(&environment));
+                    if let Some(interruption) = evaled_val.non_tail() {
+                        return interruption;
+                    }
+                    evaled_vals.push(evaled_val);
+                }
+                Rc::new(Value::PersistentVector(evaled_vals.into_iter().collect()))
             }
-            Value::PersistentListMap(plistmap) => {
-                // Evaluate each Rc<Value> our PersistentVector wraps
-                // and return a new PersistentVector wrapping the new evaluated Values
-                let evaled_vals = plistmap
-                    .iter()
-                    .map(|map_entry| MapEntry {
-                        key: map_entry.key.eval_to_rc(Rc::clone(&environment)),
-                        val: map_entry.val.eval_to_rc(Rc::clone(&environment)),
-                    })
-                    .collect::<PersistentListMap>();
-                Rc::new(Value::PersistentListMap(evaled_vals))
+            Value::PersistentListMap(plistmap) => eval_map_entries(plistmap.iter(), &environment),
+            Value::PersistentHashMap(phashmap) => eval_map_entries(phashmap.iter(), &environment),
+            // Evaluating a set #{a b c} means #{(eval a) (eval b) (eval c)}
+            Value::PersistentHashSet(pset) => {
+                let mut evaled_vals = vec![];
+                for rc_val in pset.iter() {
+                    let evaled_val = rc_val.eval_to_rc(Rc::clone(&environment));
+                    if let Some(interruption) = evaled_val.non_tail() {
+                        return interruption;
+                    }
+                    evaled_vals.push(evaled_val);
+                }
+                Rc::new(Value::PersistentHashSet(evaled_vals.into_iter().collect()))
+            }
+            // A seq made by fns rather than read, as macros often give back, is evaluated as
+            // the list of its values
+            Value::LazySeq(_) | Value::Cons(_) => {
+                let mut forms = vec![];
+                for form in seq::iter(self) {
+                    if let Value::Condition(_) = &*form {
+                        return form;
+                    }
+                    forms.push(form);
+                }
+                forms
+                    .into_iter()
+                    .collect::<PersistentList>()
+                    .eval_to_rc(environment)
             }
             // Evaluating a list (a b c) means calling a as a function or macro on arguments b and c
             Value::PersistentList(plist) => match plist {
-                Cons(head, tail, __count) => {
+                Cons(head, tail, __count, _) => {
                     // First we have to evaluate the head of our list and make sure it is function-like
                     // and can be invoked on our arguments
                     // (ie, a fn, a macro, a keyword ..)
                     // @TODO remove clone if possible
                     let ifn = Rc::clone(head).eval_to_rc(Rc::clone(&environment));
+                    if let Some(interruption) = ifn.non_tail() {
+                        return interruption;
+                    }
 
-                    let try_apply_ifn =
-                        ifn.apply_to_persistent_list(&Rc::clone(&environment), tail);
+                    let try_apply_ifn = call_stack::in_form(self, || {
+                        ifn.apply_to_persistent_list(&Rc::clone(&environment), tail)
+                    });
 
                     // Right now we're using the normal error message, however maybe later we will try
                     //
@@ -768,12 +1266,13 @@ impl Evaluable for Rc<Value> {
                     //
                     // Sounds less correct but also seems clearer; the current error message relies on
                     // you pretty much already knowing when this error message is called
-                    try_apply_ifn.unwrap_or_else(|| {
-                        Rc::new(Value::Condition(format!(
+                    let result = try_apply_ifn.unwrap_or_else(|| {
+                        Rc::new(error_message::condition(ConditionKind::ClassCastException, format!(
                             "Execution Error: {} cannot be cast to clojure.lang.IFn",
                             ifn.type_tag()
                         )))
-                    })
+                    });
+                    with_source_position(self, result)
                 }
                 // () evals to ()
                 PersistentList::Empty => Rc::new(Value::PersistentList(PersistentList::Empty)),
@@ -783,6 +1282,40 @@ impl Evaluable for Rc<Value> {
         }
     }
 }
+/// Evaluating a map {a b c d} means {(eval a) (eval b) (eval c) (eval d)}
+fn eval_map_entries<I: Iterator<Item = MapEntry>>(
+    entries: I,
+    environment: &Rc<Environment>,
+) -> Rc<Value> {
+    let mut evaled_entries = vec![];
+    for map_entry in entries {
+        let key = map_entry.key.eval_to_rc(Rc::clone(environment));
+        if let Some(interruption) = key.non_tail() {
+            return interruption;
+        }
+        let val = map_entry.val.eval_to_rc(Rc::clone(environment));
+        if let Some(interruption) = val.non_tail() {
+            return interruption;
+        }
+        evaled_entries.push(MapEntry { key, val });
+    }
+    Rc::new(persistent_hash_map::build_map(evaled_entries))
+}
+/// If evaluating form raised a condition that doesn't yet know where it was raised, tells it
+/// form's position;  as forms return outwards, this leaves it with the innermost position
+fn with_source_position(form: &Rc<Value>, result: Rc<Value>) -> Rc<Value> {
+    match &*result {
+        Value::Condition(condition) if condition.position.is_none() => {
+            match SourcePosition::of(form) {
+                Some(position) => Rc::new(Value::Condition(Rc::new(
+                    (**condition).clone().with_position(Some(position)),
+                ))),
+                None => result,
+            }
+        }
+        _ => result,
+    }
+}
 impl Evaluable for PersistentList {
     fn eval_to_rc(&self, environment: Rc<Environment>) -> Rc<Value> {
         self.to_rc_value().eval_to_rc(environment)
@@ -796,42 +1329,229 @@ impl Evaluable for Value {
 
 #[cfg(test)]
 mod tests {
+    use crate::environment::Environment;
     use crate::keyword::Keyword;
-    use crate::symbol::Symbol;
+    use crate::persistent_list_map::IPersistentMap;
+    use crate::persistent_list_map::PersistentListMap;
+    use crate::protocol::ProtocolCastable;
     use crate::protocols;
-    use crate::value::Value;
-    use crate::value::ToValue;
+    use crate::symbol::Symbol;
+    use crate::test_util::eval_str;
     use crate::traits::IMeta;
+    use crate::value::ToValue;
+    use crate::value::Value;
     use std::rc::Rc;
-    use crate::environment::Environment;
-    use crate::persistent_list_map::PersistentListMap;
-    use crate::persistent_list_map::IPersistentMap;
-    use crate::protocol::ProtocolCastable;
 
     // (def ^{:cat 1 :dog 2} a "Docstring" 1)
     // ==>
     // a with meta of {:cat 1 :dog 2 :doc "Docstring"} ?
     #[test]
     fn def_with_docstring() {
-        let sym_meta = persistent_list_map!{
+        let sym_meta = persistent_list_map! {
             "cat" => 1,
             "dog" => 2
         };
         let a = sym!("a").with_meta(sym_meta);
         let result = Value::DefMacro.apply_to_persistent_list(
             &Rc::new(Environment::new_main_environment()),
-            &Rc::new(list!(a "Docstring" 1))
+            &Rc::new(list!(a "Docstring" 1)),
+        );
+
+        let final_sym_meta = result.unwrap().as_protocol::<protocols::IMeta>().meta();
+
+        assert_eq!(
+            Value::I32(1),
+            *final_sym_meta.get(&Keyword::intern("cat").to_rc_value())
+        );
+        assert_eq!(
+            Value::I32(2),
+            *final_sym_meta.get(&Keyword::intern("dog").to_rc_value())
         );
+        assert_eq!(
+            Value::String("Docstring".to_string()),
+            *final_sym_meta.get(&Keyword::intern("doc").to_rc_value())
+        );
+    }
+
+    mod hash {
+        use crate::ifn::IFn;
+        use crate::rust_core::{DistinctFn, FrequenciesFn};
+        use crate::test_util::{read, read_args};
+        use crate::value::Value;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use std::rc::Rc;
 
-        let final_sym_meta =
-            result
-            .unwrap()
-            .as_protocol::<protocols::IMeta>()
-            .meta();
+        fn hash_of(value: &Value) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        #[test]
+        fn floats_as_keys() {
+            assert_eq!(hash_of(&read("{1.0 2}")), hash_of(&read("{1.0 2}")));
+            assert_eq!(hash_of(&read("0.0")), hash_of(&read("-0.0")));
+            match read("#{1.0}") {
+                Value::PersistentHashSet(pset) => {
+                    assert!(pset.contains(&Rc::new(Value::F64(1.0))))
+                }
+                value => panic!("expected a set, got {}", value),
+            }
+            assert_eq!(
+                read("(1.0 2.0)"),
+                DistinctFn {}.invoke(read_args(&["[1.0 2.0 1.0]"]))
+            );
+            assert_eq!(
+                read("{1.0 2}"),
+                FrequenciesFn {}.invoke(read_args(&["[1.0 1.0]"]))
+            );
+        }
+
+        #[test]
+        fn i64s_are_equal_to_i32s_of_the_same_value() {
+            assert_eq!(Value::I32(1), Value::I64(1));
+            assert_eq!(hash_of(&Value::I32(1)), hash_of(&Value::I64(1)));
+            assert_ne!(Value::I32(1), Value::I64(12345678901));
+        }
+    }
+
+    mod loop_recur {
+        use super::eval_str;
+        use crate::error_message;
+        use crate::value::Value;
 
-        assert_eq!(Value::I32(1),*final_sym_meta.get(&Keyword::intern("cat").to_rc_value()));
-        assert_eq!(Value::I32(2),*final_sym_meta.get(&Keyword::intern("dog").to_rc_value()));
-        assert_eq!(Value::String("Docstring".to_string()),*final_sym_meta.get(&Keyword::intern("doc").to_rc_value()));
+        #[test]
+        fn loop_without_recur_is_like_let() {
+            assert_eq!(Value::I32(3), eval_str("(loop [a 1 b (+ a 1)] (+ a b))"));
+        }
+
+        #[test]
+        fn loop_recur_runs_in_constant_stack() {
+            assert_eq!(
+                Value::I32(100000),
+                eval_str("(loop [i 0] (if (= i 100000) i (recur (inc i))))")
+            );
+        }
 
-    }  
+        #[test]
+        fn recur_through_do_and_when() {
+            assert_eq!(
+                Value::Nil,
+                eval_str("(loop [i 0] (when (= i 0) (do :ignored (recur 1))))")
+            );
+        }
+
+        #[test]
+        fn fn_recur_runs_in_constant_stack() {
+            assert_eq!(
+                Value::I32(100000),
+                eval_str("((fn [n acc] (if (= n 0) acc (recur (- n 1) (+ acc 1)))) 100000 0)")
+            );
+        }
+
+        #[test]
+        fn variadic_fn_recur_takes_rest_as_seq() {
+            assert_eq!(
+                Value::I32(10),
+                eval_str(
+                    "((fn [x & more] (if more (recur (+ x (first more)) (next more)) x)) 1 2 3 4)"
+                )
+            );
+        }
+
+        #[test]
+        fn non_tail_recur_is_rejected() {
+            let condition = error_message::non_tail_recur();
+            assert_eq!(condition, eval_str("(loop [i 0] (+ 1 (recur i)))"));
+            assert_eq!(condition, eval_str("(loop [i 0] (do (recur 1) 2))"));
+            assert_eq!(condition, eval_str("(loop [i 0] (if (recur 1) 1 2))"));
+            assert_eq!(condition, eval_str("(loop [i 0] [(recur 1)])"));
+        }
+
+        #[test]
+        fn recur_with_wrong_arg_count() {
+            assert_eq!(
+                error_message::recur_arg_count(1, 2),
+                eval_str("(loop [i 0] (recur 1 2))")
+            );
+        }
+    }
+
+    mod fn_arities {
+        use super::eval_str;
+        use crate::condition::ConditionKind;
+        use crate::error_message;
+        use crate::value::Value;
+
+        #[test]
+        fn dispatches_on_arg_count() {
+            let source =
+                "((fn ([] 0) ([x] x) ([x y] (+ x y)) ([x y & more] (+ x y (apply + more)))) ";
+            assert_eq!(Value::I32(0), eval_str(&format!("{})", source)));
+            assert_eq!(Value::I32(1), eval_str(&format!("{} 1)", source)));
+            assert_eq!(Value::I32(3), eval_str(&format!("{} 1 2)", source)));
+            assert_eq!(Value::I32(10), eval_str(&format!("{} 1 2 3 4)", source)));
+        }
+
+        #[test]
+        fn named_fn_can_call_itself() {
+            assert_eq!(
+                Value::I32(120),
+                eval_str("((fn fact [n] (if (= n 0) 1 (* n (fact (dec n))))) 5)")
+            );
+            assert_eq!(Value::I32(2), eval_str("((fn f ([] (f 1)) ([x] (+ x 1))))"));
+        }
+
+        #[test]
+        fn defn_with_several_arities() {
+            assert_eq!(
+                Value::I32(1),
+                eval_str("(do (defn g ([] 0) ([x] (+ x 1))) (g (g)))")
+            );
+        }
+
+        #[test]
+        fn wrong_arg_count_reports_arities() {
+            assert_eq!(
+                error_message::condition(
+                    ConditionKind::ArityException,
+                    "Wrong number of arguments given to function (Given: 0, Expected: 1)"
+                ),
+                eval_str("((fn [x] x))")
+            );
+            assert_eq!(
+                error_message::condition(
+                    ConditionKind::ArityException,
+                    "Wrong number of arguments given to function (Given: 0, Expected: [1, 2])"
+                ),
+                eval_str("((fn ([x] x) ([x y] y)))")
+            );
+            assert_eq!(
+                error_message::condition(
+                    ConditionKind::ArityException,
+                    "Wrong number of arguments given to function (Given: 0, Expected: [1, >=2])"
+                ),
+                eval_str("((fn ([x] x) ([x y & more] y)))")
+            );
+        }
+
+        #[test]
+        fn invalid_overloads_are_rejected() {
+            assert_eq!(
+                error_message::custom("Can't have 2 overloads with same arity"),
+                eval_str("(fn ([x] 1) ([y] 2))")
+            );
+            assert_eq!(
+                error_message::custom("Can't have more than 1 variadic overload"),
+                eval_str("(fn ([& xs] 1) ([x & xs] 2))")
+            );
+            assert_eq!(
+                error_message::custom(
+                    "Can't have fixed arity function with more params than variadic function"
+                ),
+                eval_str("(fn ([x y] 1) ([x & xs] 2))")
+            );
+        }
+    }
 }
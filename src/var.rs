@@ -1,5 +1,8 @@
 use crate::symbol::Symbol;
-use crate::persistent_list_map::PersistentListMap;
+use crate::condition::ConditionKind;
+use crate::error_message;
+use crate::keyword::Keyword;
+use crate::persistent_list_map::{IPersistentMap, PersistentListMap};
 use crate::value::{Value,ToValue};
 use crate::traits;
 use crate::ifn::IFn;
@@ -36,8 +39,19 @@ pub struct Var {
     // immutable, is there value in expressing this mixed mutability in someway
     // without just wrapping these in RefCells?
     meta: RefCell<protocols::IPersistentMap>,
-    pub root: RefCell<Rc<Value>>,
+    // Shared between clones, so that the Var we hand out for (var x) is the one in its
+    // namespace, rather than a copy
+    pub root: Rc<RefCell<Rc<Value>>>,
 }
+
+// One frame of a thread's dynamic bindings;  what each Var bound by some binding form is bound
+// to.  A binding is a RefCell, as set! can change it for the rest of that form
+type Frame = Vec<(Var, RefCell<Rc<Value>>)>;
+thread_local! {
+    // This thread's binding frames, innermost last
+    static BINDINGS: RefCell<Vec<Frame>> = const { RefCell::new(vec![]) };
+}
+
 macro_rules! var{
     ($ns:expr, $sym:expr) => {
         Var::intern(sym!($ns),sym!($sym))
@@ -54,12 +68,80 @@ impl Var {
             sym,
             meta: RefCell::new(empty_meta.as_protocol::<protocols::IPersistentMap>()),
             // What do if unbound? Why does unbound exist?
-            root: RefCell::new(Value::Nil.to_rc_value())
+            root: Rc::new(RefCell::new(Value::Nil.to_rc_value()))
         }
     }
 
+    /// This thread's binding of us, if it has one, and otherwise our root
     pub fn deref(&self) -> Rc<Value> {
-        self.root.borrow().clone() 
+        self.thread_binding().unwrap_or_else(|| self.root.borrow().clone())
+    }
+
+    pub fn thread_binding(&self) -> Option<Rc<Value>> {
+        BINDINGS.with(|frames| {
+            frames
+                .borrow()
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter())
+                .find(|(var, _)| var == self)
+                .map(|(_, val)| val.borrow().clone())
+        })
+    }
+
+    /// Whether we were def'd ^:dynamic, and so can be bound per thread
+    pub fn is_dynamic(&self) -> bool {
+        use crate::traits::IMeta;
+        self.meta()
+            .get(&Keyword::intern("dynamic").to_rc_value())
+            .is_truthy()
+    }
+
+    /// Binds each var to its value on this thread, until the matching pop_thread_bindings;
+    /// Err, binding nothing, if any var isn't dynamic
+    pub fn push_thread_bindings(bindings: Vec<(Var, Rc<Value>)>) -> Result<(), Rc<Value>> {
+        if let Some((var, _)) = bindings.iter().find(|(var, _)| !var.is_dynamic()) {
+            return Err(Rc::new(error_message::condition(
+                ConditionKind::IllegalStateException,
+                format!("Can't dynamically bind non-dynamic var: {}/{}", var.ns, var.sym),
+            )));
+        }
+        let frame = bindings
+            .into_iter()
+            .map(|(var, val)| (var, RefCell::new(val)))
+            .collect();
+        BINDINGS.with(|frames| frames.borrow_mut().push(frame));
+        Ok(())
+    }
+
+    pub fn pop_thread_bindings() {
+        BINDINGS.with(|frames| frames.borrow_mut().pop());
+    }
+
+    /// Sets this thread's binding of us to val, as set! does;  Err if we've no binding on
+    /// this thread, as a root can't be set this way
+    pub fn set(&self, val: Rc<Value>) -> Result<(), Rc<Value>> {
+        BINDINGS.with(|frames| {
+            let frames = frames.borrow();
+            let binding = frames
+                .iter()
+                .rev()
+                .flat_map(|frame| frame.iter())
+                .find(|(var, _)| var == self);
+            match binding {
+                Some((_, binding)) => {
+                    binding.replace(val);
+                    Ok(())
+                }
+                None => Err(Rc::new(error_message::condition(
+                    ConditionKind::IllegalStateException,
+                    format!(
+                        "Can't change/establish root binding of: {}/{} with set",
+                        self.ns, self.sym
+                    ),
+                ))),
+            }
+        })
     }
 
     pub fn bind_root(&self,root: Rc<Value>){
@@ -129,6 +211,7 @@ impl fmt::Display for Var {
 mod tests {
     use crate::var::Var;
     use crate::symbol::Symbol;
+    use crate::persistent_list_map::PersistentListMap;
     use std::rc::Rc;
     use crate::value::Value;
     
@@ -146,4 +229,21 @@ mod tests {
 
         assert!(*v.deref() == Value::I32(25));
     }
+
+    #[test]
+    fn thread_bindings() {
+        let v = var!("clojure.core","*x*");
+        v.bind_root(Rc::new(Value::I32(1)));
+        assert!(Var::push_thread_bindings(vec![(v.clone(), Rc::new(Value::I32(2)))]).is_err());
+
+        v.set_meta(persistent_list_map!{ "dynamic" => true });
+        Var::push_thread_bindings(vec![(v.clone(), Rc::new(Value::I32(2)))]).unwrap();
+        assert!(*v.deref() == Value::I32(2));
+        v.set(Rc::new(Value::I32(3))).unwrap();
+        assert!(*v.deref() == Value::I32(3));
+
+        Var::pop_thread_bindings();
+        assert!(*v.deref() == Value::I32(1));
+        assert!(v.set(Rc::new(Value::I32(4))).is_err());
+    }
 }